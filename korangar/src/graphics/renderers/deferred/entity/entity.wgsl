@@ -4,6 +4,7 @@ struct Matrices {
 }
 
 struct Constants {
+    color: vec4<f32>,
     world: mat4x4<f32>,
     texture_position: vec2<f32>,
     texture_size: vec2<f32>,
@@ -84,7 +85,7 @@ fn fs_main(
     let clamped_depth = clamp(non_linear_z, 0.0, 1.0);
 
     var output: FragmentOutput;
-    output.fragment_color = diffuse_color;
+    output.fragment_color = diffuse_color * constants.color;
     output.fragment_normal = vec4<f32>(normalize(normal), 1.0);
     output.frag_depth = clamped_depth;
     return output;
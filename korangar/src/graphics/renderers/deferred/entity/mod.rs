@@ -14,7 +14,7 @@ use wgpu::{
 use super::DeferredSubRenderer;
 use crate::graphics::cameras::NEAR_PLANE;
 use crate::graphics::renderers::sampler::{create_new_sampler, SamplerType};
-use crate::graphics::{Buffer, Camera, DeferredRenderer, Renderer, Texture};
+use crate::graphics::{Buffer, Camera, Color, DeferredRenderer, Renderer, Texture};
 
 const SHADER: ShaderModuleDescriptor = include_wgsl!("entity.wgsl");
 
@@ -28,6 +28,7 @@ pub struct Matrices {
 #[derive(Copy, Clone, Pod, Zeroable)]
 #[repr(C)]
 pub struct Constants {
+    color: [f32; 4],
     world: [[f32; 4]; 4],
     texture_position: [f32; 2],
     texture_size: [f32; 2],
@@ -240,6 +241,7 @@ impl EntityRenderer {
         cell_count: Vector2<usize>,
         cell_position: Vector2<usize>,
         mirror: bool,
+        color: Color,
     ) {
         if render_target.bound_sub_renderer(DeferredSubRenderer::Entity) {
             self.bind_pipeline(render_pass, camera);
@@ -266,6 +268,7 @@ impl EntityRenderer {
         });
 
         let push_constants = Constants {
+            color: color.components_linear(),
             world: world_matrix.into(),
             texture_position: texture_position.into(),
             texture_size: texture_size.into(),
@@ -527,6 +527,7 @@ impl EntityRendererTrait for DeferredRenderer {
         cell_count: Vector2<usize>,
         cell_position: Vector2<usize>,
         mirror: bool,
+        color: Color,
         _entity_id: EntityId,
     ) where
         Self: Renderer,
@@ -542,6 +543,7 @@ impl EntityRendererTrait for DeferredRenderer {
             cell_count,
             cell_position,
             mirror,
+            color,
         );
     }
 }
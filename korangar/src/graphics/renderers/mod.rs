@@ -137,6 +137,7 @@ pub trait EntityRenderer {
         cell_count: Vector2<usize>,
         cell_position: Vector2<usize>,
         mirror: bool,
+        color: Color,
         entity_id: EntityId,
     ) where
         Self: Renderer;
@@ -141,6 +141,7 @@ impl EntityRendererTrait for PickerRenderer {
         cell_count: Vector2<usize>,
         cell_position: Vector2<usize>,
         mirror: bool,
+        _color: Color,
         entity_id: EntityId,
     ) where
         Self: Renderer,
@@ -125,6 +125,7 @@ impl EntityRendererTrait for ShadowRenderer {
         cell_count: Vector2<usize>,
         cell_position: Vector2<usize>,
         mirror: bool,
+        _color: Color,
         _entity_id: EntityId,
     ) where
         Self: Renderer,
@@ -1,13 +1,16 @@
 use cgmath::Vector2;
 use korangar_interface::event::ClickAction;
 use korangar_interface::ElementEvent;
-use korangar_networking::ShopItem;
+use korangar_networking::{ShopItem, VendingItem};
 use ragnarok_packets::{
-    AccountId, BuyOrSellOption, CharacterId, CharacterServerInformation, EntityId, HotbarSlot, ShopId, SoldItemInformation, TilePosition,
+    AccountId, BuyOrSellOption, BuyingStoreItemInformation, CharacterId, CharacterServerInformation, ChatRoomId, EntityId, GuildId,
+    GuildRelationType, HotbarSlot, InventoryIndex, MailId, MailOpenType, PartyId, ShopId, SkillId, SoldItemInformation, StatType,
+    TilePosition, VendingShopItemInformation, VendorId,
 };
 
 use crate::interface::application::{InterfaceSettings, InternalThemeKind};
 use crate::interface::resource::Move;
+use crate::interface::windows::OutgoingMail;
 use crate::loaders::ServiceId;
 #[cfg(feature = "debug")]
 use crate::world::MarkerIdentifier;
@@ -20,6 +23,8 @@ pub enum UserEvent {
         username: String,
         password: String,
     },
+    /// Reload the login settings with a master password entered by the user.
+    EnterMasterPassword(String),
     SelectServer(CharacterServerInformation),
     LogOut,
     Exit,
@@ -32,6 +37,15 @@ pub enum UserEvent {
     OpenGraphicsSettingsWindow,
     OpenAudioSettingsWindow,
     OpenFriendsWindow,
+    OpenPartyWindow,
+    OpenGuildWindow,
+    OpenQuestLogWindow,
+    OpenMinimapWindow,
+    SendEmotion(u8),
+    OpenAchievementWindow,
+    OpenReputationWindow,
+    OpenChatRoomWindow,
+    OpenChatRoomSettingsWindow,
     ToggleShowInterface,
     SetThemeFile {
         theme_file: String,
@@ -52,8 +66,14 @@ pub enum UserEvent {
     SwitchCharacterSlot(usize),
     RequestPlayerMove(Vector2<usize>),
     RequestPlayerInteract(EntityId),
+    RequestTrade(EntityId),
     RequestWarpToMap(String, TilePosition),
     SendMessage(String),
+    SendWhisper {
+        recipient_name: String,
+        message: String,
+    },
+    CloseConversation(String),
     NextDialog(EntityId),
     CloseDialog(EntityId),
     ChooseDialogOption(EntityId, i8),
@@ -73,6 +93,55 @@ pub enum UserEvent {
         account_id: AccountId,
         character_id: CharacterId,
     },
+    CreateParty(String),
+    InviteToParty(String),
+    RejectPartyInvite(PartyId),
+    AcceptPartyInvite(PartyId),
+    LeaveParty,
+    KickPartyMember {
+        account_id: AccountId,
+        name: String,
+    },
+    ChangeGuildNotice {
+        subject: String,
+        notice: String,
+    },
+    RemoveGuildRelation {
+        guild_id: GuildId,
+        relation_type: GuildRelationType,
+    },
+    UseItem(InventoryIndex),
+    DropItem {
+        index: InventoryIndex,
+        amount: u16,
+    },
+    CloseStorage,
+    AcceptTradeRequest,
+    RejectTradeRequest,
+    AddTradeZeny(u32),
+    LockTrade,
+    CommitTrade,
+    CancelTrade,
+    /// An empty password creates a public chat room.
+    CreateChatRoom {
+        title: String,
+        limit: u16,
+        password: String,
+    },
+    ChangeChatRoom {
+        title: String,
+        limit: u16,
+        password: String,
+    },
+    EnterChatRoom {
+        chat_room_id: ChatRoomId,
+        password: String,
+    },
+    LeaveChatRoom,
+    KickFromChatRoom(String),
+    TransferChatRoomOwnership(String),
+    RaiseStat(StatType),
+    RaiseSkill(SkillId),
     BuyItems {
         items: Vec<ShopItem<u32>>,
     },
@@ -84,6 +153,51 @@ pub enum UserEvent {
     SellItems {
         items: Vec<SoldItemInformation>,
     },
+    OpenVendingShop {
+        shop_name: String,
+        items: Vec<VendingShopItemInformation>,
+    },
+    OpenBuyingStore {
+        shop_name: String,
+        zeny_limit: u32,
+        items: Vec<BuyingStoreItemInformation>,
+    },
+    CloseStoreSetup,
+    CloseVendingShop,
+    CloseBuyingStore,
+    BuyVendingItems {
+        entity_id: EntityId,
+        vendor_id: VendorId,
+        items: Vec<VendingItem<u16>>,
+    },
+    CloseVendingItemList,
+    OpenMailbox,
+    CloseMailbox,
+    /// Request the mail that is older than this one.
+    RequestNextMailPage(MailId),
+    ReadMail {
+        open_type: MailOpenType,
+        mail_id: MailId,
+    },
+    DeleteMail {
+        open_type: MailOpenType,
+        mail_id: MailId,
+    },
+    RetrieveMailZeny {
+        open_type: MailOpenType,
+        mail_id: MailId,
+    },
+    RetrieveMailItems {
+        open_type: MailOpenType,
+        mail_id: MailId,
+    },
+    OpenWriteMailWindow,
+    CancelWritingMail,
+    SendMail(OutgoingMail),
+    DetachMailItem {
+        index: InventoryIndex,
+        amount: u16,
+    },
     FocusChatWindow,
     #[cfg(feature = "debug")]
     OpenMarkerDetails(MarkerIdentifier),
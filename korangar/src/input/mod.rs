@@ -13,6 +13,7 @@ use korangar_interface::event::ClickAction;
 #[cfg(feature = "debug")]
 use korangar_interface::state::{PlainTrackedState, TrackedState};
 use korangar_interface::Interface;
+use korangar_networking::InventoryItemDetails;
 use ragnarok_packets::{ClientTick, HotbarSlot};
 use winit::dpi::PhysicalPosition;
 use winit::event::{ElementState, MouseButton, MouseScrollDelta};
@@ -27,7 +28,7 @@ use crate::graphics::{PickerRenderTarget, PickerTarget};
 use crate::interface::application::InterfaceSettings;
 use crate::interface::cursor::{MouseCursor, MouseCursorState};
 use crate::interface::layout::{ScreenPosition, ScreenSize};
-use crate::interface::resource::PartialMove;
+use crate::interface::resource::{ItemSource, PartialMove};
 
 const MOUSE_SCOLL_MULTIPLIER: f32 = 30.0;
 const KEY_COUNT: usize = variant_count::<KeyCode>();
@@ -233,6 +234,7 @@ impl InputSystem {
                         ClickAction::Move(drop_resource) => {
                             let input_mode = match drop_resource {
                                 PartialMove::Item { source, item } => MouseInputMode::MoveItem(source, item),
+                                PartialMove::StorageItem { item } => MouseInputMode::MoveStorageItem(item),
                                 PartialMove::Skill { source, skill } => MouseInputMode::MoveSkill(source, skill),
                             };
                             self.mouse_input_mode = input_mode;
@@ -280,6 +282,21 @@ impl InputSystem {
                             if let Some(resource_move) = hovered_element.borrow_mut().drop_resource(PartialMove::Item { source, item }) {
                                 events.push(UserEvent::MoveResource(resource_move));
                             }
+                        } else if window_index.is_none() && source == ItemSource::Inventory {
+                            // Items released over the world are dropped on the ground.
+                            let amount = match &item.details {
+                                InventoryItemDetails::Regular { amount, .. } => *amount,
+                                InventoryItemDetails::Equippable { .. } => 1,
+                            };
+
+                            events.push(UserEvent::DropItem { index: item.index, amount });
+                        }
+                    }
+                    MouseInputMode::MoveStorageItem(item) => {
+                        if let Some(hovered_element) = &hovered_element {
+                            if let Some(resource_move) = hovered_element.borrow_mut().drop_resource(PartialMove::StorageItem { item }) {
+                                events.push(UserEvent::MoveResource(resource_move));
+                            }
                         }
                     }
                     MouseInputMode::MoveSkill(source, skill) => {
@@ -338,7 +355,10 @@ impl InputSystem {
             }
             MouseInputMode::ClickInterface => mouse_cursor.set_state(MouseCursorState::Click, client_tick),
             MouseInputMode::None => {}
-            MouseInputMode::MoveItem(..) | MouseInputMode::MoveSkill(..) | MouseInputMode::Walk(..) => {}
+            MouseInputMode::MoveItem(..)
+            | MouseInputMode::MoveStorageItem(..)
+            | MouseInputMode::MoveSkill(..)
+            | MouseInputMode::Walk(..) => {}
         }
 
         if self.scroll_delta != 0.0 {
@@ -470,6 +490,36 @@ impl InputSystem {
                 events.push(UserEvent::OpenInventoryWindow);
             }
 
+            if alt_down && self.get_key(KeyCode::KeyQ).pressed() {
+                events.push(UserEvent::OpenQuestLogWindow);
+            }
+
+            if alt_down && self.get_key(KeyCode::KeyM).pressed() {
+                events.push(UserEvent::OpenMinimapWindow);
+            }
+
+            // Alt + 1 through Alt + 0 send the first ten emotions.
+            if alt_down {
+                let emotion_keys = [
+                    KeyCode::Digit1,
+                    KeyCode::Digit2,
+                    KeyCode::Digit3,
+                    KeyCode::Digit4,
+                    KeyCode::Digit5,
+                    KeyCode::Digit6,
+                    KeyCode::Digit7,
+                    KeyCode::Digit8,
+                    KeyCode::Digit9,
+                    KeyCode::Digit0,
+                ];
+
+                for (emotion, key) in emotion_keys.into_iter().enumerate() {
+                    if self.get_key(key).pressed() {
+                        events.push(UserEvent::SendEmotion(emotion as u8));
+                    }
+                }
+            }
+
             if control_down && self.get_key(KeyCode::KeyH).pressed() {
                 events.push(UserEvent::ToggleShowInterface);
             }
@@ -583,6 +633,10 @@ impl InputSystem {
 
                 if self.left_mouse_button.pressed() {
                     match picker_target {
+                        // Holding control while clicking another player asks them to trade.
+                        PickerTarget::Entity(entity_id) if self.get_key(KeyCode::ControlLeft).down() => {
+                            events.push(UserEvent::RequestTrade(entity_id))
+                        }
                         PickerTarget::Entity(entity_id) => events.push(UserEvent::RequestPlayerInteract(entity_id)),
                         PickerTarget::Tile { x, y } => {
                             let position = Vector2::new(x as usize, y as usize);
@@ -4,6 +4,7 @@ use cgmath::Vector2;
 use korangar_interface::application::MouseInputModeTrait;
 use korangar_interface::elements::{Element, ElementCell};
 use korangar_networking::InventoryItem;
+use ragnarok_packets::StorageIndex;
 
 use crate::graphics::Texture;
 use crate::interface::application::InterfaceSettings;
@@ -14,6 +15,7 @@ use crate::loaders::{Actions, AnimationState, ResourceMetadata, Sprite};
 #[derive(Default)]
 pub enum MouseInputMode {
     MoveItem(ItemSource, InventoryItem<ResourceMetadata>),
+    MoveStorageItem(InventoryItem<ResourceMetadata, StorageIndex>),
     MoveSkill(SkillSource, Skill),
     MoveInterface(usize),
     ResizeInterface(usize),
@@ -42,6 +44,7 @@ impl MouseInputMode {
     pub fn grabbed(&self) -> Option<Grabbed> {
         match self {
             MouseInputMode::MoveItem(_, item) => Some(Grabbed::Texture(item.metadata.texture.clone())),
+            MouseInputMode::MoveStorageItem(item) => Some(Grabbed::Texture(item.metadata.texture.clone())),
             MouseInputMode::MoveSkill(_, skill) => Some(Grabbed::Action(
                 skill.sprite.clone(),
                 skill.actions.clone(),
@@ -0,0 +1,64 @@
+use std::sync::Arc;
+
+use korangar_interface::application::ClipTraitExt;
+use korangar_networking::CutInLocation;
+use wgpu::RenderPass;
+
+use super::application::InterfaceSettings;
+use super::layout::{ScreenClip, ScreenPosition, ScreenSize};
+use crate::graphics::{Color, DeferredRenderer, Renderer, SpriteRenderer, Texture};
+
+/// Illustration shown by an NPC, usually a portrait of the NPC itself. For
+/// [`CutInLocation::MiddleColorless`] the texture is expected to be grayscale
+/// already.
+pub struct CutIn {
+    texture: Arc<Texture>,
+    location: CutInLocation,
+}
+
+impl CutIn {
+    pub fn new(texture: Arc<Texture>, location: CutInLocation) -> Self {
+        Self { texture, location }
+    }
+
+    #[cfg_attr(feature = "debug", korangar_debug::profile("render cut-in"))]
+    pub fn render(
+        &self,
+        render_target: &mut <DeferredRenderer as Renderer>::Target,
+        render_pass: &mut RenderPass,
+        renderer: &DeferredRenderer,
+        application: &InterfaceSettings,
+        window_size: ScreenSize,
+    ) {
+        let extent = self.texture.get_extend();
+        let size = ScreenSize {
+            width: extent.width as f32 * application.get_scaling_factor(),
+            height: extent.height as f32 * application.get_scaling_factor(),
+        };
+
+        let bottom = window_size.height - size.height;
+        let middle = (window_size.height - size.height) / 2.0;
+        let center = (window_size.width - size.width) / 2.0;
+
+        let position = match self.location {
+            CutInLocation::BottomLeft => ScreenPosition { left: 0.0, top: bottom },
+            CutInLocation::BottomMiddle => ScreenPosition { left: center, top: bottom },
+            CutInLocation::BottomRight => ScreenPosition {
+                left: window_size.width - size.width,
+                top: bottom,
+            },
+            CutInLocation::MiddleFloating | CutInLocation::MiddleColorless => ScreenPosition { left: center, top: middle },
+        };
+
+        renderer.render_sprite(
+            render_target,
+            render_pass,
+            &self.texture,
+            position,
+            size,
+            ScreenClip::unbound(),
+            Color::monochrome_u8(255),
+            true,
+        );
+    }
+}
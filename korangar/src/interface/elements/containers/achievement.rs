@@ -0,0 +1,331 @@
+use chrono::DateTime;
+use korangar_interface::elements::{ContainerState, Element, ElementCell, ElementState, ElementWrap, Focus, Text, WeakElementCell};
+use korangar_interface::event::{ChangeEvent, HoverInformation};
+use korangar_interface::layout::PlacementResolver;
+use korangar_interface::size_bound;
+use korangar_interface::state::{PlainRemote, Remote};
+use ragnarok_packets::ReputationEntry;
+use wgpu::RenderPass;
+
+use crate::graphics::{InterfaceRenderer, Renderer};
+use crate::input::MouseInputMode;
+use crate::interface::application::InterfaceSettings;
+use crate::interface::layout::{ScreenClip, ScreenPosition, ScreenSize};
+use crate::interface::theme::InterfaceTheme;
+use crate::interface::windows::{AchievementEntry, Achievements};
+
+/// Lists the achievements of the player and the progress on their objectives.
+pub struct AchievementView {
+    achievements: PlainRemote<Achievements>,
+    state: ContainerState<InterfaceSettings>,
+}
+
+impl AchievementView {
+    pub fn new(achievements: PlainRemote<Achievements>) -> Self {
+        let elements = {
+            let achievements = achievements.get();
+            let mut elements = Vec::new();
+
+            if let Some(summary) = &achievements.summary {
+                elements.push(
+                    Text::default()
+                        .with_text(format!(
+                            "Level {} ({}/{} exp), score {}",
+                            summary.level, summary.experience, summary.experience_to_next_level, summary.total_score
+                        ))
+                        .wrap(),
+                );
+            }
+
+            elements.extend(achievements.achievements.iter().flat_map(Self::achievement_to_elements));
+
+            if elements.is_empty() {
+                elements.push(Text::default().with_text("No achievements".to_owned()).wrap());
+            }
+
+            elements
+        };
+
+        Self {
+            achievements,
+            state: ContainerState::new(elements),
+        }
+    }
+
+    fn achievement_to_elements(achievement: &AchievementEntry) -> Vec<ElementCell<InterfaceSettings>> {
+        let status = match achievement.progress.is_completed {
+            true => {
+                let date = DateTime::from_timestamp(achievement.progress.completion_timestamp as i64, 0)
+                    .map(|date| format!(" {}", date.format("%Y-%m-%d")))
+                    .unwrap_or_default();
+
+                format!(" ^55ff55(completed{date})^000000")
+            }
+            false => String::new(),
+        };
+
+        let mut elements = vec![
+            Text::default()
+                .with_text(format!("^ffaa00{}^000000{status}", achievement.text.title))
+                .wrap(),
+        ];
+
+        if !achievement.text.summary.is_empty() {
+            elements.push(Text::default().with_text(achievement.text.summary.clone()).wrap());
+        }
+
+        // The objectives are only known if the achievement table of the client has an
+        // entry for this achievement.
+        elements.extend(
+            achievement
+                .text
+                .objectives
+                .iter()
+                .zip(achievement.progress.objectives)
+                .map(|(objective, progress)| {
+                    Text::default()
+                        .with_text(format!("{}: {}/{}", objective.description, progress, objective.count))
+                        .wrap()
+                }),
+        );
+
+        elements
+    }
+}
+
+impl Element<InterfaceSettings> for AchievementView {
+    fn get_state(&self) -> &ElementState<InterfaceSettings> {
+        &self.state.state
+    }
+
+    fn get_state_mut(&mut self) -> &mut ElementState<InterfaceSettings> {
+        &mut self.state.state
+    }
+
+    fn link_back(&mut self, weak_self: WeakElementCell<InterfaceSettings>, weak_parent: Option<WeakElementCell<InterfaceSettings>>) {
+        self.state.link_back(weak_self, weak_parent);
+    }
+
+    fn is_focusable(&self) -> bool {
+        self.state.is_focusable::<false>()
+    }
+
+    fn focus_next(
+        &self,
+        self_cell: ElementCell<InterfaceSettings>,
+        caller_cell: Option<ElementCell<InterfaceSettings>>,
+        focus: Focus,
+    ) -> Option<ElementCell<InterfaceSettings>> {
+        self.state.focus_next::<false>(self_cell, caller_cell, focus)
+    }
+
+    fn restore_focus(&self, self_cell: ElementCell<InterfaceSettings>) -> Option<ElementCell<InterfaceSettings>> {
+        self.state.restore_focus(self_cell)
+    }
+
+    fn resolve(
+        &mut self,
+        placement_resolver: &mut PlacementResolver<InterfaceSettings>,
+        application: &InterfaceSettings,
+        theme: &InterfaceTheme,
+    ) {
+        self.state.resolve(
+            placement_resolver,
+            application,
+            theme,
+            &size_bound!(100%, ?),
+            ScreenSize::default(),
+        );
+    }
+
+    fn update(&mut self) -> Option<ChangeEvent> {
+        if self.achievements.consume_changed() {
+            let weak_parent = self.state.state.parent_element.take();
+            let weak_self = self.state.state.self_element.take().unwrap();
+
+            *self = Self::new(self.achievements.clone());
+            // important: link back after creating elements, otherwise focus navigation and
+            // scrolling would break
+            self.link_back(weak_self, weak_parent);
+
+            return Some(ChangeEvent::RESOLVE_WINDOW);
+        }
+
+        None
+    }
+
+    fn hovered_element(&self, mouse_position: ScreenPosition, mouse_mode: &MouseInputMode) -> HoverInformation<InterfaceSettings> {
+        match mouse_mode {
+            MouseInputMode::None => self.state.hovered_element(mouse_position, mouse_mode, false),
+            _ => HoverInformation::Missed,
+        }
+    }
+
+    fn render(
+        &self,
+        render_target: &mut <InterfaceRenderer as Renderer>::Target,
+        render_pass: &mut RenderPass,
+        renderer: &InterfaceRenderer,
+        application: &InterfaceSettings,
+        theme: &InterfaceTheme,
+        parent_position: ScreenPosition,
+        screen_clip: ScreenClip,
+        hovered_element: Option<&dyn Element<InterfaceSettings>>,
+        focused_element: Option<&dyn Element<InterfaceSettings>>,
+        mouse_mode: &MouseInputMode,
+        second_theme: bool,
+    ) {
+        let mut renderer =
+            self.state
+                .state
+                .element_renderer(render_target, render_pass, renderer, application, parent_position, screen_clip);
+
+        self.state.render(
+            &mut renderer,
+            application,
+            theme,
+            hovered_element,
+            focused_element,
+            mouse_mode,
+            second_theme,
+        );
+    }
+}
+
+/// Lists the reputation of the player with every faction.
+pub struct ReputationView {
+    reputation: PlainRemote<Vec<ReputationEntry>>,
+    state: ContainerState<InterfaceSettings>,
+}
+
+impl ReputationView {
+    pub fn new(reputation: PlainRemote<Vec<ReputationEntry>>) -> Self {
+        let elements = {
+            let reputation = reputation.get();
+            let mut elements: Vec<_> = reputation
+                .iter()
+                .map(|entry| {
+                    let color = match entry.points {
+                        points if points < 0 => "ff5555",
+                        0 => "000000",
+                        _ => "55ff55",
+                    };
+
+                    Text::default()
+                        .with_text(format!("Faction {}: ^{color}{}^000000", entry.reputation_type, entry.points))
+                        .wrap()
+                })
+                .collect();
+
+            if elements.is_empty() {
+                elements.push(Text::default().with_text("No reputation".to_owned()).wrap());
+            }
+
+            elements
+        };
+
+        Self {
+            reputation,
+            state: ContainerState::new(elements),
+        }
+    }
+}
+
+impl Element<InterfaceSettings> for ReputationView {
+    fn get_state(&self) -> &ElementState<InterfaceSettings> {
+        &self.state.state
+    }
+
+    fn get_state_mut(&mut self) -> &mut ElementState<InterfaceSettings> {
+        &mut self.state.state
+    }
+
+    fn link_back(&mut self, weak_self: WeakElementCell<InterfaceSettings>, weak_parent: Option<WeakElementCell<InterfaceSettings>>) {
+        self.state.link_back(weak_self, weak_parent);
+    }
+
+    fn is_focusable(&self) -> bool {
+        self.state.is_focusable::<false>()
+    }
+
+    fn focus_next(
+        &self,
+        self_cell: ElementCell<InterfaceSettings>,
+        caller_cell: Option<ElementCell<InterfaceSettings>>,
+        focus: Focus,
+    ) -> Option<ElementCell<InterfaceSettings>> {
+        self.state.focus_next::<false>(self_cell, caller_cell, focus)
+    }
+
+    fn restore_focus(&self, self_cell: ElementCell<InterfaceSettings>) -> Option<ElementCell<InterfaceSettings>> {
+        self.state.restore_focus(self_cell)
+    }
+
+    fn resolve(
+        &mut self,
+        placement_resolver: &mut PlacementResolver<InterfaceSettings>,
+        application: &InterfaceSettings,
+        theme: &InterfaceTheme,
+    ) {
+        self.state.resolve(
+            placement_resolver,
+            application,
+            theme,
+            &size_bound!(100%, ?),
+            ScreenSize::default(),
+        );
+    }
+
+    fn update(&mut self) -> Option<ChangeEvent> {
+        if self.reputation.consume_changed() {
+            let weak_parent = self.state.state.parent_element.take();
+            let weak_self = self.state.state.self_element.take().unwrap();
+
+            *self = Self::new(self.reputation.clone());
+            // important: link back after creating elements, otherwise focus navigation and
+            // scrolling would break
+            self.link_back(weak_self, weak_parent);
+
+            return Some(ChangeEvent::RESOLVE_WINDOW);
+        }
+
+        None
+    }
+
+    fn hovered_element(&self, mouse_position: ScreenPosition, mouse_mode: &MouseInputMode) -> HoverInformation<InterfaceSettings> {
+        match mouse_mode {
+            MouseInputMode::None => self.state.hovered_element(mouse_position, mouse_mode, false),
+            _ => HoverInformation::Missed,
+        }
+    }
+
+    fn render(
+        &self,
+        render_target: &mut <InterfaceRenderer as Renderer>::Target,
+        render_pass: &mut RenderPass,
+        renderer: &InterfaceRenderer,
+        application: &InterfaceSettings,
+        theme: &InterfaceTheme,
+        parent_position: ScreenPosition,
+        screen_clip: ScreenClip,
+        hovered_element: Option<&dyn Element<InterfaceSettings>>,
+        focused_element: Option<&dyn Element<InterfaceSettings>>,
+        mouse_mode: &MouseInputMode,
+        second_theme: bool,
+    ) {
+        let mut renderer =
+            self.state
+                .state
+                .element_renderer(render_target, render_pass, renderer, application, parent_position, screen_clip);
+
+        self.state.render(
+            &mut renderer,
+            application,
+            theme,
+            hovered_element,
+            focused_element,
+            mouse_mode,
+            second_theme,
+        );
+    }
+}
@@ -0,0 +1,185 @@
+use korangar_interface::elements::{
+    ButtonBuilder, ContainerState, Element, ElementCell, ElementState, ElementWrap, Focus, Text, WeakElementCell,
+};
+use korangar_interface::event::{ChangeEvent, HoverInformation};
+use korangar_interface::layout::PlacementResolver;
+use korangar_interface::state::{PlainRemote, Remote};
+use korangar_interface::{dimension_bound, size_bound};
+use ragnarok_packets::{ChatRoomMember, ChatRoomRole};
+use wgpu::RenderPass;
+
+use crate::graphics::{InterfaceRenderer, Renderer};
+use crate::input::{MouseInputMode, UserEvent};
+use crate::interface::application::InterfaceSettings;
+use crate::interface::layout::{ScreenClip, ScreenPosition, ScreenSize};
+use crate::interface::theme::InterfaceTheme;
+use crate::interface::windows::ChatRoom;
+
+pub struct ChatRoomView {
+    chat_room: PlainRemote<Option<ChatRoom>>,
+    player_name: String,
+    state: ContainerState<InterfaceSettings>,
+}
+
+impl ChatRoomView {
+    pub fn new(chat_room: PlainRemote<Option<ChatRoom>>, player_name: String) -> Self {
+        let elements = match chat_room.get().as_ref() {
+            Some(chat_room) => {
+                let is_owner = chat_room.is_owner(&player_name);
+                let header = Text::default()
+                    .with_text(format!(
+                        "^ffaa00{}^000000 ({}/{})",
+                        chat_room.title,
+                        chat_room.members.len(),
+                        chat_room.limit
+                    ))
+                    .wrap();
+
+                std::iter::once(header)
+                    .chain(
+                        chat_room
+                            .members
+                            .iter()
+                            .flat_map(|member| Self::member_to_elements(member, is_owner && member.name != player_name)),
+                    )
+                    .collect()
+            }
+            None => vec![Text::default().with_text("Not in a chat room").wrap()],
+        };
+
+        Self {
+            chat_room,
+            player_name,
+            state: ContainerState::new(elements),
+        }
+    }
+
+    fn member_to_elements(member: &ChatRoomMember, can_manage: bool) -> Vec<ElementCell<InterfaceSettings>> {
+        let role = match member.role {
+            ChatRoomRole::Owner => " (owner)",
+            ChatRoomRole::Member => "",
+        };
+
+        let mut elements = vec![Text::default().with_text(format!("{}{role}", member.name)).wrap()];
+
+        if can_manage {
+            elements.push(
+                ButtonBuilder::new()
+                    .with_text("make owner")
+                    .with_event(UserEvent::TransferChatRoomOwnership(member.name.clone()))
+                    .with_width_bound(dimension_bound!(50%))
+                    .build()
+                    .wrap(),
+            );
+            elements.push(
+                ButtonBuilder::new()
+                    .with_text("kick")
+                    .with_event(UserEvent::KickFromChatRoom(member.name.clone()))
+                    .with_width_bound(dimension_bound!(!))
+                    .build()
+                    .wrap(),
+            );
+        }
+
+        elements
+    }
+}
+
+impl Element<InterfaceSettings> for ChatRoomView {
+    fn get_state(&self) -> &ElementState<InterfaceSettings> {
+        &self.state.state
+    }
+
+    fn get_state_mut(&mut self) -> &mut ElementState<InterfaceSettings> {
+        &mut self.state.state
+    }
+
+    fn link_back(&mut self, weak_self: WeakElementCell<InterfaceSettings>, weak_parent: Option<WeakElementCell<InterfaceSettings>>) {
+        self.state.link_back(weak_self, weak_parent);
+    }
+
+    fn is_focusable(&self) -> bool {
+        self.state.is_focusable::<false>()
+    }
+
+    fn focus_next(
+        &self,
+        self_cell: ElementCell<InterfaceSettings>,
+        caller_cell: Option<ElementCell<InterfaceSettings>>,
+        focus: Focus,
+    ) -> Option<ElementCell<InterfaceSettings>> {
+        self.state.focus_next::<false>(self_cell, caller_cell, focus)
+    }
+
+    fn restore_focus(&self, self_cell: ElementCell<InterfaceSettings>) -> Option<ElementCell<InterfaceSettings>> {
+        self.state.restore_focus(self_cell)
+    }
+
+    fn resolve(
+        &mut self,
+        placement_resolver: &mut PlacementResolver<InterfaceSettings>,
+        application: &InterfaceSettings,
+        theme: &InterfaceTheme,
+    ) {
+        self.state.resolve(
+            placement_resolver,
+            application,
+            theme,
+            &size_bound!(100%, ?),
+            ScreenSize::default(),
+        );
+    }
+
+    fn update(&mut self) -> Option<ChangeEvent> {
+        if self.chat_room.consume_changed() {
+            let weak_parent = self.state.state.parent_element.take();
+            let weak_self = self.state.state.self_element.take().unwrap();
+
+            *self = Self::new(self.chat_room.clone(), self.player_name.clone());
+            // important: link back after creating elements, otherwise focus navigation and
+            // scrolling would break
+            self.link_back(weak_self, weak_parent);
+
+            return Some(ChangeEvent::RESOLVE_WINDOW);
+        }
+
+        None
+    }
+
+    fn hovered_element(&self, mouse_position: ScreenPosition, mouse_mode: &MouseInputMode) -> HoverInformation<InterfaceSettings> {
+        match mouse_mode {
+            MouseInputMode::None => self.state.hovered_element(mouse_position, mouse_mode, false),
+            _ => HoverInformation::Missed,
+        }
+    }
+
+    fn render(
+        &self,
+        render_target: &mut <InterfaceRenderer as Renderer>::Target,
+        render_pass: &mut RenderPass,
+        renderer: &InterfaceRenderer,
+        application: &InterfaceSettings,
+        theme: &InterfaceTheme,
+        parent_position: ScreenPosition,
+        screen_clip: ScreenClip,
+        hovered_element: Option<&dyn Element<InterfaceSettings>>,
+        focused_element: Option<&dyn Element<InterfaceSettings>>,
+        mouse_mode: &MouseInputMode,
+        second_theme: bool,
+    ) {
+        let mut renderer =
+            self.state
+                .state
+                .element_renderer(render_target, render_pass, renderer, application, parent_position, screen_clip);
+
+        self.state.render(
+            &mut renderer,
+            application,
+            theme,
+            hovered_element,
+            focused_element,
+            mouse_mode,
+            second_theme,
+        );
+    }
+}
@@ -0,0 +1,235 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use korangar_interface::elements::{
+    ButtonBuilder, ContainerState, Element, ElementCell, ElementState, ElementWrap, Focus, ScrollView, WeakElementCell,
+};
+use korangar_interface::event::{ChangeEvent, ClickAction, HoverInformation};
+use korangar_interface::layout::PlacementResolver;
+use korangar_interface::state::{PlainRemote, PlainTrackedState, Remote, TrackedState, TrackedStateTake};
+use korangar_interface::{dimension_bound, size_bound};
+use wgpu::RenderPass;
+
+use crate::graphics::{InterfaceRenderer, Renderer};
+use crate::input::{MouseInputMode, UserEvent};
+use crate::interface::application::InterfaceSettings;
+use crate::interface::elements::ChatBuilder;
+use crate::interface::layout::{ScreenClip, ScreenPosition, ScreenSize};
+use crate::interface::theme::InterfaceTheme;
+use crate::interface::windows::{ChatMessage, Conversation};
+use crate::loaders::FontLoader;
+
+/// Tabs for the general chat and every whisper conversation, followed by the
+/// messages of the selected tab.
+pub struct ConversationView {
+    messages: PlainRemote<Vec<ChatMessage>>,
+    conversations: PlainRemote<Vec<Conversation>>,
+    selected_conversation: PlainTrackedState<Option<String>>,
+    selected_remote: PlainRemote<Option<String>>,
+    font_loader: Rc<RefCell<FontLoader>>,
+    state: ContainerState<InterfaceSettings>,
+}
+
+impl ConversationView {
+    pub fn new(
+        messages: PlainRemote<Vec<ChatMessage>>,
+        conversations: PlainRemote<Vec<Conversation>>,
+        selected_conversation: PlainTrackedState<Option<String>>,
+        font_loader: Rc<RefCell<FontLoader>>,
+    ) -> Self {
+        let selected_remote = selected_conversation.new_remote();
+
+        let elements = {
+            let selected = selected_conversation.get().clone();
+            let conversations = conversations.get();
+
+            let mut elements = vec![Self::tab_button("All".to_owned(), None, &selected_conversation)];
+            let mut selected_messages = messages.clone();
+
+            for conversation in conversations.iter() {
+                let partner_name = &conversation.partner_name;
+
+                if selected.as_ref() == Some(partner_name) {
+                    selected_messages = conversation.messages.new_remote();
+                }
+
+                elements.push(Self::tab_button(
+                    partner_name.clone(),
+                    Some(partner_name.clone()),
+                    &selected_conversation,
+                ));
+            }
+
+            elements.push(
+                ButtonBuilder::new()
+                    .with_text("Close")
+                    .with_disabled_selector(move || selected.is_none())
+                    .with_event({
+                        let mut selected_conversation = selected_conversation.clone();
+
+                        Box::new(move || match selected_conversation.take() {
+                            Some(partner_name) => vec![ClickAction::Custom(UserEvent::CloseConversation(partner_name))],
+                            None => Vec::new(),
+                        })
+                    })
+                    .with_width_bound(dimension_bound!(!))
+                    .build()
+                    .wrap(),
+            );
+
+            elements.push(
+                ScrollView::new(
+                    vec![
+                        ChatBuilder::new()
+                            .with_messages(selected_messages)
+                            .with_font_loader(font_loader.clone())
+                            .build()
+                            .wrap(),
+                    ],
+                    size_bound!(100%, !),
+                )
+                .wrap(),
+            );
+
+            elements
+        };
+
+        Self {
+            messages,
+            conversations,
+            selected_conversation,
+            selected_remote,
+            font_loader,
+            state: ContainerState::new(elements),
+        }
+    }
+
+    fn tab_button(
+        text: String,
+        partner_name: Option<String>,
+        selected_conversation: &PlainTrackedState<Option<String>>,
+    ) -> ElementCell<InterfaceSettings> {
+        // The currently selected tab is disabled, which also highlights it.
+        let disabled = *selected_conversation.get() == partner_name;
+        let mut selected_conversation = selected_conversation.clone();
+
+        ButtonBuilder::new()
+            .with_text(text)
+            .with_disabled_selector(move || disabled)
+            .with_event(Box::new(move || {
+                selected_conversation.set(partner_name.clone());
+                Vec::new()
+            }))
+            .with_width_bound(dimension_bound!(20%))
+            .build()
+            .wrap()
+    }
+}
+
+impl Element<InterfaceSettings> for ConversationView {
+    fn get_state(&self) -> &ElementState<InterfaceSettings> {
+        &self.state.state
+    }
+
+    fn get_state_mut(&mut self) -> &mut ElementState<InterfaceSettings> {
+        &mut self.state.state
+    }
+
+    fn link_back(&mut self, weak_self: WeakElementCell<InterfaceSettings>, weak_parent: Option<WeakElementCell<InterfaceSettings>>) {
+        self.state.link_back(weak_self, weak_parent);
+    }
+
+    fn is_focusable(&self) -> bool {
+        self.state.is_focusable::<false>()
+    }
+
+    fn focus_next(
+        &self,
+        self_cell: ElementCell<InterfaceSettings>,
+        caller_cell: Option<ElementCell<InterfaceSettings>>,
+        focus: Focus,
+    ) -> Option<ElementCell<InterfaceSettings>> {
+        self.state.focus_next::<false>(self_cell, caller_cell, focus)
+    }
+
+    fn restore_focus(&self, self_cell: ElementCell<InterfaceSettings>) -> Option<ElementCell<InterfaceSettings>> {
+        self.state.restore_focus(self_cell)
+    }
+
+    fn resolve(
+        &mut self,
+        placement_resolver: &mut PlacementResolver<InterfaceSettings>,
+        application: &InterfaceSettings,
+        theme: &InterfaceTheme,
+    ) {
+        self.state.resolve(
+            placement_resolver,
+            application,
+            theme,
+            &size_bound!(100%, !),
+            ScreenSize::default(),
+        );
+    }
+
+    fn update(&mut self) -> Option<ChangeEvent> {
+        // Both remotes need to be consumed, so we can't short circuit here.
+        let conversations_changed = self.conversations.consume_changed();
+        let selected_changed = self.selected_remote.consume_changed();
+
+        if conversations_changed || selected_changed {
+            let weak_parent = self.state.state.parent_element.take();
+            let weak_self = self.state.state.self_element.take().unwrap();
+
+            *self = Self::new(
+                self.messages.clone(),
+                self.conversations.clone(),
+                self.selected_conversation.clone(),
+                self.font_loader.clone(),
+            );
+            // important: link back after creating elements, otherwise focus navigation and
+            // scrolling would break
+            self.link_back(weak_self, weak_parent);
+
+            return Some(ChangeEvent::RESOLVE_WINDOW);
+        }
+
+        None
+    }
+
+    fn hovered_element(&self, mouse_position: ScreenPosition, mouse_mode: &MouseInputMode) -> HoverInformation<InterfaceSettings> {
+        match mouse_mode {
+            MouseInputMode::None => self.state.hovered_element(mouse_position, mouse_mode, false),
+            _ => HoverInformation::Missed,
+        }
+    }
+
+    fn render(
+        &self,
+        render_target: &mut <InterfaceRenderer as Renderer>::Target,
+        render_pass: &mut RenderPass,
+        renderer: &InterfaceRenderer,
+        application: &InterfaceSettings,
+        theme: &InterfaceTheme,
+        parent_position: ScreenPosition,
+        screen_clip: ScreenClip,
+        hovered_element: Option<&dyn Element<InterfaceSettings>>,
+        focused_element: Option<&dyn Element<InterfaceSettings>>,
+        mouse_mode: &MouseInputMode,
+        second_theme: bool,
+    ) {
+        let mut renderer =
+            self.state
+                .state
+                .element_renderer(render_target, render_pass, renderer, application, parent_position, screen_clip);
+
+        self.state.render(
+            &mut renderer,
+            application,
+            theme,
+            hovered_element,
+            focused_element,
+            mouse_mode,
+            second_theme,
+        );
+    }
+}
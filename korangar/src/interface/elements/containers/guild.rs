@@ -0,0 +1,214 @@
+use korangar_interface::elements::{
+    ButtonBuilder, ContainerState, Element, ElementCell, ElementState, ElementWrap, Expandable, Focus, Text, WeakElementCell,
+};
+use korangar_interface::event::{ChangeEvent, HoverInformation};
+use korangar_interface::layout::PlacementResolver;
+use korangar_interface::size_bound;
+use korangar_interface::state::{PlainRemote, Remote};
+use ragnarok_packets::{GuildMemberState, GuildRelationType};
+use wgpu::RenderPass;
+
+use crate::graphics::{InterfaceRenderer, Renderer};
+use crate::input::{MouseInputMode, UserEvent};
+use crate::interface::application::InterfaceSettings;
+use crate::interface::elements::EmblemBox;
+use crate::interface::layout::{ScreenClip, ScreenPosition, ScreenSize};
+use crate::interface::theme::InterfaceTheme;
+use crate::interface::windows::Guild;
+
+pub struct GuildView {
+    guild: PlainRemote<Option<Guild>>,
+    state: ContainerState<InterfaceSettings>,
+}
+
+impl GuildView {
+    pub fn new(guild: PlainRemote<Option<Guild>>) -> Self {
+        let elements = match &*guild.get() {
+            Some(guild) => Self::guild_to_elements(guild),
+            None => vec![Text::default().with_text("You are not a member of a guild".to_owned()).wrap()],
+        };
+
+        Self {
+            guild,
+            state: ContainerState::new(elements),
+        }
+    }
+
+    fn guild_to_elements(guild: &Guild) -> Vec<ElementCell<InterfaceSettings>> {
+        let mut elements = Vec::new();
+
+        if let Some(emblem) = &guild.emblem {
+            elements.push(EmblemBox::new(emblem.clone()).wrap());
+        }
+
+        elements.push(Text::default().with_text(format!("^ffaa00{}^000000", guild.guild_name)).wrap());
+
+        if let Some(information) = &guild.information {
+            elements.push(
+                Text::default()
+                    .with_text(format!(
+                        "level {} - {}/{} members online",
+                        information.level, information.online_member_count, information.maximum_member_count
+                    ))
+                    .wrap(),
+            );
+        }
+
+        if !guild.notice_subject.is_empty() || !guild.notice.is_empty() {
+            elements.push(Text::default().with_text(guild.notice_subject.clone()).wrap());
+            elements.push(Text::default().with_text(guild.notice.clone()).wrap());
+        }
+
+        let members = guild
+            .members
+            .iter()
+            .map(|information| {
+                let member = &information.member;
+                let name = information.name.as_deref().unwrap_or("...");
+                let position = guild
+                    .position_names
+                    .iter()
+                    .find(|position| position.position_id == member.position_id)
+                    .map(|position| position.name.as_str())
+                    .unwrap_or_default();
+                let state = match member.state {
+                    GuildMemberState::Online => "online",
+                    GuildMemberState::Offline => "offline",
+                };
+
+                Text::default()
+                    .with_text(format!("{name} - level {} {position} ({state})", member.base_level))
+                    .wrap()
+            })
+            .collect();
+
+        elements.push(Expandable::new("members".to_owned(), members, true).wrap());
+
+        let relations = guild
+            .relations
+            .iter()
+            .map(|relation| {
+                let relation_type = match relation.relation_type {
+                    GuildRelationType::Alliance => "alliance",
+                    GuildRelationType::Antagonist => "antagonist",
+                };
+
+                let elements = vec![
+                    ButtonBuilder::new()
+                        .with_text("remove")
+                        .with_event(UserEvent::RemoveGuildRelation {
+                            guild_id: relation.guild_id,
+                            relation_type: relation.relation_type,
+                        })
+                        .build()
+                        .wrap(),
+                ];
+
+                Expandable::new(format!("{} ({relation_type})", relation.guild_name), elements, false).wrap()
+            })
+            .collect();
+
+        elements.push(Expandable::new("relations".to_owned(), relations, false).wrap());
+
+        elements
+    }
+}
+
+impl Element<InterfaceSettings> for GuildView {
+    fn get_state(&self) -> &ElementState<InterfaceSettings> {
+        &self.state.state
+    }
+
+    fn get_state_mut(&mut self) -> &mut ElementState<InterfaceSettings> {
+        &mut self.state.state
+    }
+
+    fn link_back(&mut self, weak_self: WeakElementCell<InterfaceSettings>, weak_parent: Option<WeakElementCell<InterfaceSettings>>) {
+        self.state.link_back(weak_self, weak_parent);
+    }
+
+    fn is_focusable(&self) -> bool {
+        self.state.is_focusable::<false>()
+    }
+
+    fn focus_next(
+        &self,
+        self_cell: ElementCell<InterfaceSettings>,
+        caller_cell: Option<ElementCell<InterfaceSettings>>,
+        focus: Focus,
+    ) -> Option<ElementCell<InterfaceSettings>> {
+        self.state.focus_next::<false>(self_cell, caller_cell, focus)
+    }
+
+    fn restore_focus(&self, self_cell: ElementCell<InterfaceSettings>) -> Option<ElementCell<InterfaceSettings>> {
+        self.state.restore_focus(self_cell)
+    }
+
+    fn resolve(
+        &mut self,
+        placement_resolver: &mut PlacementResolver<InterfaceSettings>,
+        application: &InterfaceSettings,
+        theme: &InterfaceTheme,
+    ) {
+        self.state.resolve(
+            placement_resolver,
+            application,
+            theme,
+            &size_bound!(100%, ?),
+            ScreenSize::default(),
+        );
+    }
+
+    fn update(&mut self) -> Option<ChangeEvent> {
+        if self.guild.consume_changed() {
+            let weak_parent = self.state.state.parent_element.take();
+            let weak_self = self.state.state.self_element.take().unwrap();
+
+            *self = Self::new(self.guild.clone());
+            // important: link back after creating elements, otherwise focus navigation and
+            // scrolling would break
+            self.link_back(weak_self, weak_parent);
+
+            return Some(ChangeEvent::RESOLVE_WINDOW);
+        }
+
+        None
+    }
+
+    fn hovered_element(&self, mouse_position: ScreenPosition, mouse_mode: &MouseInputMode) -> HoverInformation<InterfaceSettings> {
+        match mouse_mode {
+            MouseInputMode::None => self.state.hovered_element(mouse_position, mouse_mode, false),
+            _ => HoverInformation::Missed,
+        }
+    }
+
+    fn render(
+        &self,
+        render_target: &mut <InterfaceRenderer as Renderer>::Target,
+        render_pass: &mut RenderPass,
+        renderer: &InterfaceRenderer,
+        application: &InterfaceSettings,
+        theme: &InterfaceTheme,
+        parent_position: ScreenPosition,
+        screen_clip: ScreenClip,
+        hovered_element: Option<&dyn Element<InterfaceSettings>>,
+        focused_element: Option<&dyn Element<InterfaceSettings>>,
+        mouse_mode: &MouseInputMode,
+        second_theme: bool,
+    ) {
+        let mut renderer =
+            self.state
+                .state
+                .element_renderer(render_target, render_pass, renderer, application, parent_position, screen_clip);
+
+        self.state.render(
+            &mut renderer,
+            application,
+            theme,
+            hovered_element,
+            focused_element,
+            mouse_mode,
+            second_theme,
+        );
+    }
+}
@@ -13,15 +13,16 @@ use crate::interface::elements::SkillBox;
 use crate::interface::layout::{ScreenClip, ScreenPosition, ScreenSize};
 use crate::interface::resource::SkillSource;
 use crate::interface::theme::InterfaceTheme;
-use crate::inventory::Skill;
+use crate::inventory::{Skill, SkillCooldowns};
 
 pub struct HotbarContainer {
     skills: PlainRemote<[Option<Skill>; 10]>,
+    cooldowns: PlainRemote<SkillCooldowns>,
     state: ContainerState<InterfaceSettings>,
 }
 
 impl HotbarContainer {
-    pub fn new(skills: PlainRemote<[Option<Skill>; 10]>) -> Self {
+    pub fn new(skills: PlainRemote<[Option<Skill>; 10]>, cooldowns: PlainRemote<SkillCooldowns>) -> Self {
         let elements = {
             let skills = skills.get();
 
@@ -37,6 +38,7 @@ impl HotbarContainer {
                         skill,
                         skill_source,
                         Box::new(move |mouse_mode| matches!(mouse_mode, MouseInputMode::MoveSkill(source, _) if *source != skill_source)),
+                        Some(cooldowns.clone()),
                     );
 
                     skill_box.wrap()
@@ -46,7 +48,7 @@ impl HotbarContainer {
 
         let state = ContainerState::new(elements);
 
-        Self { skills, state }
+        Self { skills, cooldowns, state }
     }
 }
 
@@ -96,7 +98,7 @@ impl Element<InterfaceSettings> for HotbarContainer {
             let weak_self = self.state.state.self_element.take().unwrap();
             let weak_parent = self.state.state.parent_element.take();
 
-            *self = Self::new(self.skills.clone());
+            *self = Self::new(self.skills.clone(), self.cooldowns.clone());
             // important: link back after creating elements, otherwise focus navigation and
             // scrolling would break
             self.link_back(weak_self, weak_parent);
@@ -104,7 +106,7 @@ impl Element<InterfaceSettings> for HotbarContainer {
             return Some(ChangeEvent::RESOLVE_WINDOW);
         }
 
-        None
+        self.state.update()
     }
 
     fn hovered_element(&self, mouse_position: ScreenPosition, mouse_mode: &MouseInputMode) -> HoverInformation<InterfaceSettings> {
@@ -97,22 +97,22 @@ impl Element<InterfaceSettings> for InventoryContainer {
 
     fn hovered_element(&self, mouse_position: ScreenPosition, mouse_mode: &MouseInputMode) -> HoverInformation<InterfaceSettings> {
         match mouse_mode {
-            MouseInputMode::MoveItem(..) => self.state.state.hovered_element(mouse_position),
+            MouseInputMode::MoveItem(..) | MouseInputMode::MoveStorageItem(..) => self.state.state.hovered_element(mouse_position),
             MouseInputMode::None => self.state.hovered_element(mouse_position, mouse_mode, false),
             _ => HoverInformation::Missed,
         }
     }
 
     fn drop_resource(&mut self, drop_resource: PartialMove) -> Option<Move> {
-        let PartialMove::Item { source, item } = drop_resource else {
-            return None;
-        };
-
-        (source != ItemSource::Inventory).then_some(Move::Item {
-            source,
-            destination: ItemSource::Inventory,
-            item,
-        })
+        match drop_resource {
+            PartialMove::Item { source, item } => (source != ItemSource::Inventory).then_some(Move::Item {
+                source,
+                destination: ItemSource::Inventory,
+                item,
+            }),
+            PartialMove::StorageItem { item } => Some(Move::StorageItem { item }),
+            PartialMove::Skill { .. } => None,
+        }
     }
 
     fn render(
@@ -144,7 +144,7 @@ impl Element<InterfaceSettings> for InventoryContainer {
             second_theme,
         );
 
-        if matches!(mouse_mode, MouseInputMode::MoveItem(..)) {
+        if matches!(mouse_mode, MouseInputMode::MoveItem(..) | MouseInputMode::MoveStorageItem(..)) {
             match self.is_element_self(hovered_element) {
                 true => renderer.render_background(CornerRadius::uniform(5.0), Color::rgba_u8(60, 160, 160, 160)),
                 false => renderer.render_background(CornerRadius::uniform(5.0), Color::rgba_u8(160, 160, 60, 160)),
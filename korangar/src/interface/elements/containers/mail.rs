@@ -0,0 +1,532 @@
+use korangar_interface::elements::{
+    ButtonBuilder, ContainerState, Element, ElementCell, ElementState, ElementWrap, Focus, Text, WeakElementCell,
+};
+use korangar_interface::event::{ChangeEvent, HoverInformation};
+use korangar_interface::layout::PlacementResolver;
+use korangar_interface::state::{PlainRemote, Remote};
+use korangar_interface::{dimension_bound, size_bound};
+use korangar_networking::{MailItem, MailSummary};
+use wgpu::RenderPass;
+
+use crate::graphics::{Color, InterfaceRenderer, Renderer};
+use crate::input::{MouseInputMode, UserEvent};
+use crate::interface::application::InterfaceSettings;
+use crate::interface::layout::{CornerRadius, ScreenClip, ScreenPosition, ScreenSize};
+use crate::interface::resource::{ItemSource, Move, PartialMove};
+use crate::interface::theme::InterfaceTheme;
+use crate::interface::windows::{MailAttachment, Mailbox, OpenMail};
+use crate::loaders::ResourceMetadata;
+
+const SECONDS_PER_DAY: u32 = 24 * 60 * 60;
+
+fn item_to_element(item: &MailItem<ResourceMetadata>) -> ElementCell<InterfaceSettings> {
+    let refinement = match item.refinement_level {
+        0 => String::new(),
+        level => format!("+{level} "),
+    };
+
+    Text::default()
+        .with_text(format!("{}x {refinement}{}", item.amount, item.metadata.name))
+        .wrap()
+}
+
+/// Lists the mail in the mailbox of the player.
+pub struct MailboxView {
+    mailbox: PlainRemote<Mailbox>,
+    state: ContainerState<InterfaceSettings>,
+}
+
+impl MailboxView {
+    pub fn new(mailbox: PlainRemote<Mailbox>) -> Self {
+        let elements = {
+            let mailbox = mailbox.get();
+            let mut elements: Vec<_> = mailbox.mails.iter().flat_map(Self::mail_to_elements).collect();
+
+            if elements.is_empty() {
+                elements.push(Text::default().with_text("No mail".to_owned()).wrap());
+            }
+
+            // Mail is requested in pages, starting from the newest one.
+            if let Some(oldest_mail) = mailbox.mails.last().filter(|_| !mailbox.is_last_page) {
+                elements.push(
+                    ButtonBuilder::new()
+                        .with_text("Load more")
+                        .with_event(UserEvent::RequestNextMailPage(oldest_mail.mail_id))
+                        .build()
+                        .wrap(),
+                );
+            }
+
+            elements
+        };
+
+        Self {
+            mailbox,
+            state: ContainerState::new(elements),
+        }
+    }
+
+    fn mail_to_elements(mail: &MailSummary) -> Vec<ElementCell<InterfaceSettings>> {
+        let status = match mail.is_read {
+            true => "",
+            false => " ^ff5555(new)^000000",
+        };
+        let zeny = match mail.has_zeny {
+            true => " [zeny]",
+            false => "",
+        };
+        let items = match mail.has_items {
+            true => " [items]",
+            false => "",
+        };
+
+        vec![
+            Text::default()
+                .with_text(format!("^ffaa00{}^000000{status}{zeny}{items}", mail.title))
+                .wrap(),
+            Text::default()
+                .with_text(format!(
+                    "from {}, expires in {} days",
+                    mail.sender_name,
+                    mail.expires_in / SECONDS_PER_DAY
+                ))
+                .with_width(dimension_bound!(75%))
+                .wrap(),
+            ButtonBuilder::new()
+                .with_text("read")
+                .with_event(UserEvent::ReadMail {
+                    open_type: mail.open_type,
+                    mail_id: mail.mail_id,
+                })
+                .with_width_bound(dimension_bound!(!))
+                .build()
+                .wrap(),
+        ]
+    }
+}
+
+impl Element<InterfaceSettings> for MailboxView {
+    fn get_state(&self) -> &ElementState<InterfaceSettings> {
+        &self.state.state
+    }
+
+    fn get_state_mut(&mut self) -> &mut ElementState<InterfaceSettings> {
+        &mut self.state.state
+    }
+
+    fn link_back(&mut self, weak_self: WeakElementCell<InterfaceSettings>, weak_parent: Option<WeakElementCell<InterfaceSettings>>) {
+        self.state.link_back(weak_self, weak_parent);
+    }
+
+    fn is_focusable(&self) -> bool {
+        self.state.is_focusable::<false>()
+    }
+
+    fn focus_next(
+        &self,
+        self_cell: ElementCell<InterfaceSettings>,
+        caller_cell: Option<ElementCell<InterfaceSettings>>,
+        focus: Focus,
+    ) -> Option<ElementCell<InterfaceSettings>> {
+        self.state.focus_next::<false>(self_cell, caller_cell, focus)
+    }
+
+    fn restore_focus(&self, self_cell: ElementCell<InterfaceSettings>) -> Option<ElementCell<InterfaceSettings>> {
+        self.state.restore_focus(self_cell)
+    }
+
+    fn resolve(
+        &mut self,
+        placement_resolver: &mut PlacementResolver<InterfaceSettings>,
+        application: &InterfaceSettings,
+        theme: &InterfaceTheme,
+    ) {
+        self.state.resolve(
+            placement_resolver,
+            application,
+            theme,
+            &size_bound!(100%, ?),
+            ScreenSize::default(),
+        );
+    }
+
+    fn update(&mut self) -> Option<ChangeEvent> {
+        if self.mailbox.consume_changed() {
+            let weak_parent = self.state.state.parent_element.take();
+            let weak_self = self.state.state.self_element.take().unwrap();
+
+            *self = Self::new(self.mailbox.clone());
+            // important: link back after creating elements, otherwise focus navigation and
+            // scrolling would break
+            self.link_back(weak_self, weak_parent);
+
+            return Some(ChangeEvent::RESOLVE_WINDOW);
+        }
+
+        None
+    }
+
+    fn hovered_element(&self, mouse_position: ScreenPosition, mouse_mode: &MouseInputMode) -> HoverInformation<InterfaceSettings> {
+        match mouse_mode {
+            MouseInputMode::None => self.state.hovered_element(mouse_position, mouse_mode, false),
+            _ => HoverInformation::Missed,
+        }
+    }
+
+    fn render(
+        &self,
+        render_target: &mut <InterfaceRenderer as Renderer>::Target,
+        render_pass: &mut RenderPass,
+        renderer: &InterfaceRenderer,
+        application: &InterfaceSettings,
+        theme: &InterfaceTheme,
+        parent_position: ScreenPosition,
+        screen_clip: ScreenClip,
+        hovered_element: Option<&dyn Element<InterfaceSettings>>,
+        focused_element: Option<&dyn Element<InterfaceSettings>>,
+        mouse_mode: &MouseInputMode,
+        second_theme: bool,
+    ) {
+        let mut renderer =
+            self.state
+                .state
+                .element_renderer(render_target, render_pass, renderer, application, parent_position, screen_clip);
+
+        self.state.render(
+            &mut renderer,
+            application,
+            theme,
+            hovered_element,
+            focused_element,
+            mouse_mode,
+            second_theme,
+        );
+    }
+}
+
+/// Shows the contents of the mail that the player is reading.
+pub struct MailView {
+    mailbox: PlainRemote<Mailbox>,
+    state: ContainerState<InterfaceSettings>,
+}
+
+impl MailView {
+    pub fn new(mailbox: PlainRemote<Mailbox>) -> Self {
+        let elements = match &mailbox.get().open_mail {
+            Some(mail) => Self::mail_to_elements(mail),
+            None => vec![Text::default().with_text("The mail was deleted".to_owned()).wrap()],
+        };
+
+        Self {
+            mailbox,
+            state: ContainerState::new(elements),
+        }
+    }
+
+    fn mail_to_elements(mail: &OpenMail) -> Vec<ElementCell<InterfaceSettings>> {
+        let mut elements = vec![
+            Text::default().with_text(format!("^ffaa00{}^000000", mail.title)).wrap(),
+            Text::default().with_text(format!("from {}", mail.sender_name)).wrap(),
+            Text::default().with_text(mail.text.clone()).wrap(),
+        ];
+
+        elements.extend(mail.items.iter().map(item_to_element));
+
+        if mail.zeny > 0 {
+            elements.push(Text::default().with_text(format!("{} zeny", mail.zeny)).wrap());
+            elements.push(
+                ButtonBuilder::new()
+                    .with_text("Take zeny")
+                    .with_event(UserEvent::RetrieveMailZeny {
+                        open_type: mail.open_type,
+                        mail_id: mail.mail_id,
+                    })
+                    .build()
+                    .wrap(),
+            );
+        }
+
+        if !mail.items.is_empty() {
+            elements.push(
+                ButtonBuilder::new()
+                    .with_text("Take items")
+                    .with_event(UserEvent::RetrieveMailItems {
+                        open_type: mail.open_type,
+                        mail_id: mail.mail_id,
+                    })
+                    .build()
+                    .wrap(),
+            );
+        }
+
+        elements.push(
+            ButtonBuilder::new()
+                .with_text("Delete")
+                .with_event(UserEvent::DeleteMail {
+                    open_type: mail.open_type,
+                    mail_id: mail.mail_id,
+                })
+                .build()
+                .wrap(),
+        );
+
+        elements
+    }
+}
+
+impl Element<InterfaceSettings> for MailView {
+    fn get_state(&self) -> &ElementState<InterfaceSettings> {
+        &self.state.state
+    }
+
+    fn get_state_mut(&mut self) -> &mut ElementState<InterfaceSettings> {
+        &mut self.state.state
+    }
+
+    fn link_back(&mut self, weak_self: WeakElementCell<InterfaceSettings>, weak_parent: Option<WeakElementCell<InterfaceSettings>>) {
+        self.state.link_back(weak_self, weak_parent);
+    }
+
+    fn is_focusable(&self) -> bool {
+        self.state.is_focusable::<false>()
+    }
+
+    fn focus_next(
+        &self,
+        self_cell: ElementCell<InterfaceSettings>,
+        caller_cell: Option<ElementCell<InterfaceSettings>>,
+        focus: Focus,
+    ) -> Option<ElementCell<InterfaceSettings>> {
+        self.state.focus_next::<false>(self_cell, caller_cell, focus)
+    }
+
+    fn restore_focus(&self, self_cell: ElementCell<InterfaceSettings>) -> Option<ElementCell<InterfaceSettings>> {
+        self.state.restore_focus(self_cell)
+    }
+
+    fn resolve(
+        &mut self,
+        placement_resolver: &mut PlacementResolver<InterfaceSettings>,
+        application: &InterfaceSettings,
+        theme: &InterfaceTheme,
+    ) {
+        self.state.resolve(
+            placement_resolver,
+            application,
+            theme,
+            &size_bound!(100%, ?),
+            ScreenSize::default(),
+        );
+    }
+
+    fn update(&mut self) -> Option<ChangeEvent> {
+        if self.mailbox.consume_changed() {
+            let weak_parent = self.state.state.parent_element.take();
+            let weak_self = self.state.state.self_element.take().unwrap();
+
+            *self = Self::new(self.mailbox.clone());
+            // important: link back after creating elements, otherwise focus navigation and
+            // scrolling would break
+            self.link_back(weak_self, weak_parent);
+
+            return Some(ChangeEvent::RESOLVE_WINDOW);
+        }
+
+        None
+    }
+
+    fn hovered_element(&self, mouse_position: ScreenPosition, mouse_mode: &MouseInputMode) -> HoverInformation<InterfaceSettings> {
+        match mouse_mode {
+            MouseInputMode::None => self.state.hovered_element(mouse_position, mouse_mode, false),
+            _ => HoverInformation::Missed,
+        }
+    }
+
+    fn render(
+        &self,
+        render_target: &mut <InterfaceRenderer as Renderer>::Target,
+        render_pass: &mut RenderPass,
+        renderer: &InterfaceRenderer,
+        application: &InterfaceSettings,
+        theme: &InterfaceTheme,
+        parent_position: ScreenPosition,
+        screen_clip: ScreenClip,
+        hovered_element: Option<&dyn Element<InterfaceSettings>>,
+        focused_element: Option<&dyn Element<InterfaceSettings>>,
+        mouse_mode: &MouseInputMode,
+        second_theme: bool,
+    ) {
+        let mut renderer =
+            self.state
+                .state
+                .element_renderer(render_target, render_pass, renderer, application, parent_position, screen_clip);
+
+        self.state.render(
+            &mut renderer,
+            application,
+            theme,
+            hovered_element,
+            focused_element,
+            mouse_mode,
+            second_theme,
+        );
+    }
+}
+
+/// Shows the items attached to the mail that the player is writing. Items
+/// can be attached by dropping them from the inventory onto this view.
+pub struct MailAttachmentView {
+    attachments: PlainRemote<Vec<MailAttachment>>,
+    state: ContainerState<InterfaceSettings>,
+}
+
+impl MailAttachmentView {
+    pub fn new(attachments: PlainRemote<Vec<MailAttachment>>) -> Self {
+        let elements = {
+            let attachments = attachments.get();
+
+            match attachments.is_empty() {
+                true => vec![Text::default().with_text("Drop items here to attach them".to_owned()).wrap()],
+                false => attachments.iter().flat_map(Self::attachment_to_elements).collect(),
+            }
+        };
+
+        Self {
+            attachments,
+            state: ContainerState::new(elements),
+        }
+    }
+
+    fn attachment_to_elements(attachment: &MailAttachment) -> Vec<ElementCell<InterfaceSettings>> {
+        vec![
+            item_to_element(&attachment.item),
+            ButtonBuilder::new()
+                .with_text("remove")
+                .with_event(UserEvent::DetachMailItem {
+                    index: attachment.index,
+                    amount: attachment.item.amount,
+                })
+                .build()
+                .wrap(),
+        ]
+    }
+}
+
+impl Element<InterfaceSettings> for MailAttachmentView {
+    fn get_state(&self) -> &ElementState<InterfaceSettings> {
+        &self.state.state
+    }
+
+    fn get_state_mut(&mut self) -> &mut ElementState<InterfaceSettings> {
+        &mut self.state.state
+    }
+
+    fn link_back(&mut self, weak_self: WeakElementCell<InterfaceSettings>, weak_parent: Option<WeakElementCell<InterfaceSettings>>) {
+        self.state.link_back(weak_self, weak_parent);
+    }
+
+    fn is_focusable(&self) -> bool {
+        self.state.is_focusable::<false>()
+    }
+
+    fn focus_next(
+        &self,
+        self_cell: ElementCell<InterfaceSettings>,
+        caller_cell: Option<ElementCell<InterfaceSettings>>,
+        focus: Focus,
+    ) -> Option<ElementCell<InterfaceSettings>> {
+        self.state.focus_next::<false>(self_cell, caller_cell, focus)
+    }
+
+    fn restore_focus(&self, self_cell: ElementCell<InterfaceSettings>) -> Option<ElementCell<InterfaceSettings>> {
+        self.state.restore_focus(self_cell)
+    }
+
+    fn resolve(
+        &mut self,
+        placement_resolver: &mut PlacementResolver<InterfaceSettings>,
+        application: &InterfaceSettings,
+        theme: &InterfaceTheme,
+    ) {
+        self.state.resolve(
+            placement_resolver,
+            application,
+            theme,
+            &size_bound!(100%, ?),
+            ScreenSize::default(),
+        );
+    }
+
+    fn update(&mut self) -> Option<ChangeEvent> {
+        if self.attachments.consume_changed() {
+            let weak_parent = self.state.state.parent_element.take();
+            let weak_self = self.state.state.self_element.take().unwrap();
+
+            *self = Self::new(self.attachments.clone());
+            // important: link back after creating elements, otherwise focus navigation and
+            // scrolling would break
+            self.link_back(weak_self, weak_parent);
+
+            return Some(ChangeEvent::RESOLVE_WINDOW);
+        }
+
+        None
+    }
+
+    fn hovered_element(&self, mouse_position: ScreenPosition, mouse_mode: &MouseInputMode) -> HoverInformation<InterfaceSettings> {
+        match mouse_mode {
+            MouseInputMode::MoveItem(..) => self.state.state.hovered_element(mouse_position),
+            MouseInputMode::None => self.state.hovered_element(mouse_position, mouse_mode, false),
+            _ => HoverInformation::Missed,
+        }
+    }
+
+    fn drop_resource(&mut self, drop_resource: PartialMove) -> Option<Move> {
+        let PartialMove::Item { source, item } = drop_resource else {
+            return None;
+        };
+
+        (source == ItemSource::Inventory).then_some(Move::Item {
+            source,
+            destination: ItemSource::Mail,
+            item,
+        })
+    }
+
+    fn render(
+        &self,
+        render_target: &mut <InterfaceRenderer as Renderer>::Target,
+        render_pass: &mut RenderPass,
+        renderer: &InterfaceRenderer,
+        application: &InterfaceSettings,
+        theme: &InterfaceTheme,
+        parent_position: ScreenPosition,
+        screen_clip: ScreenClip,
+        hovered_element: Option<&dyn Element<InterfaceSettings>>,
+        focused_element: Option<&dyn Element<InterfaceSettings>>,
+        mouse_mode: &MouseInputMode,
+        second_theme: bool,
+    ) {
+        let mut renderer =
+            self.state
+                .state
+                .element_renderer(render_target, render_pass, renderer, application, parent_position, screen_clip);
+
+        self.state.render(
+            &mut renderer,
+            application,
+            theme,
+            hovered_element,
+            focused_element,
+            mouse_mode,
+            second_theme,
+        );
+
+        if matches!(mouse_mode, MouseInputMode::MoveItem(..)) {
+            match self.is_element_self(hovered_element) {
+                true => renderer.render_background(CornerRadius::uniform(5.0), Color::rgba_u8(60, 160, 160, 160)),
+                false => renderer.render_background(CornerRadius::uniform(5.0), Color::rgba_u8(160, 160, 60, 160)),
+            }
+        }
+    }
+}
@@ -1,19 +1,39 @@
+mod achievement;
 mod character;
+mod chat_room;
+mod conversation;
 mod dialog;
 mod equipment;
 mod friends;
+mod guild;
 mod hotbar;
 mod inventory;
+mod mail;
 #[cfg(feature = "debug")]
 mod packet;
+mod party;
+mod quest;
 mod skill_tree;
+mod stats;
+mod storage;
+mod trade;
 
+pub use self::achievement::{AchievementView, ReputationView};
 pub use self::character::CharacterPreview;
+pub use self::chat_room::ChatRoomView;
+pub use self::conversation::ConversationView;
 pub use self::dialog::{DialogContainer, DialogElement};
 pub use self::equipment::EquipmentContainer;
 pub use self::friends::FriendView;
+pub use self::guild::GuildView;
 pub use self::hotbar::HotbarContainer;
 pub use self::inventory::InventoryContainer;
+pub use self::mail::{MailAttachmentView, MailView, MailboxView};
 #[cfg(feature = "debug")]
 pub use self::packet::{PacketHistoryCallback, PacketHistoryRemote, PacketView};
+pub use self::party::PartyView;
+pub use self::quest::QuestLogView;
 pub use self::skill_tree::SkillTreeContainer;
+pub use self::stats::StatsView;
+pub use self::storage::StorageContainer;
+pub use self::trade::TradeView;
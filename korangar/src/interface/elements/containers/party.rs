@@ -0,0 +1,178 @@
+use korangar_interface::elements::{
+    ButtonBuilder, ContainerState, Element, ElementCell, ElementState, ElementWrap, Focus, Text, WeakElementCell,
+};
+use korangar_interface::event::{ChangeEvent, HoverInformation};
+use korangar_interface::layout::PlacementResolver;
+use korangar_interface::size_bound;
+use korangar_interface::state::{PlainRemote, Remote};
+use ragnarok_packets::{AccountId, OnlineState, PartyMemberRole};
+use wgpu::RenderPass;
+
+use crate::graphics::{InterfaceRenderer, Renderer};
+use crate::input::{MouseInputMode, UserEvent};
+use crate::interface::application::InterfaceSettings;
+use crate::interface::layout::{ScreenClip, ScreenPosition, ScreenSize};
+use crate::interface::theme::InterfaceTheme;
+use crate::interface::windows::PartyMemberInformation;
+
+pub struct PartyView {
+    player_account_id: AccountId,
+    party_members: PlainRemote<Vec<PartyMemberInformation>>,
+    state: ContainerState<InterfaceSettings>,
+}
+
+impl PartyView {
+    pub fn new(player_account_id: AccountId, party_members: PlainRemote<Vec<PartyMemberInformation>>) -> Self {
+        let elements = party_members
+            .get()
+            .iter()
+            .flat_map(|information| Self::party_member_to_elements(information, player_account_id))
+            .collect();
+
+        Self {
+            player_account_id,
+            party_members,
+            state: ContainerState::new(elements),
+        }
+    }
+
+    fn party_member_to_elements(information: &PartyMemberInformation, player_account_id: AccountId) -> Vec<ElementCell<InterfaceSettings>> {
+        let member = &information.member;
+
+        let role = match member.role {
+            PartyMemberRole::Leader => " (leader)",
+            PartyMemberRole::Member => "",
+        };
+
+        let details = match member.state {
+            OnlineState::Online if information.maximum_health_points > 0 => format!(
+                "{} - {}/{} HP",
+                member.map_name, information.health_points, information.maximum_health_points
+            ),
+            OnlineState::Online => member.map_name.clone(),
+            OnlineState::Offline => "offline".to_owned(),
+        };
+
+        let mut elements = vec![
+            Text::default()
+                .with_text(format!("^ffaa00{}^000000{} - level {}", member.name, role, member.base_level))
+                .wrap(),
+            Text::default().with_text(details).wrap(),
+        ];
+
+        // The player can leave the party, but not kick themselves.
+        if member.account_id != player_account_id {
+            elements.push(
+                ButtonBuilder::new()
+                    .with_text("kick")
+                    .with_event(UserEvent::KickPartyMember {
+                        account_id: member.account_id,
+                        name: member.name.clone(),
+                    })
+                    .build()
+                    .wrap(),
+            );
+        }
+
+        elements
+    }
+}
+
+impl Element<InterfaceSettings> for PartyView {
+    fn get_state(&self) -> &ElementState<InterfaceSettings> {
+        &self.state.state
+    }
+
+    fn get_state_mut(&mut self) -> &mut ElementState<InterfaceSettings> {
+        &mut self.state.state
+    }
+
+    fn link_back(&mut self, weak_self: WeakElementCell<InterfaceSettings>, weak_parent: Option<WeakElementCell<InterfaceSettings>>) {
+        self.state.link_back(weak_self, weak_parent);
+    }
+
+    fn is_focusable(&self) -> bool {
+        self.state.is_focusable::<false>()
+    }
+
+    fn focus_next(
+        &self,
+        self_cell: ElementCell<InterfaceSettings>,
+        caller_cell: Option<ElementCell<InterfaceSettings>>,
+        focus: Focus,
+    ) -> Option<ElementCell<InterfaceSettings>> {
+        self.state.focus_next::<false>(self_cell, caller_cell, focus)
+    }
+
+    fn restore_focus(&self, self_cell: ElementCell<InterfaceSettings>) -> Option<ElementCell<InterfaceSettings>> {
+        self.state.restore_focus(self_cell)
+    }
+
+    fn resolve(
+        &mut self,
+        placement_resolver: &mut PlacementResolver<InterfaceSettings>,
+        application: &InterfaceSettings,
+        theme: &InterfaceTheme,
+    ) {
+        self.state.resolve(
+            placement_resolver,
+            application,
+            theme,
+            &size_bound!(100%, ?),
+            ScreenSize::default(),
+        );
+    }
+
+    fn update(&mut self) -> Option<ChangeEvent> {
+        if self.party_members.consume_changed() {
+            let weak_parent = self.state.state.parent_element.take();
+            let weak_self = self.state.state.self_element.take().unwrap();
+
+            *self = Self::new(self.player_account_id, self.party_members.clone());
+            // important: link back after creating elements, otherwise focus navigation and
+            // scrolling would break
+            self.link_back(weak_self, weak_parent);
+
+            return Some(ChangeEvent::RESOLVE_WINDOW);
+        }
+
+        None
+    }
+
+    fn hovered_element(&self, mouse_position: ScreenPosition, mouse_mode: &MouseInputMode) -> HoverInformation<InterfaceSettings> {
+        match mouse_mode {
+            MouseInputMode::None => self.state.hovered_element(mouse_position, mouse_mode, false),
+            _ => HoverInformation::Missed,
+        }
+    }
+
+    fn render(
+        &self,
+        render_target: &mut <InterfaceRenderer as Renderer>::Target,
+        render_pass: &mut RenderPass,
+        renderer: &InterfaceRenderer,
+        application: &InterfaceSettings,
+        theme: &InterfaceTheme,
+        parent_position: ScreenPosition,
+        screen_clip: ScreenClip,
+        hovered_element: Option<&dyn Element<InterfaceSettings>>,
+        focused_element: Option<&dyn Element<InterfaceSettings>>,
+        mouse_mode: &MouseInputMode,
+        second_theme: bool,
+    ) {
+        let mut renderer =
+            self.state
+                .state
+                .element_renderer(render_target, render_pass, renderer, application, parent_position, screen_clip);
+
+        self.state.render(
+            &mut renderer,
+            application,
+            theme,
+            hovered_element,
+            focused_element,
+            mouse_mode,
+            second_theme,
+        );
+    }
+}
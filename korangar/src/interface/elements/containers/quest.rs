@@ -0,0 +1,196 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use korangar_interface::elements::{ContainerState, Element, ElementCell, ElementState, ElementWrap, Focus, Text, WeakElementCell};
+use korangar_interface::event::{ChangeEvent, HoverInformation};
+use korangar_interface::layout::PlacementResolver;
+use korangar_interface::size_bound;
+use korangar_interface::state::{PlainRemote, Remote};
+use wgpu::RenderPass;
+
+use crate::graphics::{InterfaceRenderer, Renderer};
+use crate::input::MouseInputMode;
+use crate::interface::application::InterfaceSettings;
+use crate::interface::layout::{ScreenClip, ScreenPosition, ScreenSize};
+use crate::interface::theme::InterfaceTheme;
+use crate::interface::windows::{QuestLog, QuestLogEntry};
+
+/// Lists the quests of the player and the progress of their objectives.
+pub struct QuestLogView {
+    quest_log: PlainRemote<QuestLog>,
+    state: ContainerState<InterfaceSettings>,
+}
+
+impl QuestLogView {
+    pub fn new(quest_log: PlainRemote<QuestLog>) -> Self {
+        let elements = {
+            let quest_log = quest_log.get();
+            let current_time = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|duration| duration.as_secs())
+                .unwrap_or_default();
+
+            let mut elements: Vec<_> = quest_log
+                .quests
+                .iter()
+                .flat_map(|quest| Self::quest_to_elements(quest, current_time))
+                .collect();
+
+            if elements.is_empty() {
+                elements.push(Text::default().with_text("No quests".to_owned()).wrap());
+            }
+
+            elements
+        };
+
+        Self {
+            quest_log,
+            state: ContainerState::new(elements),
+        }
+    }
+
+    fn quest_to_elements(quest: &QuestLogEntry, current_time: u64) -> Vec<ElementCell<InterfaceSettings>> {
+        let status = match quest.active {
+            true => "",
+            false => " ^999999(inactive)^000000",
+        };
+
+        let mut elements = vec![
+            Text::default()
+                .with_text(format!("^ffaa00{}^000000{status}", quest.text.title))
+                .wrap(),
+        ];
+
+        if !quest.text.summary.is_empty() {
+            elements.push(Text::default().with_text(quest.text.summary.clone()).wrap());
+        }
+
+        elements.extend(
+            quest
+                .text
+                .description
+                .iter()
+                .map(|line| Text::default().with_text(line.clone()).wrap()),
+        );
+
+        elements.extend(quest.objectives.iter().map(|objective| {
+            let progress = match objective.total_count {
+                0 => objective.current_count.to_string(),
+                total_count => format!("{}/{total_count}", objective.current_count),
+            };
+
+            Text::default()
+                .with_text(format!("^55aaff{}^000000: {progress}", objective.mob_name))
+                .wrap()
+        }));
+
+        if quest.expire_time != 0 {
+            let text = match (quest.expire_time as u64).checked_sub(current_time) {
+                Some(remaining) => format!("expires in {}h {}m", remaining / 3600, remaining % 3600 / 60),
+                None => "^ff5555expired^000000".to_owned(),
+            };
+
+            elements.push(Text::default().with_text(text).wrap());
+        }
+
+        elements
+    }
+}
+
+impl Element<InterfaceSettings> for QuestLogView {
+    fn get_state(&self) -> &ElementState<InterfaceSettings> {
+        &self.state.state
+    }
+
+    fn get_state_mut(&mut self) -> &mut ElementState<InterfaceSettings> {
+        &mut self.state.state
+    }
+
+    fn link_back(&mut self, weak_self: WeakElementCell<InterfaceSettings>, weak_parent: Option<WeakElementCell<InterfaceSettings>>) {
+        self.state.link_back(weak_self, weak_parent);
+    }
+
+    fn is_focusable(&self) -> bool {
+        self.state.is_focusable::<false>()
+    }
+
+    fn focus_next(
+        &self,
+        self_cell: ElementCell<InterfaceSettings>,
+        caller_cell: Option<ElementCell<InterfaceSettings>>,
+        focus: Focus,
+    ) -> Option<ElementCell<InterfaceSettings>> {
+        self.state.focus_next::<false>(self_cell, caller_cell, focus)
+    }
+
+    fn restore_focus(&self, self_cell: ElementCell<InterfaceSettings>) -> Option<ElementCell<InterfaceSettings>> {
+        self.state.restore_focus(self_cell)
+    }
+
+    fn resolve(
+        &mut self,
+        placement_resolver: &mut PlacementResolver<InterfaceSettings>,
+        application: &InterfaceSettings,
+        theme: &InterfaceTheme,
+    ) {
+        self.state.resolve(
+            placement_resolver,
+            application,
+            theme,
+            &size_bound!(100%, ?),
+            ScreenSize::default(),
+        );
+    }
+
+    fn update(&mut self) -> Option<ChangeEvent> {
+        if self.quest_log.consume_changed() {
+            let weak_parent = self.state.state.parent_element.take();
+            let weak_self = self.state.state.self_element.take().unwrap();
+
+            *self = Self::new(self.quest_log.clone());
+            // important: link back after creating elements, otherwise focus navigation and
+            // scrolling would break
+            self.link_back(weak_self, weak_parent);
+
+            return Some(ChangeEvent::RESOLVE_WINDOW);
+        }
+
+        None
+    }
+
+    fn hovered_element(&self, mouse_position: ScreenPosition, mouse_mode: &MouseInputMode) -> HoverInformation<InterfaceSettings> {
+        match mouse_mode {
+            MouseInputMode::None => self.state.hovered_element(mouse_position, mouse_mode, false),
+            _ => HoverInformation::Missed,
+        }
+    }
+
+    fn render(
+        &self,
+        render_target: &mut <InterfaceRenderer as Renderer>::Target,
+        render_pass: &mut RenderPass,
+        renderer: &InterfaceRenderer,
+        application: &InterfaceSettings,
+        theme: &InterfaceTheme,
+        parent_position: ScreenPosition,
+        screen_clip: ScreenClip,
+        hovered_element: Option<&dyn Element<InterfaceSettings>>,
+        focused_element: Option<&dyn Element<InterfaceSettings>>,
+        mouse_mode: &MouseInputMode,
+        second_theme: bool,
+    ) {
+        let mut renderer =
+            self.state
+                .state
+                .element_renderer(render_target, render_pass, renderer, application, parent_position, screen_clip);
+
+        self.state.render(
+            &mut renderer,
+            application,
+            theme,
+            hovered_element,
+            focused_element,
+            mouse_mode,
+            second_theme,
+        );
+    }
+}
@@ -1,12 +1,14 @@
-use korangar_interface::elements::{ContainerState, Element, ElementCell, ElementState, ElementWrap, Focus, WeakElementCell};
+use korangar_interface::elements::{
+    ButtonBuilder, ContainerState, Element, ElementCell, ElementState, ElementWrap, Focus, WeakElementCell,
+};
 use korangar_interface::event::{ChangeEvent, HoverInformation};
 use korangar_interface::layout::PlacementResolver;
-use korangar_interface::size_bound;
 use korangar_interface::state::{PlainRemote, Remote};
+use korangar_interface::{dimension_bound, size_bound};
 use wgpu::RenderPass;
 
 use crate::graphics::{InterfaceRenderer, Renderer};
-use crate::input::MouseInputMode;
+use crate::input::{MouseInputMode, UserEvent};
 use crate::interface::application::InterfaceSettings;
 use crate::interface::elements::SkillBox;
 use crate::interface::layout::{ScreenClip, ScreenPosition, ScreenSize};
@@ -28,7 +30,20 @@ impl SkillTreeContainer {
             skills
                 .iter()
                 .cloned()
-                .map(|skill| SkillBox::new(Some(skill), SkillSource::SkillTree, Box::new(|_| false)).wrap())
+                .flat_map(|skill| {
+                    // Skills that can be leveled up get a small button next to them.
+                    let level_up_button = skill.upgradable.then(|| {
+                        ButtonBuilder::new()
+                            .with_text("+")
+                            .with_event(UserEvent::RaiseSkill(skill.skill_id))
+                            .with_width_bound(dimension_bound!(20))
+                            .build()
+                            .wrap()
+                    });
+
+                    std::iter::once(SkillBox::new(Some(skill), SkillSource::SkillTree, Box::new(|_| false), None).wrap())
+                        .chain(level_up_button)
+                })
                 .collect()
         };
 
@@ -0,0 +1,171 @@
+use korangar_interface::elements::{
+    ButtonBuilder, ContainerState, Element, ElementCell, ElementState, ElementWrap, Focus, Text, WeakElementCell,
+};
+use korangar_interface::event::{ChangeEvent, HoverInformation};
+use korangar_interface::layout::PlacementResolver;
+use korangar_interface::state::{PlainRemote, Remote};
+use korangar_interface::{dimension_bound, size_bound};
+use ragnarok_packets::StatType;
+use wgpu::RenderPass;
+
+use crate::graphics::{InterfaceRenderer, Renderer};
+use crate::input::{MouseInputMode, UserEvent};
+use crate::interface::application::InterfaceSettings;
+use crate::interface::layout::{ScreenClip, ScreenPosition, ScreenSize};
+use crate::interface::theme::InterfaceTheme;
+use crate::interface::windows::CharacterStats;
+
+pub struct StatsView {
+    stats: PlainRemote<CharacterStats>,
+    state: ContainerState<InterfaceSettings>,
+}
+
+impl StatsView {
+    pub fn new(stats: PlainRemote<CharacterStats>) -> Self {
+        let elements = {
+            let stats = stats.get();
+
+            let mut elements = vec![Text::default().with_text(format!("status points: {}", stats.status_points)).wrap()];
+
+            for stat_type in CharacterStats::STAT_TYPES {
+                elements.extend(Self::stat_to_elements(&stats, stat_type));
+            }
+
+            elements
+        };
+
+        Self {
+            stats,
+            state: ContainerState::new(elements),
+        }
+    }
+
+    fn stat_to_elements(stats: &CharacterStats, stat_type: StatType) -> [ElementCell<InterfaceSettings>; 2] {
+        let stat = stats.get_stat(stat_type);
+
+        let name = match stat_type {
+            StatType::Strength => "str",
+            StatType::Agility => "agi",
+            StatType::Vitality => "vit",
+            StatType::Intelligence => "int",
+            StatType::Dexterity => "dex",
+            StatType::Luck => "luk",
+        };
+
+        // A cost of zero means that the stat is already at its maximum.
+        let disabled = stat.cost == 0 || stats.status_points < stat.cost as u32;
+
+        [
+            Text::default()
+                .with_text(format!("{name} {} + {} ({})", stat.value, stat.bonus, stat.cost))
+                .with_width(dimension_bound!(75%))
+                .wrap(),
+            ButtonBuilder::new()
+                .with_text("+")
+                .with_event(UserEvent::RaiseStat(stat_type))
+                .with_disabled_selector(move || disabled)
+                .with_width_bound(dimension_bound!(!))
+                .build()
+                .wrap(),
+        ]
+    }
+}
+
+impl Element<InterfaceSettings> for StatsView {
+    fn get_state(&self) -> &ElementState<InterfaceSettings> {
+        &self.state.state
+    }
+
+    fn get_state_mut(&mut self) -> &mut ElementState<InterfaceSettings> {
+        &mut self.state.state
+    }
+
+    fn link_back(&mut self, weak_self: WeakElementCell<InterfaceSettings>, weak_parent: Option<WeakElementCell<InterfaceSettings>>) {
+        self.state.link_back(weak_self, weak_parent);
+    }
+
+    fn is_focusable(&self) -> bool {
+        self.state.is_focusable::<false>()
+    }
+
+    fn focus_next(
+        &self,
+        self_cell: ElementCell<InterfaceSettings>,
+        caller_cell: Option<ElementCell<InterfaceSettings>>,
+        focus: Focus,
+    ) -> Option<ElementCell<InterfaceSettings>> {
+        self.state.focus_next::<false>(self_cell, caller_cell, focus)
+    }
+
+    fn restore_focus(&self, self_cell: ElementCell<InterfaceSettings>) -> Option<ElementCell<InterfaceSettings>> {
+        self.state.restore_focus(self_cell)
+    }
+
+    fn resolve(
+        &mut self,
+        placement_resolver: &mut PlacementResolver<InterfaceSettings>,
+        application: &InterfaceSettings,
+        theme: &InterfaceTheme,
+    ) {
+        self.state.resolve(
+            placement_resolver,
+            application,
+            theme,
+            &size_bound!(100%, ?),
+            ScreenSize::default(),
+        );
+    }
+
+    fn update(&mut self) -> Option<ChangeEvent> {
+        if self.stats.consume_changed() {
+            let weak_parent = self.state.state.parent_element.take();
+            let weak_self = self.state.state.self_element.take().unwrap();
+
+            *self = Self::new(self.stats.clone());
+            // important: link back after creating elements, otherwise focus navigation and
+            // scrolling would break
+            self.link_back(weak_self, weak_parent);
+
+            return Some(ChangeEvent::RESOLVE_WINDOW);
+        }
+
+        None
+    }
+
+    fn hovered_element(&self, mouse_position: ScreenPosition, mouse_mode: &MouseInputMode) -> HoverInformation<InterfaceSettings> {
+        match mouse_mode {
+            MouseInputMode::None => self.state.hovered_element(mouse_position, mouse_mode, false),
+            _ => HoverInformation::Missed,
+        }
+    }
+
+    fn render(
+        &self,
+        render_target: &mut <InterfaceRenderer as Renderer>::Target,
+        render_pass: &mut RenderPass,
+        renderer: &InterfaceRenderer,
+        application: &InterfaceSettings,
+        theme: &InterfaceTheme,
+        parent_position: ScreenPosition,
+        screen_clip: ScreenClip,
+        hovered_element: Option<&dyn Element<InterfaceSettings>>,
+        focused_element: Option<&dyn Element<InterfaceSettings>>,
+        mouse_mode: &MouseInputMode,
+        second_theme: bool,
+    ) {
+        let mut renderer =
+            self.state
+                .state
+                .element_renderer(render_target, render_pass, renderer, application, parent_position, screen_clip);
+
+        self.state.render(
+            &mut renderer,
+            application,
+            theme,
+            hovered_element,
+            focused_element,
+            mouse_mode,
+            second_theme,
+        );
+    }
+}
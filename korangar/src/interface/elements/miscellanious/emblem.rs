@@ -0,0 +1,77 @@
+use std::sync::Arc;
+
+use derive_new::new;
+use korangar_interface::application::SizeTraitExt;
+use korangar_interface::elements::{Element, ElementState};
+use korangar_interface::event::HoverInformation;
+use korangar_interface::layout::PlacementResolver;
+use korangar_interface::size_bound;
+use wgpu::RenderPass;
+
+use crate::graphics::{Color, InterfaceRenderer, Renderer, SpriteRenderer, Texture};
+use crate::input::MouseInputMode;
+use crate::interface::application::InterfaceSettings;
+use crate::interface::layout::{ScreenClip, ScreenPosition, ScreenSize};
+use crate::interface::theme::InterfaceTheme;
+use crate::loaders::Scaling;
+
+/// Displays a guild emblem.
+#[derive(new)]
+pub struct EmblemBox {
+    texture: Arc<Texture>,
+    #[new(default)]
+    state: ElementState<InterfaceSettings>,
+}
+
+impl Element<InterfaceSettings> for EmblemBox {
+    fn get_state(&self) -> &ElementState<InterfaceSettings> {
+        &self.state
+    }
+
+    fn get_state_mut(&mut self) -> &mut ElementState<InterfaceSettings> {
+        &mut self.state
+    }
+
+    fn resolve(
+        &mut self,
+        placement_resolver: &mut PlacementResolver<InterfaceSettings>,
+        _application: &InterfaceSettings,
+        _theme: &InterfaceTheme,
+    ) {
+        self.state.resolve(placement_resolver, &size_bound!(24, 24));
+    }
+
+    fn hovered_element(&self, _mouse_position: ScreenPosition, _mouse_mode: &MouseInputMode) -> HoverInformation<InterfaceSettings> {
+        HoverInformation::Missed
+    }
+
+    fn render(
+        &self,
+        render_target: &mut <InterfaceRenderer as Renderer>::Target,
+        render_pass: &mut RenderPass,
+        renderer: &InterfaceRenderer,
+        application: &InterfaceSettings,
+        _theme: &InterfaceTheme,
+        parent_position: ScreenPosition,
+        screen_clip: ScreenClip,
+        _hovered_element: Option<&dyn Element<InterfaceSettings>>,
+        _focused_element: Option<&dyn Element<InterfaceSettings>>,
+        _mouse_mode: &MouseInputMode,
+        _second_theme: bool,
+    ) {
+        let renderer = self
+            .state
+            .element_renderer(render_target, render_pass, renderer, application, parent_position, screen_clip);
+
+        renderer.renderer.render_sprite(
+            renderer.render_target,
+            renderer.render_pass,
+            &self.texture,
+            renderer.position,
+            ScreenSize::uniform(24.0).scaled(Scaling::new(application.get_scaling_factor())),
+            renderer.clip,
+            Color::monochrome_u8(255),
+            false,
+        );
+    }
+}
@@ -5,26 +5,55 @@ use korangar_interface::event::{ClickAction, HoverInformation};
 use korangar_interface::layout::PlacementResolver;
 use korangar_interface::size_bound;
 use korangar_networking::{InventoryItem, InventoryItemDetails};
+use ragnarok_packets::{InventoryIndex, StorageIndex};
 use wgpu::RenderPass;
 
 use crate::graphics::{Color, InterfaceRenderer, Renderer, SpriteRenderer};
-use crate::input::MouseInputMode;
+use crate::input::{MouseInputMode, UserEvent};
 use crate::interface::application::InterfaceSettings;
 use crate::interface::layout::{CornerRadius, ScreenClip, ScreenPosition, ScreenSize};
 use crate::interface::resource::{ItemSource, Move, PartialMove};
 use crate::interface::theme::InterfaceTheme;
 use crate::loaders::{FontSize, ResourceMetadata, Scaling};
 
+/// Index of an item shown in an [`ItemBox`], which decides how the item is
+/// moved and used.
+pub trait ItemBoxIndex: Clone + 'static {
+    fn start_move(source: ItemSource, item: InventoryItem<ResourceMetadata, Self>) -> PartialMove;
+
+    fn use_item(&self) -> Option<UserEvent>;
+}
+
+impl ItemBoxIndex for InventoryIndex {
+    fn start_move(source: ItemSource, item: InventoryItem<ResourceMetadata, Self>) -> PartialMove {
+        PartialMove::Item { source, item }
+    }
+
+    fn use_item(&self) -> Option<UserEvent> {
+        Some(UserEvent::UseItem(*self))
+    }
+}
+
+impl ItemBoxIndex for StorageIndex {
+    fn start_move(_source: ItemSource, item: InventoryItem<ResourceMetadata, Self>) -> PartialMove {
+        PartialMove::StorageItem { item }
+    }
+
+    fn use_item(&self) -> Option<UserEvent> {
+        None
+    }
+}
+
 #[derive(new)]
-pub struct ItemBox {
-    item: Option<InventoryItem<ResourceMetadata>>,
+pub struct ItemBox<Index = InventoryIndex> {
+    item: Option<InventoryItem<ResourceMetadata, Index>>,
     source: ItemSource,
     highlight: Box<dyn Fn(&MouseInputMode) -> bool>,
     #[new(default)]
     state: ElementState<InterfaceSettings>,
 }
 
-impl Element<InterfaceSettings> for ItemBox {
+impl<Index: ItemBoxIndex> Element<InterfaceSettings> for ItemBox<Index> {
     fn get_state(&self) -> &ElementState<InterfaceSettings> {
         &self.state
     }
@@ -47,7 +76,7 @@ impl Element<InterfaceSettings> for ItemBox {
     }
 
     fn hovered_element(&self, mouse_position: ScreenPosition, mouse_mode: &MouseInputMode) -> HoverInformation<InterfaceSettings> {
-        match self.item.is_some() || matches!(mouse_mode, MouseInputMode::MoveItem(..)) {
+        match self.item.is_some() || matches!(mouse_mode, MouseInputMode::MoveItem(..) | MouseInputMode::MoveStorageItem(..)) {
             true => self.state.hovered_element(mouse_position),
             false => HoverInformation::Missed,
         }
@@ -55,25 +84,34 @@ impl Element<InterfaceSettings> for ItemBox {
 
     fn left_click(&mut self, _force_update: &mut bool) -> Vec<ClickAction<InterfaceSettings>> {
         if let Some(item) = &self.item {
-            return vec![ClickAction::Move(PartialMove::Item {
-                source: self.source,
-                item: item.clone(),
-            })];
+            return vec![ClickAction::Move(Index::start_move(self.source, item.clone()))];
         }
 
         Vec::new()
     }
 
-    fn drop_resource(&mut self, drop_resource: PartialMove) -> Option<Move> {
-        let PartialMove::Item { source, item } = drop_resource else {
-            return None;
-        };
+    fn right_click(&mut self, _force_update: &mut bool) -> Vec<ClickAction<InterfaceSettings>> {
+        if let Some(item) = &self.item
+            && self.source == ItemSource::Inventory
+            && matches!(item.details, InventoryItemDetails::Regular { .. })
+            && let Some(event) = item.index.use_item()
+        {
+            return vec![ClickAction::Custom(event)];
+        }
 
-        (source != self.source).then_some(Move::Item {
-            source,
-            destination: self.source,
-            item,
-        })
+        Vec::new()
+    }
+
+    fn drop_resource(&mut self, drop_resource: PartialMove) -> Option<Move> {
+        match drop_resource {
+            PartialMove::Item { source, item } => (source != self.source).then_some(Move::Item {
+                source,
+                destination: self.source,
+                item,
+            }),
+            PartialMove::StorageItem { item } => (self.source == ItemSource::Inventory).then_some(Move::StorageItem { item }),
+            PartialMove::Skill { .. } => None,
+        }
     }
 
     fn render(
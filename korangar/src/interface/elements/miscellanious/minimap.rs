@@ -0,0 +1,131 @@
+use cgmath::Vector2;
+use derive_new::new;
+use korangar_interface::elements::{Element, ElementState};
+use korangar_interface::event::{ChangeEvent, ClickAction, HoverInformation};
+use korangar_interface::layout::PlacementResolver;
+use korangar_interface::size_bound;
+use korangar_interface::state::{PlainRemote, Remote};
+use wgpu::RenderPass;
+
+use crate::graphics::{Color, InterfaceRenderer, Renderer, SpriteRenderer};
+use crate::input::{MouseInputMode, UserEvent};
+use crate::interface::application::InterfaceSettings;
+use crate::interface::layout::{CornerRadius, ScreenClip, ScreenPosition, ScreenSize};
+use crate::interface::theme::InterfaceTheme;
+use crate::interface::windows::Minimap;
+
+/// Displays the minimap of the current map. Clicking on it moves the player to
+/// the clicked tile.
+#[derive(new)]
+pub struct MinimapView {
+    minimap: PlainRemote<Minimap>,
+    #[new(default)]
+    state: ElementState<InterfaceSettings>,
+}
+
+impl Element<InterfaceSettings> for MinimapView {
+    fn get_state(&self) -> &ElementState<InterfaceSettings> {
+        &self.state
+    }
+
+    fn get_state_mut(&mut self) -> &mut ElementState<InterfaceSettings> {
+        &mut self.state
+    }
+
+    fn is_focusable(&self) -> bool {
+        false
+    }
+
+    fn resolve(
+        &mut self,
+        placement_resolver: &mut PlacementResolver<InterfaceSettings>,
+        _application: &InterfaceSettings,
+        _theme: &InterfaceTheme,
+    ) {
+        self.state.resolve(placement_resolver, &size_bound!(200, 200));
+    }
+
+    fn update(&mut self) -> Option<ChangeEvent> {
+        self.minimap.consume_changed().then_some(ChangeEvent::RENDER_WINDOW)
+    }
+
+    fn hovered_element(&self, mouse_position: ScreenPosition, _mouse_mode: &MouseInputMode) -> HoverInformation<InterfaceSettings> {
+        self.state.hovered_element(mouse_position)
+    }
+
+    fn left_click(&mut self, _force_update: &mut bool) -> Vec<ClickAction<InterfaceSettings>> {
+        let mouse_position = self.state.mouse_position.get();
+        let fraction = Vector2::new(
+            mouse_position.left / self.state.cached_size.width,
+            mouse_position.top / self.state.cached_size.height,
+        );
+
+        match self.minimap.get().fraction_to_tile(fraction) {
+            Some(tile) => vec![ClickAction::Custom(UserEvent::RequestPlayerMove(tile))],
+            None => Vec::new(),
+        }
+    }
+
+    fn render(
+        &self,
+        render_target: &mut <InterfaceRenderer as Renderer>::Target,
+        render_pass: &mut RenderPass,
+        renderer: &InterfaceRenderer,
+        application: &InterfaceSettings,
+        theme: &InterfaceTheme,
+        parent_position: ScreenPosition,
+        screen_clip: ScreenClip,
+        _hovered_element: Option<&dyn Element<InterfaceSettings>>,
+        _focused_element: Option<&dyn Element<InterfaceSettings>>,
+        _mouse_mode: &MouseInputMode,
+        _second_theme: bool,
+    ) {
+        let mut renderer = self
+            .state
+            .element_renderer(render_target, render_pass, renderer, application, parent_position, screen_clip);
+
+        renderer.render_background(CornerRadius::uniform(5.0), theme.button.background_color.get());
+
+        let minimap = self.minimap.get();
+        let size = self.state.cached_size;
+
+        if let Some(texture) = &minimap.texture {
+            renderer.renderer.render_sprite(
+                renderer.render_target,
+                renderer.render_pass,
+                texture,
+                renderer.position,
+                size,
+                renderer.clip,
+                Color::monochrome_u8(255),
+                true,
+            );
+        }
+
+        let scaling = application.get_scaling_factor();
+        let mut render_dot = |position: Vector2<usize>, dot_size: f32, color: Color| {
+            // The corner radius is scaled by the renderer but the size is not.
+            let corner_radius = CornerRadius::uniform(dot_size / 2.0);
+            let fraction = minimap.tile_to_fraction(position);
+            let dot_size = ScreenSize::uniform(dot_size * scaling);
+            let position = ScreenPosition {
+                left: fraction.x * size.width - dot_size.width / 2.0,
+                top: fraction.y * size.height - dot_size.height / 2.0,
+            };
+
+            renderer.render_rectangle(position, dot_size, corner_radius, color);
+        };
+
+        for icon in &minimap.icons {
+            render_dot(icon.position, 4.0, icon.color);
+        }
+
+        for marker in &minimap.markers {
+            render_dot(marker.position, 7.0, marker.color);
+        }
+
+        if let Some(player_position) = minimap.player_position {
+            render_dot(player_position, 6.0, Color::rgb_u8(255, 255, 255));
+        }
+    }
+}
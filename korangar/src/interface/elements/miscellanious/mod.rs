@@ -1,7 +1,11 @@
 mod chat;
+mod emblem;
 mod item;
+mod minimap;
 mod skill;
 
 pub use self::chat::ChatBuilder;
+pub use self::emblem::EmblemBox;
 pub use self::item::ItemBox;
+pub use self::minimap::MinimapView;
 pub use self::skill::SkillBox;
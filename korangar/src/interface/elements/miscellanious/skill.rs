@@ -1,18 +1,19 @@
 use derive_new::new;
 use korangar_interface::application::FontSizeTrait;
 use korangar_interface::elements::{Element, ElementState};
-use korangar_interface::event::{ClickAction, HoverInformation};
+use korangar_interface::event::{ChangeEvent, ClickAction, HoverInformation};
 use korangar_interface::layout::PlacementResolver;
 use korangar_interface::size_bound;
+use korangar_interface::state::{PlainRemote, Remote};
 use wgpu::RenderPass;
 
 use crate::graphics::{Color, InterfaceRenderer, Renderer};
 use crate::input::MouseInputMode;
 use crate::interface::application::InterfaceSettings;
-use crate::interface::layout::{CornerRadius, ScreenClip, ScreenPosition};
+use crate::interface::layout::{CornerRadius, ScreenClip, ScreenPosition, ScreenSize};
 use crate::interface::resource::{Move, PartialMove, SkillSource};
 use crate::interface::theme::InterfaceTheme;
-use crate::inventory::Skill;
+use crate::inventory::{Skill, SkillCooldowns};
 use crate::loaders::FontSize;
 
 #[derive(new)]
@@ -20,6 +21,7 @@ pub struct SkillBox {
     skill: Option<Skill>,
     source: SkillSource,
     highlight: Box<dyn Fn(&MouseInputMode) -> bool>,
+    cooldowns: Option<PlainRemote<SkillCooldowns>>,
     #[new(default)]
     state: ElementState<InterfaceSettings>,
 }
@@ -46,6 +48,12 @@ impl Element<InterfaceSettings> for SkillBox {
         self.state.resolve(placement_resolver, &size_bound!(30, 30));
     }
 
+    fn update(&mut self) -> Option<ChangeEvent> {
+        let cooldowns = self.cooldowns.as_mut()?;
+
+        cooldowns.consume_changed().then_some(ChangeEvent::RENDER_WINDOW)
+    }
+
     fn hovered_element(&self, mouse_position: ScreenPosition, mouse_mode: &MouseInputMode) -> HoverInformation<InterfaceSettings> {
         match self.skill.is_some() || matches!(mouse_mode, MouseInputMode::MoveSkill(..)) {
             true => self.state.hovered_element(mouse_position),
@@ -129,6 +137,41 @@ impl Element<InterfaceSettings> for SkillBox {
                 Color::monochrome_u8(255),
                 FontSize::new(15.0),
             );
+
+            let remaining = self
+                .cooldowns
+                .as_ref()
+                .and_then(|cooldowns| cooldowns.get().get_remaining(skill.skill_id));
+
+            if let Some((fraction, remaining_time)) = remaining {
+                // Darken the part of the slot that is still on cooldown, so the overlay
+                // shrinks from the top as the skill becomes usable again.
+                let size = renderer.size;
+                let overlay_height = size.height * fraction;
+
+                renderer.render_rectangle(
+                    ScreenPosition::only_top(size.height - overlay_height),
+                    ScreenSize {
+                        width: size.width,
+                        height: overlay_height,
+                    },
+                    CornerRadius::uniform(5.0),
+                    Color::rgba_u8(0, 0, 0, 150),
+                );
+
+                // Text offsets are scaled by the renderer, so they are relative to the
+                // unscaled size of the box.
+                let text = format!("{}", remaining_time.div_ceil(1000));
+                renderer.render_text(
+                    &text,
+                    ScreenPosition {
+                        left: 30.0 - text.len() as f32 * 8.0,
+                        top: 15.0,
+                    },
+                    Color::monochrome_u8(255),
+                    FontSize::new(15.0),
+                );
+            }
         }
     }
 }
@@ -2,9 +2,10 @@ use korangar_interface::application::{FontSizeTrait, SizeTraitExt};
 use korangar_interface::elements::{Element, ElementState};
 use korangar_interface::layout::PlacementResolver;
 use korangar_interface::size_bound;
-use korangar_networking::{SellItem, ShopItem};
+use korangar_networking::{InventoryItem, SellItem, ShopItem, VendingItem};
 use wgpu::RenderPass;
 
+use super::StoreListing;
 use crate::graphics::{Color, InterfaceRenderer, Renderer, SpriteRenderer};
 use crate::input::MouseInputMode;
 use crate::interface::application::InterfaceSettings;
@@ -34,6 +35,30 @@ impl ItemResourceProvider for SellItem<(ResourceMetadata, u16)> {
     }
 }
 
+impl ItemResourceProvider for VendingItem<ResourceMetadata> {
+    fn get_resource_metadata(&self) -> &ResourceMetadata {
+        &self.metadata
+    }
+}
+
+impl ItemResourceProvider for VendingItem<(ResourceMetadata, u16)> {
+    fn get_resource_metadata(&self) -> &ResourceMetadata {
+        &self.metadata.0
+    }
+}
+
+impl ItemResourceProvider for InventoryItem<ResourceMetadata> {
+    fn get_resource_metadata(&self) -> &ResourceMetadata {
+        &self.metadata
+    }
+}
+
+impl ItemResourceProvider for StoreListing {
+    fn get_resource_metadata(&self) -> &ResourceMetadata {
+        &self.metadata
+    }
+}
+
 pub struct ItemDisplay<Item, Quantity> {
     item: Item,
     get_quantity: Quantity,
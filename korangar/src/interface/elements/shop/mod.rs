@@ -4,7 +4,11 @@ mod display;
 mod entry;
 mod sell;
 mod sell_cart;
+mod store_listing;
+mod store_setup;
 mod sum;
+mod vending;
+mod vending_cart;
 
 pub use self::buy::BuyContainer;
 pub use self::buy_cart::BuyCartContainer;
@@ -12,4 +16,8 @@ pub use self::display::{ItemDisplay, ItemResourceProvider};
 pub use self::entry::{ShopEntry, ShopEntryOperation};
 pub use self::sell::SellContainer;
 pub use self::sell_cart::SellCartContainer;
+pub use self::store_listing::StoreListingContainer;
+pub use self::store_setup::{StoreListing, StoreSetupContainer};
 pub use self::sum::CartSum;
+pub use self::vending::VendingContainer;
+pub use self::vending_cart::VendingCartContainer;
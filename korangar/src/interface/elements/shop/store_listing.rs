@@ -0,0 +1,260 @@
+use korangar_interface::application::SizeTraitExt;
+use korangar_interface::elements::{
+    ButtonBuilder, ContainerState, Element, ElementCell, ElementState, ElementWrap, Focus, WeakElementCell,
+};
+use korangar_interface::event::{ChangeEvent, ClickAction, HoverInformation};
+use korangar_interface::layout::PlacementResolver;
+use korangar_interface::state::{PlainRemote, PlainTrackedState, Remote, TrackedState, TrackedStateClone, TrackedStateExt};
+use korangar_interface::{dimension_bound, size_bound};
+use korangar_networking::StoreType;
+use num::Integer;
+use ragnarok_packets::{BuyingStoreItemInformation, VendingShopItemInformation};
+use wgpu::RenderPass;
+
+use super::{CartSum, StoreListing};
+use crate::graphics::{InterfaceRenderer, Renderer};
+use crate::input::{MouseInputMode, UserEvent};
+use crate::interface::application::InterfaceSettings;
+use crate::interface::elements::{ShopEntry, ShopEntryOperation};
+use crate::interface::layout::{ScreenClip, ScreenPosition, ScreenSize};
+use crate::interface::theme::InterfaceTheme;
+
+/// Items that the player is about to put up in their own vending shop or
+/// buying store.
+pub struct StoreListingContainer {
+    store_type: StoreType,
+    shop_name: PlainTrackedState<String>,
+    zeny_limit: PlainTrackedState<String>,
+    listing: PlainTrackedState<Vec<StoreListing>>,
+    listing_remote: PlainRemote<Vec<StoreListing>>,
+    state: ContainerState<InterfaceSettings>,
+}
+
+impl StoreListingContainer {
+    pub fn new(
+        store_type: StoreType,
+        shop_name: PlainTrackedState<String>,
+        zeny_limit: PlainTrackedState<String>,
+        listing: PlainTrackedState<Vec<StoreListing>>,
+    ) -> Self {
+        let mut elements = listing
+            .get()
+            .iter()
+            .enumerate()
+            .map(|(index, item)| {
+                ShopEntry::new(
+                    item.clone(),
+                    listing.clone(),
+                    ShopEntryOperation::RemoveFromCart,
+                    index.is_odd(),
+                    |item| Some(item.amount as usize),
+                    |item, listing, amount| {
+                        listing.mutate(|listing| {
+                            let listed_item = listing.iter_mut().find(|listed_item| listed_item.index == item.index).unwrap();
+
+                            listed_item.amount = listed_item.amount.saturating_sub(amount);
+
+                            if listed_item.amount == 0 {
+                                listing.retain(|listed_item| listed_item.index != item.index);
+                            }
+                        });
+                    },
+                    |item, listing, amount| {
+                        listing
+                            .get()
+                            .iter()
+                            .find(|listed_item| listed_item.index == item.index)
+                            .map(|listed_item| amount.saturating_sub(listed_item.amount) == 0)
+                            .unwrap_or(true)
+                    },
+                )
+            })
+            .map(ElementWrap::wrap)
+            .collect::<Vec<ElementCell<InterfaceSettings>>>();
+
+        {
+            let shop_name = shop_name.clone();
+            let zeny_limit = zeny_limit.clone();
+            let listing = listing.clone();
+
+            elements.insert(
+                0,
+                ButtonBuilder::new()
+                    .with_text("open")
+                    .with_event(move || {
+                        let shop_name = shop_name.cloned();
+                        let listing = listing.get();
+
+                        if shop_name.is_empty() || listing.is_empty() {
+                            return Vec::new();
+                        }
+
+                        let event = match store_type {
+                            StoreType::Vending => {
+                                let items = listing
+                                    .iter()
+                                    .map(|item| VendingShopItemInformation {
+                                        index: item.index,
+                                        amount: item.amount,
+                                        price: item.price,
+                                    })
+                                    .collect();
+
+                                UserEvent::OpenVendingShop { shop_name, items }
+                            }
+                            StoreType::Buying => {
+                                let Ok(zeny_limit) = zeny_limit.get().trim().parse() else {
+                                    return Vec::new();
+                                };
+
+                                let items = listing
+                                    .iter()
+                                    .map(|item| BuyingStoreItemInformation {
+                                        item_id: item.item_id,
+                                        amount: item.amount,
+                                        price: item.price,
+                                    })
+                                    .collect();
+
+                                UserEvent::OpenBuyingStore {
+                                    shop_name,
+                                    zeny_limit,
+                                    items,
+                                }
+                            }
+                        };
+
+                        vec![ClickAction::Custom(event)]
+                    })
+                    .with_width_bound(dimension_bound!(50%))
+                    .build()
+                    .wrap(),
+            );
+        }
+
+        elements.insert(
+            1,
+            ButtonBuilder::new()
+                .with_text("cancel")
+                .with_event(move || vec![ClickAction::Custom(UserEvent::CloseStoreSetup)])
+                .with_width_bound(dimension_bound!(!))
+                .build()
+                .wrap(),
+        );
+
+        elements.insert(0, CartSum::new(&listing, |item| item.price.0, |item| item.amount as u32).wrap());
+
+        let listing_remote = listing.new_remote();
+        let state = ContainerState::new(elements);
+
+        Self {
+            store_type,
+            shop_name,
+            zeny_limit,
+            listing,
+            listing_remote,
+            state,
+        }
+    }
+}
+
+impl Element<InterfaceSettings> for StoreListingContainer {
+    fn get_state(&self) -> &ElementState<InterfaceSettings> {
+        &self.state.state
+    }
+
+    fn get_state_mut(&mut self) -> &mut ElementState<InterfaceSettings> {
+        &mut self.state.state
+    }
+
+    fn link_back(&mut self, weak_self: WeakElementCell<InterfaceSettings>, weak_parent: Option<WeakElementCell<InterfaceSettings>>) {
+        self.state.link_back(weak_self, weak_parent);
+    }
+
+    fn is_focusable(&self) -> bool {
+        self.state.is_focusable::<false>()
+    }
+
+    fn focus_next(
+        &self,
+        self_cell: ElementCell<InterfaceSettings>,
+        caller_cell: Option<ElementCell<InterfaceSettings>>,
+        focus: Focus,
+    ) -> Option<ElementCell<InterfaceSettings>> {
+        self.state.focus_next::<false>(self_cell, caller_cell, focus)
+    }
+
+    fn restore_focus(&self, self_cell: ElementCell<InterfaceSettings>) -> Option<ElementCell<InterfaceSettings>> {
+        self.state.restore_focus(self_cell)
+    }
+
+    fn resolve(
+        &mut self,
+        placement_resolver: &mut PlacementResolver<InterfaceSettings>,
+        application: &InterfaceSettings,
+        theme: &InterfaceTheme,
+    ) {
+        let size_bound = &size_bound!(100%, ?);
+        self.state
+            .resolve(placement_resolver, application, theme, size_bound, ScreenSize::zero());
+    }
+
+    fn update(&mut self) -> Option<ChangeEvent> {
+        if self.listing_remote.consume_changed() {
+            let weak_parent = self.state.state.parent_element.take();
+            let weak_self = self.state.state.self_element.take().unwrap();
+
+            *self = Self::new(
+                self.store_type,
+                self.shop_name.clone(),
+                self.zeny_limit.clone(),
+                self.listing.clone(),
+            );
+            // important: link back after creating elements, otherwise focus navigation and
+            // scrolling would break
+            self.link_back(weak_self, weak_parent);
+
+            return Some(ChangeEvent::RESOLVE_WINDOW);
+        }
+
+        None
+    }
+
+    fn hovered_element(&self, mouse_position: ScreenPosition, mouse_mode: &MouseInputMode) -> HoverInformation<InterfaceSettings> {
+        match mouse_mode {
+            MouseInputMode::MoveItem(..) => self.state.state.hovered_element(mouse_position),
+            MouseInputMode::None => self.state.hovered_element(mouse_position, mouse_mode, false),
+            _ => HoverInformation::Missed,
+        }
+    }
+
+    fn render(
+        &self,
+        render_target: &mut <InterfaceRenderer as Renderer>::Target,
+        render_pass: &mut RenderPass,
+        renderer: &InterfaceRenderer,
+        application: &InterfaceSettings,
+        theme: &InterfaceTheme,
+        parent_position: ScreenPosition,
+        screen_clip: ScreenClip,
+        hovered_element: Option<&dyn Element<InterfaceSettings>>,
+        focused_element: Option<&dyn Element<InterfaceSettings>>,
+        mouse_mode: &MouseInputMode,
+        second_theme: bool,
+    ) {
+        let mut renderer =
+            self.state
+                .state
+                .element_renderer(render_target, render_pass, renderer, application, parent_position, screen_clip);
+
+        self.state.render(
+            &mut renderer,
+            application,
+            theme,
+            hovered_element,
+            focused_element,
+            mouse_mode,
+            second_theme,
+        );
+    }
+}
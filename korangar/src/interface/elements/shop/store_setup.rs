@@ -0,0 +1,201 @@
+use korangar_interface::application::SizeTraitExt;
+use korangar_interface::elements::{ContainerState, Element, ElementCell, ElementState, ElementWrap, Focus, WeakElementCell};
+use korangar_interface::event::HoverInformation;
+use korangar_interface::layout::PlacementResolver;
+use korangar_interface::size_bound;
+use korangar_interface::state::{PlainTrackedState, TrackedState, TrackedStateExt};
+use korangar_networking::{InventoryItem, InventoryItemDetails, StoreType};
+use num::Integer;
+use ragnarok_packets::{InventoryIndex, ItemId, Price};
+use wgpu::RenderPass;
+
+use crate::graphics::{InterfaceRenderer, Renderer};
+use crate::input::MouseInputMode;
+use crate::interface::application::InterfaceSettings;
+use crate::interface::elements::{ShopEntry, ShopEntryOperation};
+use crate::interface::layout::{ScreenClip, ScreenPosition, ScreenSize};
+use crate::interface::theme::InterfaceTheme;
+use crate::loaders::ResourceMetadata;
+
+/// Item that the player puts up in their own vending shop or buying store.
+#[derive(Debug, Clone)]
+pub struct StoreListing {
+    pub metadata: ResourceMetadata,
+    pub index: InventoryIndex,
+    pub item_id: ItemId,
+    pub price: Price,
+    pub amount: u16,
+}
+
+fn parse_price(price: &PlainTrackedState<String>) -> Option<Price> {
+    price.get().trim().parse().ok().filter(|price| *price > 0).map(Price)
+}
+
+fn held_amount(item: &InventoryItem<ResourceMetadata>) -> u16 {
+    match &item.details {
+        InventoryItemDetails::Regular { amount, .. } => *amount,
+        InventoryItemDetails::Equippable { .. } => 1,
+    }
+}
+
+/// Items that the player can put up in their own vending shop or buying
+/// store. Items are listed for the price that is currently entered.
+pub struct StoreSetupContainer {
+    state: ContainerState<InterfaceSettings>,
+}
+
+impl StoreSetupContainer {
+    pub fn new(
+        store_type: StoreType,
+        slot_count: usize,
+        items: &[InventoryItem<ResourceMetadata>],
+        price: PlainTrackedState<String>,
+        listing: PlainTrackedState<Vec<StoreListing>>,
+    ) -> Self {
+        let elements = items
+            .iter()
+            .enumerate()
+            .map(|(index, item)| {
+                let disabled_price = price.clone();
+                let price = price.clone();
+
+                ShopEntry::new(
+                    item.clone(),
+                    listing.clone(),
+                    ShopEntryOperation::AddToCart,
+                    index.is_odd(),
+                    move |item| match store_type {
+                        StoreType::Vending => Some(held_amount(item) as usize),
+                        // A buying store can ask for more items than the player has.
+                        StoreType::Buying => None,
+                    },
+                    move |item, listing, amount: u16| {
+                        let Some(price) = parse_price(&price) else {
+                            return;
+                        };
+
+                        listing.mutate(|listing| {
+                            if let Some(listed_item) = listing.iter_mut().find(|listed_item| listed_item.index == item.index) {
+                                listed_item.amount = listed_item.amount.saturating_add(amount);
+                                listed_item.price = price;
+                            } else {
+                                listing.push(StoreListing {
+                                    metadata: item.metadata.clone(),
+                                    index: item.index,
+                                    item_id: item.item_id,
+                                    price,
+                                    amount,
+                                });
+                            }
+                        });
+                    },
+                    move |item, listing, amount| {
+                        if parse_price(&disabled_price).is_none() {
+                            return true;
+                        }
+
+                        let listing = listing.get();
+                        let listed_amount = listing
+                            .iter()
+                            .find(|listed_item| listed_item.index == item.index)
+                            .map(|listed_item| listed_item.amount);
+
+                        if listed_amount.is_none() && listing.len() >= slot_count {
+                            return true;
+                        }
+
+                        match store_type {
+                            StoreType::Vending => held_amount(item).saturating_sub(listed_amount.unwrap_or(0)) < amount,
+                            StoreType::Buying => false,
+                        }
+                    },
+                )
+            })
+            .map(ElementWrap::wrap)
+            .collect::<Vec<ElementCell<InterfaceSettings>>>();
+
+        let state = ContainerState::new(elements);
+
+        Self { state }
+    }
+}
+
+impl Element<InterfaceSettings> for StoreSetupContainer {
+    fn get_state(&self) -> &ElementState<InterfaceSettings> {
+        &self.state.state
+    }
+
+    fn get_state_mut(&mut self) -> &mut ElementState<InterfaceSettings> {
+        &mut self.state.state
+    }
+
+    fn link_back(&mut self, weak_self: WeakElementCell<InterfaceSettings>, weak_parent: Option<WeakElementCell<InterfaceSettings>>) {
+        self.state.link_back(weak_self, weak_parent);
+    }
+
+    fn is_focusable(&self) -> bool {
+        self.state.is_focusable::<false>()
+    }
+
+    fn focus_next(
+        &self,
+        self_cell: ElementCell<InterfaceSettings>,
+        caller_cell: Option<ElementCell<InterfaceSettings>>,
+        focus: Focus,
+    ) -> Option<ElementCell<InterfaceSettings>> {
+        self.state.focus_next::<false>(self_cell, caller_cell, focus)
+    }
+
+    fn restore_focus(&self, self_cell: ElementCell<InterfaceSettings>) -> Option<ElementCell<InterfaceSettings>> {
+        self.state.restore_focus(self_cell)
+    }
+
+    fn resolve(
+        &mut self,
+        placement_resolver: &mut PlacementResolver<InterfaceSettings>,
+        application: &InterfaceSettings,
+        theme: &InterfaceTheme,
+    ) {
+        let size_bound = &size_bound!(100%, ?);
+        self.state
+            .resolve(placement_resolver, application, theme, size_bound, ScreenSize::zero());
+    }
+
+    fn hovered_element(&self, mouse_position: ScreenPosition, mouse_mode: &MouseInputMode) -> HoverInformation<InterfaceSettings> {
+        match mouse_mode {
+            MouseInputMode::MoveItem(..) => self.state.state.hovered_element(mouse_position),
+            MouseInputMode::None => self.state.hovered_element(mouse_position, mouse_mode, false),
+            _ => HoverInformation::Missed,
+        }
+    }
+
+    fn render(
+        &self,
+        render_target: &mut <InterfaceRenderer as Renderer>::Target,
+        render_pass: &mut RenderPass,
+        renderer: &InterfaceRenderer,
+        application: &InterfaceSettings,
+        theme: &InterfaceTheme,
+        parent_position: ScreenPosition,
+        screen_clip: ScreenClip,
+        hovered_element: Option<&dyn Element<InterfaceSettings>>,
+        focused_element: Option<&dyn Element<InterfaceSettings>>,
+        mouse_mode: &MouseInputMode,
+        second_theme: bool,
+    ) {
+        let mut renderer =
+            self.state
+                .state
+                .element_renderer(render_target, render_pass, renderer, application, parent_position, screen_clip);
+
+        self.state.render(
+            &mut renderer,
+            application,
+            theme,
+            hovered_element,
+            focused_element,
+            mouse_mode,
+            second_theme,
+        );
+    }
+}
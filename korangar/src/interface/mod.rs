@@ -4,6 +4,7 @@ pub mod theme;
 pub mod elements;
 pub mod application;
 pub mod cursor;
+pub mod cut_in;
 pub mod dialog;
 pub mod linked;
 pub mod resource;
@@ -1,5 +1,5 @@
 use korangar_networking::InventoryItem;
-use ragnarok_packets::{EquipPosition, HotbarSlot};
+use ragnarok_packets::{EquipPosition, HotbarSlot, StorageIndex};
 
 use crate::inventory::Skill;
 use crate::loaders::ResourceMetadata;
@@ -8,6 +8,9 @@ use crate::loaders::ResourceMetadata;
 pub enum ItemSource {
     Inventory,
     Equipment { position: EquipPosition },
+    Storage,
+    Trade,
+    Mail,
 }
 
 #[derive(Debug, Clone)]
@@ -36,6 +39,10 @@ pub enum PartialMove {
         source: ItemSource,
         item: InventoryItem<ResourceMetadata>,
     },
+    /// Storage items are kept apart since they are indexed differently.
+    StorageItem {
+        item: InventoryItem<ResourceMetadata, StorageIndex>,
+    },
     Skill {
         source: SkillSource,
         skill: Skill,
@@ -49,6 +56,10 @@ pub enum Move {
         destination: ItemSource,
         item: InventoryItem<ResourceMetadata>,
     },
+    /// Take an item out of the storage and put it into the inventory.
+    StorageItem {
+        item: InventoryItem<ResourceMetadata, StorageIndex>,
+    },
     Skill {
         source: SkillSource,
         destination: SkillSource,
@@ -868,6 +868,9 @@ pub struct ChatTheme {
     pub server_color: Mutable<Color, Render>,
     pub error_color: Mutable<Color, Render>,
     pub information_color: Mutable<Color, Render>,
+    pub party_color: Mutable<Color, Render>,
+    pub guild_color: Mutable<Color, Render>,
+    pub whisper_color: Mutable<Color, Render>,
 }
 
 impl ThemeDefault<DefaultMenu> for ChatTheme {
@@ -879,6 +882,9 @@ impl ThemeDefault<DefaultMenu> for ChatTheme {
             server_color: Mutable::new(Color::rgb_u8(255, 255, 210)),
             error_color: Mutable::new(Color::rgb_u8(255, 150, 150)),
             information_color: Mutable::new(Color::rgb_u8(200, 255, 200)),
+            party_color: Mutable::new(Color::rgb_u8(255, 200, 200)),
+            guild_color: Mutable::new(Color::rgb_u8(180, 255, 180)),
+            whisper_color: Mutable::new(Color::rgb_u8(255, 255, 120)),
         }
     }
 }
@@ -892,6 +898,9 @@ impl ThemeDefault<DefaultMain> for ChatTheme {
             server_color: Mutable::new(Color::rgb_u8(255, 255, 210)),
             error_color: Mutable::new(Color::rgb_u8(255, 150, 150)),
             information_color: Mutable::new(Color::rgb_u8(200, 255, 200)),
+            party_color: Mutable::new(Color::rgb_u8(255, 200, 200)),
+            guild_color: Mutable::new(Color::rgb_u8(180, 255, 180)),
+            whisper_color: Mutable::new(Color::rgb_u8(255, 255, 120)),
         }
     }
 }
@@ -1060,12 +1069,14 @@ pub struct StatusBarTheme {
     pub enemy_health_color: Mutable<Color, Nothing>,
     pub spell_point_color: Mutable<Color, Nothing>,
     pub activity_point_color: Mutable<Color, Nothing>,
+    pub cast_time_color: Mutable<Color, Nothing>,
     pub player_bar_width: MutableRange<f32, Render>,
     pub enemy_bar_width: MutableRange<f32, Render>,
     pub health_height: MutableRange<f32, Render>,
     pub enemy_health_height: MutableRange<f32, Render>,
     pub spell_point_height: MutableRange<f32, Render>,
     pub activity_point_height: MutableRange<f32, Render>,
+    pub cast_time_height: MutableRange<f32, Render>,
     pub border_size: MutableRange<ScreenSize, Render>,
     pub gap: MutableRange<f32, Render>,
 }
@@ -1078,12 +1089,14 @@ impl Default for StatusBarTheme {
             enemy_health_color: Mutable::new(Color::rgb_u8(206, 49, 116)),
             spell_point_color: Mutable::new(Color::rgb_u8(0, 129, 163)),
             activity_point_color: Mutable::new(Color::rgb_u8(218, 145, 81)),
+            cast_time_color: Mutable::new(Color::rgb_u8(228, 198, 78)),
             player_bar_width: MutableRange::new(85.0, 20.0, 300.0),
             enemy_bar_width: MutableRange::new(60.0, 20.0, 300.0),
             health_height: MutableRange::new(8.0, 2.0, 30.0),
             enemy_health_height: MutableRange::new(6.0, 2.0, 30.0),
             spell_point_height: MutableRange::new(4.0, 2.0, 30.0),
             activity_point_height: MutableRange::new(4.0, 2.0, 30.0),
+            cast_time_height: MutableRange::new(5.0, 2.0, 30.0),
             border_size: MutableRange::new(
                 ScreenSize { width: 2.0, height: 1.0 },
                 ScreenSize::default(),
@@ -1107,6 +1120,33 @@ impl Default for IndicatorTheme {
     }
 }
 
+#[derive(Serialize, Deserialize, PrototypeElement)]
+pub struct SignboardTheme {
+    pub background_color: Mutable<Color, Nothing>,
+    pub border_color: Mutable<Color, Nothing>,
+    pub foreground_color: Mutable<Color, Nothing>,
+    pub font_size: MutableRange<FontSize, Nothing>,
+    pub border_size: MutableRange<ScreenSize, Render>,
+    pub text_offset: MutableRange<ScreenPosition, Render>,
+}
+
+impl Default for SignboardTheme {
+    fn default() -> Self {
+        Self {
+            background_color: Mutable::new(Color::rgb_u8(250, 240, 200)),
+            border_color: Mutable::new(Color::rgb_u8(120, 90, 50)),
+            foreground_color: Mutable::new(Color::monochrome_u8(30)),
+            font_size: MutableRange::new(FontSize::new(12.0), FontSize::new(6.0), FontSize::new(30.0)),
+            border_size: MutableRange::new(ScreenSize::uniform(1.0), ScreenSize::default(), ScreenSize::uniform(10.0)),
+            text_offset: MutableRange::new(
+                ScreenPosition { left: 6.0, top: 3.0 },
+                ScreenPosition::default(),
+                ScreenPosition::uniform(20.0),
+            ),
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, PrototypeElement)]
 pub struct InterfaceTheme {
     pub button: ButtonTheme,
@@ -1209,6 +1249,7 @@ pub struct GameTheme {
     pub overlay: OverlayTheme,
     pub status_bar: StatusBarTheme,
     pub indicator: IndicatorTheme,
+    pub signboard: SignboardTheme,
     pub cursor: CursorTheme,
 }
 
@@ -9,17 +9,20 @@ use korangar_interface::size_bound;
 use korangar_interface::state::{PlainTrackedState, TrackedState, TrackedStateBinary, TrackedStateClone, TrackedStateExt};
 use korangar_interface::windows::{PrototypeWindow, Window, WindowBuilder};
 
+use crate::graphics::Color;
 use crate::input::UserEvent;
 use crate::interface::application::InterfaceSettings;
 use crate::interface::layout::ScreenSize;
 use crate::interface::theme::InterfaceThemeKind;
 use crate::interface::windows::WindowCache;
-use crate::loaders::client::LoginSettings;
+use crate::loaders::client::{LoginSettings, PasswordDecryptionError, MASTER_PASSWORD_VARIABLE};
 use crate::loaders::ClientInfo;
 
 #[derive(new)]
 pub struct LoginWindow<'a> {
     client_info: &'a ClientInfo,
+    /// Master password entered by the user, if any.
+    master_password: Option<String>,
 }
 
 impl<'a> LoginWindow<'a> {
@@ -37,7 +40,7 @@ impl<'a> PrototypeWindow<InterfaceSettings> for LoginWindow<'a> {
         application: &InterfaceSettings,
         available_space: ScreenSize,
     ) -> Window<InterfaceSettings> {
-        let mut login_settings = LoginSettings::new();
+        let mut login_settings = LoginSettings::new(self.master_password.as_deref());
 
         let options = self
             .client_info
@@ -64,6 +67,9 @@ impl<'a> PrototypeWindow<InterfaceSettings> for LoginWindow<'a> {
         let username = PlainTrackedState::new(saved_settings.username.clone());
         let password = PlainTrackedState::new(saved_settings.password.clone());
 
+        let decryption_error = login_settings.decryption_error;
+        let can_save_passwords = login_settings.can_save_passwords();
+        let needs_master_password = login_settings.needs_master_password();
         let selected_service = PlainTrackedState::new(selected_service);
         let login_settings = PlainTrackedState::new(login_settings);
 
@@ -175,7 +181,7 @@ impl<'a> PrototypeWindow<InterfaceSettings> for LoginWindow<'a> {
             login_settings.mapped(move |login_settings| &login_settings.service_settings.get(&service_id.get()).unwrap().remember_password)
         };
 
-        let elements = vec![
+        let mut elements = vec![
             Text::default().with_text("Select service").wrap(),
             PickList::default()
                 .with_options(options)
@@ -225,6 +231,68 @@ impl<'a> PrototypeWindow<InterfaceSettings> for LoginWindow<'a> {
                 .wrap(),
         ];
 
+        if needs_master_password {
+            let master_password = PlainTrackedState::<String>::default();
+
+            let master_password_action = {
+                let master_password = master_password.clone();
+
+                move || match master_password.get().is_empty() {
+                    true => Vec::new(),
+                    false => vec![ClickAction::Custom(UserEvent::EnterMasterPassword(master_password.cloned()))],
+                }
+            };
+
+            let selector = {
+                let master_password = master_password.clone();
+                move || !master_password.get().is_empty()
+            };
+
+            elements.extend([
+                Text::default().with_text("Master password").wrap(),
+                InputFieldBuilder::new()
+                    .with_state(master_password)
+                    .with_ghost_text("Master password")
+                    .with_enter_action(master_password_action.clone())
+                    .with_length(64)
+                    .hidden()
+                    .build()
+                    .wrap(),
+                ButtonBuilder::new()
+                    .with_text("Unlock saved passwords")
+                    .with_disabled_selector(selector)
+                    .with_event(Box::new(master_password_action))
+                    .build()
+                    .wrap(),
+            ]);
+        }
+
+        if !can_save_passwords && needs_master_password {
+            elements.push(
+                Text::default()
+                    .with_text(format!(
+                        "Passwords are only saved if a master password is entered or {MASTER_PASSWORD_VARIABLE} is set"
+                    ))
+                    .wrap(),
+            );
+        }
+
+        if let Some(error) = decryption_error {
+            let fix = match error {
+                PasswordDecryptionError::Malformed if can_save_passwords => "log in to save it again",
+                PasswordDecryptionError::Malformed => "delete the login settings to save passwords again",
+                PasswordDecryptionError::WrongMasterPassword => "enter the master password it was saved with or log in to save it again",
+                PasswordDecryptionError::MissingMasterPassword => "enter the master password it was saved with",
+            };
+
+            elements.push(
+                Text::default()
+                    .with_text(format!("Failed to load password: {error}, {fix}"))
+                    .with_foreground_color(|_| Color::rgb_u8(255, 100, 100))
+                    .wrap(),
+            );
+        }
+
         WindowBuilder::new()
             .with_title("Log In".to_string())
             .with_class(Self::WINDOW_CLASS.to_string())
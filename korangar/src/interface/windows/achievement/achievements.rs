@@ -0,0 +1,89 @@
+use derive_new::new;
+use korangar_interface::elements::{ElementWrap, ScrollView};
+use korangar_interface::size_bound;
+use korangar_interface::state::PlainRemote;
+use korangar_interface::windows::{PrototypeWindow, Window, WindowBuilder};
+use korangar_networking::{AchievementProgress, AchievementSummary};
+
+use crate::interface::application::InterfaceSettings;
+use crate::interface::elements::AchievementView;
+use crate::interface::layout::ScreenSize;
+use crate::interface::windows::WindowCache;
+use crate::loaders::AchievementText;
+
+/// Achievement together with the text from the client files.
+pub struct AchievementEntry {
+    pub progress: AchievementProgress,
+    pub text: AchievementText,
+}
+
+/// Achievements that the player made progress on.
+#[derive(Default)]
+pub struct Achievements {
+    pub summary: Option<AchievementSummary>,
+    pub achievements: Vec<AchievementEntry>,
+}
+
+impl Achievements {
+    /// Returns `true` if the achievement was completed by this update.
+    pub fn update(&mut self, summary: AchievementSummary, achievement: AchievementEntry) -> bool {
+        self.summary = Some(summary);
+
+        let achievement_id = achievement.progress.achievement_id;
+        let is_completed = achievement.progress.is_completed;
+
+        match self
+            .achievements
+            .iter_mut()
+            .find(|entry| entry.progress.achievement_id == achievement_id)
+        {
+            Some(entry) => {
+                let was_completed = entry.progress.is_completed;
+                *entry = achievement;
+                is_completed && !was_completed
+            }
+            None => {
+                self.achievements.push(achievement);
+                is_completed
+            }
+        }
+    }
+}
+
+#[derive(new)]
+pub struct AchievementWindow {
+    achievements: PlainRemote<Achievements>,
+}
+
+impl AchievementWindow {
+    pub const WINDOW_CLASS: &'static str = "achievements";
+}
+
+impl PrototypeWindow<InterfaceSettings> for AchievementWindow {
+    fn window_class(&self) -> Option<&str> {
+        Self::WINDOW_CLASS.into()
+    }
+
+    fn to_window(
+        &self,
+        window_cache: &WindowCache,
+        application: &InterfaceSettings,
+        available_space: ScreenSize,
+    ) -> Window<InterfaceSettings> {
+        let elements = vec![
+            ScrollView::new(
+                vec![AchievementView::new(self.achievements.clone()).wrap()],
+                size_bound!(100%, ? < super),
+            )
+            .wrap(),
+        ];
+
+        WindowBuilder::new()
+            .with_title("Achievements".to_string())
+            .with_class(Self::WINDOW_CLASS.to_string())
+            .with_size_bound(size_bound!(300 > 350 < 500, ? < 80%))
+            .with_elements(elements)
+            .closable()
+            .build(window_cache, application, available_space)
+    }
+}
@@ -0,0 +1,5 @@
+mod achievements;
+mod reputation;
+
+pub use self::achievements::{AchievementEntry, AchievementWindow, Achievements};
+pub use self::reputation::ReputationWindow;
@@ -0,0 +1,49 @@
+use derive_new::new;
+use korangar_interface::elements::{ElementWrap, ScrollView};
+use korangar_interface::size_bound;
+use korangar_interface::state::PlainRemote;
+use korangar_interface::windows::{PrototypeWindow, Window, WindowBuilder};
+use ragnarok_packets::ReputationEntry;
+
+use crate::interface::application::InterfaceSettings;
+use crate::interface::elements::ReputationView;
+use crate::interface::layout::ScreenSize;
+use crate::interface::windows::WindowCache;
+
+#[derive(new)]
+pub struct ReputationWindow {
+    reputation: PlainRemote<Vec<ReputationEntry>>,
+}
+
+impl ReputationWindow {
+    pub const WINDOW_CLASS: &'static str = "reputation";
+}
+
+impl PrototypeWindow<InterfaceSettings> for ReputationWindow {
+    fn window_class(&self) -> Option<&str> {
+        Self::WINDOW_CLASS.into()
+    }
+
+    fn to_window(
+        &self,
+        window_cache: &WindowCache,
+        application: &InterfaceSettings,
+        available_space: ScreenSize,
+    ) -> Window<InterfaceSettings> {
+        let elements = vec![
+            ScrollView::new(
+                vec![ReputationView::new(self.reputation.clone()).wrap()],
+                size_bound!(100%, ? < super),
+            )
+            .wrap(),
+        ];
+
+        WindowBuilder::new()
+            .with_title("Reputation".to_string())
+            .with_class(Self::WINDOW_CLASS.to_string())
+            .with_size_bound(size_bound!(250 > 300 < 400, ? < 80%))
+            .with_elements(elements)
+            .closable()
+            .build(window_cache, application, available_space)
+    }
+}
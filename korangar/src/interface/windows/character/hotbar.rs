@@ -8,11 +8,12 @@ use crate::interface::application::InterfaceSettings;
 use crate::interface::elements::HotbarContainer;
 use crate::interface::layout::ScreenSize;
 use crate::interface::windows::WindowCache;
-use crate::inventory::Skill;
+use crate::inventory::{Skill, SkillCooldowns};
 
 #[derive(new)]
 pub struct HotbarWindow {
     skills: PlainRemote<[Option<Skill>; 10]>,
+    cooldowns: PlainRemote<SkillCooldowns>,
 }
 
 impl HotbarWindow {
@@ -30,7 +31,7 @@ impl PrototypeWindow<InterfaceSettings> for HotbarWindow {
         application: &InterfaceSettings,
         available_space: ScreenSize,
     ) -> Window<InterfaceSettings> {
-        let elements = vec![HotbarContainer::new(self.skills.clone()).wrap()];
+        let elements = vec![HotbarContainer::new(self.skills.clone(), self.cooldowns.clone()).wrap()];
 
         WindowBuilder::new()
             .with_title("Hotbar".to_string())
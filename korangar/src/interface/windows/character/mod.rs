@@ -5,11 +5,13 @@ mod inventory;
 mod overview;
 mod selection;
 mod skill_tree;
+mod storage;
 
 pub use self::creation::CharacterCreationWindow;
 pub use self::equipment::EquipmentWindow;
 pub use self::hotbar::HotbarWindow;
 pub use self::inventory::InventoryWindow;
-pub use self::overview::CharacterOverviewWindow;
+pub use self::overview::{CharacterOverviewWindow, CharacterStats};
 pub use self::selection::CharacterSelectionWindow;
 pub use self::skill_tree::SkillTreeWindow;
+pub use self::storage::StorageWindow;
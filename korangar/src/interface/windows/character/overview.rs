@@ -1,15 +1,86 @@
 use derive_new::new;
 use korangar_interface::elements::{ButtonBuilder, ElementWrap};
 use korangar_interface::size_bound;
+use korangar_interface::state::PlainRemote;
 use korangar_interface::windows::{PrototypeWindow, Window, WindowBuilder};
+use ragnarok_packets::{StatType, StatusType};
 
 use crate::input::UserEvent;
 use crate::interface::application::InterfaceSettings;
+use crate::interface::elements::StatsView;
 use crate::interface::layout::ScreenSize;
 use crate::interface::windows::WindowCache;
 
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Stat {
+    pub value: u32,
+    pub bonus: u32,
+    /// Status points required to raise the stat by one.
+    pub cost: u8,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct CharacterStats {
+    pub status_points: u32,
+    pub skill_points: u32,
+    pub strength: Stat,
+    pub agility: Stat,
+    pub vitality: Stat,
+    pub intelligence: Stat,
+    pub dexterity: Stat,
+    pub luck: Stat,
+}
+
+impl CharacterStats {
+    pub const STAT_TYPES: [StatType; 6] = [
+        StatType::Strength,
+        StatType::Agility,
+        StatType::Vitality,
+        StatType::Intelligence,
+        StatType::Dexterity,
+        StatType::Luck,
+    ];
+
+    pub fn get_stat(&self, stat_type: StatType) -> &Stat {
+        match stat_type {
+            StatType::Strength => &self.strength,
+            StatType::Agility => &self.agility,
+            StatType::Vitality => &self.vitality,
+            StatType::Intelligence => &self.intelligence,
+            StatType::Dexterity => &self.dexterity,
+            StatType::Luck => &self.luck,
+        }
+    }
+
+    /// Apply a status update from the server. Returns `true` if the update
+    /// changed any of the values tracked here.
+    pub fn update(&mut self, status_type: &StatusType) -> bool {
+        match *status_type {
+            StatusType::StatusPoint(points) => self.status_points = points,
+            StatusType::SkillPoint(points) => self.skill_points = points,
+            StatusType::Strength(value, bonus) => (self.strength.value, self.strength.bonus) = (value, bonus),
+            StatusType::Agility(value, bonus) => (self.agility.value, self.agility.bonus) = (value, bonus),
+            StatusType::Vitality(value, bonus) => (self.vitality.value, self.vitality.bonus) = (value, bonus),
+            StatusType::Intelligence(value, bonus) => (self.intelligence.value, self.intelligence.bonus) = (value, bonus),
+            StatusType::Dexterity(value, bonus) => (self.dexterity.value, self.dexterity.bonus) = (value, bonus),
+            StatusType::Luck(value, bonus) => (self.luck.value, self.luck.bonus) = (value, bonus),
+            StatusType::SpUstr(cost) => self.strength.cost = cost,
+            StatusType::SpUagi(cost) => self.agility.cost = cost,
+            StatusType::SpUvit(cost) => self.vitality.cost = cost,
+            StatusType::SpUint(cost) => self.intelligence.cost = cost,
+            StatusType::SpUdex(cost) => self.dexterity.cost = cost,
+            StatusType::SpUluk(cost) => self.luck.cost = cost,
+            _ => return false,
+        }
+
+        true
+    }
+}
+
 #[derive(new)]
-pub struct CharacterOverviewWindow;
+pub struct CharacterOverviewWindow {
+    stats: PlainRemote<CharacterStats>,
+}
 
 impl CharacterOverviewWindow {
     pub const WINDOW_CLASS: &'static str = "character_overview";
@@ -33,6 +104,7 @@ impl PrototypeWindow<InterfaceSettings> for CharacterOverviewWindow {
             Text::default()
                 .with_text(|| format!("job level: {}", player.get_job_level()))
                 .wrap(),*/
+            StatsView::new(self.stats.clone()).wrap(),
             ButtonBuilder::new()
                 .with_text("Inventory")
                 .with_event(UserEvent::OpenInventoryWindow)
@@ -53,6 +125,46 @@ impl PrototypeWindow<InterfaceSettings> for CharacterOverviewWindow {
                 .with_event(UserEvent::OpenFriendsWindow)
                 .build()
                 .wrap(),
+            ButtonBuilder::new()
+                .with_text("Party")
+                .with_event(UserEvent::OpenPartyWindow)
+                .build()
+                .wrap(),
+            ButtonBuilder::new()
+                .with_text("Guild")
+                .with_event(UserEvent::OpenGuildWindow)
+                .build()
+                .wrap(),
+            ButtonBuilder::new()
+                .with_text("Quests")
+                .with_event(UserEvent::OpenQuestLogWindow)
+                .build()
+                .wrap(),
+            ButtonBuilder::new()
+                .with_text("Minimap")
+                .with_event(UserEvent::OpenMinimapWindow)
+                .build()
+                .wrap(),
+            ButtonBuilder::new()
+                .with_text("Achievements")
+                .with_event(UserEvent::OpenAchievementWindow)
+                .build()
+                .wrap(),
+            ButtonBuilder::new()
+                .with_text("Reputation")
+                .with_event(UserEvent::OpenReputationWindow)
+                .build()
+                .wrap(),
+            ButtonBuilder::new()
+                .with_text("Chat room")
+                .with_event(UserEvent::OpenChatRoomWindow)
+                .build()
+                .wrap(),
+            ButtonBuilder::new()
+                .with_text("Mail")
+                .with_event(UserEvent::OpenMailbox)
+                .build()
+                .wrap(),
             ButtonBuilder::new()
                 .with_text("Menu")
                 .with_event(UserEvent::OpenMenuWindow)
@@ -0,0 +1,7 @@
+mod password;
+mod room;
+mod settings;
+
+pub use self::password::ChatRoomPasswordWindow;
+pub use self::room::{ChatRoom, ChatRoomWindow};
+pub use self::settings::ChatRoomSettingsWindow;
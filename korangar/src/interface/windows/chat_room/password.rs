@@ -0,0 +1,77 @@
+use derive_new::new;
+use korangar_interface::elements::{ButtonBuilder, ElementWrap, InputFieldBuilder, Text};
+use korangar_interface::event::ClickAction;
+use korangar_interface::state::{PlainTrackedState, TrackedStateTake};
+use korangar_interface::windows::{PrototypeWindow, Window, WindowBuilder};
+use korangar_interface::{dimension_bound, size_bound};
+use ragnarok_packets::ChatRoomId;
+
+use crate::input::UserEvent;
+use crate::interface::application::InterfaceSettings;
+use crate::interface::layout::ScreenSize;
+use crate::interface::windows::WindowCache;
+
+/// Asks for the password before entering a private chat room.
+#[derive(new)]
+pub struct ChatRoomPasswordWindow {
+    chat_room_id: ChatRoomId,
+    title: String,
+}
+
+impl ChatRoomPasswordWindow {
+    pub const WINDOW_CLASS: &'static str = "chat_room_password";
+}
+
+impl PrototypeWindow<InterfaceSettings> for ChatRoomPasswordWindow {
+    fn window_class(&self) -> Option<&str> {
+        Self::WINDOW_CLASS.into()
+    }
+
+    fn to_window(
+        &self,
+        window_cache: &WindowCache,
+        application: &InterfaceSettings,
+        available_space: ScreenSize,
+    ) -> Window<InterfaceSettings> {
+        let password = PlainTrackedState::<String>::default();
+
+        let enter_action = {
+            let mut password = password.clone();
+            let chat_room_id = self.chat_room_id;
+
+            Box::new(move || {
+                vec![ClickAction::Custom(UserEvent::EnterChatRoom {
+                    chat_room_id,
+                    password: password.take(),
+                })]
+            })
+        };
+
+        let elements = vec![
+            Text::default().with_text(format!("^ffaa00{}^000000 is private", self.title)).wrap(),
+            InputFieldBuilder::new()
+                .with_state(password)
+                .with_ghost_text("Password")
+                .with_enter_action(enter_action.clone())
+                .with_length(7)
+                .hidden()
+                .with_width_bound(dimension_bound!(70%))
+                .build()
+                .wrap(),
+            ButtonBuilder::new()
+                .with_text("Enter")
+                .with_event(enter_action)
+                .with_width_bound(dimension_bound!(!))
+                .build()
+                .wrap(),
+        ];
+
+        WindowBuilder::new()
+            .with_title("Private chat room".to_string())
+            .with_class(Self::WINDOW_CLASS.to_owned())
+            .with_size_bound(size_bound!(250 > 250 < 250, ?))
+            .with_elements(elements)
+            .closable()
+            .build(window_cache, application, available_space)
+    }
+}
@@ -0,0 +1,109 @@
+use derive_new::new;
+use korangar_interface::elements::{ButtonBuilder, ElementWrap, InputFieldBuilder};
+use korangar_interface::event::ClickAction;
+use korangar_interface::state::{PlainRemote, PlainTrackedState, TrackedStateTake};
+use korangar_interface::windows::{PrototypeWindow, Window, WindowBuilder};
+use korangar_interface::{dimension_bound, size_bound};
+use ragnarok_packets::{ChatRoomId, ChatRoomMember, ChatRoomRole, ChatRoomType};
+
+use crate::input::UserEvent;
+use crate::interface::application::InterfaceSettings;
+use crate::interface::elements::ChatRoomView;
+use crate::interface::layout::ScreenSize;
+use crate::interface::windows::WindowCache;
+
+/// State of the chat room that the player is in.
+#[derive(Debug, Clone)]
+pub struct ChatRoom {
+    /// After creating a chat room, the id is only known once the chat room
+    /// appears on screen.
+    pub chat_room_id: Option<ChatRoomId>,
+    pub title: String,
+    pub limit: u16,
+    pub chat_room_type: ChatRoomType,
+    pub members: Vec<ChatRoomMember>,
+}
+
+impl ChatRoom {
+    pub fn is_owner(&self, name: &str) -> bool {
+        self.members
+            .iter()
+            .any(|member| member.role == ChatRoomRole::Owner && member.name == name)
+    }
+}
+
+#[derive(new)]
+pub struct ChatRoomWindow {
+    chat_room: PlainRemote<Option<ChatRoom>>,
+    player_name: String,
+}
+
+impl ChatRoomWindow {
+    pub const WINDOW_CLASS: &'static str = "chat_room";
+}
+
+impl PrototypeWindow<InterfaceSettings> for ChatRoomWindow {
+    fn window_class(&self) -> Option<&str> {
+        Self::WINDOW_CLASS.into()
+    }
+
+    fn to_window(
+        &self,
+        window_cache: &WindowCache,
+        application: &InterfaceSettings,
+        available_space: ScreenSize,
+    ) -> Window<InterfaceSettings> {
+        let message = PlainTrackedState::<String>::default();
+
+        // Messages sent while in a chat room are only delivered to its members, so we
+        // can just send a regular chat message.
+        let send_action = {
+            let mut message = message.clone();
+
+            Box::new(move || {
+                let taken_string = message.take();
+
+                (!taken_string.is_empty())
+                    .then_some(vec![ClickAction::Custom(UserEvent::SendMessage(taken_string))])
+                    .unwrap_or_default()
+            })
+        };
+
+        let elements = vec![
+            ChatRoomView::new(self.chat_room.clone(), self.player_name.clone()).wrap(),
+            InputFieldBuilder::new()
+                .with_state(message)
+                .with_ghost_text("Message")
+                .with_enter_action(send_action.clone())
+                .with_length(80)
+                .with_width_bound(dimension_bound!(75%))
+                .build()
+                .wrap(),
+            ButtonBuilder::new()
+                .with_text("Send")
+                .with_event(send_action)
+                .with_width_bound(dimension_bound!(!))
+                .build()
+                .wrap(),
+            ButtonBuilder::new()
+                .with_text("Settings")
+                .with_event(UserEvent::OpenChatRoomSettingsWindow)
+                .with_width_bound(dimension_bound!(50%))
+                .build()
+                .wrap(),
+            ButtonBuilder::new()
+                .with_text("Leave")
+                .with_event(UserEvent::LeaveChatRoom)
+                .with_width_bound(dimension_bound!(!))
+                .build()
+                .wrap(),
+        ];
+
+        WindowBuilder::new()
+            .with_title("Chat room".to_string())
+            .with_class(Self::WINDOW_CLASS.to_owned())
+            .with_size_bound(size_bound!(250 > 300 < 400, ?))
+            .with_elements(elements)
+            .build(window_cache, application, available_space)
+    }
+}
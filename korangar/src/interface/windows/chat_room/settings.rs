@@ -0,0 +1,119 @@
+use derive_new::new;
+use korangar_interface::elements::{ButtonBuilder, ElementWrap, InputFieldBuilder, Text};
+use korangar_interface::event::ClickAction;
+use korangar_interface::size_bound;
+use korangar_interface::state::{PlainTrackedState, TrackedStateClone};
+use korangar_interface::windows::{PrototypeWindow, Window, WindowBuilder};
+
+use super::ChatRoom;
+use crate::input::UserEvent;
+use crate::interface::application::InterfaceSettings;
+use crate::interface::layout::ScreenSize;
+use crate::interface::windows::WindowCache;
+
+/// Limit of a new chat room, including the owner.
+const DEFAULT_LIMIT: u16 = 20;
+
+/// Creates a new chat room or changes the settings of the chat room that the
+/// player owns.
+#[derive(new)]
+pub struct ChatRoomSettingsWindow {
+    chat_room: Option<ChatRoom>,
+}
+
+impl ChatRoomSettingsWindow {
+    pub const WINDOW_CLASS: &'static str = "chat_room_settings";
+}
+
+impl PrototypeWindow<InterfaceSettings> for ChatRoomSettingsWindow {
+    fn window_class(&self) -> Option<&str> {
+        Self::WINDOW_CLASS.into()
+    }
+
+    fn to_window(
+        &self,
+        window_cache: &WindowCache,
+        application: &InterfaceSettings,
+        available_space: ScreenSize,
+    ) -> Window<InterfaceSettings> {
+        let (title, limit) = match &self.chat_room {
+            Some(chat_room) => (chat_room.title.clone(), chat_room.limit),
+            None => (String::new(), DEFAULT_LIMIT),
+        };
+
+        let title = PlainTrackedState::new(title);
+        let limit = PlainTrackedState::new(limit.to_string());
+        let password = PlainTrackedState::<String>::default();
+
+        let confirm_action = {
+            let title = title.clone();
+            let limit = limit.clone();
+            let password = password.clone();
+            let is_change = self.chat_room.is_some();
+
+            Box::new(move || {
+                let (title, password) = (title.cloned(), password.cloned());
+
+                let Some(limit) = limit.cloned().parse::<u16>().ok().filter(|limit| (2..=20).contains(limit)) else {
+                    return Vec::new();
+                };
+
+                if title.is_empty() {
+                    return Vec::new();
+                }
+
+                let event = match is_change {
+                    true => UserEvent::ChangeChatRoom { title, limit, password },
+                    false => UserEvent::CreateChatRoom { title, limit, password },
+                };
+
+                vec![ClickAction::Custom(event), ClickAction::CloseWindow]
+            })
+        };
+
+        let (window_title, button_text) = match self.chat_room.is_some() {
+            true => ("Chat room settings", "Change"),
+            false => ("Create chat room", "Create"),
+        };
+
+        let elements = vec![
+            InputFieldBuilder::new()
+                .with_state(title)
+                .with_ghost_text("Title")
+                .with_enter_action(confirm_action.clone())
+                .with_length(36)
+                .build()
+                .wrap(),
+            Text::default().with_text("Limit (2 - 20)").wrap(),
+            InputFieldBuilder::new()
+                .with_state(limit)
+                .with_ghost_text("Limit")
+                .with_enter_action(confirm_action.clone())
+                .with_length(2)
+                .build()
+                .wrap(),
+            Text::default().with_text("Password (leave empty for a public room)").wrap(),
+            InputFieldBuilder::new()
+                .with_state(password)
+                .with_ghost_text("Password")
+                .with_enter_action(confirm_action.clone())
+                .with_length(7)
+                .hidden()
+                .build()
+                .wrap(),
+            ButtonBuilder::new()
+                .with_text(button_text)
+                .with_event(confirm_action)
+                .build()
+                .wrap(),
+        ];
+
+        WindowBuilder::new()
+            .with_title(window_title.to_string())
+            .with_class(Self::WINDOW_CLASS.to_owned())
+            .with_size_bound(size_bound!(250 > 300 < 400, ?))
+            .with_elements(elements)
+            .closable()
+            .build(window_cache, application, available_space)
+    }
+}
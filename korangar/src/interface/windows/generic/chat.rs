@@ -2,16 +2,16 @@ use std::cell::RefCell;
 use std::rc::Rc;
 
 use derive_new::new;
-use korangar_interface::elements::{ButtonBuilder, ElementWrap, InputFieldBuilder, ScrollView};
+use korangar_interface::elements::{ButtonBuilder, ElementWrap, InputFieldBuilder};
 use korangar_interface::event::ClickAction;
-use korangar_interface::state::{PlainRemote, PlainTrackedState, TrackedState, TrackedStateTake};
+use korangar_interface::state::{PlainRemote, PlainTrackedState, TrackedState, TrackedStateTake, TrackedStateVec};
 use korangar_interface::windows::{PrototypeWindow, Window, WindowBuilder};
 use korangar_interface::{dimension_bound, size_bound};
 use korangar_networking::MessageColor;
 
 use crate::input::UserEvent;
 use crate::interface::application::InterfaceSettings;
-use crate::interface::elements::ChatBuilder;
+use crate::interface::elements::ConversationView;
 use crate::interface::layout::ScreenSize;
 use crate::interface::theme::InterfaceTheme;
 use crate::interface::windows::WindowCache;
@@ -23,9 +23,125 @@ pub struct ChatMessage {
     pub color: MessageColor,
 }
 
+/// Private messages exchanged with a single player.
+#[derive(Clone)]
+pub struct Conversation {
+    pub partner_name: String,
+    pub messages: PlainTrackedState<Vec<ChatMessage>>,
+}
+
+/// History of all whisper conversations, grouped by the name of the
+/// conversation partner.
+#[derive(Default)]
+pub struct Conversations {
+    conversations: PlainTrackedState<Vec<Conversation>>,
+}
+
+impl Conversations {
+    /// Get the messages exchanged with a player, starting a new conversation
+    /// if there is none yet.
+    pub fn get_messages_with(&mut self, partner_name: &str) -> PlainTrackedState<Vec<ChatMessage>> {
+        let existing = self
+            .conversations
+            .get()
+            .iter()
+            .find(|conversation| conversation.partner_name == partner_name)
+            .map(|conversation| conversation.messages.clone());
+
+        existing.unwrap_or_else(|| {
+            let messages = PlainTrackedState::default();
+
+            self.conversations.push(Conversation {
+                partner_name: partner_name.to_owned(),
+                messages: messages.clone(),
+            });
+
+            messages
+        })
+    }
+
+    pub fn remove(&mut self, partner_name: &str) {
+        self.conversations.retain(|conversation| conversation.partner_name != partner_name);
+    }
+
+    pub fn clear(&mut self) {
+        self.conversations.set(Vec::new());
+    }
+
+    pub fn new_remote(&self) -> PlainRemote<Vec<Conversation>> {
+        self.conversations.new_remote()
+    }
+}
+
+/// Split a whisper command of the form `/w "name" message` into the name of
+/// the recipient and the message. Names without spaces may also be written
+/// without quotes.
+pub fn parse_whisper_command(command: &str) -> Option<(&str, &str)> {
+    let arguments = command.strip_prefix("/w ")?.trim_start();
+
+    let (recipient_name, message) = match arguments.strip_prefix('"') {
+        Some(quoted) => quoted.split_once('"')?,
+        None => arguments.split_once(' ')?,
+    };
+
+    let message = message.trim_start();
+
+    (!recipient_name.is_empty() && !message.is_empty()).then_some((recipient_name, message))
+}
+
+/// Chat commands for emotions, in the order of the emotions in the emotion
+/// sprite.
+const EMOTION_COMMANDS: &[&str] = &[
+    "/!",
+    "/?",
+    "/ho",
+    "/lv",
+    "/swt",
+    "/ic",
+    "/an",
+    "/ag",
+    "/$",
+    "/...",
+    "/scissors",
+    "/rock",
+    "/paper",
+    "/flag",
+    "/lv2",
+    "/thx",
+    "/wah",
+    "/sry",
+    "/heh",
+    "/swt2",
+    "/hmm",
+    "/no1",
+    "/??",
+    "/omg",
+    "/oh",
+    "/x",
+    "/hlp",
+    "/go",
+    "/sob",
+    "/gg",
+    "/kis",
+    "/kis2",
+    "/pif",
+    "/ok",
+];
+
+/// Get the emotion that is used by a command like `/thx`.
+pub fn parse_emotion_command(command: &str) -> Option<u8> {
+    let command = command.trim_end().to_lowercase();
+
+    EMOTION_COMMANDS
+        .iter()
+        .position(|emotion_command| *emotion_command == command)
+        .map(|emotion| emotion as u8)
+}
+
 #[derive(new)]
 pub struct ChatWindow {
     messages: PlainRemote<Vec<ChatMessage>>,
+    conversations: PlainRemote<Vec<Conversation>>,
     font_loader: Rc<RefCell<FontLoader>>,
 }
 
@@ -45,6 +161,18 @@ impl PrototypeWindow<InterfaceSettings> for ChatWindow {
         available_space: ScreenSize,
     ) -> Window<InterfaceSettings> {
         let input_text = PlainTrackedState::<String>::default();
+        let selected_conversation = PlainTrackedState::<Option<String>>::default();
+
+        // While a conversation is selected, messages are sent as whispers to the
+        // conversation partner.
+        let create_event = {
+            let selected_conversation = selected_conversation.clone();
+
+            move |message: String| match selected_conversation.get().clone() {
+                Some(recipient_name) => UserEvent::SendWhisper { recipient_name, message },
+                None => UserEvent::SendMessage(message),
+            }
+        };
 
         let button_selector = {
             let input_text = input_text.clone();
@@ -54,10 +182,11 @@ impl PrototypeWindow<InterfaceSettings> for ChatWindow {
 
         let button_action = {
             let mut input_text = input_text.clone();
+            let create_event = create_event.clone();
 
             move || {
                 let message = input_text.take();
-                vec![ClickAction::Custom(UserEvent::SendMessage(message))]
+                vec![ClickAction::Custom(create_event(message))]
             }
         };
 
@@ -67,7 +196,7 @@ impl PrototypeWindow<InterfaceSettings> for ChatWindow {
                 let message = input_text.take();
 
                 (!message.is_empty())
-                    .then_some(vec![ClickAction::Custom(UserEvent::SendMessage(message))])
+                    .then(|| vec![ClickAction::Custom(create_event(message))])
                     .unwrap_or_default()
             })
         };
@@ -88,15 +217,11 @@ impl PrototypeWindow<InterfaceSettings> for ChatWindow {
                 .with_width_bound(dimension_bound!(25%))
                 .build()
                 .wrap(),
-            ScrollView::new(
-                vec![
-                    ChatBuilder::new()
-                        .with_messages(self.messages.clone())
-                        .with_font_loader(self.font_loader.clone())
-                        .build()
-                        .wrap(),
-                ],
-                size_bound!(100%, !),
+            ConversationView::new(
+                self.messages.clone(),
+                self.conversations.clone(),
+                selected_conversation,
+                self.font_loader.clone(),
             )
             .wrap(),
         ];
@@ -109,3 +234,52 @@ impl PrototypeWindow<InterfaceSettings> for ChatWindow {
             .build(window_cache, application, available_space)
     }
 }
+
+#[cfg(test)]
+mod whisper_command {
+    use super::parse_whisper_command;
+
+    #[test]
+    fn quoted_name() {
+        assert_eq!(
+            parse_whisper_command("/w \"Some Name\" hello there"),
+            Some(("Some Name", "hello there"))
+        );
+    }
+
+    #[test]
+    fn unquoted_name() {
+        assert_eq!(parse_whisper_command("/w Name hello"), Some(("Name", "hello")));
+    }
+
+    #[test]
+    fn missing_message() {
+        assert_eq!(parse_whisper_command("/w \"Some Name\""), None);
+        assert_eq!(parse_whisper_command("/w Name"), None);
+    }
+
+    #[test]
+    fn not_a_whisper() {
+        assert_eq!(parse_whisper_command("/where"), None);
+        assert_eq!(parse_whisper_command("hello"), None);
+    }
+}
+
+#[cfg(test)]
+mod emotion_command {
+    use super::parse_emotion_command;
+
+    #[test]
+    fn known_command() {
+        assert_eq!(parse_emotion_command("/!"), Some(0));
+        assert_eq!(parse_emotion_command("/thx"), Some(15));
+        assert_eq!(parse_emotion_command("/X"), Some(25));
+    }
+
+    #[test]
+    fn not_an_emotion() {
+        assert_eq!(parse_emotion_command("/thanks"), None);
+        assert_eq!(parse_emotion_command("/thx for the help"), None);
+        assert_eq!(parse_emotion_command("thx"), None);
+    }
+}
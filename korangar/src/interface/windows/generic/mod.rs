@@ -3,7 +3,7 @@ mod dialog;
 mod error;
 mod menu;
 
-pub use self::chat::{ChatMessage, ChatWindow};
+pub use self::chat::{parse_emotion_command, parse_whisper_command, ChatMessage, ChatWindow, Conversation, Conversations};
 pub use self::dialog::DialogWindow;
 pub use self::error::ErrorWindow;
 pub use self::menu::MenuWindow;
@@ -0,0 +1,3 @@
+mod overview;
+
+pub use self::overview::{Guild, GuildMemberInformation, GuildWindow};
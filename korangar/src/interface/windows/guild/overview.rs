@@ -0,0 +1,127 @@
+use std::sync::Arc;
+
+use derive_new::new;
+use korangar_interface::elements::{ButtonBuilder, ElementWrap, InputFieldBuilder, ScrollView};
+use korangar_interface::event::ClickAction;
+use korangar_interface::state::{PlainRemote, PlainTrackedState, TrackedStateTake};
+use korangar_interface::windows::{PrototypeWindow, Window, WindowBuilder};
+use korangar_interface::{dimension_bound, size_bound};
+use ragnarok_packets::{GuildId, GuildInformation, GuildMember, GuildPositionName, GuildRelation};
+
+use crate::graphics::Texture;
+use crate::input::UserEvent;
+use crate::interface::application::InterfaceSettings;
+use crate::interface::elements::GuildView;
+use crate::interface::layout::ScreenSize;
+use crate::interface::windows::WindowCache;
+
+#[derive(Clone)]
+pub struct GuildMemberInformation {
+    pub member: GuildMember,
+    pub name: Option<String>,
+}
+
+/// Everything the client knows about the guild of the player.
+#[derive(Clone)]
+pub struct Guild {
+    pub guild_id: GuildId,
+    pub guild_name: String,
+    pub emblem_id: u32,
+    pub emblem: Option<Arc<Texture>>,
+    pub information: Option<GuildInformation>,
+    pub notice_subject: String,
+    pub notice: String,
+    pub members: Vec<GuildMemberInformation>,
+    pub position_names: Vec<GuildPositionName>,
+    pub relations: Vec<GuildRelation>,
+}
+
+impl Guild {
+    pub fn new(guild_id: GuildId, guild_name: String, emblem_id: u32) -> Self {
+        Self {
+            guild_id,
+            guild_name,
+            emblem_id,
+            emblem: None,
+            information: None,
+            notice_subject: String::new(),
+            notice: String::new(),
+            members: Vec::new(),
+            position_names: Vec::new(),
+            relations: Vec::new(),
+        }
+    }
+}
+
+#[derive(new)]
+pub struct GuildWindow {
+    guild: PlainRemote<Option<Guild>>,
+}
+
+impl GuildWindow {
+    pub const WINDOW_CLASS: &'static str = "guild";
+}
+
+impl PrototypeWindow<InterfaceSettings> for GuildWindow {
+    fn window_class(&self) -> Option<&str> {
+        Self::WINDOW_CLASS.into()
+    }
+
+    fn to_window(
+        &self,
+        window_cache: &WindowCache,
+        application: &InterfaceSettings,
+        available_space: ScreenSize,
+    ) -> Window<InterfaceSettings> {
+        let notice_subject = PlainTrackedState::<String>::default();
+        let notice = PlainTrackedState::<String>::default();
+
+        let change_notice_action = {
+            let mut notice_subject = notice_subject.clone();
+            let mut notice = notice.clone();
+
+            Box::new(move || {
+                let subject = notice_subject.take();
+                let notice = notice.take();
+
+                (!subject.is_empty() || !notice.is_empty())
+                    .then_some(vec![ClickAction::Custom(UserEvent::ChangeGuildNotice { subject, notice })])
+                    .unwrap_or_default()
+            })
+        };
+
+        let elements = vec![
+            GuildView::new(self.guild.clone()).wrap(),
+            InputFieldBuilder::new()
+                .with_state(notice_subject)
+                .with_ghost_text("Notice subject")
+                .with_enter_action(change_notice_action.clone())
+                .with_length(60)
+                .build()
+                .wrap(),
+            InputFieldBuilder::new()
+                .with_state(notice)
+                .with_ghost_text("Notice")
+                .with_enter_action(change_notice_action.clone())
+                .with_length(120)
+                .with_width_bound(dimension_bound!(75%))
+                .build()
+                .wrap(),
+            ButtonBuilder::new()
+                .with_text("Change")
+                .with_event(change_notice_action)
+                .with_width_bound(dimension_bound!(!))
+                .build()
+                .wrap(),
+        ];
+        let elements = vec![ScrollView::new(elements, size_bound!(100%, ? < super)).wrap()];
+
+        WindowBuilder::new()
+            .with_title("Guild".to_string())
+            .with_class(Self::WINDOW_CLASS.to_owned())
+            .with_size_bound(size_bound!(250 > 350 < 500, ? < 70%))
+            .with_elements(elements)
+            .closable()
+            .build(window_cache, application, available_space)
+    }
+}
@@ -0,0 +1,116 @@
+use derive_new::new;
+use korangar_interface::elements::{ButtonBuilder, ElementWrap, ScrollView};
+use korangar_interface::state::PlainRemote;
+use korangar_interface::windows::{PrototypeWindow, Window, WindowBuilder};
+use korangar_interface::{dimension_bound, size_bound};
+use korangar_networking::MailSummary;
+use ragnarok_packets::MailId;
+
+use super::OpenMail;
+use crate::input::UserEvent;
+use crate::interface::application::InterfaceSettings;
+use crate::interface::elements::MailboxView;
+use crate::interface::layout::ScreenSize;
+use crate::interface::windows::WindowCache;
+
+/// Mail that the player received.
+#[derive(Default)]
+pub struct Mailbox {
+    /// Newest mail first.
+    pub mails: Vec<MailSummary>,
+    pub is_last_page: bool,
+    /// The mail that is currently being read.
+    pub open_mail: Option<OpenMail>,
+}
+
+impl Mailbox {
+    pub fn add_page(&mut self, mails: Vec<MailSummary>, is_last_page: bool) {
+        self.mails.extend(mails);
+        self.is_last_page = is_last_page;
+    }
+
+    pub fn open(&mut self, open_mail: OpenMail) {
+        if let Some(mail) = self.mails.iter_mut().find(|mail| mail.mail_id == open_mail.mail_id) {
+            mail.is_read = true;
+        }
+
+        self.open_mail = Some(open_mail);
+    }
+
+    pub fn remove_mail(&mut self, mail_id: MailId) {
+        self.mails.retain(|mail| mail.mail_id != mail_id);
+
+        if self.open_mail.as_ref().is_some_and(|mail| mail.mail_id == mail_id) {
+            self.open_mail = None;
+        }
+    }
+
+    pub fn remove_zeny(&mut self, mail_id: MailId) {
+        if let Some(mail) = self.mails.iter_mut().find(|mail| mail.mail_id == mail_id) {
+            mail.has_zeny = false;
+        }
+
+        if let Some(mail) = self.open_mail.as_mut().filter(|mail| mail.mail_id == mail_id) {
+            mail.zeny = 0;
+        }
+    }
+
+    pub fn remove_items(&mut self, mail_id: MailId) {
+        if let Some(mail) = self.mails.iter_mut().find(|mail| mail.mail_id == mail_id) {
+            mail.has_items = false;
+        }
+
+        if let Some(mail) = self.open_mail.as_mut().filter(|mail| mail.mail_id == mail_id) {
+            mail.items.clear();
+        }
+    }
+}
+
+#[derive(new)]
+pub struct MailboxWindow {
+    mailbox: PlainRemote<Mailbox>,
+}
+
+impl MailboxWindow {
+    pub const WINDOW_CLASS: &'static str = "mailbox";
+}
+
+impl PrototypeWindow<InterfaceSettings> for MailboxWindow {
+    fn window_class(&self) -> Option<&str> {
+        Self::WINDOW_CLASS.into()
+    }
+
+    fn to_window(
+        &self,
+        window_cache: &WindowCache,
+        application: &InterfaceSettings,
+        available_space: ScreenSize,
+    ) -> Window<InterfaceSettings> {
+        let elements = vec![
+            ScrollView::new(
+                vec![MailboxView::new(self.mailbox.clone()).wrap()],
+                size_bound!(100%, ? < super),
+            )
+            .wrap(),
+            ButtonBuilder::new()
+                .with_text("Write mail")
+                .with_event(UserEvent::OpenWriteMailWindow)
+                .with_width_bound(dimension_bound!(50%))
+                .build()
+                .wrap(),
+            ButtonBuilder::new()
+                .with_text("Close")
+                .with_event(UserEvent::CloseMailbox)
+                .with_width_bound(dimension_bound!(!))
+                .build()
+                .wrap(),
+        ];
+
+        WindowBuilder::new()
+            .with_title("Mailbox".to_string())
+            .with_class(Self::WINDOW_CLASS.to_string())
+            .with_size_bound(size_bound!(300 > 350 < 500, ? < 80%))
+            .with_elements(elements)
+            .build(window_cache, application, available_space)
+    }
+}
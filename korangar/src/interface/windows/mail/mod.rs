@@ -0,0 +1,7 @@
+mod mailbox;
+mod read;
+mod write;
+
+pub use self::mailbox::{Mailbox, MailboxWindow};
+pub use self::read::{OpenMail, ReadMailWindow};
+pub use self::write::{MailAttachment, OutgoingMail, WriteMailWindow};
@@ -0,0 +1,57 @@
+use derive_new::new;
+use korangar_interface::elements::{ElementWrap, ScrollView};
+use korangar_interface::size_bound;
+use korangar_interface::state::PlainRemote;
+use korangar_interface::windows::{PrototypeWindow, Window, WindowBuilder};
+use korangar_networking::MailItem;
+use ragnarok_packets::{MailId, MailOpenType};
+
+use super::Mailbox;
+use crate::interface::application::InterfaceSettings;
+use crate::interface::elements::MailView;
+use crate::interface::layout::ScreenSize;
+use crate::interface::windows::WindowCache;
+use crate::loaders::ResourceMetadata;
+
+/// Contents of a mail that the player is reading.
+pub struct OpenMail {
+    pub mail_id: MailId,
+    pub open_type: MailOpenType,
+    pub sender_name: String,
+    pub title: String,
+    pub text: String,
+    pub zeny: u64,
+    pub items: Vec<MailItem<ResourceMetadata>>,
+}
+
+#[derive(new)]
+pub struct ReadMailWindow {
+    mailbox: PlainRemote<Mailbox>,
+}
+
+impl ReadMailWindow {
+    pub const WINDOW_CLASS: &'static str = "read_mail";
+}
+
+impl PrototypeWindow<InterfaceSettings> for ReadMailWindow {
+    fn window_class(&self) -> Option<&str> {
+        Self::WINDOW_CLASS.into()
+    }
+
+    fn to_window(
+        &self,
+        window_cache: &WindowCache,
+        application: &InterfaceSettings,
+        available_space: ScreenSize,
+    ) -> Window<InterfaceSettings> {
+        let elements = vec![ScrollView::new(vec![MailView::new(self.mailbox.clone()).wrap()], size_bound!(100%, ? < super)).wrap()];
+
+        WindowBuilder::new()
+            .with_title("Mail".to_string())
+            .with_class(Self::WINDOW_CLASS.to_string())
+            .with_size_bound(size_bound!(300 > 350 < 500, ? < 80%))
+            .with_elements(elements)
+            .closable()
+            .build(window_cache, application, available_space)
+    }
+}
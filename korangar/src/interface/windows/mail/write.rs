@@ -0,0 +1,145 @@
+use derive_new::new;
+use korangar_interface::elements::{ButtonBuilder, ElementWrap, FocusMode, InputFieldBuilder, ScrollView};
+use korangar_interface::event::ClickAction;
+use korangar_interface::state::{PlainRemote, PlainTrackedState, TrackedState, TrackedStateClone};
+use korangar_interface::windows::{PrototypeWindow, Window, WindowBuilder};
+use korangar_interface::{dimension_bound, size_bound};
+use korangar_networking::MailItem;
+use ragnarok_packets::InventoryIndex;
+
+use crate::input::UserEvent;
+use crate::interface::application::InterfaceSettings;
+use crate::interface::elements::MailAttachmentView;
+use crate::interface::layout::ScreenSize;
+use crate::interface::windows::WindowCache;
+use crate::loaders::ResourceMetadata;
+
+/// Item from the inventory that is attached to the mail that the player is
+/// writing.
+pub struct MailAttachment {
+    pub index: InventoryIndex,
+    pub item: MailItem<ResourceMetadata>,
+}
+
+/// Mail that is sent once the server found the receiver.
+#[derive(Clone, Debug)]
+pub struct OutgoingMail {
+    pub receiver_name: String,
+    pub title: String,
+    pub text: String,
+    pub zeny: u64,
+}
+
+#[derive(new)]
+pub struct WriteMailWindow {
+    attachments: PlainRemote<Vec<MailAttachment>>,
+}
+
+impl WriteMailWindow {
+    pub const WINDOW_CLASS: &'static str = "write_mail";
+}
+
+impl PrototypeWindow<InterfaceSettings> for WriteMailWindow {
+    fn window_class(&self) -> Option<&str> {
+        Self::WINDOW_CLASS.into()
+    }
+
+    fn to_window(
+        &self,
+        window_cache: &WindowCache,
+        application: &InterfaceSettings,
+        available_space: ScreenSize,
+    ) -> Window<InterfaceSettings> {
+        let receiver_name = PlainTrackedState::<String>::default();
+        let title = PlainTrackedState::<String>::default();
+        let text = PlainTrackedState::<String>::default();
+        let zeny = PlainTrackedState::<String>::default();
+
+        // The fields are not cleared when sending, so nothing is lost if the mail
+        // can't be delivered.
+        let send_action = {
+            let receiver_name = receiver_name.clone();
+            let title = title.clone();
+            let text = text.clone();
+            let zeny = zeny.clone();
+
+            Box::new(move || {
+                let receiver_name = receiver_name.cloned();
+                let title = title.cloned();
+                let zeny = match zeny.get().as_str() {
+                    "" => Some(0),
+                    zeny => zeny.parse::<u64>().ok(),
+                };
+
+                match zeny {
+                    Some(zeny) if !receiver_name.is_empty() && !title.is_empty() => {
+                        vec![ClickAction::Custom(UserEvent::SendMail(OutgoingMail {
+                            receiver_name,
+                            title,
+                            text: text.cloned(),
+                            zeny,
+                        }))]
+                    }
+                    _ => Vec::new(),
+                }
+            })
+        };
+
+        let focus_next_action = Box::new(move || vec![ClickAction::FocusNext(FocusMode::FocusNext)]);
+
+        let elements = vec![
+            InputFieldBuilder::new()
+                .with_state(receiver_name)
+                .with_ghost_text("Receiver")
+                .with_enter_action(focus_next_action.clone())
+                .with_length(24)
+                .build()
+                .wrap(),
+            InputFieldBuilder::new()
+                .with_state(title)
+                .with_ghost_text("Title")
+                .with_enter_action(focus_next_action.clone())
+                .with_length(40)
+                .build()
+                .wrap(),
+            InputFieldBuilder::new()
+                .with_state(text)
+                .with_ghost_text("Message")
+                .with_enter_action(focus_next_action)
+                .with_length(200)
+                .build()
+                .wrap(),
+            InputFieldBuilder::new()
+                .with_state(zeny)
+                .with_ghost_text("Zeny")
+                .with_enter_action(send_action.clone())
+                .with_length(10)
+                .build()
+                .wrap(),
+            ScrollView::new(
+                vec![MailAttachmentView::new(self.attachments.clone()).wrap()],
+                size_bound!(100%, ? < super),
+            )
+            .wrap(),
+            ButtonBuilder::new()
+                .with_text("Send")
+                .with_event(send_action)
+                .with_width_bound(dimension_bound!(50%))
+                .build()
+                .wrap(),
+            ButtonBuilder::new()
+                .with_text("Cancel")
+                .with_event(UserEvent::CancelWritingMail)
+                .with_width_bound(dimension_bound!(!))
+                .build()
+                .wrap(),
+        ];
+
+        WindowBuilder::new()
+            .with_title("Write mail".to_string())
+            .with_class(Self::WINDOW_CLASS.to_string())
+            .with_size_bound(size_bound!(300 > 350 < 500, ? < 80%))
+            .with_elements(elements)
+            .build(window_cache, application, available_space)
+    }
+}
@@ -0,0 +1,174 @@
+use std::sync::Arc;
+
+use cgmath::Vector2;
+use derive_new::new;
+use korangar_interface::elements::ElementWrap;
+use korangar_interface::size_bound;
+use korangar_interface::state::PlainRemote;
+use korangar_interface::windows::{PrototypeWindow, Window, WindowBuilder};
+use ragnarok_packets::EntityId;
+
+use crate::graphics::{Color, Texture};
+use crate::interface::application::InterfaceSettings;
+use crate::interface::elements::MinimapView;
+use crate::interface::layout::ScreenSize;
+use crate::interface::windows::WindowCache;
+
+/// Entity or party member shown on the minimap.
+#[derive(Clone, Copy, PartialEq)]
+pub struct MinimapIcon {
+    pub position: Vector2<usize>,
+    pub color: Color,
+}
+
+/// What placed a [`MinimapMarker`]. Each source can only have one marker at a
+/// time.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum MarkerSource {
+    /// Marker placed by an NPC. NPCs can place multiple markers with different
+    /// ids.
+    Npc { npc_id: EntityId, marker_id: u8 },
+    /// Destination of a navigation hint.
+    Navigation,
+}
+
+/// Position that was marked by the server.
+pub struct MinimapMarker {
+    pub source: MarkerSource,
+    pub position: Vector2<usize>,
+    pub color: Color,
+    /// Client tick at which the marker is removed, `None` if it stays until the
+    /// player leaves the map.
+    pub expire_tick: Option<u32>,
+}
+
+/// Everything that is shown on the minimap of the current map.
+pub struct Minimap {
+    pub map_name: String,
+    pub texture: Option<Arc<Texture>>,
+    pub map_size: Vector2<usize>,
+    pub player_position: Option<Vector2<usize>>,
+    pub icons: Vec<MinimapIcon>,
+    pub markers: Vec<MinimapMarker>,
+}
+
+impl Default for Minimap {
+    fn default() -> Self {
+        Self {
+            map_name: String::new(),
+            texture: None,
+            map_size: Vector2::new(0, 0),
+            player_position: None,
+            icons: Vec::new(),
+            markers: Vec::new(),
+        }
+    }
+}
+
+impl Minimap {
+    pub fn new(map_name: String, texture: Arc<Texture>, map_size: Vector2<usize>) -> Self {
+        Self {
+            map_name,
+            texture: Some(texture),
+            map_size,
+            ..Default::default()
+        }
+    }
+
+    pub fn add_marker(&mut self, marker: MinimapMarker) {
+        self.remove_marker(marker.source);
+        self.markers.push(marker);
+    }
+
+    pub fn remove_marker(&mut self, source: MarkerSource) {
+        self.markers.retain(|marker| marker.source != source);
+    }
+
+    pub fn has_expired_markers(&self, client_tick: u32) -> bool {
+        self.markers
+            .iter()
+            .any(|marker| marker.expire_tick.is_some_and(|expire_tick| expire_tick <= client_tick))
+    }
+
+    pub fn remove_expired_markers(&mut self, client_tick: u32) {
+        self.markers
+            .retain(|marker| marker.expire_tick.map_or(true, |expire_tick| expire_tick > client_tick));
+    }
+
+    /// Position of a tile on the minimap, from 0 to 1 along each axis. The map
+    /// is centered in a square with north facing up.
+    pub fn tile_to_fraction(&self, tile: Vector2<usize>) -> Vector2<f32> {
+        let side = self.map_size.x.max(self.map_size.y).max(1) as f32;
+        let offset_x = (side - self.map_size.x as f32) / 2.0;
+        let offset_y = (side - self.map_size.y as f32) / 2.0;
+
+        Vector2::new(
+            (offset_x + tile.x as f32 + 0.5) / side,
+            1.0 - (offset_y + tile.y as f32 + 0.5) / side,
+        )
+    }
+
+    /// Tile at a position on the minimap, if that position is on the map.
+    pub fn fraction_to_tile(&self, fraction: Vector2<f32>) -> Option<Vector2<usize>> {
+        let side = self.map_size.x.max(self.map_size.y) as f32;
+        let x = fraction.x * side - (side - self.map_size.x as f32) / 2.0;
+        let y = (1.0 - fraction.y) * side - (side - self.map_size.y as f32) / 2.0;
+
+        (x >= 0.0 && y >= 0.0 && x < self.map_size.x as f32 && y < self.map_size.y as f32).then(|| Vector2::new(x as usize, y as usize))
+    }
+}
+
+#[derive(new)]
+pub struct MinimapWindow {
+    minimap: PlainRemote<Minimap>,
+}
+
+impl MinimapWindow {
+    pub const WINDOW_CLASS: &'static str = "minimap";
+}
+
+impl PrototypeWindow<InterfaceSettings> for MinimapWindow {
+    fn window_class(&self) -> Option<&str> {
+        Self::WINDOW_CLASS.into()
+    }
+
+    fn to_window(
+        &self,
+        window_cache: &WindowCache,
+        application: &InterfaceSettings,
+        available_space: ScreenSize,
+    ) -> Window<InterfaceSettings> {
+        let elements = vec![MinimapView::new(self.minimap.clone()).wrap()];
+
+        WindowBuilder::new()
+            .with_title("Minimap".to_string())
+            .with_class(Self::WINDOW_CLASS.to_string())
+            .with_size_bound(size_bound!(200 > 220 < 300, ?))
+            .with_elements(elements)
+            .closable()
+            .build(window_cache, application, available_space)
+    }
+}
+
+#[cfg(test)]
+mod minimap_position {
+    use cgmath::Vector2;
+
+    use super::Minimap;
+
+    #[test]
+    fn fraction_to_tile_reverses_tile_to_fraction() {
+        let minimap = Minimap {
+            map_size: Vector2::new(200, 100),
+            ..Default::default()
+        };
+
+        let tile = Vector2::new(150, 20);
+        let fraction = minimap.tile_to_fraction(tile);
+
+        assert_eq!(minimap.fraction_to_tile(fraction), Some(tile));
+        // The top of the minimap is outside of the map, since the map is wider than it
+        // is tall.
+        assert_eq!(minimap.fraction_to_tile(Vector2::new(0.5, 0.1)), None);
+    }
+}
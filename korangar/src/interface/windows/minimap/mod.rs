@@ -0,0 +1,3 @@
+mod map;
+
+pub use self::map::{MarkerSource, Minimap, MinimapIcon, MinimapMarker, MinimapWindow};
@@ -1,21 +1,37 @@
 mod account;
+mod achievement;
 mod cache;
 mod character;
+mod chat_room;
 #[cfg(feature = "debug")]
 mod debug;
 mod friends;
 mod generic;
+mod guild;
+mod mail;
+mod minimap;
 mod mutable;
+mod party;
+mod quest;
 mod settings;
 mod shop;
+mod trade;
 
 pub use self::account::*;
+pub use self::achievement::*;
 pub use self::cache::WindowCache;
 pub use self::character::*;
+pub use self::chat_room::*;
 #[cfg(feature = "debug")]
 pub use self::debug::*;
 pub use self::friends::*;
 pub use self::generic::*;
+pub use self::guild::*;
+pub use self::mail::*;
+pub use self::minimap::*;
 pub use self::mutable::*;
+pub use self::party::*;
+pub use self::quest::*;
 pub use self::settings::*;
 pub use self::shop::*;
+pub use self::trade::*;
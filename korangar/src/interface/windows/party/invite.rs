@@ -0,0 +1,59 @@
+use derive_new::new;
+use korangar_interface::elements::{ButtonBuilder, ElementWrap, Text};
+use korangar_interface::windows::{PrototypeWindow, Window, WindowBuilder};
+use korangar_interface::{dimension_bound, size_bound};
+use ragnarok_packets::PartyId;
+
+use crate::input::UserEvent;
+use crate::interface::application::InterfaceSettings;
+use crate::interface::layout::ScreenSize;
+use crate::interface::windows::WindowCache;
+
+#[derive(new)]
+pub struct PartyInviteWindow {
+    party_id: PartyId,
+    party_name: String,
+}
+
+impl PartyInviteWindow {
+    pub const WINDOW_CLASS: &'static str = "party_invite";
+}
+
+impl PrototypeWindow<InterfaceSettings> for PartyInviteWindow {
+    fn to_window(
+        &self,
+        window_cache: &WindowCache,
+        application: &InterfaceSettings,
+        available_space: ScreenSize,
+    ) -> Window<InterfaceSettings> {
+        let elements = vec![
+            Text::default()
+                .with_text(format!(
+                    "You have been invited to join the party ^ffaa00{}^000000",
+                    self.party_name
+                ))
+                .wrap(),
+            ButtonBuilder::new()
+                .with_text("reject")
+                .with_event(UserEvent::RejectPartyInvite(self.party_id))
+                .with_width_bound(dimension_bound!(50%))
+                .build()
+                .wrap(),
+            ButtonBuilder::new()
+                .with_text("accept")
+                .with_event(UserEvent::AcceptPartyInvite(self.party_id))
+                .with_width_bound(dimension_bound!(!))
+                .build()
+                .wrap(),
+        ];
+
+        WindowBuilder::new()
+            .with_title("Party invitation".to_string())
+            // Same as the friend request window, we give the builder a class without
+            // implementing `window_class` so we can close all invitations at once.
+            .with_class(Self::WINDOW_CLASS.to_owned())
+            .with_size_bound(size_bound!(250 > 250 < 250, ?))
+            .with_elements(elements)
+            .build(window_cache, application, available_space)
+    }
+}
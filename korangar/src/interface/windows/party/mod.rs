@@ -0,0 +1,5 @@
+mod invite;
+mod list;
+
+pub use self::invite::PartyInviteWindow;
+pub use self::list::{PartyMemberInformation, PartyWindow};
@@ -0,0 +1,197 @@
+use derive_new::new;
+use korangar_interface::elements::{ElementWrap, ScrollView};
+use korangar_interface::size_bound;
+use korangar_interface::state::PlainRemote;
+use korangar_interface::windows::{PrototypeWindow, Window, WindowBuilder};
+use korangar_networking::{QuestData, QuestObjectiveData};
+use ragnarok_packets::HuntingObjective;
+
+use crate::interface::application::InterfaceSettings;
+use crate::interface::elements::QuestLogView;
+use crate::interface::layout::ScreenSize;
+use crate::interface::windows::WindowCache;
+use crate::loaders::QuestText;
+
+/// Quest in the quest log, together with the text from the client files.
+pub struct QuestLogEntry {
+    pub quest_id: u32,
+    pub active: bool,
+    /// Unix timestamp of when the quest expires, zero if it doesn't.
+    pub expire_time: u32,
+    pub text: QuestText,
+    pub objectives: Vec<QuestObjectiveData>,
+}
+
+impl QuestLogEntry {
+    pub fn new(quest: QuestData, text: QuestText) -> Self {
+        Self {
+            quest_id: quest.quest_id,
+            active: quest.active,
+            expire_time: quest.expire_time,
+            text,
+            objectives: quest.objectives,
+        }
+    }
+}
+
+/// Quests that the player accepted.
+#[derive(Default)]
+pub struct QuestLog {
+    pub quests: Vec<QuestLogEntry>,
+}
+
+impl QuestLog {
+    pub fn add_quest(&mut self, quest: QuestLogEntry) {
+        match self.quests.iter_mut().find(|entry| entry.quest_id == quest.quest_id) {
+            Some(entry) => *entry = quest,
+            None => self.quests.push(quest),
+        }
+    }
+
+    pub fn remove_quest(&mut self, quest_id: u32) {
+        self.quests.retain(|quest| quest.quest_id != quest_id);
+    }
+
+    pub fn update_objectives(&mut self, updates: &[HuntingObjective]) {
+        for update in updates {
+            // Depending on the packet version, the first field either holds the hunt
+            // identification of the objective or only the quest id. The hunt
+            // identification is preferred since a quest can have multiple objectives
+            // for the same monster.
+            let matches_hunt_identification =
+                |objective: &QuestObjectiveData| objective.hunt_identification == update.quest_id && objective.mob_id == update.mob_id;
+            let by_hunt_identification = self
+                .quests
+                .iter()
+                .flat_map(|quest| &quest.objectives)
+                .any(matches_hunt_identification);
+
+            for quest in &mut self.quests {
+                let quest_id = quest.quest_id;
+
+                for objective in &mut quest.objectives {
+                    let matches = match by_hunt_identification {
+                        true => matches_hunt_identification(objective),
+                        false => quest_id == update.quest_id && objective.mob_id == update.mob_id,
+                    };
+
+                    if matches {
+                        objective.current_count = update.current_count;
+                        objective.total_count = update.total_count;
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[derive(new)]
+pub struct QuestLogWindow {
+    quest_log: PlainRemote<QuestLog>,
+}
+
+impl QuestLogWindow {
+    pub const WINDOW_CLASS: &'static str = "quest_log";
+}
+
+impl PrototypeWindow<InterfaceSettings> for QuestLogWindow {
+    fn window_class(&self) -> Option<&str> {
+        Self::WINDOW_CLASS.into()
+    }
+
+    fn to_window(
+        &self,
+        window_cache: &WindowCache,
+        application: &InterfaceSettings,
+        available_space: ScreenSize,
+    ) -> Window<InterfaceSettings> {
+        let elements = vec![
+            ScrollView::new(
+                vec![QuestLogView::new(self.quest_log.clone()).wrap()],
+                size_bound!(100%, ? < super),
+            )
+            .wrap(),
+        ];
+
+        WindowBuilder::new()
+            .with_title("Quest log".to_string())
+            .with_class(Self::WINDOW_CLASS.to_string())
+            .with_size_bound(size_bound!(300 > 350 < 500, ? < 80%))
+            .with_elements(elements)
+            .closable()
+            .build(window_cache, application, available_space)
+    }
+}
+
+#[cfg(test)]
+mod update_objectives {
+    use korangar_networking::QuestObjectiveData;
+    use ragnarok_packets::HuntingObjective;
+
+    use super::{QuestLog, QuestLogEntry};
+    use crate::loaders::QuestText;
+
+    fn quest(quest_id: u32, objectives: &[(u32, u32)]) -> QuestLogEntry {
+        QuestLogEntry {
+            quest_id,
+            active: true,
+            expire_time: 0,
+            text: QuestText {
+                title: String::new(),
+                summary: String::new(),
+                description: Vec::new(),
+            },
+            objectives: objectives
+                .iter()
+                .map(|&(hunt_identification, mob_id)| QuestObjectiveData {
+                    hunt_identification,
+                    mob_id,
+                    mob_name: String::new(),
+                    current_count: 0,
+                    total_count: 0,
+                })
+                .collect(),
+        }
+    }
+
+    fn counts(quest_log: &QuestLog) -> Vec<u16> {
+        quest_log
+            .quests
+            .iter()
+            .flat_map(|quest| &quest.objectives)
+            .map(|objective| objective.current_count)
+            .collect()
+    }
+
+    #[test]
+    fn by_hunt_identification() {
+        let mut quest_log = QuestLog {
+            quests: vec![quest(1000, &[(1000000, 1002), (1000001, 1002)]), quest(2000, &[(2000000, 1002)])],
+        };
+
+        quest_log.update_objectives(&[HuntingObjective {
+            quest_id: 1000001,
+            mob_id: 1002,
+            total_count: 10,
+            current_count: 3,
+        }]);
+
+        assert_eq!(counts(&quest_log), [0, 3, 0]);
+    }
+
+    #[test]
+    fn by_quest_id() {
+        let mut quest_log = QuestLog {
+            quests: vec![quest(1000, &[(0, 1002), (0, 1113)]), quest(2000, &[(0, 1002)])],
+        };
+
+        quest_log.update_objectives(&[HuntingObjective {
+            quest_id: 1000,
+            mob_id: 1002,
+            total_count: 10,
+            current_count: 3,
+        }]);
+
+        assert_eq!(counts(&quest_log), [3, 0, 0]);
+    }
+}
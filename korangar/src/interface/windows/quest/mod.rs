@@ -0,0 +1,3 @@
+mod log;
+
+pub use self::log::{QuestLog, QuestLogEntry, QuestLogWindow};
@@ -1,11 +1,21 @@
 mod buy;
 mod buy_cart;
 mod buy_or_sell;
+mod own_store;
 mod sell;
 mod sell_cart;
+mod store_listing;
+mod store_setup;
+mod vending;
+mod vending_cart;
 
 pub use self::buy::*;
 pub use self::buy_cart::*;
 pub use self::buy_or_sell::*;
+pub use self::own_store::*;
 pub use self::sell::*;
 pub use self::sell_cart::*;
+pub use self::store_listing::*;
+pub use self::store_setup::*;
+pub use self::vending::*;
+pub use self::vending_cart::*;
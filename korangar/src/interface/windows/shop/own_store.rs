@@ -0,0 +1,62 @@
+use derive_new::new;
+use korangar_interface::elements::{ButtonBuilder, ElementWrap, Text};
+use korangar_interface::event::ClickAction;
+use korangar_interface::size_bound;
+use korangar_interface::windows::{PrototypeWindow, Window, WindowBuilder};
+use korangar_networking::StoreType;
+
+use crate::input::UserEvent;
+use crate::interface::application::InterfaceSettings;
+use crate::interface::layout::ScreenSize;
+use crate::interface::windows::WindowCache;
+
+/// Shown while the vending shop or buying store of the player is open.
+#[derive(new)]
+pub struct OwnStoreWindow {
+    store_type: StoreType,
+    /// Descriptions of the items in the store.
+    items: Vec<String>,
+}
+
+impl OwnStoreWindow {
+    pub const WINDOW_CLASS: &'static str = "own_store";
+}
+
+impl PrototypeWindow<InterfaceSettings> for OwnStoreWindow {
+    fn window_class(&self) -> Option<&str> {
+        Self::WINDOW_CLASS.into()
+    }
+
+    fn to_window(
+        &self,
+        window_cache: &WindowCache,
+        application: &InterfaceSettings,
+        available_space: ScreenSize,
+    ) -> Window<InterfaceSettings> {
+        let (title, close_event) = match self.store_type {
+            StoreType::Vending => ("Vending shop", UserEvent::CloseVendingShop),
+            StoreType::Buying => ("Buying store", UserEvent::CloseBuyingStore),
+        };
+
+        let mut elements = self
+            .items
+            .iter()
+            .map(|item| Text::default().with_text(item.clone()).wrap())
+            .collect::<Vec<_>>();
+
+        elements.push(
+            ButtonBuilder::new()
+                .with_text("Close shop")
+                .with_event(move || vec![ClickAction::Custom(close_event.clone())])
+                .build()
+                .wrap(),
+        );
+
+        WindowBuilder::new()
+            .with_title(title.to_string())
+            .with_class(Self::WINDOW_CLASS.to_string())
+            .with_size_bound(size_bound!(250 > 300 < 400, ?))
+            .with_elements(elements)
+            .build(window_cache, application, available_space)
+    }
+}
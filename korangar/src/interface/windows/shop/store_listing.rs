@@ -0,0 +1,54 @@
+use derive_new::new;
+use korangar_interface::elements::{ElementWrap, ScrollView};
+use korangar_interface::size_bound;
+use korangar_interface::state::PlainTrackedState;
+use korangar_interface::windows::{PrototypeWindow, Window, WindowBuilder};
+use korangar_networking::StoreType;
+
+use crate::interface::application::InterfaceSettings;
+use crate::interface::elements::{StoreListing, StoreListingContainer};
+use crate::interface::layout::ScreenSize;
+use crate::interface::windows::WindowCache;
+
+#[derive(new)]
+pub struct StoreListingWindow {
+    store_type: StoreType,
+    shop_name: PlainTrackedState<String>,
+    zeny_limit: PlainTrackedState<String>,
+    listing: PlainTrackedState<Vec<StoreListing>>,
+}
+
+impl StoreListingWindow {
+    pub const WINDOW_CLASS: &'static str = "store_listing";
+}
+
+impl PrototypeWindow<InterfaceSettings> for StoreListingWindow {
+    fn window_class(&self) -> Option<&str> {
+        Self::WINDOW_CLASS.into()
+    }
+
+    fn to_window(
+        &self,
+        window_cache: &WindowCache,
+        application: &InterfaceSettings,
+        available_space: ScreenSize,
+    ) -> Window<InterfaceSettings> {
+        let elements = vec![
+            StoreListingContainer::new(
+                self.store_type,
+                self.shop_name.clone(),
+                self.zeny_limit.clone(),
+                self.listing.clone(),
+            )
+            .wrap(),
+        ];
+        let elements = vec![ScrollView::new(elements, size_bound!(100%, ? < super)).wrap()];
+
+        WindowBuilder::new()
+            .with_title("Listed items".to_string())
+            .with_class(Self::WINDOW_CLASS.to_string())
+            .with_size_bound(size_bound!(300 > 400 < 500, ? < 60%))
+            .with_elements(elements)
+            .build(window_cache, application, available_space)
+    }
+}
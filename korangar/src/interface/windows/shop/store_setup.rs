@@ -0,0 +1,93 @@
+use derive_new::new;
+use korangar_interface::elements::{ElementWrap, FocusMode, InputFieldBuilder, ScrollView, Text};
+use korangar_interface::event::ClickAction;
+use korangar_interface::size_bound;
+use korangar_interface::state::PlainTrackedState;
+use korangar_interface::windows::{PrototypeWindow, Window, WindowBuilder};
+use korangar_networking::{InventoryItem, StoreType};
+
+use crate::interface::application::InterfaceSettings;
+use crate::interface::elements::{StoreListing, StoreSetupContainer};
+use crate::interface::layout::ScreenSize;
+use crate::interface::windows::WindowCache;
+use crate::loaders::ResourceMetadata;
+
+/// Lets the player pick the items for their own vending shop (from the cart)
+/// or buying store (from the inventory).
+#[derive(new)]
+pub struct StoreSetupWindow {
+    store_type: StoreType,
+    slot_count: usize,
+    items: Vec<InventoryItem<ResourceMetadata>>,
+    shop_name: PlainTrackedState<String>,
+    zeny_limit: PlainTrackedState<String>,
+    listing: PlainTrackedState<Vec<StoreListing>>,
+}
+
+impl StoreSetupWindow {
+    pub const WINDOW_CLASS: &'static str = "store_setup";
+}
+
+impl PrototypeWindow<InterfaceSettings> for StoreSetupWindow {
+    fn window_class(&self) -> Option<&str> {
+        Self::WINDOW_CLASS.into()
+    }
+
+    fn to_window(
+        &self,
+        window_cache: &WindowCache,
+        application: &InterfaceSettings,
+        available_space: ScreenSize,
+    ) -> Window<InterfaceSettings> {
+        let price = PlainTrackedState::<String>::default();
+        let input_action = Box::new(move || vec![ClickAction::FocusNext(FocusMode::FocusNext)]);
+
+        let mut elements = vec![
+            InputFieldBuilder::new()
+                .with_state(self.shop_name.clone())
+                .with_ghost_text("Shop name")
+                .with_enter_action(input_action.clone())
+                .with_length(79)
+                .build()
+                .wrap(),
+        ];
+
+        if self.store_type == StoreType::Buying {
+            elements.push(
+                InputFieldBuilder::new()
+                    .with_state(self.zeny_limit.clone())
+                    .with_ghost_text("Zeny limit")
+                    .with_enter_action(input_action.clone())
+                    .with_length(10)
+                    .build()
+                    .wrap(),
+            );
+        }
+
+        elements.push(Text::default().with_text("Items are added for the price below").wrap());
+        elements.push(
+            InputFieldBuilder::new()
+                .with_state(price.clone())
+                .with_ghost_text("Price")
+                .with_enter_action(input_action)
+                .with_length(10)
+                .build()
+                .wrap(),
+        );
+
+        let items = vec![StoreSetupContainer::new(self.store_type, self.slot_count, &self.items, price, self.listing.clone()).wrap()];
+        elements.push(ScrollView::new(items, size_bound!(100%, ? < super)).wrap());
+
+        let title = match self.store_type {
+            StoreType::Vending => "Open vending shop",
+            StoreType::Buying => "Open buying store",
+        };
+
+        WindowBuilder::new()
+            .with_title(title.to_string())
+            .with_class(Self::WINDOW_CLASS.to_string())
+            .with_size_bound(size_bound!(300 > 400 < 500, ? < 60%))
+            .with_elements(elements)
+            .build(window_cache, application, available_space)
+    }
+}
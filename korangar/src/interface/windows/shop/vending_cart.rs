@@ -0,0 +1,47 @@
+use derive_new::new;
+use korangar_interface::elements::{ElementWrap, ScrollView};
+use korangar_interface::size_bound;
+use korangar_interface::state::PlainTrackedState;
+use korangar_interface::windows::{PrototypeWindow, Window, WindowBuilder};
+use korangar_networking::VendingItem;
+use ragnarok_packets::{EntityId, VendorId};
+
+use crate::interface::application::InterfaceSettings;
+use crate::interface::elements::VendingCartContainer;
+use crate::interface::layout::ScreenSize;
+use crate::interface::windows::WindowCache;
+use crate::loaders::ResourceMetadata;
+
+#[derive(new)]
+pub struct VendingCartWindow {
+    entity_id: EntityId,
+    vendor_id: VendorId,
+    cart: PlainTrackedState<Vec<VendingItem<(ResourceMetadata, u16)>>>,
+}
+
+impl VendingCartWindow {
+    pub const WINDOW_CLASS: &'static str = "vending_cart";
+}
+
+impl PrototypeWindow<InterfaceSettings> for VendingCartWindow {
+    fn window_class(&self) -> Option<&str> {
+        Self::WINDOW_CLASS.into()
+    }
+
+    fn to_window(
+        &self,
+        window_cache: &WindowCache,
+        application: &InterfaceSettings,
+        available_space: ScreenSize,
+    ) -> Window<InterfaceSettings> {
+        let elements = vec![VendingCartContainer::new(self.entity_id, self.vendor_id, self.cart.clone()).wrap()];
+        let elements = vec![ScrollView::new(elements, size_bound!(100%, ? < super)).wrap()];
+
+        WindowBuilder::new()
+            .with_title("Cart".to_string())
+            .with_class(Self::WINDOW_CLASS.to_string())
+            .with_size_bound(size_bound!(300 > 400 < 500, ? < 60%))
+            .with_elements(elements)
+            .build(window_cache, application, available_space)
+    }
+}
@@ -0,0 +1,159 @@
+use derive_new::new;
+use korangar_interface::elements::{ButtonBuilder, ElementWrap, InputFieldBuilder, ScrollView};
+use korangar_interface::event::ClickAction;
+use korangar_interface::state::{PlainRemote, PlainTrackedState, TrackedStateTake};
+use korangar_interface::windows::{PrototypeWindow, Window, WindowBuilder};
+use korangar_interface::{dimension_bound, size_bound};
+use korangar_networking::{InventoryItem, InventoryItemDetails, TradeItem};
+use ragnarok_packets::InventoryIndex;
+
+use crate::input::UserEvent;
+use crate::interface::application::InterfaceSettings;
+use crate::interface::elements::TradeView;
+use crate::interface::layout::ScreenSize;
+use crate::interface::windows::WindowCache;
+use crate::loaders::ResourceMetadata;
+
+/// State of an ongoing trade with another player.
+#[derive(Default)]
+pub struct Trade {
+    pub partner_name: Option<String>,
+    pub partner_base_level: u16,
+    pub own_items: Vec<TradeItem<ResourceMetadata>>,
+    pub own_zeny: u32,
+    pub own_locked: bool,
+    pub partner_items: Vec<TradeItem<ResourceMetadata>>,
+    pub partner_zeny: u32,
+    pub partner_locked: bool,
+    /// Items and zeny that were offered but not yet acknowledged by the
+    /// server.
+    pending_items: Vec<(InventoryIndex, u32)>,
+    pending_zeny: Option<u32>,
+}
+
+impl Trade {
+    pub fn new(partner_name: Option<String>, partner_base_level: u16) -> Self {
+        Self {
+            partner_name,
+            partner_base_level,
+            ..Default::default()
+        }
+    }
+
+    pub fn add_pending_item(&mut self, index: InventoryIndex, amount: u32) {
+        self.pending_items.push((index, amount));
+    }
+
+    pub fn add_pending_zeny(&mut self, amount: u32) {
+        self.pending_zeny = Some(amount);
+    }
+
+    /// Removes a pending item from the trade and returns the amount that was
+    /// offered.
+    pub fn take_pending_item(&mut self, index: InventoryIndex) -> Option<u32> {
+        let position = self.pending_items.iter().position(|(pending_index, _)| *pending_index == index)?;
+        Some(self.pending_items.remove(position).1)
+    }
+
+    pub fn take_pending_zeny(&mut self) -> Option<u32> {
+        self.pending_zeny.take()
+    }
+
+    pub fn add_own_item(&mut self, item: &InventoryItem<ResourceMetadata>, amount: u32) {
+        let refinement_level = match &item.details {
+            InventoryItemDetails::Regular { .. } => 0,
+            InventoryItemDetails::Equippable { refinement_level, .. } => *refinement_level,
+        };
+
+        self.own_items.push(TradeItem {
+            metadata: item.metadata.clone(),
+            item_id: item.item_id,
+            item_type: item.item_type,
+            amount,
+            is_identified: item.is_identifed(),
+            refinement_level,
+            slot: item.slot,
+        });
+    }
+}
+
+#[derive(new)]
+pub struct TradeWindow {
+    trade: PlainRemote<Option<Trade>>,
+}
+
+impl TradeWindow {
+    pub const WINDOW_CLASS: &'static str = "trade";
+}
+
+impl PrototypeWindow<InterfaceSettings> for TradeWindow {
+    fn window_class(&self) -> Option<&str> {
+        Self::WINDOW_CLASS.into()
+    }
+
+    fn to_window(
+        &self,
+        window_cache: &WindowCache,
+        application: &InterfaceSettings,
+        available_space: ScreenSize,
+    ) -> Window<InterfaceSettings> {
+        let zeny = PlainTrackedState::<String>::default();
+
+        let add_zeny_action = {
+            let mut zeny = zeny.clone();
+
+            Box::new(move || {
+                zeny.take()
+                    .parse::<u32>()
+                    .ok()
+                    .filter(|amount| *amount > 0)
+                    .map(|amount| vec![ClickAction::Custom(UserEvent::AddTradeZeny(amount))])
+                    .unwrap_or_default()
+            })
+        };
+
+        let elements = vec![
+            TradeView::new(self.trade.clone()).wrap(),
+            InputFieldBuilder::new()
+                .with_state(zeny)
+                .with_ghost_text("Zeny")
+                .with_enter_action(add_zeny_action.clone())
+                .with_length(10)
+                .with_width_bound(dimension_bound!(70%))
+                .build()
+                .wrap(),
+            ButtonBuilder::new()
+                .with_text("Add zeny")
+                .with_event(add_zeny_action)
+                .with_width_bound(dimension_bound!(!))
+                .build()
+                .wrap(),
+            ButtonBuilder::new()
+                .with_text("Lock")
+                .with_event(UserEvent::LockTrade)
+                .with_width_bound(dimension_bound!(33.33%))
+                .build()
+                .wrap(),
+            ButtonBuilder::new()
+                .with_text("Trade")
+                .with_event(UserEvent::CommitTrade)
+                .with_width_bound(dimension_bound!(33.33%))
+                .build()
+                .wrap(),
+            ButtonBuilder::new()
+                .with_text("Cancel")
+                .with_event(UserEvent::CancelTrade)
+                .with_width_bound(dimension_bound!(!))
+                .build()
+                .wrap(),
+        ];
+        let elements = vec![ScrollView::new(elements, size_bound!(100%, ? < super)).wrap()];
+
+        WindowBuilder::new()
+            .with_title("Trade".to_string())
+            .with_class(Self::WINDOW_CLASS.to_owned())
+            .with_size_bound(size_bound!(250 > 300 < 400, ? < 70%))
+            .with_elements(elements)
+            .build(window_cache, application, available_space)
+    }
+}
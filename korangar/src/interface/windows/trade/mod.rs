@@ -0,0 +1,5 @@
+mod deal;
+mod request;
+
+pub use self::deal::{Trade, TradeWindow};
+pub use self::request::TradeRequestWindow;
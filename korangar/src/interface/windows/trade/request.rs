@@ -0,0 +1,60 @@
+use derive_new::new;
+use korangar_interface::elements::{ButtonBuilder, ElementWrap, Text};
+use korangar_interface::windows::{PrototypeWindow, Window, WindowBuilder};
+use korangar_interface::{dimension_bound, size_bound};
+
+use crate::input::UserEvent;
+use crate::interface::application::InterfaceSettings;
+use crate::interface::layout::ScreenSize;
+use crate::interface::windows::WindowCache;
+
+#[derive(new)]
+pub struct TradeRequestWindow {
+    name: String,
+    base_level: u16,
+}
+
+impl TradeRequestWindow {
+    pub const WINDOW_CLASS: &'static str = "trade_request";
+}
+
+impl PrototypeWindow<InterfaceSettings> for TradeRequestWindow {
+    fn window_class(&self) -> Option<&str> {
+        Self::WINDOW_CLASS.into()
+    }
+
+    fn to_window(
+        &self,
+        window_cache: &WindowCache,
+        application: &InterfaceSettings,
+        available_space: ScreenSize,
+    ) -> Window<InterfaceSettings> {
+        let elements = vec![
+            Text::default()
+                .with_text(format!(
+                    "^ffaa00{}^000000 (level {}) wants to trade with you",
+                    self.name, self.base_level
+                ))
+                .wrap(),
+            ButtonBuilder::new()
+                .with_text("reject")
+                .with_event(UserEvent::RejectTradeRequest)
+                .with_width_bound(dimension_bound!(50%))
+                .build()
+                .wrap(),
+            ButtonBuilder::new()
+                .with_text("accept")
+                .with_event(UserEvent::AcceptTradeRequest)
+                .with_width_bound(dimension_bound!(!))
+                .build()
+                .wrap(),
+        ];
+
+        WindowBuilder::new()
+            .with_title("Trade request".to_string())
+            .with_class(Self::WINDOW_CLASS.to_owned())
+            .with_size_bound(size_bound!(250 > 250 < 250, ?))
+            .with_elements(elements)
+            .build(window_cache, application, available_space)
+    }
+}
@@ -1,15 +1,38 @@
 use std::cell::Ref;
+use std::collections::HashMap;
 
 use korangar_interface::state::{PlainRemote, PlainTrackedState, TrackedState, TrackedStateExt};
 use korangar_networking::NetworkingSystem;
 use ragnarok_packets::handler::PacketCallback;
-use ragnarok_packets::{HotbarSlot, HotbarTab, HotkeyData};
+use ragnarok_packets::{ClientTick, HotbarSlot, HotbarTab, HotkeyData, SkillId};
 
 use super::Skill;
 
+/// Skills of the player that are on cooldown, together with the client tick
+/// they were last updated at.
+#[derive(Default)]
+pub struct SkillCooldowns {
+    client_tick: u32,
+    /// Start and end tick of the cooldown of each skill.
+    cooldowns: HashMap<SkillId, (u32, u32)>,
+}
+
+impl SkillCooldowns {
+    /// Get the fraction of the cooldown that is left and the remaining time in
+    /// milliseconds.
+    pub fn get_remaining(&self, skill_id: SkillId) -> Option<(f32, u32)> {
+        let &(start_tick, end_tick) = self.cooldowns.get(&skill_id)?;
+        let remaining = end_tick.saturating_sub(self.client_tick);
+        let duration = end_tick.saturating_sub(start_tick).max(1);
+
+        (remaining > 0).then_some((remaining as f32 / duration as f32, remaining))
+    }
+}
+
 #[derive(Default)]
 pub struct Hotbar {
     skills: PlainTrackedState<[Option<Skill>; 10]>,
+    cooldowns: PlainTrackedState<SkillCooldowns>,
 }
 
 impl Hotbar {
@@ -103,4 +126,56 @@ impl Hotbar {
     pub fn get_skills(&self) -> PlainRemote<[Option<Skill>; 10]> {
         self.skills.new_remote()
     }
+
+    pub fn set_cooldown(&mut self, skill_id: SkillId, duration: u32, client_tick: ClientTick) {
+        self.cooldowns.mutate(|skill_cooldowns| {
+            skill_cooldowns.client_tick = client_tick.0;
+            skill_cooldowns
+                .cooldowns
+                .insert(skill_id, (client_tick.0, client_tick.0.wrapping_add(duration)));
+        });
+    }
+
+    /// Advance the cooldowns, so the hotbar is redrawn while any skill is on
+    /// cooldown.
+    pub fn update_cooldowns(&mut self, client_tick: ClientTick) {
+        if self.cooldowns.get().cooldowns.is_empty() {
+            return;
+        }
+
+        self.cooldowns.mutate(|skill_cooldowns| {
+            skill_cooldowns.client_tick = client_tick.0;
+            skill_cooldowns.cooldowns.retain(|_, (_, end_tick)| *end_tick > client_tick.0);
+        });
+    }
+
+    pub fn clear_cooldowns(&mut self) {
+        self.cooldowns.set(SkillCooldowns::default());
+    }
+
+    pub fn get_cooldowns(&self) -> PlainRemote<SkillCooldowns> {
+        self.cooldowns.new_remote()
+    }
+}
+
+#[cfg(test)]
+mod skill_cooldowns {
+    use ragnarok_packets::SkillId;
+
+    use super::SkillCooldowns;
+
+    #[test]
+    fn get_remaining_counts_down() {
+        let mut skill_cooldowns = SkillCooldowns {
+            client_tick: 1500,
+            ..Default::default()
+        };
+        skill_cooldowns.cooldowns.insert(SkillId(5), (1000, 3000));
+
+        assert_eq!(skill_cooldowns.get_remaining(SkillId(5)), Some((0.75, 1500)));
+        assert_eq!(skill_cooldowns.get_remaining(SkillId(6)), None);
+
+        skill_cooldowns.client_tick = 3000;
+        assert_eq!(skill_cooldowns.get_remaining(SkillId(5)), None);
+    }
 }
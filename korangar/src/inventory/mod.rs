@@ -7,17 +7,25 @@ use korangar_interface::state::{PlainRemote, PlainTrackedState, TrackedState, Tr
 use korangar_networking::{InventoryItem, InventoryItemDetails, NoMetadata};
 use ragnarok_packets::{EquipPosition, InventoryIndex};
 
-pub use self::hotbar::Hotbar;
+pub use self::hotbar::{Hotbar, SkillCooldowns};
 pub use self::skills::{Skill, SkillTree};
 use crate::loaders::{ResourceMetadata, ScriptLoader, TextureLoader};
 
-#[derive(Default)]
-pub struct Inventory {
-    items: PlainTrackedState<Vec<InventoryItem<ResourceMetadata>>>,
+/// Items of the player, either in the inventory or in the storage.
+pub struct Inventory<Index = InventoryIndex> {
+    items: PlainTrackedState<Vec<InventoryItem<ResourceMetadata, Index>>>,
 }
 
-impl Inventory {
-    pub fn fill(&mut self, texture_loader: &mut TextureLoader, script_loader: &ScriptLoader, items: Vec<InventoryItem<NoMetadata>>) {
+impl<Index> Default for Inventory<Index> {
+    fn default() -> Self {
+        Self {
+            items: PlainTrackedState::default(),
+        }
+    }
+}
+
+impl<Index: PartialEq + 'static> Inventory<Index> {
+    pub fn fill(&mut self, texture_loader: &mut TextureLoader, script_loader: &ScriptLoader, items: Vec<InventoryItem<NoMetadata, Index>>) {
         let items = items
             .into_iter()
             .map(|item| script_loader.load_inventory_item_metadata(texture_loader, item))
@@ -26,7 +34,7 @@ impl Inventory {
         self.items.set(items);
     }
 
-    pub fn add_item(&mut self, texture_loader: &mut TextureLoader, script_loader: &ScriptLoader, item: InventoryItem<NoMetadata>) {
+    pub fn add_item(&mut self, texture_loader: &mut TextureLoader, script_loader: &ScriptLoader, item: InventoryItem<NoMetadata, Index>) {
         self.items.with_mut(|items| {
             if let Some(found_item) = items.iter_mut().find(|inventory_item| inventory_item.index == item.index) {
                 let InventoryItemDetails::Regular { amount, .. } = &mut found_item.details else {
@@ -48,7 +56,7 @@ impl Inventory {
         });
     }
 
-    pub fn remove_item(&mut self, index: InventoryIndex, remove_amount: u16) {
+    pub fn remove_item(&mut self, index: Index, remove_amount: u16) {
         self.items.with_mut(|items| {
             let position = items.iter().position(|item| item.index == index).expect("item not in inventory");
 
@@ -65,7 +73,26 @@ impl Inventory {
         });
     }
 
-    pub fn update_equipped_position(&mut self, index: InventoryIndex, new_equipped_position: EquipPosition) {
+    /// Sets the amount of a regular item, removing it if the amount reaches 0.
+    pub fn set_item_amount(&mut self, index: Index, new_amount: u16) {
+        self.items.with_mut(|items| {
+            let Some(position) = items.iter().position(|item| item.index == index) else {
+                return ValueState::Unchanged(());
+            };
+
+            match &mut items[position].details {
+                _ if new_amount == 0 => {
+                    items.remove(position);
+                }
+                InventoryItemDetails::Regular { amount, .. } => *amount = new_amount,
+                InventoryItemDetails::Equippable { .. } => return ValueState::Unchanged(()),
+            }
+
+            ValueState::Mutated(())
+        });
+    }
+
+    pub fn update_equipped_position(&mut self, index: Index, new_equipped_position: EquipPosition) {
         self.items.mutate(|items| {
             let item = items.iter_mut().find(|item| item.index == index).unwrap();
             let InventoryItemDetails::Equippable { equipped_position, .. } = &mut item.details else {
@@ -76,11 +103,11 @@ impl Inventory {
         });
     }
 
-    pub fn get_items(&self) -> Ref<'_, Vec<InventoryItem<ResourceMetadata>>> {
+    pub fn get_items(&self) -> Ref<'_, Vec<InventoryItem<ResourceMetadata, Index>>> {
         self.items.get()
     }
 
-    pub fn item_remote(&self) -> PlainRemote<Vec<InventoryItem<ResourceMetadata>>> {
+    pub fn item_remote(&self) -> PlainRemote<Vec<InventoryItem<ResourceMetadata, Index>>> {
         self.items.new_remote()
     }
 }
@@ -1,6 +1,6 @@
 use std::sync::Arc;
 
-use korangar_interface::state::{PlainRemote, PlainTrackedState, TrackedState};
+use korangar_interface::state::{PlainRemote, PlainTrackedState, TrackedState, ValueState};
 use ragnarok_packets::{ClientTick, SkillId, SkillInformation, SkillLevel, SkillType};
 
 use crate::loaders::{ActionLoader, Actions, AnimationState, Sprite, SpriteLoader};
@@ -14,6 +14,8 @@ pub struct Skill {
     pub sprite: Arc<Sprite>,
     pub actions: Arc<Actions>,
     pub animation_state: AnimationState,
+    /// Whether or not the skill can be leveled up with a skill point.
+    pub upgradable: bool,
 }
 
 #[derive(Default)]
@@ -39,6 +41,7 @@ impl SkillTree {
                     actions,
                     // FIX: give correct client tick
                     animation_state: AnimationState::new(ClientTick(0)),
+                    upgradable: skill_data.upgraded != 0,
                 }
             })
             .collect();
@@ -46,6 +49,18 @@ impl SkillTree {
         self.skills.set(skills);
     }
 
+    pub fn update_skill(&mut self, skill_id: SkillId, skill_level: SkillLevel, upgradable: bool) {
+        self.skills
+            .with_mut(|skills| match skills.iter_mut().find(|skill| skill.skill_id == skill_id) {
+                Some(skill) => {
+                    skill.skill_level = skill_level;
+                    skill.upgradable = upgradable;
+                    ValueState::Mutated(())
+                }
+                None => ValueState::Unchanged(()),
+            });
+    }
+
     pub fn get_skills(&self) -> PlainRemote<Vec<Skill>> {
         self.skills.new_remote()
     }
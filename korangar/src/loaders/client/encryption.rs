@@ -0,0 +1,116 @@
+use argon2::Argon2;
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use chacha20poly1305::aead::{Aead, KeyInit};
+use chacha20poly1305::{ChaCha20Poly1305, Key, Nonce};
+use rand::{thread_rng, RngCore};
+
+pub const SALT_SIZE: usize = 16;
+const NONCE_SIZE: usize = 12;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PasswordDecryptionError {
+    /// The stored password is not something we wrote.
+    Malformed,
+    /// The stored password was encrypted with a different master password.
+    WrongMasterPassword,
+    /// There is a stored password, but no master password to decrypt it.
+    MissingMasterPassword,
+}
+
+impl std::fmt::Display for PasswordDecryptionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PasswordDecryptionError::Malformed => write!(f, "the saved password is corrupted"),
+            PasswordDecryptionError::WrongMasterPassword => write!(f, "the saved password was stored with a different master password"),
+            PasswordDecryptionError::MissingMasterPassword => write!(f, "no master password is set"),
+        }
+    }
+}
+
+/// Key used to encrypt the stored passwords, derived from the master password
+/// and a salt that is stored alongside the passwords.
+#[derive(Clone)]
+pub struct PasswordKey {
+    cipher: ChaCha20Poly1305,
+}
+
+impl PasswordKey {
+    pub fn derive(master_password: &str, salt: [u8; SALT_SIZE]) -> Self {
+        let mut key = Key::default();
+
+        Argon2::default()
+            .hash_password_into(master_password.as_bytes(), &salt, &mut key)
+            .expect("failed to derive password key");
+
+        Self {
+            cipher: ChaCha20Poly1305::new(&key),
+        }
+    }
+
+    pub fn new_salt() -> [u8; SALT_SIZE] {
+        let mut salt = [0; SALT_SIZE];
+        thread_rng().fill_bytes(&mut salt);
+        salt
+    }
+
+    /// Returns the nonce followed by the cipher text, encoded as base64.
+    pub fn encrypt(&self, password: &str) -> String {
+        let mut nonce = Nonce::default();
+        thread_rng().fill_bytes(&mut nonce);
+
+        let mut data = nonce.to_vec();
+        let cipher_text = self
+            .cipher
+            .encrypt(&nonce, password.as_bytes())
+            .expect("failed to encrypt password");
+        data.extend(cipher_text);
+
+        STANDARD.encode(data)
+    }
+
+    pub fn decrypt(&self, encrypted_password: &str) -> Result<String, PasswordDecryptionError> {
+        let data = STANDARD
+            .decode(encrypted_password)
+            .map_err(|_| PasswordDecryptionError::Malformed)?;
+
+        if data.len() < NONCE_SIZE {
+            return Err(PasswordDecryptionError::Malformed);
+        }
+
+        let (nonce, cipher_text) = data.split_at(NONCE_SIZE);
+        let password = self
+            .cipher
+            .decrypt(Nonce::from_slice(nonce), cipher_text)
+            .map_err(|_| PasswordDecryptionError::WrongMasterPassword)?;
+
+        String::from_utf8(password).map_err(|_| PasswordDecryptionError::Malformed)
+    }
+}
+
+#[cfg(test)]
+mod password_key {
+    use super::{PasswordDecryptionError, PasswordKey};
+
+    #[test]
+    fn decrypt_returns_encrypted_password() {
+        let key = PasswordKey::derive("master", PasswordKey::new_salt());
+        let encrypted_password = key.encrypt("password");
+
+        assert_ne!(encrypted_password, "password");
+        assert_eq!(key.decrypt(&encrypted_password), Ok("password".to_owned()));
+    }
+
+    #[test]
+    fn decrypt_with_wrong_master_password_fails() {
+        let salt = PasswordKey::new_salt();
+        let encrypted_password = PasswordKey::derive("master", salt).encrypt("password");
+        let key = PasswordKey::derive("other", salt);
+
+        assert_eq!(
+            key.decrypt(&encrypted_password),
+            Err(PasswordDecryptionError::WrongMasterPassword)
+        );
+        assert_eq!(key.decrypt("not base64"), Err(PasswordDecryptionError::Malformed));
+    }
+}
@@ -1,76 +1,244 @@
+mod encryption;
+
 use std::collections::HashMap;
 
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
 #[cfg(feature = "debug")]
 use korangar_debug::logging::{print_debug, Colorize};
 use ron::ser::PrettyConfig;
-use serde::ser::SerializeStruct;
-use serde::{Deserialize, Serialize, Serializer};
+use serde::{Deserialize, Serialize};
 
+pub use self::encryption::PasswordDecryptionError;
+use self::encryption::{PasswordKey, SALT_SIZE};
 use crate::loaders::ServiceId;
 
-#[derive(Clone, Default, Serialize, Deserialize)]
+/// Environment variable holding the master password that the stored passwords
+/// are encrypted with. If it is not set (or empty), the master password has to
+/// be entered in the login window before passwords are saved.
+pub const MASTER_PASSWORD_VARIABLE: &str = "KORANGAR_MASTER_PASSWORD";
+
+#[derive(Clone)]
 pub struct LoginSettings {
     pub service: String,
     pub service_settings: HashMap<ServiceId, ServiceSettings>,
     pub recent_service_id: Option<ServiceId>,
+    /// Set if any of the stored passwords could not be decrypted.
+    pub decryption_error: Option<PasswordDecryptionError>,
+    /// `None` if the stored salt is corrupted. Passwords can't be saved in that
+    /// case, since the ones that are already stored would become unreadable.
+    salt: Option<[u8; SALT_SIZE]>,
+    /// The stored salt, written back as is if it is corrupted.
+    stored_salt: String,
+    /// Only set if there is a master password. Without it, anyone who can read
+    /// the settings file could decrypt the passwords, so they are not saved.
+    key: Option<PasswordKey>,
 }
 
-#[derive(Clone, Default, Deserialize)]
+#[derive(Clone, Default)]
 pub struct ServiceSettings {
     pub username: String,
     pub password: String,
     pub remember_username: bool,
     pub remember_password: bool,
+    /// Stored password that could not be decrypted. It is written back as is,
+    /// so starting the client with the wrong master password once doesn't
+    /// lose it.
+    undecryptable_password: Option<String>,
 }
 
-impl Serialize for ServiceSettings {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: serde::Serializer,
-    {
-        let mut serde_state = Serializer::serialize_struct(serializer, "ServiceSettings", 4)?;
-        SerializeStruct::serialize_field(
-            &mut serde_state,
-            "username",
-            self.remember_username.then_some(self.username.as_str()).unwrap_or_default(),
-        )?;
-        SerializeStruct::serialize_field(
-            &mut serde_state,
-            "password",
-            self.remember_password.then_some(self.password.as_str()).unwrap_or_default(),
-        )?;
-        SerializeStruct::serialize_field(&mut serde_state, "remember_username", &self.remember_username)?;
-        SerializeStruct::serialize_field(&mut serde_state, "remember_password", &self.remember_password)?;
-        SerializeStruct::end(serde_state)
-    }
+/// Layout of the settings file.
+#[derive(Default, Serialize, Deserialize)]
+struct StoredLoginSettings {
+    service: String,
+    service_settings: HashMap<ServiceId, StoredServiceSettings>,
+    recent_service_id: Option<ServiceId>,
+    #[serde(default)]
+    password_salt: String,
+}
+
+#[derive(Default, Serialize, Deserialize)]
+struct StoredServiceSettings {
+    username: String,
+    /// Plain text password written by older versions of the client. It is
+    /// only read to migrate the file.
+    #[serde(default, skip_serializing)]
+    password: String,
+    #[serde(default)]
+    encrypted_password: String,
+    remember_username: bool,
+    remember_password: bool,
+}
+
+/// A master password entered by the user takes precedence over the one in
+/// [`MASTER_PASSWORD_VARIABLE`].
+fn derive_key(salt: [u8; SALT_SIZE], master_password: Option<&str>) -> Option<PasswordKey> {
+    master_password
+        .map(str::to_owned)
+        .or_else(|| std::env::var(MASTER_PASSWORD_VARIABLE).ok())
+        .filter(|master_password| !master_password.is_empty())
+        .map(|master_password| PasswordKey::derive(&master_password, salt))
 }
 
 impl LoginSettings {
     const FILE_NAME: &'static str = "client/login_settings.ron";
 
-    pub fn new() -> Self {
-        Self::load().unwrap_or_else(|| {
+    /// Passwords are only saved if a master password is set.
+    pub fn can_save_passwords(&self) -> bool {
+        self.key.is_some()
+    }
+
+    /// Entering a (different) master password would allow saving passwords or
+    /// decrypting the stored ones.
+    pub fn needs_master_password(&self) -> bool {
+        self.salt.is_some() && (self.key.is_none() || self.decryption_error == Some(PasswordDecryptionError::WrongMasterPassword))
+    }
+
+    pub fn new(master_password: Option<&str>) -> Self {
+        Self::load(master_password).unwrap_or_else(|| {
             #[cfg(feature = "debug")]
             print_debug!("failed to load login settings from {}", Self::FILE_NAME.magenta());
 
-            Default::default()
+            let salt = PasswordKey::new_salt();
+
+            Self {
+                service: String::new(),
+                service_settings: HashMap::new(),
+                recent_service_id: None,
+                decryption_error: None,
+                salt: Some(salt),
+                stored_salt: String::new(),
+                key: derive_key(salt, master_password),
+            }
         })
     }
 
-    pub fn load() -> Option<Self> {
+    pub fn load(master_password: Option<&str>) -> Option<Self> {
         #[cfg(feature = "debug")]
         print_debug!("loading login settings from {}", Self::FILE_NAME.magenta());
 
-        std::fs::read_to_string(Self::FILE_NAME)
+        let stored: StoredLoginSettings = std::fs::read_to_string(Self::FILE_NAME)
             .ok()
-            .and_then(|data| ron::from_str(&data).ok())
+            .and_then(|data| ron::from_str(&data).ok())?;
+
+        let mut decryption_error = None;
+
+        // Files written by older versions don't have a salt yet.
+        let salt = match stored.password_salt.is_empty() {
+            true => Some(PasswordKey::new_salt()),
+            false => STANDARD
+                .decode(&stored.password_salt)
+                .ok()
+                .and_then(|salt| <[u8; SALT_SIZE]>::try_from(salt).ok()),
+        };
+
+        if salt.is_none() {
+            #[cfg(feature = "debug")]
+            print_debug!("[{}] saved password salt is corrupted", "error".red());
+
+            decryption_error = Some(PasswordDecryptionError::Malformed);
+        }
+
+        let key = salt.and_then(|salt| derive_key(salt, master_password));
+        let mut contains_plain_text = false;
+
+        let service_settings = stored
+            .service_settings
+            .into_iter()
+            .map(|(service_id, stored)| {
+                let mut settings = ServiceSettings {
+                    username: stored.username,
+                    password: String::new(),
+                    remember_username: stored.remember_username,
+                    remember_password: stored.remember_password,
+                    undecryptable_password: None,
+                };
+
+                if !stored.encrypted_password.is_empty() {
+                    let password = match (&key, salt) {
+                        (Some(key), _) => key.decrypt(&stored.encrypted_password),
+                        (None, Some(_)) => Err(PasswordDecryptionError::MissingMasterPassword),
+                        (None, None) => Err(PasswordDecryptionError::Malformed),
+                    };
+
+                    match password {
+                        Ok(password) => settings.password = password,
+                        Err(error) => {
+                            #[cfg(feature = "debug")]
+                            print_debug!("[{}] failed to decrypt saved password: {}", "error".red(), error);
+
+                            decryption_error = Some(error);
+                            settings.undecryptable_password = Some(stored.encrypted_password);
+                        }
+                    }
+                } else if !stored.password.is_empty() {
+                    contains_plain_text = true;
+                    settings.password = stored.password;
+                }
+
+                (service_id, settings)
+            })
+            .collect();
+
+        let login_settings = Self {
+            service: stored.service,
+            service_settings,
+            recent_service_id: stored.recent_service_id,
+            decryption_error,
+            salt,
+            stored_salt: stored.password_salt,
+            key,
+        };
+
+        // Don't leave plain text passwords on disk any longer than necessary. Without a
+        // master password they are removed from the file instead.
+        if contains_plain_text {
+            #[cfg(feature = "debug")]
+            print_debug!("encrypting plain text passwords in {}", Self::FILE_NAME.magenta());
+
+            login_settings.save();
+        }
+
+        Some(login_settings)
     }
 
     pub fn save(&self) {
         #[cfg(feature = "debug")]
         print_debug!("saving login settings to {}", Self::FILE_NAME.magenta());
 
-        let data = ron::ser::to_string_pretty(self, PrettyConfig::new()).unwrap();
+        let service_settings = self
+            .service_settings
+            .iter()
+            .map(|(&service_id, settings)| {
+                let encrypted_password = match (settings.remember_password, &self.key) {
+                    (true, Some(key)) if !settings.password.is_empty() => key.encrypt(&settings.password),
+                    (true, _) => settings.undecryptable_password.clone().unwrap_or_default(),
+                    (false, _) => String::new(),
+                };
+
+                let stored = StoredServiceSettings {
+                    username: settings.remember_username.then(|| settings.username.clone()).unwrap_or_default(),
+                    password: String::new(),
+                    encrypted_password,
+                    remember_username: settings.remember_username,
+                    remember_password: settings.remember_password,
+                };
+
+                (service_id, stored)
+            })
+            .collect();
+
+        let stored = StoredLoginSettings {
+            service: self.service.clone(),
+            service_settings,
+            recent_service_id: self.recent_service_id,
+            password_salt: self
+                .salt
+                .map(|salt| STANDARD.encode(salt))
+                .unwrap_or_else(|| self.stored_salt.clone()),
+        };
+
+        let data = ron::ser::to_string_pretty(&stored, PrettyConfig::new()).unwrap();
         std::fs::write(Self::FILE_NAME, data).expect("unable to write file");
     }
 }
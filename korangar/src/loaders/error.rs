@@ -6,4 +6,6 @@ pub enum LoadError {
     File(FileNotFoundError),
     Conversion(Box<ConversionError>),
     UnsupportedFormat(String),
+    Decompression(yazi::Error),
+    Image(image::ImageError),
 }
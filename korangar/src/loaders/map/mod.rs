@@ -4,7 +4,7 @@ use std::collections::HashMap;
 use std::sync::Arc;
 
 use bytemuck::Pod;
-use cgmath::Vector3;
+use cgmath::{Vector2, Vector3};
 use derive_new::new;
 use korangar_audio::AudioEngine;
 #[cfg(feature = "debug")]
@@ -13,11 +13,11 @@ use korangar_util::collision::{KDTree, AABB};
 use korangar_util::container::SimpleSlab;
 use korangar_util::FileLoader;
 use ragnarok_bytes::{ByteStream, FromBytes};
-use ragnarok_formats::map::{GatData, GroundData, GroundTile, MapData, MapResources};
+use ragnarok_formats::map::{GatData, GroundData, GroundTile, MapData, MapResources, TileFlags};
 use ragnarok_formats::version::InternalVersion;
 use wgpu::{BufferUsages, Device, Queue};
 
-use self::vertices::{generate_tile_vertices, ground_water_vertices, load_textures};
+use self::vertices::{generate_tile_vertices, ground_water_vertices, load_textures, tile_vertices};
 use super::error::LoadError;
 use crate::graphics::{Buffer, NativeModelVertex, Texture, TextureGroup};
 use crate::loaders::{GameFileLoader, ModelLoader, TextureLoader};
@@ -161,6 +161,35 @@ impl MapLoader {
         Ok(map)
     }
 
+    /// Change the flags of a tile of a loaded map. The map is taken out of the
+    /// cache, since the server only keeps these changes while the player is on
+    /// the map. The tile vertices are only regenerated by
+    /// [`update_tile_vertices`](Self::update_tile_vertices), so multiple
+    /// changes can be applied at once.
+    pub fn set_tile_flags(&mut self, map: &mut Arc<Map>, position: Vector2<usize>, flags: TileFlags) {
+        self.cache.retain(|_, cached_map| !Arc::ptr_eq(cached_map, map));
+
+        let map = Arc::get_mut(map).expect("map is still referenced");
+        map.set_tile_flags(position, flags);
+    }
+
+    /// Regenerate the tile vertices of a map if any of its tile flags changed.
+    /// Returns `true` if the vertices were regenerated.
+    pub fn update_tile_vertices(&mut self, map: &mut Arc<Map>) -> bool {
+        if !map.tiles_changed() {
+            return false;
+        }
+
+        let map = Arc::get_mut(map).expect("map is still referenced");
+        let (tile_vertices, tile_picker_vertices) = tile_vertices(map.get_tiles(), map.get_size().x);
+        let tile_vertex_buffer = self.create_vertex_buffer("changed map", "tile", &tile_vertices);
+        let tile_picker_vertex_buffer = self.create_vertex_buffer("changed map", "tile picker", &tile_picker_vertices);
+
+        map.set_tile_vertex_buffers(tile_vertex_buffer, tile_picker_vertex_buffer);
+
+        true
+    }
+
     fn create_vertex_buffer<T: Pod>(&self, resource: &str, label: &str, vertices: &[T]) -> Buffer<T> {
         Buffer::with_data(
             &self.device,
@@ -1,7 +1,7 @@
 use std::sync::Arc;
 
 use cgmath::{Vector2, Vector3};
-use ragnarok_formats::map::{GatData, GroundData, GroundTile, SurfaceType};
+use ragnarok_formats::map::{GatData, GroundData, GroundTile, SurfaceType, Tile};
 
 use super::GroundTileExt;
 use crate::graphics::{ModelVertex, NativeModelVertex, PickerTarget, Texture, TileVertex, WaterVertex};
@@ -156,100 +156,106 @@ pub fn load_textures(ground_data: &GroundData, texture_loader: &mut TextureLoade
 }
 
 pub fn generate_tile_vertices(gat_data: &mut GatData) -> (Vec<ModelVertex>, Vec<TileVertex>) {
-    let mut tile_vertices = Vec::new();
-    let mut tile_picker_vertices = Vec::new();
+    for tile in &mut gat_data.tiles {
+        tile.upper_left_height = -tile.upper_left_height;
+        tile.upper_right_height = -tile.upper_right_height;
+        tile.lower_left_height = -tile.lower_left_height;
+        tile.lower_right_height = -tile.lower_right_height;
+    }
 
-    let mut count = 0;
-    for y in 0..gat_data.map_height {
-        for x in 0..gat_data.map_width {
-            let tile = &mut gat_data.tiles[count];
+    tile_vertices(&gat_data.tiles, gat_data.map_width as usize)
+}
 
-            tile.upper_left_height = -tile.upper_left_height;
-            tile.upper_right_height = -tile.upper_right_height;
-            tile.lower_left_height = -tile.lower_left_height;
-            tile.lower_right_height = -tile.lower_right_height;
-            count += 1;
+/// Generate the vertices of the tiles of a map, where the heights of the tiles
+/// have already been flipped.
+pub fn tile_vertices(tiles: &[Tile], map_width: usize) -> (Vec<ModelVertex>, Vec<TileVertex>) {
+    let mut tile_vertices = Vec::new();
+    let mut tile_picker_vertices = Vec::new();
 
-            if tile.flags.is_empty() {
-                continue;
-            }
+    for (index, tile) in tiles.iter().enumerate() {
+        let x = index % map_width;
+        let y = index / map_width;
 
-            let offset = Vector2::new(x as f32 * 5.0, y as f32 * 5.0);
-
-            let first_position = Vector3::new(offset.x, tile.upper_left_height + 1.0, offset.y);
-            let second_position = Vector3::new(offset.x + 5.0, tile.upper_right_height + 1.0, offset.y);
-            let third_position = Vector3::new(offset.x + 5.0, tile.lower_right_height + 1.0, offset.y + 5.0);
-            let fourth_position = Vector3::new(offset.x, tile.lower_left_height + 1.0, offset.y + 5.0);
-
-            let first_normal = NativeModelVertex::calculate_normal(first_position, second_position, third_position);
-            let second_normal = NativeModelVertex::calculate_normal(fourth_position, first_position, third_position);
-
-            let first_texture_coordinates = Vector2::new(0.0, 0.0);
-            let second_texture_coordinates = Vector2::new(0.0, 1.0);
-            let third_texture_coordinates = Vector2::new(1.0, 1.0);
-            let fourth_texture_coordinates = Vector2::new(1.0, 0.0);
-
-            let tile_type_index = tile.flags.bits() as i32;
-
-            tile_vertices.push(ModelVertex::new(
-                first_position,
-                first_normal,
-                first_texture_coordinates,
-                tile_type_index,
-                0.0,
-            ));
-            tile_vertices.push(ModelVertex::new(
-                second_position,
-                first_normal,
-                second_texture_coordinates,
-                tile_type_index,
-                0.0,
-            ));
-            tile_vertices.push(ModelVertex::new(
-                third_position,
-                first_normal,
-                third_texture_coordinates,
-                tile_type_index,
-                0.0,
-            ));
-
-            tile_vertices.push(ModelVertex::new(
-                first_position,
-                second_normal,
-                first_texture_coordinates,
-                tile_type_index,
-                0.0,
-            ));
-            tile_vertices.push(ModelVertex::new(
-                third_position,
-                second_normal,
-                third_texture_coordinates,
-                tile_type_index,
-                0.0,
-            ));
-            tile_vertices.push(ModelVertex::new(
-                fourth_position,
-                second_normal,
-                fourth_texture_coordinates,
-                tile_type_index,
-                0.0,
-            ));
-
-            let first_position = Vector3::new(offset.x, tile.upper_left_height, offset.y);
-            let second_position = Vector3::new(offset.x + 5.0, tile.upper_right_height, offset.y);
-            let third_position = Vector3::new(offset.x + 5.0, tile.lower_right_height, offset.y + 5.0);
-            let fourth_position = Vector3::new(offset.x, tile.lower_left_height, offset.y + 5.0);
-
-            let color = PickerTarget::Tile { x: x as u16, y: y as u16 }.into();
-            tile_picker_vertices.push(TileVertex::new(first_position, color));
-            tile_picker_vertices.push(TileVertex::new(second_position, color));
-            tile_picker_vertices.push(TileVertex::new(third_position, color));
-
-            tile_picker_vertices.push(TileVertex::new(first_position, color));
-            tile_picker_vertices.push(TileVertex::new(third_position, color));
-            tile_picker_vertices.push(TileVertex::new(fourth_position, color));
+        if tile.flags.is_empty() {
+            continue;
         }
+
+        let offset = Vector2::new(x as f32 * 5.0, y as f32 * 5.0);
+
+        let first_position = Vector3::new(offset.x, tile.upper_left_height + 1.0, offset.y);
+        let second_position = Vector3::new(offset.x + 5.0, tile.upper_right_height + 1.0, offset.y);
+        let third_position = Vector3::new(offset.x + 5.0, tile.lower_right_height + 1.0, offset.y + 5.0);
+        let fourth_position = Vector3::new(offset.x, tile.lower_left_height + 1.0, offset.y + 5.0);
+
+        let first_normal = NativeModelVertex::calculate_normal(first_position, second_position, third_position);
+        let second_normal = NativeModelVertex::calculate_normal(fourth_position, first_position, third_position);
+
+        let first_texture_coordinates = Vector2::new(0.0, 0.0);
+        let second_texture_coordinates = Vector2::new(0.0, 1.0);
+        let third_texture_coordinates = Vector2::new(1.0, 1.0);
+        let fourth_texture_coordinates = Vector2::new(1.0, 0.0);
+
+        let tile_type_index = tile.flags.bits() as i32;
+
+        tile_vertices.push(ModelVertex::new(
+            first_position,
+            first_normal,
+            first_texture_coordinates,
+            tile_type_index,
+            0.0,
+        ));
+        tile_vertices.push(ModelVertex::new(
+            second_position,
+            first_normal,
+            second_texture_coordinates,
+            tile_type_index,
+            0.0,
+        ));
+        tile_vertices.push(ModelVertex::new(
+            third_position,
+            first_normal,
+            third_texture_coordinates,
+            tile_type_index,
+            0.0,
+        ));
+
+        tile_vertices.push(ModelVertex::new(
+            first_position,
+            second_normal,
+            first_texture_coordinates,
+            tile_type_index,
+            0.0,
+        ));
+        tile_vertices.push(ModelVertex::new(
+            third_position,
+            second_normal,
+            third_texture_coordinates,
+            tile_type_index,
+            0.0,
+        ));
+        tile_vertices.push(ModelVertex::new(
+            fourth_position,
+            second_normal,
+            fourth_texture_coordinates,
+            tile_type_index,
+            0.0,
+        ));
+
+        let first_position = Vector3::new(offset.x, tile.upper_left_height, offset.y);
+        let second_position = Vector3::new(offset.x + 5.0, tile.upper_right_height, offset.y);
+        let third_position = Vector3::new(offset.x + 5.0, tile.lower_right_height, offset.y + 5.0);
+        let fourth_position = Vector3::new(offset.x, tile.lower_left_height, offset.y + 5.0);
+
+        let color = PickerTarget::Tile { x: x as u16, y: y as u16 }.into();
+        tile_picker_vertices.push(TileVertex::new(first_position, color));
+        tile_picker_vertices.push(TileVertex::new(second_position, color));
+        tile_picker_vertices.push(TileVertex::new(third_position, color));
+
+        tile_picker_vertices.push(TileVertex::new(first_position, color));
+        tile_picker_vertices.push(TileVertex::new(third_position, color));
+        tile_picker_vertices.push(TileVertex::new(fourth_position, color));
     }
+
     (tile_vertices, tile_picker_vertices)
 }
 
@@ -18,7 +18,7 @@ pub use self::font::{FontLoader, FontSize, Scaling};
 pub use self::gamefile::*;
 pub use self::map::MapLoader;
 pub use self::model::*;
-pub use self::script::{ResourceMetadata, ScriptLoader};
+pub use self::script::{AchievementText, QuestText, ResourceMetadata, ScriptLoader};
 pub use self::server::{load_client_info, ClientInfo, ServiceId};
 pub use self::sprite::*;
 pub use self::texture::TextureLoader;
@@ -1,6 +1,9 @@
+use std::collections::HashMap;
 use std::sync::Arc;
 
-use korangar_networking::{InventoryItem, NoMetadata, ShopItem};
+#[cfg(feature = "debug")]
+use korangar_debug::logging::{print_debug, Colorize};
+use korangar_networking::{InventoryItem, MailItem, NoMetadata, ShopItem, TradeItem, VendingItem};
 use korangar_util::FileLoader;
 use mlua::Lua;
 use ragnarok_packets::ItemId;
@@ -9,14 +12,44 @@ use super::TextureLoader;
 use crate::graphics::Texture;
 use crate::loaders::GameFileLoader;
 
+const ACHIEVEMENT_LIST_FILE: &str = "System\\achievement_list.lub";
+const STATUS_ID_FILE: &str = "data\\luafiles514\\lua files\\stateicon\\efstids.lub";
+const STATUS_ICON_FILE: &str = "data\\luafiles514\\lua files\\stateicon\\stateiconimginfo.lub";
+const QUEST_INFO_FILE: &str = "System\\OngoingQuestInfoList_True.lub";
+const QUEST_DISPLAY_FILE: &str = "data\\questid2display.txt";
+
 #[derive(Debug, Clone)]
 pub struct ResourceMetadata {
     pub texture: Arc<Texture>,
     pub name: String,
 }
 
+/// Title and description of a quest as shown in the quest log.
+#[derive(Debug, Clone)]
+pub struct QuestText {
+    pub title: String,
+    pub summary: String,
+    pub description: Vec<String>,
+}
+
+/// Objective of an achievement, the progress on it is sent by the server.
+#[derive(Debug, Clone)]
+pub struct AchievementObjective {
+    pub description: String,
+    pub count: u32,
+}
+
+/// Title and objectives of an achievement as shown in the achievement window.
+#[derive(Debug, Clone)]
+pub struct AchievementText {
+    pub title: String,
+    pub summary: String,
+    pub objectives: Vec<AchievementObjective>,
+}
+
 pub struct ScriptLoader {
     state: Lua,
+    quest_texts: HashMap<u32, QuestText>,
 }
 
 impl ScriptLoader {
@@ -60,7 +93,28 @@ end
 
         state.load(job_id_function).exec()?;
 
-        Ok(Self { state })
+        // Newer clients describe quests in a Lua table, older ones only ship the
+        // text table. Neither of them is required to play, and neither is the
+        // achievement table.
+        if let Ok(data) = game_file_loader.get(QUEST_INFO_FILE) {
+            load_optional_script(&state, &data, QUEST_INFO_FILE);
+        }
+
+        if let Ok(data) = game_file_loader.get(ACHIEVEMENT_LIST_FILE) {
+            load_optional_script(&state, &data, ACHIEVEMENT_LIST_FILE);
+        }
+
+        // The icon table refers to the status ids by name, so it can only be used if
+        // both files are present. Without it, status effects are shown without icons.
+        if let (Ok(id_data), Ok(icon_data)) = (game_file_loader.get(STATUS_ID_FILE), game_file_loader.get(STATUS_ICON_FILE))
+            && load_optional_script(&state, &id_data, STATUS_ID_FILE)
+        {
+            load_optional_script(&state, &icon_data, STATUS_ICON_FILE);
+        }
+
+        let quest_texts = parse_quest_display_table(game_file_loader);
+
+        Ok(Self { state, quest_texts })
     }
 
     // TODO: move this to a different class that utilizes the script loader
@@ -80,7 +134,7 @@ end
     }
 
     // TODO: move this to a different class that utilizes the script loader
-    fn get_item_name_from_id(&self, item_id: ItemId, is_identified: bool) -> String {
+    pub fn get_item_name_from_id(&self, item_id: ItemId, is_identified: bool) -> String {
         use mlua::prelude::*;
 
         let globals = self.state.globals();
@@ -98,7 +152,7 @@ end
     }
 
     // TODO: move this to a different class that utilizes the script loader
-    fn get_item_resource_from_id(&self, item_id: ItemId, is_identified: bool) -> String {
+    pub fn get_item_resource_from_id(&self, item_id: ItemId, is_identified: bool) -> String {
         use mlua::prelude::*;
 
         let globals = self.state.globals();
@@ -115,11 +169,115 @@ end
             .unwrap_or_else(|_| "»ç°ú".to_owned())
     }
 
-    pub fn load_inventory_item_metadata(
+    pub fn get_quest_text(&self, quest_id: u32) -> QuestText {
+        use mlua::prelude::*;
+
+        let globals = self.state.globals();
+        let quest_info = globals
+            .get::<_, LuaTable>("QuestInfoList")
+            .and_then(|table| table.get::<_, LuaTable>(quest_id));
+
+        if let Ok(quest_info) = quest_info {
+            let get_string = |key: &str| {
+                quest_info
+                    .get::<_, LuaString>(key)
+                    .map(|string| string.to_string_lossy().into_owned())
+                    .unwrap_or_default()
+            };
+
+            let description = match quest_info.get::<_, LuaTable>("Description") {
+                Ok(lines) => lines
+                    .sequence_values::<LuaString>()
+                    .filter_map(Result::ok)
+                    .map(|line| line.to_string_lossy().into_owned())
+                    .collect(),
+                Err(_) => vec![get_string("Description")],
+            };
+
+            return QuestText {
+                title: get_string("Title"),
+                summary: get_string("Summary"),
+                description,
+            };
+        }
+
+        self.quest_texts.get(&quest_id).cloned().unwrap_or_else(|| QuestText {
+            title: format!("Quest {quest_id}"),
+            summary: String::new(),
+            description: Vec::new(),
+        })
+    }
+
+    pub fn get_achievement_text(&self, achievement_id: u32) -> AchievementText {
+        use mlua::prelude::*;
+
+        let get_string = |table: &LuaTable, key: &str| {
+            table
+                .get::<_, LuaString>(key)
+                .map(|string| string.to_string_lossy().into_owned())
+                .unwrap_or_default()
+        };
+
+        let globals = self.state.globals();
+        let achievement = globals
+            .get::<_, LuaTable>("achievement_tbl")
+            .and_then(|table| table.get::<_, LuaTable>(achievement_id));
+
+        let Ok(achievement) = achievement else {
+            return AchievementText {
+                title: format!("Achievement {achievement_id}"),
+                summary: String::new(),
+                objectives: Vec::new(),
+            };
+        };
+
+        let summary = achievement
+            .get::<_, LuaTable>("content")
+            .map(|content| get_string(&content, "summary"))
+            .unwrap_or_default();
+
+        let objectives = achievement
+            .get::<_, LuaTable>("resource")
+            .map(|resource| {
+                resource
+                    .sequence_values::<LuaTable>()
+                    .filter_map(Result::ok)
+                    .map(|objective| AchievementObjective {
+                        description: get_string(&objective, "text"),
+                        count: objective.get::<_, u32>("count").unwrap_or_default(),
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        AchievementText {
+            title: get_string(&achievement, "title"),
+            summary,
+            objectives,
+        }
+    }
+
+    /// Get the file name of the icon that is shown while a status effect is
+    /// active. Most status effects don't have an icon.
+    pub fn get_status_icon_name(&self, status_id: u16) -> Option<String> {
+        use mlua::prelude::*;
+
+        let globals = self.state.globals();
+        let icon_lists = globals.get::<_, LuaTable>("StateIconImgList").ok()?;
+
+        // The icons are grouped by their priority.
+        icon_lists
+            .pairs::<LuaValue, LuaTable>()
+            .filter_map(Result::ok)
+            .find_map(|(_, icons)| icons.get::<_, Option<LuaString>>(status_id).ok().flatten())
+            .map(|icon_name| icon_name.to_string_lossy().into_owned())
+    }
+
+    pub fn load_inventory_item_metadata<Index>(
         &self,
         texture_loader: &mut TextureLoader,
-        item: InventoryItem<NoMetadata>,
-    ) -> InventoryItem<ResourceMetadata> {
+        item: InventoryItem<NoMetadata, Index>,
+    ) -> InventoryItem<ResourceMetadata, Index> {
         let is_identified = item.is_identifed();
 
         let resource_name = self.get_item_resource_from_id(item.item_id, is_identified);
@@ -142,4 +300,165 @@ end
 
         ShopItem { metadata, ..item }
     }
+
+    pub fn load_vending_item_metadata(
+        &self,
+        texture_loader: &mut TextureLoader,
+        item: VendingItem<NoMetadata>,
+    ) -> VendingItem<ResourceMetadata> {
+        let resource_name = self.get_item_resource_from_id(item.item_id, item.is_identified);
+        let full_path = format!("À¯ÀúÀÎÅÍÆäÀÌ½º\\item\\{resource_name}.bmp");
+        let texture = texture_loader.get(&full_path).unwrap();
+        let name = self.get_item_name_from_id(item.item_id, item.is_identified);
+
+        let metadata = ResourceMetadata { texture, name };
+
+        VendingItem { metadata, ..item }
+    }
+
+    pub fn load_trade_item_metadata(&self, texture_loader: &mut TextureLoader, item: TradeItem<NoMetadata>) -> TradeItem<ResourceMetadata> {
+        let resource_name = self.get_item_resource_from_id(item.item_id, item.is_identified);
+        let full_path = format!("À¯ÀúÀÎÅÍÆäÀÌ½º\\item\\{resource_name}.bmp");
+        let texture = texture_loader.get(&full_path).unwrap();
+        let name = self.get_item_name_from_id(item.item_id, item.is_identified);
+
+        let metadata = ResourceMetadata { texture, name };
+
+        TradeItem { metadata, ..item }
+    }
+
+    pub fn load_mail_item_metadata(&self, texture_loader: &mut TextureLoader, item: MailItem<NoMetadata>) -> MailItem<ResourceMetadata> {
+        let resource_name = self.get_item_resource_from_id(item.item_id, item.is_identified);
+        let full_path = format!("À¯ÀúÀÎÅÍÆäÀÌ½º\\item\\{resource_name}.bmp");
+        let texture = texture_loader.get(&full_path).unwrap();
+        let name = self.get_item_name_from_id(item.item_id, item.is_identified);
+
+        let metadata = ResourceMetadata { texture, name };
+
+        MailItem { metadata, ..item }
+    }
+}
+
+/// Runs a script that the client can do without. If it fails, the client
+/// continues without the data it provides. Returns whether the script ran
+/// successfully.
+#[cfg_attr(not(feature = "debug"), allow(unused_variables))]
+fn load_optional_script(state: &Lua, data: &[u8], path: &str) -> bool {
+    match state.load(data).exec() {
+        Ok(()) => true,
+        Err(error) => {
+            #[cfg(feature = "debug")]
+            print_debug!("[{}] failed to load {}: {}", "error".red(), path.magenta(), error);
+
+            false
+        }
+    }
+}
+
+/// Parses `questid2display.txt`, where every quest is described by the six
+/// fields `id#title#icon#image#description#summary#`.
+fn parse_quest_display_table(game_file_loader: &GameFileLoader) -> HashMap<u32, QuestText> {
+    match game_file_loader.get(QUEST_DISPLAY_FILE) {
+        Ok(data) => {
+            // The file is encoded with the Korean code page of the client (CP949).
+            let (content, ..) = encoding_rs::EUC_KR.decode(&data);
+
+            parse_quest_display_records(&content)
+        }
+        Err(_error) => {
+            #[cfg(feature = "debug")]
+            print_debug!("[{}] can't find quest display table: {:?}", "error".red(), _error);
+
+            HashMap::new()
+        }
+    }
+}
+
+/// Descriptions may span multiple lines, so a new record starts at every line
+/// that begins with a quest id followed by the separator. That way a malformed
+/// record is skipped on its own instead of shifting all records after it.
+fn parse_quest_display_records(content: &str) -> HashMap<u32, QuestText> {
+    let mut records: Vec<String> = Vec::new();
+
+    for line in content.lines() {
+        let trimmed_line = line.trim_start();
+
+        if trimmed_line.starts_with("//") {
+            continue;
+        }
+
+        let starts_record = trimmed_line
+            .split_once('#')
+            .is_some_and(|(quest_id, _)| !quest_id.is_empty() && quest_id.bytes().all(|byte| byte.is_ascii_digit()));
+
+        match records.last_mut() {
+            _ if starts_record => records.push(trimmed_line.to_owned()),
+            Some(record) => {
+                record.push('\n');
+                record.push_str(line);
+            }
+            // Anything before the first record can't belong to a quest.
+            None => {}
+        }
+    }
+
+    let mut quest_texts = HashMap::new();
+
+    for record in records {
+        let fields: Vec<&str> = record.split('#').map(str::trim).collect();
+
+        let [quest_id, title, _icon, _image, description, summary, ..] = fields.as_slice() else {
+            #[cfg(feature = "debug")]
+            print_debug!("[{}] malformed quest display record: {:?}", "error".red(), record);
+
+            continue;
+        };
+
+        let Ok(quest_id) = quest_id.parse::<u32>() else {
+            continue;
+        };
+
+        let description = description
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(str::to_owned)
+            .collect();
+
+        quest_texts.insert(quest_id, QuestText {
+            title: title.to_string(),
+            summary: summary.to_string(),
+            description,
+        });
+    }
+
+    quest_texts
+}
+
+#[cfg(test)]
+mod quest_display {
+    use super::parse_quest_display_records;
+
+    #[test]
+    fn multi_line_description() {
+        let content = "// comment\n1000#Title#SG_FEEL#QUE_NOIMAGE#\nFirst line\nSecond line\n#Summary#\n";
+
+        let quest_texts = parse_quest_display_records(content);
+        let quest_text = &quest_texts[&1000];
+
+        assert_eq!(quest_text.title, "Title");
+        assert_eq!(quest_text.description, ["First line", "Second line"]);
+        assert_eq!(quest_text.summary, "Summary");
+    }
+
+    #[test]
+    fn malformed_record_is_skipped() {
+        let content = "1000#Broken#\n1001#Title#SG_FEEL#QUE_NOIMAGE#Description#Summary#\n";
+
+        let quest_texts = parse_quest_display_records(content);
+
+        assert!(!quest_texts.contains_key(&1000));
+        assert_eq!(quest_texts[&1001].title, "Title");
+        assert_eq!(quest_texts[&1001].summary, "Summary");
+    }
 }
@@ -93,6 +93,12 @@ pub struct Service {
     /// login_athena.conf.
     pub version: i8,
 
+    /// The `PACKETVER` that the server was compiled for. Not part of the
+    /// official format. If not set, the packet version that Korangar is
+    /// developed against is used.
+    #[serde(default, alias = "packetversion")]
+    pub packet_version: Option<u32>,
+
     /// Uses the same value from `ServiceType` enum.
     #[serde(default, alias = "langtype", deserialize_with = "language_type_from_index")]
     pub language_type: Option<ServiceType>,
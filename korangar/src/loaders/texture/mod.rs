@@ -3,11 +3,13 @@ use std::io::Cursor;
 use std::sync::Arc;
 
 use derive_new::new;
-use image::{EncodableLayout, ImageFormat, ImageReader, Rgba};
+use image::{EncodableLayout, ImageFormat, ImageReader, Pixel, Rgba, RgbaImage};
 #[cfg(feature = "debug")]
 use korangar_debug::logging::{print_debug, Colorize, Timer};
 use korangar_util::FileLoader;
+use ragnarok_packets::GuildId;
 use wgpu::{Device, Extent3d, Queue, TextureDescriptor, TextureDimension, TextureFormat, TextureUsages};
+use yazi::{decompress, Format};
 
 use super::error::LoadError;
 use super::{FALLBACK_BMP_FILE, FALLBACK_PNG_FILE, FALLBACK_TGA_FILE};
@@ -28,21 +30,10 @@ impl TextureLoader {
         #[cfg(feature = "debug")]
         let timer = Timer::new_dynamic(format!("load texture from {}", path.magenta()));
 
-        let image_format = match &path[path.len() - 4..] {
-            ".png" => ImageFormat::Png,
-            ".bmp" | ".BMP" => ImageFormat::Bmp,
-            ".tga" | ".TGA" => ImageFormat::Tga,
-            extension => return Err(LoadError::UnsupportedFormat(extension.to_owned())),
-        };
-
-        let file_data = self
-            .game_file_loader
-            .get(&format!("data\\texture\\{path}"))
-            .map_err(LoadError::File)?;
-        let reader = ImageReader::with_format(Cursor::new(file_data), image_format);
+        let (image_format, decode_result) = self.decode(path)?;
 
-        let mut image_buffer = match reader.decode() {
-            Ok(image) => image.to_rgba8(),
+        let image_buffer = match decode_result {
+            Ok(image_buffer) => image_buffer,
             Err(_error) => {
                 #[cfg(feature = "debug")]
                 {
@@ -61,14 +52,90 @@ impl TextureLoader {
             }
         };
 
-        if image_format == ImageFormat::Bmp {
-            // These numbers are taken from https://github.com/Duckwhale/RagnarokFileFormats
+        let texture = self.create_texture(path, &image_buffer);
+
+        #[cfg(feature = "debug")]
+        timer.stop();
+
+        Ok(texture)
+    }
+
+    /// Read and decode a texture file. Decoding errors are returned separately
+    /// so the caller can decide whether to use a fallback.
+    fn decode(&self, path: &str) -> Result<(ImageFormat, Result<RgbaImage, image::ImageError>), LoadError> {
+        let image_format = match &path[path.len() - 4..] {
+            ".png" => ImageFormat::Png,
+            ".bmp" | ".BMP" => ImageFormat::Bmp,
+            ".tga" | ".TGA" => ImageFormat::Tga,
+            extension => return Err(LoadError::UnsupportedFormat(extension.to_owned())),
+        };
+
+        let file_data = self
+            .game_file_loader
+            .get(&format!("data\\texture\\{path}"))
+            .map_err(LoadError::File)?;
+        let reader = ImageReader::with_format(Cursor::new(file_data), image_format);
+
+        let decode_result = reader.decode().map(|image| {
+            let mut image_buffer = image.to_rgba8();
+
+            if image_format == ImageFormat::Bmp {
+                Self::apply_color_key(&mut image_buffer);
+            }
+
             image_buffer
-                .pixels_mut()
-                .filter(|pixel| pixel.0[0] > 0xF0 && pixel.0[1] < 0x10 && pixel.0[2] > 0x0F)
-                .for_each(|pixel| *pixel = Rgba([0; 4]));
+        });
+
+        Ok((image_format, decode_result))
+    }
+
+    /// Decode a guild emblem sent by the map server. The emblem data is a zlib
+    /// compressed BMP or GIF image.
+    pub fn load_guild_emblem(&mut self, guild_id: GuildId, emblem_id: u32, emblem_data: &[u8]) -> Result<Arc<Texture>, LoadError> {
+        let path = format!("guild emblem {} ({})", guild_id.0, emblem_id);
+
+        if let Some(texture) = self.cache.get(&path) {
+            return Ok(texture.clone());
         }
 
+        let (image_data, _checksum) = decompress(emblem_data, Format::Zlib).map_err(LoadError::Decompression)?;
+
+        let image_format = match image::guess_format(&image_data) {
+            Ok(image_format @ (ImageFormat::Bmp | ImageFormat::Gif)) => image_format,
+            _ => return Err(LoadError::UnsupportedFormat("guild emblem".to_owned())),
+        };
+
+        let mut image_buffer = ImageReader::with_format(Cursor::new(image_data), image_format)
+            .decode()
+            .map_err(LoadError::Image)?
+            .to_rgba8();
+
+        // GIF emblems have proper transparency but BMP emblems use the same color
+        // key as other BMP textures.
+        if image_format == ImageFormat::Bmp {
+            Self::apply_color_key(&mut image_buffer);
+        }
+
+        Ok(self.create_texture(&path, &image_buffer))
+    }
+
+    fn apply_color_key(image_buffer: &mut RgbaImage) {
+        // These numbers are taken from https://github.com/Duckwhale/RagnarokFileFormats
+        image_buffer
+            .pixels_mut()
+            .filter(|pixel| pixel.0[0] > 0xF0 && pixel.0[1] < 0x10 && pixel.0[2] > 0x0F)
+            .for_each(|pixel| *pixel = Rgba([0; 4]));
+    }
+
+    fn create_texture(&mut self, path: &str, image_buffer: &RgbaImage) -> Arc<Texture> {
+        let texture = self.create_uncached_texture(path, image_buffer);
+
+        self.cache.insert(path.to_string(), texture.clone());
+
+        texture
+    }
+
+    fn create_uncached_texture(&self, path: &str, image_buffer: &RgbaImage) -> Arc<Texture> {
         let texture = Texture::new_with_data(
             &self.device,
             &self.queue,
@@ -88,14 +155,8 @@ impl TextureLoader {
             },
             image_buffer.as_bytes(),
         );
-        let texture = Arc::new(texture);
-
-        self.cache.insert(path.to_string(), texture.clone());
-
-        #[cfg(feature = "debug")]
-        timer.stop();
 
-        Ok(texture)
+        Arc::new(texture)
     }
 
     pub fn get(&mut self, path: &str) -> Result<Arc<Texture>, LoadError> {
@@ -104,4 +165,53 @@ impl TextureLoader {
             None => self.load(path),
         }
     }
+
+    /// Get a texture with all colors removed. Transparency is kept as is.
+    pub fn get_grayscale(&mut self, path: &str) -> Result<Arc<Texture>, LoadError> {
+        let grayscale_path = format!("grayscale {path}");
+
+        if let Some(texture) = self.cache.get(&grayscale_path) {
+            return Ok(texture.clone());
+        }
+
+        let (_, decode_result) = self.decode(path)?;
+        let mut image_buffer = decode_result.map_err(LoadError::Image)?;
+
+        image_buffer.pixels_mut().for_each(|pixel| {
+            let [luma] = pixel.to_luma().0;
+            *pixel = Rgba([luma, luma, luma, pixel.0[3]]);
+        });
+
+        Ok(self.create_texture(&grayscale_path, &image_buffer))
+    }
+
+    /// Get the official minimap of a map. Maps without one use the image
+    /// created by `generate` instead.
+    pub fn get_minimap(&mut self, map_name: &str, generate: impl FnOnce() -> RgbaImage) -> Arc<Texture> {
+        if let Ok(texture) = self.get(&format!("À¯ÀúÀÎÅÍÆäÀÌ½º\\map\\{map_name}.bmp")) {
+            return texture;
+        }
+
+        let path = format!("generated minimap {map_name}");
+
+        match self.cache.get(&path) {
+            Some(texture) => texture.clone(),
+            None => self.create_texture(&path, &generate()),
+        }
+    }
+
+    /// Regenerate the minimap of a map after its tiles changed. Returns
+    /// [`None`] for maps with an official minimap. The cached minimap is
+    /// dropped and the new one isn't cached, since the server only keeps tile
+    /// changes while the player is on the map.
+    pub fn regenerate_minimap(&mut self, map_name: &str, generate: impl FnOnce() -> RgbaImage) -> Option<Arc<Texture>> {
+        if self.get(&format!("À¯ÀúÀÎÅÍÆäÀÌ½º\\map\\{map_name}.bmp")).is_ok() {
+            return None;
+        }
+
+        let path = format!("generated minimap {map_name}");
+        self.cache.remove(&path);
+
+        Some(self.create_uncached_texture(&path, &generate()))
+    }
 }
@@ -37,14 +37,22 @@ use korangar_debug::profiling::Profiler;
 #[cfg(feature = "debug")]
 use korangar_interface::application::{Application, FontSizeTraitExt, PositionTraitExt};
 use korangar_interface::application::{FocusState, FontSizeTrait};
-use korangar_interface::state::{PlainTrackedState, Remote, RemoteClone, TrackedState, TrackedStateExt, TrackedStateTake, TrackedStateVec};
+use korangar_interface::state::{
+    PlainTrackedState, Remote, RemoteClone, TrackedState, TrackedStateExt, TrackedStateTake, TrackedStateVec, ValueState,
+};
 use korangar_interface::Interface;
 use korangar_networking::{
-    DisconnectReason, HotkeyState, LoginServerLoginData, MessageColor, NetworkEvent, NetworkingSystem, SellItem, ShopItem,
+    CutInLocation, DisconnectReason, HotkeyState, InventoryItemDetails, LoginServerLoginData, MessageColor, NetworkEvent, NetworkingSystem,
+    SellItem, ShopItem, StoreType, VendingItem, WhisperFailedReason,
 };
+use ragnarok_formats::map::TileFlags;
 use ragnarok_packets::{
-    BuyShopItemsResult, CharacterId, CharacterInformation, CharacterServerInformation, Friend, HotbarSlot, SellItemsResult, SkillId,
-    SkillType, TilePosition, UnitId, WorldPosition,
+    AccountId, AddMailItemResult, AddTradeItemResult, BuyShopItemsResult, BuyingStoreClosedReason, BuyingStoreId, CharacterId,
+    CharacterInformation, CharacterServerInformation, ChatRoomExitReason, ChatRoomMember, ChatRoomRole, ChatRoomType, CreateChatRoomResult,
+    EnterChatRoomFailedReason, EntityId, Friend, GuildInformationType, GuildMemberState, HotbarSlot, OpenBuyingStoreFailure, PacketProfile,
+    PacketVersion, PartyMemberRole, ReputationEntry, RetrieveMailItemsResult, RetrieveMailZenyResult, SellItemsResult,
+    SellToBuyingStoreFailure, SellToBuyingStoreItemInformation, SendMailResult, SkillId, SkillType, StorageIndex, TilePosition, TradeParty,
+    TradeResponse, TradeResult, UnitId, VendingPurchaseResult, WorldPosition,
 };
 use rayon::in_place_scope;
 use wgpu::{CommandEncoderDescriptor, Features, Instance, InstanceFlags, Limits, Maintain, MemoryHints, TextureViewDescriptor};
@@ -57,6 +65,7 @@ use crate::graphics::*;
 use crate::input::{InputSystem, UserEvent};
 use crate::interface::application::InterfaceSettings;
 use crate::interface::cursor::{MouseCursor, MouseCursorState};
+use crate::interface::cut_in::CutIn;
 use crate::interface::dialog::DialogSystem;
 use crate::interface::layout::{ScreenPosition, ScreenSize};
 use crate::interface::linked::LinkedElement;
@@ -347,20 +356,39 @@ fn main() {
         let mut networking_system = NetworkingSystem::spawn_with_callback(packet_callback.clone());
 
         let mut friend_list: PlainTrackedState<Vec<(Friend, LinkedElement)>> = PlainTrackedState::default();
+        let mut party_members: PlainTrackedState<Vec<PartyMemberInformation>> = PlainTrackedState::default();
+        let mut guild: PlainTrackedState<Option<Guild>> = PlainTrackedState::default();
+        let mut trade: PlainTrackedState<Option<Trade>> = PlainTrackedState::default();
+        let mut chat_room: PlainTrackedState<Option<ChatRoom>> = PlainTrackedState::default();
+        let mut player_stats: PlainTrackedState<CharacterStats> = PlainTrackedState::default();
+        let mut trade_partner_name: Option<String> = None;
         let mut saved_login_data: Option<LoginServerLoginData> = None;
         let mut saved_character_server: Option<CharacterServerInformation> = None;
         let mut saved_characters: PlainTrackedState<Vec<CharacterInformation>> = PlainTrackedState::default();
         let mut shop_items: PlainTrackedState<Vec<ShopItem<ResourceMetadata>>> = PlainTrackedState::default();
         let mut sell_items: PlainTrackedState<Vec<SellItem<(ResourceMetadata, u16)>>> = PlainTrackedState::default();
+        let mut vending_items: PlainTrackedState<Vec<VendingItem<ResourceMetadata>>> = PlainTrackedState::default();
+        // Set while the player is selling items to the buying store of another player.
+        let mut browsed_buying_store: Option<(EntityId, BuyingStoreId)> = None;
+        let mut mailbox: PlainTrackedState<Mailbox> = PlainTrackedState::default();
+        let mut mail_attachments: PlainTrackedState<Vec<MailAttachment>> = PlainTrackedState::default();
+        // Set while waiting for the server to find the receiver of a mail.
+        let mut pending_mail: Option<OutgoingMail> = None;
+        let mut quest_log: PlainTrackedState<QuestLog> = PlainTrackedState::default();
+        let mut minimap: PlainTrackedState<Minimap> = PlainTrackedState::default();
+        let mut cut_in: Option<CutIn> = None;
+        let mut achievements: PlainTrackedState<Achievements> = PlainTrackedState::default();
+        let mut reputation: PlainTrackedState<Vec<ReputationEntry>> = PlainTrackedState::default();
         let mut currently_deleting: Option<CharacterId> = None;
         let mut saved_player_name = String::new();
         let mut move_request: PlainTrackedState<Option<usize>> = PlainTrackedState::default();
         let mut saved_login_server_address = None;
+        let mut saved_packet_profile = PacketProfile::default();
         let mut saved_password = String::new();
         let mut saved_username = String::new();
         let mut saved_slot_count = 0;
 
-        interface.open_window(&application, &mut focus_state, &LoginWindow::new(&client_info));
+        interface.open_window(&application, &mut focus_state, &LoginWindow::new(&client_info, None));
     });
 
     time_phase!("create resources", {
@@ -368,6 +396,8 @@ fn main() {
         let mut effect_holder = EffectHolder::default();
         let mut entities = Vec::<Entity>::new();
         let mut player_inventory = Inventory::default();
+        let mut player_storage = Inventory::<StorageIndex>::default();
+        let mut player_cart = Inventory::default();
         let mut player_skill_tree = SkillTree::default();
         let mut hotbar = Hotbar::default();
         let mut frustum_query_result: Vec<ObjectKey> = Vec::default();
@@ -381,6 +411,8 @@ fn main() {
             text: welcome_string,
             color: MessageColor::Server,
         }]);
+        let mut conversations = Conversations::default();
+        let mut last_whisper_recipient: Option<String> = None;
 
         let main_menu_click_sound_effect = audio_engine.load(MAIN_MENU_CLICK_SOUND_EFFECT);
     });
@@ -506,6 +538,7 @@ fn main() {
                             EntityType::Npc => mouse_cursor.set_state(MouseCursorState::Dialog, client_tick),
                             EntityType::Warp => mouse_cursor.set_state(MouseCursorState::Warp, client_tick),
                             EntityType::Monster => mouse_cursor.set_state(MouseCursorState::Attack, client_tick),
+                            EntityType::Item => mouse_cursor.set_state(MouseCursorState::Grab, client_tick),
                             _ => {}
                         }
                     }
@@ -539,7 +572,12 @@ fn main() {
                                 print_debug!("Disconnection from the character server with error");
 
                                 let socket_address = saved_login_server_address.unwrap();
-                                networking_system.connect_to_login_server(socket_address, &saved_username, &saved_password);
+                                networking_system.connect_to_login_server(
+                                    socket_address,
+                                    saved_packet_profile.clone(),
+                                    &saved_username,
+                                    &saved_password,
+                                );
                             }
                         },
                         NetworkEvent::CharacterServerConnected { normal_slot_count } => {
@@ -575,6 +613,22 @@ fn main() {
                             entities.clear();
                             particle_holder.clear();
                             effect_holder.clear();
+                            party_members.clear();
+                            guild.set(None);
+                            trade.set(None);
+                            chat_room.set(None);
+                            browsed_buying_store = None;
+                            mailbox.set(Mailbox::default());
+                            mail_attachments.set(Vec::new());
+                            pending_mail = None;
+                            quest_log.set(QuestLog::default());
+                            hotbar.clear_cooldowns();
+                            minimap.set(Minimap::default());
+                            cut_in = None;
+                            achievements.set(Achievements::default());
+                            reputation.set(Vec::new());
+                            conversations.clear();
+                            last_whisper_recipient = None;
                             audio_engine.play_background_music_track(None);
 
                             map = map_loader
@@ -637,12 +691,15 @@ fn main() {
 
                             map = map_loader
                                 .get(
-                                    map_name,
+                                    map_name.clone(),
                                     &mut model_loader,
                                     &mut texture_loader,
                                 )
                                 .unwrap();
 
+                            let minimap_texture = texture_loader.get_minimap(&map_name, || map.generate_minimap_image());
+                            minimap.set(Minimap::new(map_name, minimap_texture, map.get_size()));
+
                             map.set_ambient_sound_sources(&audio_engine);
                             audio_engine.play_background_music_track(map.background_music_track_name());
 
@@ -666,13 +723,13 @@ fn main() {
                             // TODO: this will do one unnecessary restore_focus. check if
                             // that will be problematic
                             interface.close_window_with_class(&mut focus_state, CharacterSelectionWindow::WINDOW_CLASS);
-                            interface.open_window(&application, &mut focus_state, &CharacterOverviewWindow::new());
+                            interface.open_window(&application, &mut focus_state, &CharacterOverviewWindow::new(player_stats.new_remote()));
                             interface.open_window(
                                 &application,
                                 &mut focus_state,
-                                &ChatWindow::new(chat_messages.new_remote(), font_loader.clone()),
+                                &ChatWindow::new(chat_messages.new_remote(), conversations.new_remote(), font_loader.clone()),
                             );
-                            interface.open_window(&application, &mut focus_state, &HotbarWindow::new(hotbar.get_skills()));
+                            interface.open_window(&application, &mut focus_state, &HotbarWindow::new(hotbar.get_skills(), hotbar.get_cooldowns()));
 
                             // Put the dialog system in a well-defined state.
                             dialog_system.close_dialog();
@@ -714,6 +771,20 @@ fn main() {
                             let npc = Entity::Npc(npc);
                             entities.push(npc);
                         }
+                        NetworkEvent::AddGroundItem(item_data) => {
+                            entities.retain(|entity| entity.get_entity_id() != item_data.entity_id);
+
+                            let item = GroundItem::new(
+                                &mut sprite_loader,
+                                &mut action_loader,
+                                &script_loader,
+                                &map,
+                                item_data,
+                                client_tick,
+                            );
+
+                            entities.push(Entity::Item(item));
+                        }
                         NetworkEvent::RemoveEntity(entity_id) => {
                             entities.retain(|entity| entity.get_entity_id() != entity_id);
                         }
@@ -739,15 +810,19 @@ fn main() {
                         }
                         NetworkEvent::ChangeMap(map_name, player_position) => {
                             entities.truncate(1);
+                            cut_in = None;
 
                             map = map_loader
                                 .get(
-                                    map_name,
+                                    map_name.clone(),
                                     &mut model_loader,
                                     &mut texture_loader,
                                 )
                                 .unwrap();
 
+                            let minimap_texture = texture_loader.get_minimap(&map_name, || map.generate_minimap_image());
+                            minimap.set(Minimap::new(map_name, minimap_texture, map.get_size()));
+
                             map.set_ambient_sound_sources(&audio_engine);
                             audio_engine.play_background_music_track(map.background_music_track_name());
 
@@ -763,6 +838,33 @@ fn main() {
                             // cursor always look correct.
                             mouse_cursor.set_start_time(client_tick);
                         }
+                        NetworkEvent::MapServerReconnecting {
+                            attempt,
+                            maximum_attempts,
+                            delay,
+                        } => {
+                            chat_messages.push(ChatMessage {
+                                text: format!(
+                                    "Lost connection to the map server, reconnecting in {:.1}s (attempt {attempt}/{maximum_attempts})",
+                                    delay.as_secs_f32()
+                                ),
+                                color: MessageColor::Error,
+                            });
+                        }
+                        NetworkEvent::MapServerReconnected { login_data } => {
+                            saved_login_data = Some(login_data);
+
+                            // The server will send all entities again once the map is loaded.
+                            entities.truncate(1);
+                            particle_holder.clear();
+                            effect_holder.clear();
+                            let _ = networking_system.map_loaded();
+
+                            chat_messages.push(ChatMessage {
+                                text: "Reconnected to the map server".to_owned(),
+                                color: MessageColor::Information,
+                            });
+                        }
                         NetworkEvent::SetPlayerPosition(player_position) => {
                             let player_position = Vector2::new(player_position.x, player_position.y);
                             entities[0].set_position(&map, player_position, client_tick);
@@ -774,6 +876,35 @@ fn main() {
                         NetworkEvent::ChatMessage { text, color } => {
                             chat_messages.push(ChatMessage { text, color });
                         }
+                        NetworkEvent::Whisper { sender, text } => {
+                            chat_messages.push(ChatMessage {
+                                text: format!("(from {sender}) : {text}"),
+                                color: MessageColor::Whisper,
+                            });
+                            conversations.get_messages_with(&sender).push(ChatMessage {
+                                text: format!("{sender} : {text}"),
+                                color: MessageColor::Whisper,
+                            });
+                        }
+                        NetworkEvent::WhisperFailed { reason } => {
+                            let text = match reason {
+                                WhisperFailedReason::RecipientOffline => "The recipient is not online.",
+                                WhisperFailedReason::IgnoredByRecipient => "The recipient is ignoring you.",
+                                WhisperFailedReason::RecipientIgnoresEveryone => "The recipient is ignoring all whispers.",
+                            };
+
+                            if let Some(recipient_name) = &last_whisper_recipient {
+                                conversations.get_messages_with(recipient_name).push(ChatMessage {
+                                    text: text.to_owned(),
+                                    color: MessageColor::Error,
+                                });
+                            }
+
+                            chat_messages.push(ChatMessage {
+                                text: text.to_owned(),
+                                color: MessageColor::Error,
+                            });
+                        }
                         NetworkEvent::UpdateEntityDetails(entity_id, name) => {
                             let entity = entities.iter_mut().find(|entity| entity.get_entity_id() == entity_id);
 
@@ -809,6 +940,10 @@ fn main() {
                                 panic!();
                             };
 
+                            player_stats.with_mut(|stats| match stats.update(&status_type) {
+                                true => ValueState::Mutated(()),
+                                false => ValueState::Unchanged(()),
+                            });
                             player.update_status(status_type);
                         }
                         NetworkEvent::OpenDialog(text, npc_id) => {
@@ -844,9 +979,40 @@ fn main() {
                                 index, amount,
                             );
                         }
+                        NetworkEvent::ItemUsed { index, entity_id, remaining_amount, success } => {
+                            if success && entities.first().is_some_and(|player| player.get_entity_id() == entity_id) {
+                                player_inventory.set_item_amount(index, remaining_amount);
+                            }
+                        }
+                        NetworkEvent::SetStorage { items } => {
+                            player_storage.fill(&mut texture_loader, &script_loader, items);
+
+                            interface.open_window(&application, &mut focus_state, &StorageWindow::new(player_storage.item_remote()));
+                            interface.open_window(
+                                &application,
+                                &mut focus_state,
+                                &InventoryWindow::new(player_inventory.item_remote()),
+                            );
+                        }
+                        NetworkEvent::StorageItemAdded { item } => {
+                            player_storage.add_item(&mut texture_loader, &script_loader, item);
+                        }
+                        NetworkEvent::StorageItemRemoved { index, amount } => {
+                            player_storage.remove_item(index, amount);
+                        }
+                        NetworkEvent::StorageClosed => {
+                            interface.close_window_with_class(&mut focus_state, StorageWindow::WINDOW_CLASS);
+                        }
                         NetworkEvent::SkillTree(skill_information) => {
                             player_skill_tree.fill(&mut sprite_loader, &mut action_loader, skill_information);
                         }
+                        NetworkEvent::SkillUpdated {
+                            skill_id,
+                            skill_level,
+                            upgradable,
+                        } => {
+                            player_skill_tree.update_skill(skill_id, skill_level, upgradable);
+                        }
                         NetworkEvent::UpdateEquippedPosition { index, equipped_position } => {
                             player_inventory.update_equipped_position(index, equipped_position);
                         }
@@ -860,6 +1026,131 @@ fn main() {
                             entity.set_job(job_id as usize);
                             entity.reload_sprite(&mut sprite_loader, &mut action_loader, &script_loader);
                         }
+                        NetworkEvent::Emotion { entity_id, emotion } => {
+                            if let Some(entity) = entities.iter_mut().find(|entity| entity.get_entity_id() == entity_id) {
+                                entity.set_emotion(&mut sprite_loader, &mut action_loader, emotion, client_tick);
+                            }
+                        }
+                        NetworkEvent::ChangeMapCell {
+                            map_name,
+                            position,
+                            cell_type,
+                        } => {
+                            let position = Vector2::new(position.x as usize, position.y as usize);
+                            let map_size = map.get_size();
+                            let flags = u8::try_from(cell_type).ok().and_then(TileFlags::from_cell_type);
+
+                            if map_name == minimap.get().map_name
+                                && position.x < map_size.x
+                                && position.y < map_size.y
+                                && let Some(flags) = flags
+                            {
+                                map_loader.set_tile_flags(&mut map, position, flags);
+                            }
+                        }
+                        NetworkEvent::DisplayCutIn { image_name, location } => {
+                            // Scripts usually leave out the file extension.
+                            let file_name = match image_name.contains('.') {
+                                true => image_name,
+                                false => format!("{image_name}.bmp"),
+                            };
+
+                            let path = format!("À¯ÀúÀÎÅÍÆäÀÌ½º\\illust\\{file_name}");
+                            let texture = match location {
+                                CutInLocation::MiddleColorless => texture_loader.get_grayscale(&path),
+                                _ => texture_loader.get(&path),
+                            };
+
+                            match texture {
+                                Ok(texture) => cut_in = Some(CutIn::new(texture, location)),
+                                Err(_error) => {
+                                    #[cfg(feature = "debug")]
+                                    print_debug!("failed to load cut-in {}: {:?}", file_name.magenta(), _error);
+                                }
+                            }
+                        }
+                        NetworkEvent::ClearCutIn => cut_in = None,
+                        NetworkEvent::MinimapMarkerAdded {
+                            npc_id,
+                            marker_id,
+                            position,
+                            color,
+                            duration,
+                        } => {
+                            minimap.mutate(|minimap| {
+                                minimap.add_marker(MinimapMarker {
+                                    source: MarkerSource::Npc { npc_id, marker_id },
+                                    position: Vector2::new(position.x as usize, position.y as usize),
+                                    color: Color::rgb_u8(color.red, color.green, color.blue),
+                                    expire_tick: duration.map(|duration| client_tick.0.wrapping_add(duration.as_millis() as u32)),
+                                })
+                            });
+                        }
+                        NetworkEvent::MinimapMarkerRemoved { npc_id, marker_id } => {
+                            minimap.mutate(|minimap| minimap.remove_marker(MarkerSource::Npc { npc_id, marker_id }));
+                        }
+                        NetworkEvent::NavigationHint { map_name, position } => {
+                            let map_name = map_name.trim_end_matches(".gat");
+
+                            let is_current_map = map_name == minimap.get().map_name;
+
+                            match is_current_map {
+                                true => minimap.mutate(|minimap| {
+                                    minimap.add_marker(MinimapMarker {
+                                        source: MarkerSource::Navigation,
+                                        position: Vector2::new(position.x as usize, position.y as usize),
+                                        color: Color::rgb_u8(255, 220, 0),
+                                        expire_tick: None,
+                                    })
+                                }),
+                                false => chat_messages.push(ChatMessage {
+                                    text: format!("Head to {map_name} ({}, {})", position.x, position.y),
+                                    color: MessageColor::Information,
+                                }),
+                            }
+                        }
+                        NetworkEvent::SkillCasting { entity_id, cast_time, .. } => {
+                            if let Some(entity) = entities.iter_mut().find(|entity| entity.get_entity_id() == entity_id) {
+                                entity.start_casting(cast_time, client_tick);
+                            }
+                        }
+                        NetworkEvent::SkillCastCancelled { entity_id } => {
+                            if let Some(entity) = entities.iter_mut().find(|entity| entity.get_entity_id() == entity_id) {
+                                entity.stop_casting();
+                            }
+                        }
+                        NetworkEvent::SkillFailed { .. } => {
+                            if let Some(player) = entities.first_mut() {
+                                player.stop_casting();
+                            }
+                        }
+                        NetworkEvent::SkillCooldown { skill_id, duration } => {
+                            hotbar.set_cooldown(skill_id, duration, client_tick);
+                        }
+                        NetworkEvent::StatusEffectStarted { entity_id, status_effect } => {
+                            if let Some(entity) = entities.iter_mut().find(|entity| entity.get_entity_id() == entity_id) {
+                                let icon = script_loader
+                                    .get_status_icon_name(status_effect.status_id)
+                                    .and_then(|icon_name| texture_loader.get(&format!("effect\\{icon_name}")).ok());
+                                let end_tick = status_effect.remaining.map(|remaining| client_tick.0.wrapping_add(remaining));
+
+                                entity.add_status_effect(StatusEffect {
+                                    status_id: status_effect.status_id,
+                                    icon,
+                                    end_tick,
+                                });
+                            }
+                        }
+                        NetworkEvent::StatusEffectEnded { entity_id, status_id } => {
+                            if let Some(entity) = entities.iter_mut().find(|entity| entity.get_entity_id() == entity_id) {
+                                entity.remove_status_effect(status_id);
+                            }
+                        }
+                        NetworkEvent::VisualStateChanged { entity_id, visual_state } => {
+                            if let Some(entity) = entities.iter_mut().find(|entity| entity.get_entity_id() == entity_id) {
+                                entity.set_visual_state(visual_state);
+                            }
+                        }
                         NetworkEvent::LoggedOut => {
                             networking_system.disconnect_from_map_server();
                         }
@@ -1033,159 +1324,1187 @@ fn main() {
                                 },
                             }
                         },
-                    }
-                }
-
-                #[cfg(feature = "debug")]
-                network_event_measurement.stop();
-
-                #[cfg(feature = "debug")]
-                let user_event_measurement = Profiler::start_measurement("process user events");
-
-                for event in user_events {
-                    match event {
-                        UserEvent::LogIn {
-                            service_id,
-                            username,
-                            password,
-                        } => {
-                            let service = client_info
-                                .services
-                                .iter()
-                                .find(|service| service.service_id() == service_id)
-                                .unwrap();
-                            let address = format!("{}:{}", service.address, service.port);
-                            let socket_address = address.to_socket_addrs().expect("Failed to resolve IP").next().expect("ill formatted service IP");
-
-                            saved_login_server_address = Some(socket_address);
-                            saved_username = username.clone();
-                            saved_password = password.clone();
-
-                            networking_system.connect_to_login_server(socket_address, username, password);
+                        NetworkEvent::PartyInvite { party_id, party_name } => {
+                            interface.open_window(&application, &mut focus_state, &PartyInviteWindow::new(party_id, party_name))
                         }
-                        UserEvent::SelectServer(server) => {
-                            saved_character_server = Some(server.clone());
-
-                            networking_system.disconnect_from_login_server();
-
-                            // Korangar should never attempt to connect to the character
-                            // server before it logged in to the login server, so it's fine to
-                            // unwrap here.
-                            let login_data = saved_login_data.as_ref().unwrap();
-                            networking_system.connect_to_character_server(login_data, server);
+                        NetworkEvent::SetPartyInformation { members, .. } => {
+                            party_members.mutate(|party_members| {
+                                *party_members = members.into_iter().map(PartyMemberInformation::new).collect();
+                            });
                         }
-                        UserEvent::LogOut => {
-                            let _ = networking_system.log_out();
-                        },
-                        UserEvent::Exit => active_event_loop.exit(),
-                        UserEvent::CameraZoom(factor) => player_camera.soft_zoom(factor),
-                        UserEvent::CameraRotate(factor) => player_camera.soft_rotate(factor),
-                        UserEvent::OpenMenuWindow => {
-                            if !entities.is_empty() {
-                                interface.open_window(&application, &mut focus_state, &MenuWindow)
-                            }
+                        NetworkEvent::PartyMemberUpdated { member } => {
+                            party_members.mutate(|party_members| {
+                                match party_members.iter_mut().find(|information| information.member.account_id == member.account_id) {
+                                    Some(information) => information.member = member,
+                                    None => party_members.push(PartyMemberInformation::new(member)),
+                                }
+                            });
                         }
-                        UserEvent::OpenInventoryWindow => {
-                            if !entities.is_empty() {
-                                interface.open_window(
-                                    &application,
-                                    &mut focus_state,
-                                    &InventoryWindow::new(player_inventory.item_remote()),
-                                )
+                        NetworkEvent::PartyMemberRemoved { account_id } => {
+                            let is_player = saved_login_data.as_ref().is_some_and(|login_data| login_data.account_id == account_id);
+
+                            match is_player {
+                                true => party_members.clear(),
+                                false => party_members.retain(|information| information.member.account_id != account_id),
                             }
                         }
-                        UserEvent::OpenEquipmentWindow => {
-                            if !entities.is_empty() {
-                                interface.open_window(
-                                    &application,
-                                    &mut focus_state,
-                                    &EquipmentWindow::new(player_inventory.item_remote()),
-                                )
-                            }
+                        NetworkEvent::PartyLeaderChanged { old_leader_account_id, new_leader_account_id } => {
+                            party_members.mutate(|party_members| {
+                                for information in party_members.iter_mut() {
+                                    if information.member.account_id == old_leader_account_id {
+                                        information.member.role = PartyMemberRole::Member;
+                                    } else if information.member.account_id == new_leader_account_id {
+                                        information.member.role = PartyMemberRole::Leader;
+                                    }
+                                }
+                            });
                         }
-                        UserEvent::OpenSkillTreeWindow => {
-                            if !entities.is_empty() {
-                                interface.open_window(
-                                    &application,
-                                    &mut focus_state,
-                                    &SkillTreeWindow::new(player_skill_tree.get_skills()),
-                                )
-                            }
+                        NetworkEvent::UpdatePartyMemberPosition { account_id, position } => {
+                            party_members.with_mut(|party_members| {
+                                match party_members.iter_mut().find(|information| information.member.account_id == account_id) {
+                                    Some(information) => {
+                                        information.position = Some(position);
+                                        ValueState::Mutated(())
+                                    }
+                                    None => ValueState::Unchanged(()),
+                                }
+                            });
                         }
-                        UserEvent::OpenGraphicsSettingsWindow => interface.open_window(
-                            &application,
-                            &mut focus_state,
-                            &GraphicsSettingsWindow::new(surface.present_mode_info(), shadow_detail.clone_state(), framerate_limit.clone_state()),
-                        ),
-                        UserEvent::OpenAudioSettingsWindow => interface.open_window(&application, &mut focus_state, &AudioSettingsWindow),
-                        UserEvent::OpenFriendsWindow => {
-                            interface.open_window(&application, &mut focus_state, &FriendsWindow::new(friend_list.new_remote()));
+                        NetworkEvent::UpdatePartyMemberHealth { account_id, health_points, maximum_health_points } => {
+                            party_members.with_mut(|party_members| {
+                                match party_members.iter_mut().find(|information| information.member.account_id == account_id) {
+                                    Some(information) => {
+                                        information.health_points = health_points;
+                                        information.maximum_health_points = maximum_health_points;
+                                        ValueState::Mutated(())
+                                    }
+                                    None => ValueState::Unchanged(()),
+                                }
+                            });
                         }
-                        UserEvent::ToggleShowInterface => show_interface = !show_interface,
-                        UserEvent::SetThemeFile { theme_file, theme_kind } => application.set_theme_file(theme_file, theme_kind),
-                        UserEvent::SaveTheme { theme_kind } => application.save_theme(theme_kind),
-                        UserEvent::ReloadTheme { theme_kind } => application.reload_theme(theme_kind),
-                        UserEvent::SelectCharacter(character_slot) => {
-                            let _ = networking_system.select_character(character_slot);
-                        },
-                        UserEvent::OpenCharacterCreationWindow(character_slot) => {
-                            interface.open_window(&application, &mut focus_state, &CharacterCreationWindow::new(character_slot))
+                        NetworkEvent::SetGuild { guild_id, emblem_id, guild_name } => {
+                            guild.set(Some(Guild::new(guild_id, guild_name, emblem_id)));
+                            let _ = networking_system.request_guild_emblem(guild_id);
                         }
-                        UserEvent::CreateCharacter(character_slot, name) => {
-                            let _ = networking_system.create_character(character_slot, name);
-                        },
-                        UserEvent::DeleteCharacter(character_id) => {
-                            if currently_deleting.is_none() {
-                                let _ = networking_system.delete_character(character_id);
-                                currently_deleting = Some(character_id);
-                            }
-                        },
-                        UserEvent::RequestSwitchCharacterSlot(origin_slot) => move_request.set(Some(origin_slot)),
-                        UserEvent::CancelSwitchCharacterSlot => move_request.set(None),
-                        UserEvent::SwitchCharacterSlot(destination_slot) => {
-                            let _ = networking_system.switch_character_slot(move_request.take().unwrap(), destination_slot);
-                        },
-                        UserEvent::RequestPlayerMove(destination) => {
-                            if !entities.is_empty() {
-                                let _ = networking_system.player_move(WorldPosition { x: destination.x, y: destination.y });
-                            }
+                        NetworkEvent::SetGuildInformation { information } => {
+                            guild.with_mut(|guild| match guild {
+                                Some(guild) => {
+                                    guild.information = Some(information);
+                                    ValueState::Mutated(())
+                                }
+                                None => ValueState::Unchanged(()),
+                            });
                         }
-                        UserEvent::RequestPlayerInteract(entity_id) => {
-                            let entity = entities.iter_mut().find(|entity| entity.get_entity_id() == entity_id);
+                        NetworkEvent::SetGuildRelations { relations } => {
+                            guild.with_mut(|guild| match guild {
+                                Some(guild) => {
+                                    guild.relations = relations;
+                                    ValueState::Mutated(())
+                                }
+                                None => ValueState::Unchanged(()),
+                            });
+                        }
+                        NetworkEvent::GuildRelationRemoved { guild_id, relation_type } => {
+                            guild.with_mut(|guild| match guild {
+                                Some(guild) => {
+                                    guild
+                                        .relations
+                                        .retain(|relation| relation.guild_id != guild_id || relation.relation_type != relation_type);
+                                    ValueState::Mutated(())
+                                }
+                                None => ValueState::Unchanged(()),
+                            });
+                        }
+                        NetworkEvent::SetGuildMembers { members } => {
+                            // The member list doesn't contain any names, so we need to request them
+                            // one by one.
+                            for member in &members {
+                                let _ = networking_system.request_character_name(member.character_id);
+                            }
 
-                            if let Some(entity) = entity {
-                                let _ = match entity.get_entity_type() {
-                                    EntityType::Npc => networking_system.start_dialog(entity_id),
-                                    EntityType::Monster => networking_system.player_attack(entity_id),
-                                    EntityType::Warp => networking_system.player_move({
-                                        let position = entity.get_grid_position();
-                                        WorldPosition { x: position.x, y: position.y }
-                                    }),
-                                    _ => Ok(())
+                            guild.with_mut(|guild| match guild {
+                                Some(guild) => {
+                                    guild.members = members
+                                        .into_iter()
+                                        .map(|member| GuildMemberInformation { member, name: None })
+                                        .collect();
+                                    ValueState::Mutated(())
+                                }
+                                None => ValueState::Unchanged(()),
+                            });
+                        }
+                        NetworkEvent::CharacterName { character_id, name } => {
+                            guild.with_mut(|guild| {
+                                match guild.as_mut().and_then(|guild| {
+                                    guild
+                                        .members
+                                        .iter_mut()
+                                        .find(|information| information.member.character_id == character_id)
+                                }) {
+                                    Some(information) => {
+                                        information.name = Some(name);
+                                        ValueState::Mutated(())
+                                    }
+                                    None => ValueState::Unchanged(()),
+                                }
+                            });
+                        }
+                        NetworkEvent::UpdateGuildMemberState { account_id, character_id, state } => {
+                            guild.with_mut(|guild| {
+                                let Some(guild) = guild else {
+                                    return ValueState::Unchanged(());
                                 };
+
+                                match guild.members.iter_mut().find(|information| information.member.account_id == account_id) {
+                                    Some(information) => {
+                                        information.member.character_id = character_id;
+                                        let previous_state = std::mem::replace(&mut information.member.state, state);
+
+                                        // The server may repeat the current state, which must not change the count.
+                                        if previous_state != state
+                                            && let Some(information) = &mut guild.information
+                                        {
+                                            match state {
+                                                GuildMemberState::Online => information.online_member_count += 1,
+                                                GuildMemberState::Offline => {
+                                                    information.online_member_count = information.online_member_count.saturating_sub(1)
+                                                }
+                                            }
+                                        }
+
+                                        ValueState::Mutated(())
+                                    }
+                                    None => ValueState::Unchanged(()),
+                                }
+                            });
+                        }
+                        NetworkEvent::SetGuildPositionNames { positions } => {
+                            guild.with_mut(|guild| match guild {
+                                Some(guild) => {
+                                    guild.position_names = positions;
+                                    ValueState::Mutated(())
+                                }
+                                None => ValueState::Unchanged(()),
+                            });
+                        }
+                        NetworkEvent::SetGuildPositions { .. } => {}
+                        NetworkEvent::GuildNotice { subject, notice } => {
+                            if !subject.is_empty() {
+                                chat_messages.push(ChatMessage {
+                                    text: format!("[Guild] {subject}: {notice}"),
+                                    color: MessageColor::Guild,
+                                });
                             }
+
+                            guild.with_mut(|guild| match guild {
+                                Some(guild) => {
+                                    guild.notice_subject = subject;
+                                    guild.notice = notice;
+                                    ValueState::Mutated(())
+                                }
+                                None => ValueState::Unchanged(()),
+                            });
                         }
-                        UserEvent::RequestWarpToMap(map_name, position) => {
-                            let _ = networking_system.warp_to_map(map_name, position);
-                        },
-                        UserEvent::SendMessage(message) => {
-                            let _ = networking_system.send_chat_message(&saved_player_name, &message);
-                            // TODO: maybe find a better solution for unfocusing the message box if
-                            // this becomes problematic
-                            focus_state.remove_focus();
+                        NetworkEvent::GuildEmblem { guild_id, emblem_id, emblem_data } => {
+                            match texture_loader.load_guild_emblem(guild_id, emblem_id, &emblem_data) {
+                                Ok(texture) => guild.with_mut(|guild| match guild {
+                                    Some(guild) if guild.guild_id == guild_id && guild.emblem_id == emblem_id => {
+                                        guild.emblem = Some(texture);
+                                        ValueState::Mutated(())
+                                    }
+                                    _ => ValueState::Unchanged(()),
+                                }),
+                                #[cfg_attr(not(feature = "debug"), allow(unused_variables))]
+                                Err(error) => {
+                                    #[cfg(feature = "debug")]
+                                    print_debug!("[{}] failed to decode guild emblem: {:?}", "error".red(), error);
+                                }
+                            }
                         }
-                        UserEvent::NextDialog(npc_id) => {
-                            let _ = networking_system.next_dialog(npc_id);
-                        },
-                        UserEvent::CloseDialog(npc_id) => {
-                            let _ = networking_system.close_dialog(npc_id);
-                            dialog_system.close_dialog();
-                            interface.close_window_with_class(&mut focus_state, DialogWindow::WINDOW_CLASS);
+                        NetworkEvent::TradeRequested { name, base_level, .. } => {
+                            trade_partner_name = Some(name.clone());
+                            interface.open_window(&application, &mut focus_state, &TradeRequestWindow::new(name, base_level));
                         }
-                        UserEvent::ChooseDialogOption(npc_id, option) => {
-                            let _ = networking_system.choose_dialog_option(npc_id, option);
+                        NetworkEvent::TradeStarted { base_level, .. } => {
+                            interface.close_window_with_class(&mut focus_state, TradeRequestWindow::WINDOW_CLASS);
 
-                            if option == -1 {
+                            trade.set(Some(Trade::new(trade_partner_name.take(), base_level)));
+                            interface.open_window(&application, &mut focus_state, &TradeWindow::new(trade.new_remote()));
+                        }
+                        NetworkEvent::TradeRequestFailed { result } => {
+                            trade_partner_name = None;
+
+                            let text = match result {
+                                TradeResponse::TooFarAway => "The other player is too far away",
+                                TradeResponse::CharacterDoesNotExist => "The other player does not exist",
+                                TradeResponse::Busy => "The other player is already trading",
+                                TradeResponse::Cancel => "The trade request was rejected",
+                                TradeResponse::Failed | TradeResponse::Accept => "The trade request failed",
+                            };
+
+                            chat_messages.push(ChatMessage {
+                                text: text.to_owned(),
+                                color: MessageColor::Error,
+                            });
+                        }
+                        NetworkEvent::TradeItemAdded { item } => {
+                            let item = script_loader.load_trade_item_metadata(&mut texture_loader, item);
+
+                            trade.with_mut(|trade| match trade {
+                                Some(trade) => {
+                                    trade.partner_items.push(item);
+                                    ValueState::Mutated(())
+                                }
+                                None => ValueState::Unchanged(()),
+                            });
+                        }
+                        NetworkEvent::TradeZenyAdded { amount } => {
+                            trade.with_mut(|trade| match trade {
+                                Some(trade) => {
+                                    trade.partner_zeny = amount;
+                                    ValueState::Mutated(())
+                                }
+                                None => ValueState::Unchanged(()),
+                            });
+                        }
+                        NetworkEvent::AddTradeItemResult { index, result } => {
+                            let inventory_items = player_inventory.get_items();
+
+                            trade.with_mut(|trade| {
+                                let Some(trade) = trade else {
+                                    return ValueState::Unchanged(());
+                                };
+
+                                let amount = match index {
+                                    Some(index) => trade.take_pending_item(index),
+                                    None => trade.take_pending_zeny(),
+                                };
+
+                                match (result, index, amount) {
+                                    (AddTradeItemResult::Success, Some(index), Some(amount)) => {
+                                        match inventory_items.iter().find(|item| item.index == index) {
+                                            Some(item) => {
+                                                trade.add_own_item(item, amount);
+                                                ValueState::Mutated(())
+                                            }
+                                            None => ValueState::Unchanged(()),
+                                        }
+                                    }
+                                    (AddTradeItemResult::Success, None, Some(amount)) => {
+                                        trade.own_zeny = amount;
+                                        ValueState::Mutated(())
+                                    }
+                                    _ => ValueState::Unchanged(()),
+                                }
+                            });
+
+                            let text = match result {
+                                AddTradeItemResult::Success => None,
+                                AddTradeItemResult::Overweight => Some("The other player can't carry that much weight"),
+                                AddTradeItemResult::TradeClosed => Some("The trade is already locked"),
+                                AddTradeItemResult::TooManyItems | AddTradeItemResult::TooManyOfItem => {
+                                    Some("The other player can't carry any more items")
+                                }
+                            };
+
+                            if let Some(text) = text {
+                                chat_messages.push(ChatMessage {
+                                    text: text.to_owned(),
+                                    color: MessageColor::Error,
+                                });
+                            }
+                        }
+                        NetworkEvent::TradeLocked { party } => {
+                            trade.with_mut(|trade| match trade {
+                                Some(trade) => {
+                                    match party {
+                                        TradeParty::Player => trade.own_locked = true,
+                                        TradeParty::Partner => trade.partner_locked = true,
+                                    }
+                                    ValueState::Mutated(())
+                                }
+                                None => ValueState::Unchanged(()),
+                            });
+                        }
+                        NetworkEvent::TradeCancelled => {
+                            trade.set(None);
+                            interface.close_window_with_class(&mut focus_state, TradeWindow::WINDOW_CLASS);
+
+                            chat_messages.push(ChatMessage {
+                                text: "The trade was cancelled".to_owned(),
+                                color: MessageColor::Information,
+                            });
+                        }
+                        NetworkEvent::TradeCompleted { result } => {
+                            trade.set(None);
+                            interface.close_window_with_class(&mut focus_state, TradeWindow::WINDOW_CLASS);
+
+                            let (text, color) = match result {
+                                TradeResult::Success => ("The trade was completed", MessageColor::Information),
+                                TradeResult::Failed => ("The trade failed", MessageColor::Error),
+                            };
+
+                            chat_messages.push(ChatMessage {
+                                text: text.to_owned(),
+                                color,
+                            });
+                        }
+                        NetworkEvent::ChatRoomCreated => {
+                            // The title and limit are filled in once the chat room appears on screen.
+                            chat_room.set(Some(ChatRoom {
+                                chat_room_id: None,
+                                title: String::new(),
+                                limit: 0,
+                                chat_room_type: ChatRoomType::Public,
+                                members: vec![ChatRoomMember {
+                                    role: ChatRoomRole::Owner,
+                                    name: saved_player_name.clone(),
+                                }],
+                            }));
+                            interface.open_window(
+                                &application,
+                                &mut focus_state,
+                                &ChatRoomWindow::new(chat_room.new_remote(), saved_player_name.clone()),
+                            );
+                        }
+                        NetworkEvent::ChatRoomCreationFailed { result } => {
+                            let text = match result {
+                                CreateChatRoomResult::LimitExceeded => "Too many chat rooms are open",
+                                CreateChatRoomResult::AlreadyExists | CreateChatRoomResult::Success => "You are already in a chat room",
+                            };
+
+                            chat_messages.push(ChatMessage {
+                                text: text.to_owned(),
+                                color: MessageColor::Error,
+                            });
+                        }
+                        NetworkEvent::ChatRoomUpdated { chat_room: chat_room_data } => {
+                            let player_entity_id = entities[0].get_entity_id();
+
+                            chat_room.with_mut(|chat_room| match chat_room {
+                                Some(chat_room)
+                                    if chat_room.chat_room_id == Some(chat_room_data.chat_room_id)
+                                        || (chat_room.chat_room_id.is_none() && chat_room_data.owner_id == player_entity_id) =>
+                                {
+                                    chat_room.chat_room_id = Some(chat_room_data.chat_room_id);
+                                    chat_room.title = chat_room_data.title.clone();
+                                    chat_room.limit = chat_room_data.limit;
+                                    chat_room.chat_room_type = chat_room_data.chat_room_type;
+                                    ValueState::Mutated(())
+                                }
+                                _ => ValueState::Unchanged(()),
+                            });
+
+                            if let Some(entity) = entities.iter_mut().find(|entity| entity.get_entity_id() == chat_room_data.owner_id) {
+                                entity.set_chat_room(Some(chat_room_data));
+                            }
+                        }
+                        NetworkEvent::ChatRoomDestroyed { chat_room_id } => {
+                            entities
+                                .iter_mut()
+                                .filter(|entity| entity.get_chat_room().is_some_and(|chat_room| chat_room.chat_room_id == chat_room_id))
+                                .for_each(|entity| entity.set_chat_room(None));
+                        }
+                        NetworkEvent::ChatRoomEntered { chat_room_id, members } => {
+                            interface.close_window_with_class(&mut focus_state, ChatRoomPasswordWindow::WINDOW_CLASS);
+
+                            let chat_room_data = entities
+                                .iter()
+                                .find_map(|entity| entity.get_chat_room().filter(|chat_room| chat_room.chat_room_id == chat_room_id));
+
+                            chat_room.set(Some(ChatRoom {
+                                chat_room_id: Some(chat_room_id),
+                                title: chat_room_data.map(|chat_room| chat_room.title.clone()).unwrap_or_default(),
+                                limit: chat_room_data.map(|chat_room| chat_room.limit).unwrap_or_default(),
+                                chat_room_type: chat_room_data
+                                    .map(|chat_room| chat_room.chat_room_type)
+                                    .unwrap_or(ChatRoomType::Public),
+                                members,
+                            }));
+                            interface.open_window(
+                                &application,
+                                &mut focus_state,
+                                &ChatRoomWindow::new(chat_room.new_remote(), saved_player_name.clone()),
+                            );
+                        }
+                        NetworkEvent::EnterChatRoomFailed { reason } => {
+                            let text = match reason {
+                                EnterChatRoomFailedReason::RoomFull => "The chat room is full",
+                                EnterChatRoomFailedReason::WrongPassword => "Wrong password",
+                                EnterChatRoomFailedReason::Kicked => "You were kicked from this chat room",
+                                EnterChatRoomFailedReason::NotEnoughZeny => "You don't have enough zeny",
+                                EnterChatRoomFailedReason::LevelTooLow => "Your level is too low",
+                                EnterChatRoomFailedReason::LevelTooHigh => "Your level is too high",
+                                EnterChatRoomFailedReason::UnsuitableJob => "Your job can't enter this chat room",
+                                EnterChatRoomFailedReason::Success => "Failed to enter the chat room",
+                            };
+
+                            chat_messages.push(ChatMessage {
+                                text: text.to_owned(),
+                                color: MessageColor::Error,
+                            });
+                        }
+                        NetworkEvent::ChatRoomMemberEntered { name, .. } => {
+                            chat_room.with_mut(|chat_room| match chat_room {
+                                Some(chat_room) => {
+                                    chat_room.members.push(ChatRoomMember {
+                                        role: ChatRoomRole::Member,
+                                        name,
+                                    });
+                                    ValueState::Mutated(())
+                                }
+                                None => ValueState::Unchanged(()),
+                            });
+                        }
+                        NetworkEvent::ChatRoomMemberExited { name, reason, .. } => {
+                            if name == saved_player_name {
+                                chat_room.set(None);
+                                interface.close_window_with_class(&mut focus_state, ChatRoomWindow::WINDOW_CLASS);
+
+                                if reason == ChatRoomExitReason::Kicked {
+                                    chat_messages.push(ChatMessage {
+                                        text: "You were kicked from the chat room".to_owned(),
+                                        color: MessageColor::Information,
+                                    });
+                                }
+                            } else {
+                                chat_room.with_mut(|chat_room| match chat_room {
+                                    Some(chat_room) => {
+                                        chat_room.members.retain(|member| member.name != name);
+                                        ValueState::Mutated(())
+                                    }
+                                    None => ValueState::Unchanged(()),
+                                });
+                            }
+                        }
+                        NetworkEvent::ChatRoomRoleChanged { name, role } => {
+                            chat_room.with_mut(|chat_room| match chat_room {
+                                Some(chat_room) => {
+                                    if let Some(member) = chat_room.members.iter_mut().find(|member| member.name == name) {
+                                        member.role = role;
+                                    }
+                                    ValueState::Mutated(())
+                                }
+                                None => ValueState::Unchanged(()),
+                            });
+                        }
+                        NetworkEvent::SetCart { items } => {
+                            player_cart.fill(&mut texture_loader, &script_loader, items);
+                        }
+                        NetworkEvent::VendingShopSetup { slot_count } => {
+                            let items = player_cart.get_items().clone();
+                            let shop_name = PlainTrackedState::default();
+                            let zeny_limit = PlainTrackedState::default();
+                            let listing = PlainTrackedState::default();
+
+                            interface.open_window(&application, &mut focus_state, &StoreSetupWindow::new(
+                                StoreType::Vending,
+                                slot_count as usize,
+                                items,
+                                shop_name.clone(),
+                                zeny_limit.clone(),
+                                listing.clone(),
+                            ));
+                            interface.open_window(&application, &mut focus_state, &StoreListingWindow::new(StoreType::Vending, shop_name, zeny_limit, listing));
+                        }
+                        NetworkEvent::BuyingStoreSetup { slot_count } => {
+                            // Buying stores can only ask for stackable items.
+                            let items = player_inventory
+                                .get_items()
+                                .iter()
+                                .filter(|item| matches!(item.details, InventoryItemDetails::Regular { .. }))
+                                .cloned()
+                                .collect();
+                            let shop_name = PlainTrackedState::default();
+                            let zeny_limit = PlainTrackedState::default();
+                            let listing = PlainTrackedState::default();
+
+                            interface.open_window(&application, &mut focus_state, &StoreSetupWindow::new(
+                                StoreType::Buying,
+                                slot_count as usize,
+                                items,
+                                shop_name.clone(),
+                                zeny_limit.clone(),
+                                listing.clone(),
+                            ));
+                            interface.open_window(&application, &mut focus_state, &StoreListingWindow::new(StoreType::Buying, shop_name, zeny_limit, listing));
+                        }
+                        NetworkEvent::VendingShopOpened { items } => {
+                            let items = items
+                                .into_iter()
+                                .map(|item| {
+                                    let name = script_loader.get_item_name_from_id(item.item_id, item.is_identified);
+                                    format!("{} x {} for {}z", name, item.amount, item.price.0)
+                                })
+                                .collect();
+
+                            interface.close_window_with_class(&mut focus_state, StoreSetupWindow::WINDOW_CLASS);
+                            interface.close_window_with_class(&mut focus_state, StoreListingWindow::WINDOW_CLASS);
+                            interface.open_window(&application, &mut focus_state, &OwnStoreWindow::new(StoreType::Vending, items));
+                        }
+                        NetworkEvent::VendingShopOpenFailed => {
+                            chat_messages.push(ChatMessage {
+                                text: "Failed to open vending shop".to_owned(),
+                                color: MessageColor::Error,
+                            });
+                        }
+                        NetworkEvent::VendingItemSold { index, amount, zeny } => {
+                            let name = player_cart
+                                .get_items()
+                                .iter()
+                                .find(|item| item.index == index)
+                                .map(|item| item.metadata.name.clone())
+                                .unwrap_or_default();
+
+                            player_cart.remove_item(index, amount);
+
+                            chat_messages.push(ChatMessage {
+                                text: format!("Sold {amount} x {name} for {zeny} zeny"),
+                                color: MessageColor::Information,
+                            });
+                        }
+                        NetworkEvent::StoreAppeared { entity_id, store } => {
+                            if let Some(entity) = entities.iter_mut().find(|entity| entity.get_entity_id() == entity_id) {
+                                entity.set_store(Some(store));
+                            }
+                        }
+                        NetworkEvent::StoreDisappeared { entity_id, .. } => {
+                            if let Some(entity) = entities.iter_mut().find(|entity| entity.get_entity_id() == entity_id) {
+                                entity.set_store(None);
+                            }
+
+                            if entity_id == entities[0].get_entity_id() {
+                                interface.close_window_with_class(&mut focus_state, OwnStoreWindow::WINDOW_CLASS);
+                            }
+                        }
+                        NetworkEvent::VendingItemList { entity_id, vendor_id, items } => {
+                            let shop_name = entities
+                                .iter()
+                                .find(|entity| entity.get_entity_id() == entity_id)
+                                .and_then(|entity| entity.get_store())
+                                .map(|store| store.shop_name.clone())
+                                .unwrap_or_default();
+
+                            vending_items.mutate(|vending_items| *vending_items = items.into_iter().map(|item| {
+                                script_loader.load_vending_item_metadata(&mut texture_loader, item)
+                            }).collect());
+
+                            let cart = PlainTrackedState::default();
+
+                            interface.open_window(&application, &mut focus_state, &VendingWindow::new(shop_name, vending_items.new_remote(), cart.clone()));
+                            interface.open_window(&application, &mut focus_state, &VendingCartWindow::new(entity_id, vendor_id, cart));
+                        }
+                        NetworkEvent::VendingPurchaseFailed { result, .. } => {
+                            let text = match result {
+                                VendingPurchaseResult::NotEnoughZeny => "Not enough zeny to buy the items",
+                                VendingPurchaseResult::Overweight => "You can't carry the items",
+                                VendingPurchaseResult::OutOfStock => "The items are out of stock",
+                                _ => "Failed to buy items",
+                            };
+
+                            chat_messages.push(ChatMessage {
+                                text: text.to_owned(),
+                                color: MessageColor::Error,
+                            });
+                        }
+                        NetworkEvent::BuyingStoreOpened { zeny_limit, items } => {
+                            let mut lines = vec![format!("Zeny limit: {zeny_limit}")];
+                            lines.extend(items.into_iter().map(|item| {
+                                let name = script_loader.get_item_name_from_id(item.item_id, true);
+                                format!("{} x {} for {}z", name, item.amount, item.price.0)
+                            }));
+
+                            interface.close_window_with_class(&mut focus_state, StoreSetupWindow::WINDOW_CLASS);
+                            interface.close_window_with_class(&mut focus_state, StoreListingWindow::WINDOW_CLASS);
+                            interface.open_window(&application, &mut focus_state, &OwnStoreWindow::new(StoreType::Buying, lines));
+                        }
+                        NetworkEvent::BuyingStoreOpenFailed { result, weight } => {
+                            let text = match result {
+                                OpenBuyingStoreFailure::Failed => "Failed to open buying store".to_owned(),
+                                OpenBuyingStoreFailure::Overweight => format!("The items exceed the weight limit by {weight}"),
+                                OpenBuyingStoreFailure::NoItems => "The buying store has no items".to_owned(),
+                            };
+
+                            chat_messages.push(ChatMessage {
+                                text,
+                                color: MessageColor::Error,
+                            });
+                        }
+                        NetworkEvent::BuyingStoreClosed { reason } => {
+                            let text = match reason {
+                                BuyingStoreClosedReason::ZenyLimitReached => "All items within the zeny limit were bought",
+                                BuyingStoreClosedReason::AllItemsBought => "All items were bought",
+                            };
+
+                            chat_messages.push(ChatMessage {
+                                text: text.to_owned(),
+                                color: MessageColor::Information,
+                            });
+                        }
+                        NetworkEvent::BuyingStoreItemBought { item_id, amount, zeny_limit } => {
+                            let name = script_loader.get_item_name_from_id(item_id, true);
+
+                            chat_messages.push(ChatMessage {
+                                text: format!("Bought {amount} x {name}, {zeny_limit} zeny left"),
+                                color: MessageColor::Information,
+                            });
+                        }
+                        NetworkEvent::BuyingStoreItemList { entity_id, store_id, items, .. } => {
+                            let inventory_items = player_inventory.get_items();
+
+                            // Only offer the items that the player actually has.
+                            let items = items
+                                .iter()
+                                .filter_map(|store_item| {
+                                    let inventory_item = inventory_items.iter().find(|inventory_item| inventory_item.item_id == store_item.item_id)?;
+                                    let InventoryItemDetails::Regular { amount, .. } = inventory_item.details else {
+                                        return None;
+                                    };
+
+                                    Some(SellItem {
+                                        metadata: (inventory_item.metadata.clone(), amount.min(store_item.amount)),
+                                        inventory_index: inventory_item.index,
+                                        price: store_item.price,
+                                        overcharge_price: store_item.price,
+                                    })
+                                })
+                                .collect::<Vec<_>>();
+
+                            if items.is_empty() {
+                                chat_messages.push(ChatMessage {
+                                    text: "You don't have any of the items this store is buying".to_owned(),
+                                    color: MessageColor::Error,
+                                });
+                                continue;
+                            }
+
+                            browsed_buying_store = Some((entity_id, store_id));
+                            sell_items.set(items);
+
+                            let cart = PlainTrackedState::default();
+
+                            interface.open_window(&application, &mut focus_state, &SellWindow::new(sell_items.new_remote(), cart.clone()));
+                            interface.open_window(&application, &mut focus_state, &SellCartWindow::new(cart));
+                        }
+                        NetworkEvent::BuyingStoreItemSold { index, amount, zeny } => {
+                            let name = player_inventory
+                                .get_items()
+                                .iter()
+                                .find(|item| item.index == index)
+                                .map(|item| item.metadata.name.clone())
+                                .unwrap_or_default();
+
+                            // The server doesn't send a separate packet for removing the items.
+                            player_inventory.remove_item(index, amount);
+
+                            chat_messages.push(ChatMessage {
+                                text: format!("Sold {amount} x {name} for {zeny} zeny"),
+                                color: MessageColor::Information,
+                            });
+                        }
+                        NetworkEvent::SellToBuyingStoreFailed { result, item_id } => {
+                            let name = script_loader.get_item_name_from_id(item_id, true);
+                            let text = match result {
+                                SellToBuyingStoreFailure::Failed => format!("Failed to sell {name}"),
+                                SellToBuyingStoreFailure::AmountTooHigh => format!("The store doesn't buy that many {name}"),
+                                SellToBuyingStoreFailure::NotEnoughZeny => format!("The store can't afford to buy {name}"),
+                            };
+
+                            chat_messages.push(ChatMessage {
+                                text,
+                                color: MessageColor::Error,
+                            });
+                        }
+                        NetworkEvent::NewMail => chat_messages.push(ChatMessage {
+                            text: "You have new mail".to_owned(),
+                            color: MessageColor::Information,
+                        }),
+                        NetworkEvent::MailList { mails, is_last_page } => mailbox.mutate(|mailbox| mailbox.add_page(mails, is_last_page)),
+                        NetworkEvent::MailContents {
+                            mail_id,
+                            open_type,
+                            text,
+                            zeny,
+                            items,
+                        } => {
+                            let (sender_name, title) = mailbox
+                                .get()
+                                .mails
+                                .iter()
+                                .find(|mail| mail.mail_id == mail_id)
+                                .map(|mail| (mail.sender_name.clone(), mail.title.clone()))
+                                .unwrap_or_default();
+                            let items = items
+                                .into_iter()
+                                .map(|item| script_loader.load_mail_item_metadata(&mut texture_loader, item))
+                                .collect();
+
+                            mailbox.mutate(|mailbox| {
+                                mailbox.open(OpenMail {
+                                    mail_id,
+                                    open_type,
+                                    sender_name,
+                                    title,
+                                    text,
+                                    zeny,
+                                    items,
+                                })
+                            });
+                            interface.open_window(&application, &mut focus_state, &ReadMailWindow::new(mailbox.new_remote()));
+                        }
+                        NetworkEvent::MailDeleted { mail_id } => mailbox.mutate(|mailbox| mailbox.remove_mail(mail_id)),
+                        NetworkEvent::MailZenyRetrieved { mail_id } => mailbox.mutate(|mailbox| mailbox.remove_zeny(mail_id)),
+                        NetworkEvent::RetrieveMailZenyFailed { result } => {
+                            let text = match result {
+                                RetrieveMailZenyResult::ZenyLimitReached => "You can't carry that much zeny",
+                                RetrieveMailZenyResult::Success | RetrieveMailZenyResult::Failed => "Failed to take the zeny",
+                            };
+
+                            chat_messages.push(ChatMessage {
+                                text: text.to_owned(),
+                                color: MessageColor::Error,
+                            });
+                        }
+                        NetworkEvent::MailItemsRetrieved { mail_id } => mailbox.mutate(|mailbox| mailbox.remove_items(mail_id)),
+                        NetworkEvent::RetrieveMailItemsFailed { result } => {
+                            let text = match result {
+                                RetrieveMailItemsResult::InventoryFull => "Your inventory is full",
+                                RetrieveMailItemsResult::Success | RetrieveMailItemsResult::Failed => "Failed to take the items",
+                            };
+
+                            chat_messages.push(ChatMessage {
+                                text: text.to_owned(),
+                                color: MessageColor::Error,
+                            });
+                        }
+                        NetworkEvent::MailItemAttached { index, item } => {
+                            let item = script_loader.load_mail_item_metadata(&mut texture_loader, item);
+
+                            mail_attachments.mutate(|attachments| {
+                                match attachments.iter_mut().find(|attachment| attachment.index == index) {
+                                    Some(attachment) => attachment.item.amount += item.amount,
+                                    None => attachments.push(MailAttachment { index, item }),
+                                }
+                            });
+                        }
+                        NetworkEvent::AttachMailItemFailed { result } => {
+                            let text = match result {
+                                AddMailItemResult::Overweight => "The mail is too heavy",
+                                AddMailItemResult::NoSpace => "No more items can be attached",
+                                AddMailItemResult::NotTradeable => "This item can't be sent",
+                                AddMailItemResult::Success | AddMailItemResult::Failed => "Failed to attach the item",
+                            };
+
+                            chat_messages.push(ChatMessage {
+                                text: text.to_owned(),
+                                color: MessageColor::Error,
+                            });
+                        }
+                        NetworkEvent::MailItemDetached { index, remaining_amount } => mail_attachments.mutate(|attachments| {
+                            match remaining_amount {
+                                0 => attachments.retain(|attachment| attachment.index != index),
+                                amount => attachments
+                                    .iter_mut()
+                                    .filter(|attachment| attachment.index == index)
+                                    .for_each(|attachment| attachment.item.amount = amount),
+                            }
+                        }),
+                        NetworkEvent::MailReceiverChecked { character_id, .. } => {
+                            if let Some(mail) = pending_mail.take() {
+                                match character_id {
+                                    Some(character_id) => {
+                                        let _ = networking_system.send_mail(
+                                            &mail.receiver_name,
+                                            character_id,
+                                            &saved_player_name,
+                                            &mail.title,
+                                            &mail.text,
+                                            mail.zeny,
+                                        );
+                                    }
+                                    None => chat_messages.push(ChatMessage {
+                                        text: format!("There is no character named {}", mail.receiver_name),
+                                        color: MessageColor::Error,
+                                    }),
+                                }
+                            }
+                        }
+                        NetworkEvent::MailSent => {
+                            mail_attachments.set(Vec::new());
+                            interface.close_window_with_class(&mut focus_state, WriteMailWindow::WINDOW_CLASS);
+
+                            chat_messages.push(ChatMessage {
+                                text: "The mail was sent".to_owned(),
+                                color: MessageColor::Information,
+                            });
+                        }
+                        NetworkEvent::SendMailFailed { result } => {
+                            let text = match result {
+                                SendMailResult::TooManyMails => "You can't send any more mail today",
+                                SendMailResult::ItemFailed => "The attached items can't be sent",
+                                SendMailResult::ReceiverNotFound => "The receiver doesn't exist",
+                                SendMailResult::ReceiverBlocked => "The receiver doesn't accept your mail",
+                                SendMailResult::Success | SendMailResult::Failed => "Failed to send the mail",
+                            };
+
+                            chat_messages.push(ChatMessage {
+                                text: text.to_owned(),
+                                color: MessageColor::Error,
+                            });
+                        }
+                        NetworkEvent::QuestList { quests } => {
+                            let quests = quests
+                                .into_iter()
+                                .map(|quest| {
+                                    let text = script_loader.get_quest_text(quest.quest_id);
+                                    QuestLogEntry::new(quest, text)
+                                })
+                                .collect();
+
+                            quest_log.set(QuestLog { quests });
+                        }
+                        NetworkEvent::QuestAdded { quest } => {
+                            let text = script_loader.get_quest_text(quest.quest_id);
+                            quest_log.mutate(|quest_log| quest_log.add_quest(QuestLogEntry::new(quest, text)));
+                        }
+                        NetworkEvent::QuestObjectivesUpdated { objectives } => {
+                            quest_log.mutate(|quest_log| quest_log.update_objectives(&objectives));
+                        }
+                        NetworkEvent::QuestRemoved { quest_id } => quest_log.mutate(|quest_log| quest_log.remove_quest(quest_id)),
+                        NetworkEvent::AchievementList {
+                            summary,
+                            achievements: achievement_list,
+                        } => {
+                            let achievement_list = achievement_list
+                                .into_iter()
+                                .map(|progress| {
+                                    let text = script_loader.get_achievement_text(progress.achievement_id);
+                                    AchievementEntry { progress, text }
+                                })
+                                .collect();
+
+                            achievements.set(Achievements {
+                                summary: Some(summary),
+                                achievements: achievement_list,
+                            });
+                        }
+                        NetworkEvent::AchievementUpdated { summary, achievement } => {
+                            let text = script_loader.get_achievement_text(achievement.achievement_id);
+                            let title = text.title.clone();
+                            let entry = AchievementEntry { progress: achievement, text };
+
+                            if achievements.mutate(|achievements| achievements.update(summary, entry)) {
+                                chat_messages.push(ChatMessage {
+                                    text: format!("Achievement completed: {title}"),
+                                    color: MessageColor::Information,
+                                });
+                            }
+                        }
+                        NetworkEvent::ReputationList { entries } => reputation.set(entries),
+                        NetworkEvent::GuildEmblemChanged { guild_id, emblem_id } => {
+                            let is_own_guild = guild.get().as_ref().is_some_and(|guild| guild.guild_id == guild_id);
+
+                            if is_own_guild {
+                                guild.with_mut(|guild| {
+                                    if let Some(guild) = guild {
+                                        guild.emblem_id = emblem_id;
+                                    }
+                                    ValueState::Mutated(())
+                                });
+                                let _ = networking_system.request_guild_emblem(guild_id);
+                            }
+                        }
+                    }
+                }
+
+                // Cell changes are only applied to the tiles while processing the events, so the
+                // tile vertices and the minimap are regenerated at most once per frame.
+                if map_loader.update_tile_vertices(&mut map) {
+                    let map_name = minimap.get().map_name.clone();
+
+                    if let Some(minimap_texture) = texture_loader.regenerate_minimap(&map_name, || map.generate_minimap_image()) {
+                        minimap.mutate(|minimap| minimap.texture = Some(minimap_texture));
+                    }
+                }
+
+                #[cfg(feature = "debug")]
+                network_event_measurement.stop();
+
+                #[cfg(feature = "debug")]
+                let user_event_measurement = Profiler::start_measurement("process user events");
+
+                for event in user_events {
+                    match event {
+                        UserEvent::LogIn {
+                            service_id,
+                            username,
+                            password,
+                        } => {
+                            let service = client_info
+                                .services
+                                .iter()
+                                .find(|service| service.service_id() == service_id)
+                                .unwrap();
+                            let address = format!("{}:{}", service.address, service.port);
+                            let socket_address = address.to_socket_addrs().expect("Failed to resolve IP").next().expect("ill formatted service IP");
+
+                            let packet_profile = service
+                                .packet_version
+                                .map(|packet_version| PacketProfile::new(PacketVersion(packet_version)))
+                                .unwrap_or_default();
+
+                            saved_login_server_address = Some(socket_address);
+                            saved_packet_profile = packet_profile.clone();
+                            saved_username = username.clone();
+                            saved_password = password.clone();
+
+                            networking_system.connect_to_login_server(socket_address, packet_profile, username, password);
+                        }
+                        UserEvent::EnterMasterPassword(master_password) => {
+                            // Closing the window saves the login settings before they are loaded
+                            // again with the new master password.
+                            interface.close_window_with_class(&mut focus_state, LoginWindow::WINDOW_CLASS);
+                            interface.open_window(
+                                &application,
+                                &mut focus_state,
+                                &LoginWindow::new(&client_info, Some(master_password)),
+                            );
+                        }
+                        UserEvent::SelectServer(server) => {
+                            saved_character_server = Some(server.clone());
+
+                            networking_system.disconnect_from_login_server();
+
+                            // Korangar should never attempt to connect to the character
+                            // server before it logged in to the login server, so it's fine to
+                            // unwrap here.
+                            let login_data = saved_login_data.as_ref().unwrap();
+                            networking_system.connect_to_character_server(login_data, server);
+                        }
+                        UserEvent::LogOut => {
+                            let _ = networking_system.log_out();
+                        },
+                        UserEvent::Exit => active_event_loop.exit(),
+                        UserEvent::CameraZoom(factor) => player_camera.soft_zoom(factor),
+                        UserEvent::CameraRotate(factor) => player_camera.soft_rotate(factor),
+                        UserEvent::OpenMenuWindow => {
+                            if !entities.is_empty() {
+                                interface.open_window(&application, &mut focus_state, &MenuWindow)
+                            }
+                        }
+                        UserEvent::OpenInventoryWindow => {
+                            if !entities.is_empty() {
+                                interface.open_window(
+                                    &application,
+                                    &mut focus_state,
+                                    &InventoryWindow::new(player_inventory.item_remote()),
+                                )
+                            }
+                        }
+                        UserEvent::OpenEquipmentWindow => {
+                            if !entities.is_empty() {
+                                interface.open_window(
+                                    &application,
+                                    &mut focus_state,
+                                    &EquipmentWindow::new(player_inventory.item_remote()),
+                                )
+                            }
+                        }
+                        UserEvent::OpenSkillTreeWindow => {
+                            if !entities.is_empty() {
+                                interface.open_window(
+                                    &application,
+                                    &mut focus_state,
+                                    &SkillTreeWindow::new(player_skill_tree.get_skills()),
+                                )
+                            }
+                        }
+                        UserEvent::OpenGraphicsSettingsWindow => interface.open_window(
+                            &application,
+                            &mut focus_state,
+                            &GraphicsSettingsWindow::new(surface.present_mode_info(), shadow_detail.clone_state(), framerate_limit.clone_state()),
+                        ),
+                        UserEvent::OpenAudioSettingsWindow => interface.open_window(&application, &mut focus_state, &AudioSettingsWindow),
+                        UserEvent::OpenFriendsWindow => {
+                            interface.open_window(&application, &mut focus_state, &FriendsWindow::new(friend_list.new_remote()));
+                        }
+                        UserEvent::OpenPartyWindow => {
+                            let player_account_id = saved_login_data.as_ref().unwrap().account_id;
+
+                            interface.open_window(
+                                &application,
+                                &mut focus_state,
+                                &PartyWindow::new(player_account_id, party_members.new_remote()),
+                            );
+                        }
+                        UserEvent::OpenGuildWindow => {
+                            if guild.get().is_some() {
+                                let _ = networking_system.request_guild_information(GuildInformationType::BasicInformation);
+                                let _ = networking_system.request_guild_information(GuildInformationType::Members);
+                            }
+
+                            interface.open_window(&application, &mut focus_state, &GuildWindow::new(guild.new_remote()));
+                        }
+                        UserEvent::OpenMinimapWindow => {
+                            interface.open_window(&application, &mut focus_state, &MinimapWindow::new(minimap.new_remote()));
+                        }
+                        UserEvent::OpenQuestLogWindow => {
+                            interface.open_window(&application, &mut focus_state, &QuestLogWindow::new(quest_log.new_remote()));
+                        }
+                        UserEvent::OpenAchievementWindow => {
+                            interface.open_window(&application, &mut focus_state, &AchievementWindow::new(achievements.new_remote()));
+                        }
+                        UserEvent::OpenReputationWindow => {
+                            interface.open_window(&application, &mut focus_state, &ReputationWindow::new(reputation.new_remote()));
+                        }
+                        UserEvent::OpenChatRoomWindow => match chat_room.get().is_some() {
+                            true => interface.open_window(
+                                &application,
+                                &mut focus_state,
+                                &ChatRoomWindow::new(chat_room.new_remote(), saved_player_name.clone()),
+                            ),
+                            false => interface.open_window(&application, &mut focus_state, &ChatRoomSettingsWindow::new(None)),
+                        },
+                        UserEvent::OpenChatRoomSettingsWindow => {
+                            let settings_window = ChatRoomSettingsWindow::new(chat_room.get().clone());
+                            interface.open_window(&application, &mut focus_state, &settings_window);
+                        }
+                        UserEvent::ToggleShowInterface => show_interface = !show_interface,
+                        UserEvent::SetThemeFile { theme_file, theme_kind } => application.set_theme_file(theme_file, theme_kind),
+                        UserEvent::SaveTheme { theme_kind } => application.save_theme(theme_kind),
+                        UserEvent::ReloadTheme { theme_kind } => application.reload_theme(theme_kind),
+                        UserEvent::SelectCharacter(character_slot) => {
+                            let _ = networking_system.select_character(character_slot);
+                        },
+                        UserEvent::OpenCharacterCreationWindow(character_slot) => {
+                            interface.open_window(&application, &mut focus_state, &CharacterCreationWindow::new(character_slot))
+                        }
+                        UserEvent::CreateCharacter(character_slot, name) => {
+                            let _ = networking_system.create_character(character_slot, name);
+                        },
+                        UserEvent::DeleteCharacter(character_id) => {
+                            if currently_deleting.is_none() {
+                                let _ = networking_system.delete_character(character_id);
+                                currently_deleting = Some(character_id);
+                            }
+                        },
+                        UserEvent::RequestSwitchCharacterSlot(origin_slot) => move_request.set(Some(origin_slot)),
+                        UserEvent::CancelSwitchCharacterSlot => move_request.set(None),
+                        UserEvent::SwitchCharacterSlot(destination_slot) => {
+                            let _ = networking_system.switch_character_slot(move_request.take().unwrap(), destination_slot);
+                        },
+                        UserEvent::RequestPlayerMove(destination) => {
+                            if !entities.is_empty() {
+                                let _ = networking_system.player_move(WorldPosition { x: destination.x, y: destination.y });
+                            }
+                        }
+                        UserEvent::RequestPlayerInteract(entity_id) => {
+                            let entity = entities.iter().find(|entity| entity.get_entity_id() == entity_id);
+
+                            if let Some(entity) = entity {
+                                // Clicking on the owner of a chat room enters the chat room instead.
+                                if let Some(chat_room_data) = entity.get_chat_room().filter(|_| entity_id != entities[0].get_entity_id()) {
+                                    match chat_room_data.chat_room_type {
+                                        ChatRoomType::Private => {
+                                            let password_window =
+                                                ChatRoomPasswordWindow::new(chat_room_data.chat_room_id, chat_room_data.title.clone());
+                                            interface.open_window(&application, &mut focus_state, &password_window);
+                                        }
+                                        _ => {
+                                            let _ = networking_system.enter_chat_room(chat_room_data.chat_room_id, String::new());
+                                        }
+                                    }
+
+                                    continue;
+                                }
+
+                                // Clicking on the owner of a vending shop or buying store opens the store.
+                                if let Some(store) = entity.get_store().filter(|_| entity_id != entities[0].get_entity_id()) {
+                                    let _ = match store.store_type {
+                                        StoreType::Vending => networking_system.request_vending_item_list(entity_id),
+                                        StoreType::Buying => networking_system.request_buying_store_item_list(entity_id),
+                                    };
+
+                                    continue;
+                                }
+
+                                let _ = match entity.get_entity_type() {
+                                    EntityType::Npc => networking_system.start_dialog(entity_id),
+                                    EntityType::Monster => networking_system.player_attack(entity_id),
+                                    EntityType::Item => networking_system.pick_up_item(entity_id),
+                                    EntityType::Warp => networking_system.player_move({
+                                        let position = entity.get_grid_position();
+                                        WorldPosition { x: position.x, y: position.y }
+                                    }),
+                                    _ => Ok(())
+                                };
+                            }
+                        }
+                        UserEvent::RequestTrade(entity_id) => {
+                            let entity = entities
+                                .iter()
+                                .skip(1)
+                                .find(|entity| entity.get_entity_id() == entity_id && entity.get_entity_type() == EntityType::Player);
+
+                            if let Some(entity) = entity {
+                                trade_partner_name = entity.get_details().cloned();
+                                let _ = networking_system.request_trade(entity_id);
+                            }
+                        }
+                        UserEvent::RequestWarpToMap(map_name, position) => {
+                            let _ = networking_system.warp_to_map(map_name, position);
+                        },
+                        UserEvent::SendMessage(message) => {
+                            // Messages starting with a '%' are sent to the party chat and messages
+                            // starting with a '$' are sent to the guild chat. Whispers are sent with
+                            // `/w "name" message` and emotions with commands like `/thx`.
+                            let _ = if let Some((recipient_name, message)) = parse_whisper_command(&message) {
+                                conversations.get_messages_with(recipient_name).push(ChatMessage {
+                                    text: format!("{saved_player_name} : {message}"),
+                                    color: MessageColor::Whisper,
+                                });
+                                last_whisper_recipient = Some(recipient_name.to_owned());
+
+                                networking_system.send_whisper(recipient_name, message)
+                            } else if let Some(emotion) = parse_emotion_command(&message) {
+                                networking_system.send_emotion(emotion)
+                            } else if let Some(message) = message.strip_prefix('%') {
+                                networking_system.send_party_message(&saved_player_name, message.trim_start())
+                            } else if let Some(message) = message.strip_prefix('$') {
+                                networking_system.send_guild_message(&saved_player_name, message.trim_start())
+                            } else {
+                                networking_system.send_chat_message(&saved_player_name, &message)
+                            };
+                            // TODO: maybe find a better solution for unfocusing the message box if
+                            // this becomes problematic
+                            focus_state.remove_focus();
+                        }
+                        UserEvent::SendEmotion(emotion) => {
+                            let _ = networking_system.send_emotion(emotion);
+                        }
+                        UserEvent::SendWhisper { recipient_name, message } => {
+                            conversations.get_messages_with(&recipient_name).push(ChatMessage {
+                                text: format!("{saved_player_name} : {message}"),
+                                color: MessageColor::Whisper,
+                            });
+
+                            let _ = networking_system.send_whisper(&recipient_name, &message);
+                            last_whisper_recipient = Some(recipient_name);
+                            focus_state.remove_focus();
+                        }
+                        UserEvent::CloseConversation(partner_name) => conversations.remove(&partner_name),
+                        UserEvent::NextDialog(npc_id) => {
+                            let _ = networking_system.next_dialog(npc_id);
+                        },
+                        UserEvent::CloseDialog(npc_id) => {
+                            let _ = networking_system.close_dialog(npc_id);
+                            dialog_system.close_dialog();
+                            interface.close_window_with_class(&mut focus_state, DialogWindow::WINDOW_CLASS);
+                        }
+                        UserEvent::ChooseDialogOption(npc_id, option) => {
+                            let _ = networking_system.choose_dialog_option(npc_id, option);
+
+                            if option == -1 {
                                 dialog_system.close_dialog();
                                 interface.close_window_with_class(&mut focus_state, DialogWindow::WINDOW_CLASS);
                             }
@@ -1199,8 +2518,48 @@ fn main() {
                                     (ItemSource::Equipment { .. }, ItemSource::Inventory) => {
                                         let _ = networking_system.request_item_unequip(item.index);
                                     }
+                                    (ItemSource::Inventory, ItemSource::Storage) => {
+                                        let amount = match &item.details {
+                                            InventoryItemDetails::Regular { amount, .. } => *amount as u32,
+                                            InventoryItemDetails::Equippable { .. } => 1,
+                                        };
+
+                                        let _ = networking_system.storage_add_item(item.index, amount);
+                                    }
+                                    (ItemSource::Inventory, ItemSource::Mail) => {
+                                        let amount = match &item.details {
+                                            InventoryItemDetails::Regular { amount, .. } => *amount,
+                                            InventoryItemDetails::Equippable { .. } => 1,
+                                        };
+
+                                        let _ = networking_system.attach_mail_item(item.index, amount);
+                                    }
+                                    (ItemSource::Inventory, ItemSource::Trade) => {
+                                        let amount = match &item.details {
+                                            InventoryItemDetails::Regular { amount, .. } => *amount as u32,
+                                            InventoryItemDetails::Equippable { .. } => 1,
+                                        };
+
+                                        trade.with_mut(|trade| match trade {
+                                            Some(trade) => {
+                                                trade.add_pending_item(item.index, amount);
+                                                ValueState::Mutated(())
+                                            }
+                                            None => ValueState::Unchanged(()),
+                                        });
+
+                                        let _ = networking_system.add_trade_item(item.index, amount);
+                                    }
                                     _ => {}
                                 },
+                                Move::StorageItem { item } => {
+                                    let amount = match &item.details {
+                                        InventoryItemDetails::Regular { amount, .. } => *amount as u32,
+                                        InventoryItemDetails::Equippable { .. } => 1,
+                                    };
+
+                                    let _ = networking_system.storage_remove_item(item.index, amount);
+                                }
                                 Move::Skill {
                                     source,
                                     destination,
@@ -1278,11 +2637,116 @@ fn main() {
                             let _ = networking_system.accept_friend_request(account_id, character_id);
                             interface.close_window_with_class(&mut focus_state, FriendRequestWindow::WINDOW_CLASS);
                         }
+                        UserEvent::CreateParty(party_name) => {
+                            let _ = networking_system.create_party(party_name);
+                        }
+                        UserEvent::InviteToParty(name) => {
+                            let _ = networking_system.invite_to_party(name);
+                        }
+                        UserEvent::RejectPartyInvite(party_id) => {
+                            let _ = networking_system.reject_party_invite(party_id);
+                            interface.close_window_with_class(&mut focus_state, PartyInviteWindow::WINDOW_CLASS);
+                        }
+                        UserEvent::AcceptPartyInvite(party_id) => {
+                            let _ = networking_system.accept_party_invite(party_id);
+                            interface.close_window_with_class(&mut focus_state, PartyInviteWindow::WINDOW_CLASS);
+                        }
+                        UserEvent::LeaveParty => {
+                            let _ = networking_system.leave_party();
+                        }
+                        UserEvent::KickPartyMember { account_id, name } => {
+                            let _ = networking_system.kick_party_member(account_id, name);
+                        }
+                        UserEvent::ChangeGuildNotice { subject, notice } => {
+                            if let Some(guild_id) = guild.get().as_ref().map(|guild| guild.guild_id) {
+                                let _ = networking_system.change_guild_notice(guild_id, subject, notice);
+                            }
+                        }
+                        UserEvent::RemoveGuildRelation { guild_id, relation_type } => {
+                            let _ = networking_system.remove_guild_relation(guild_id, relation_type);
+                        }
+                        UserEvent::UseItem(index) => {
+                            if let Some(player) = entities.first() {
+                                let _ = networking_system.use_item(AccountId(player.get_entity_id().0), index);
+                            }
+                        }
+                        UserEvent::DropItem { index, amount } => {
+                            let _ = networking_system.drop_item(index, amount);
+                        }
+                        UserEvent::CloseStorage => {
+                            let _ = networking_system.storage_close();
+                        }
+                        UserEvent::AcceptTradeRequest => {
+                            let _ = networking_system.accept_trade_request();
+                            interface.close_window_with_class(&mut focus_state, TradeRequestWindow::WINDOW_CLASS);
+                        }
+                        UserEvent::RejectTradeRequest => {
+                            trade_partner_name = None;
+                            let _ = networking_system.reject_trade_request();
+                            interface.close_window_with_class(&mut focus_state, TradeRequestWindow::WINDOW_CLASS);
+                        }
+                        UserEvent::AddTradeZeny(amount) => {
+                            trade.with_mut(|trade| match trade {
+                                Some(trade) => {
+                                    trade.add_pending_zeny(amount);
+                                    ValueState::Mutated(())
+                                }
+                                None => ValueState::Unchanged(()),
+                            });
+
+                            let _ = networking_system.add_trade_zeny(amount);
+                        }
+                        UserEvent::LockTrade => {
+                            let _ = networking_system.lock_trade();
+                        }
+                        UserEvent::CommitTrade => {
+                            let _ = networking_system.commit_trade();
+                        }
+                        UserEvent::CancelTrade => {
+                            let _ = networking_system.cancel_trade();
+                        }
+                        UserEvent::CreateChatRoom { title, limit, password } => {
+                            let chat_room_type = match password.is_empty() {
+                                true => ChatRoomType::Public,
+                                false => ChatRoomType::Private,
+                            };
+
+                            let _ = networking_system.create_chat_room(title, limit, chat_room_type, password);
+                        }
+                        UserEvent::ChangeChatRoom { title, limit, password } => {
+                            let chat_room_type = match password.is_empty() {
+                                true => ChatRoomType::Public,
+                                false => ChatRoomType::Private,
+                            };
+
+                            let _ = networking_system.change_chat_room(title, limit, chat_room_type, password);
+                        }
+                        UserEvent::EnterChatRoom { chat_room_id, password } => {
+                            let _ = networking_system.enter_chat_room(chat_room_id, password);
+                        }
+                        UserEvent::LeaveChatRoom => {
+                            let _ = networking_system.leave_chat_room();
+                        }
+                        UserEvent::KickFromChatRoom(name) => {
+                            let _ = networking_system.kick_from_chat_room(name);
+                        }
+                        UserEvent::TransferChatRoomOwnership(name) => {
+                            let _ = networking_system.transfer_chat_room_ownership(name);
+                        }
+                        UserEvent::RaiseStat(stat_type) => {
+                            let _ = networking_system.request_stat_up(stat_type);
+                        }
+                        UserEvent::RaiseSkill(skill_id) => {
+                            let _ = networking_system.request_skill_up(skill_id);
+                        }
                         UserEvent::BuyItems { items } => {
                             let _ = networking_system.purchase_items(items);
                         }
                         UserEvent::CloseShop => {
-                            let _ = networking_system.close_shop();
+                            // Selling to a buying store doesn't need to be ended.
+                            if browsed_buying_store.take().is_none() {
+                                let _ = networking_system.close_shop();
+                            }
 
                             interface.close_window_with_class(&mut focus_state, BuyWindow::WINDOW_CLASS);
                             interface.close_window_with_class(&mut focus_state, BuyCartWindow::WINDOW_CLASS);
@@ -1294,7 +2758,116 @@ fn main() {
                             interface.close_window_with_class(&mut focus_state, BuyOrSellWindow::WINDOW_CLASS);
                         },
                         UserEvent::SellItems { items } => {
-                            let _ = networking_system.sell_items(items);
+                            match browsed_buying_store.take() {
+                                Some((entity_id, store_id)) => {
+                                    let inventory_items = player_inventory.get_items();
+                                    let items = items
+                                        .into_iter()
+                                        .filter_map(|item| {
+                                            let inventory_item = inventory_items.iter().find(|inventory_item| inventory_item.index == item.inventory_index)?;
+
+                                            Some(SellToBuyingStoreItemInformation {
+                                                index: item.inventory_index,
+                                                item_id: inventory_item.item_id,
+                                                amount: item.amount,
+                                            })
+                                        })
+                                        .collect();
+
+                                    let _ = networking_system.sell_to_buying_store(entity_id, store_id, items);
+
+                                    // There is no result for a successful sale, so we close the windows right away.
+                                    interface.close_window_with_class(&mut focus_state, SellWindow::WINDOW_CLASS);
+                                    interface.close_window_with_class(&mut focus_state, SellCartWindow::WINDOW_CLASS);
+                                }
+                                None => {
+                                    let _ = networking_system.sell_items(items);
+                                }
+                            }
+                        }
+                        UserEvent::OpenVendingShop { shop_name, items } => {
+                            let _ = networking_system.open_vending_shop(shop_name, items);
+                        }
+                        UserEvent::OpenBuyingStore { shop_name, zeny_limit, items } => {
+                            let _ = networking_system.open_buying_store(shop_name, zeny_limit, items);
+                        }
+                        UserEvent::CloseStoreSetup => {
+                            interface.close_window_with_class(&mut focus_state, StoreSetupWindow::WINDOW_CLASS);
+                            interface.close_window_with_class(&mut focus_state, StoreListingWindow::WINDOW_CLASS);
+                        }
+                        UserEvent::CloseVendingShop => {
+                            let _ = networking_system.close_vending_shop();
+                            interface.close_window_with_class(&mut focus_state, OwnStoreWindow::WINDOW_CLASS);
+                        }
+                        UserEvent::CloseBuyingStore => {
+                            let _ = networking_system.close_buying_store();
+                            interface.close_window_with_class(&mut focus_state, OwnStoreWindow::WINDOW_CLASS);
+                        }
+                        UserEvent::BuyVendingItems { entity_id, vendor_id, items } => {
+                            let _ = networking_system.purchase_vending_items(entity_id, vendor_id, items);
+
+                            // Only failures are reported, so we close the windows right away.
+                            interface.close_window_with_class(&mut focus_state, VendingWindow::WINDOW_CLASS);
+                            interface.close_window_with_class(&mut focus_state, VendingCartWindow::WINDOW_CLASS);
+                        }
+                        UserEvent::CloseVendingItemList => {
+                            interface.close_window_with_class(&mut focus_state, VendingWindow::WINDOW_CLASS);
+                            interface.close_window_with_class(&mut focus_state, VendingCartWindow::WINDOW_CLASS);
+                        }
+                        UserEvent::OpenMailbox => {
+                            mailbox.set(Mailbox::default());
+
+                            if networking_system.open_mailbox().is_ok() {
+                                interface.open_window(&application, &mut focus_state, &MailboxWindow::new(mailbox.new_remote()));
+                            }
+                        }
+                        UserEvent::CloseMailbox => {
+                            let _ = networking_system.close_mailbox();
+                            interface.close_window_with_class(&mut focus_state, MailboxWindow::WINDOW_CLASS);
+                            interface.close_window_with_class(&mut focus_state, ReadMailWindow::WINDOW_CLASS);
+                        }
+                        UserEvent::RequestNextMailPage(mail_id) => {
+                            let _ = networking_system.request_next_mail_page(mail_id);
+                        }
+                        UserEvent::ReadMail { open_type, mail_id } => {
+                            let _ = networking_system.read_mail(open_type, mail_id);
+                        }
+                        UserEvent::DeleteMail { open_type, mail_id } => {
+                            let _ = networking_system.delete_mail(open_type, mail_id);
+                        }
+                        UserEvent::RetrieveMailZeny { open_type, mail_id } => {
+                            let _ = networking_system.retrieve_mail_zeny(open_type, mail_id);
+                        }
+                        UserEvent::RetrieveMailItems { open_type, mail_id } => {
+                            let _ = networking_system.retrieve_mail_items(open_type, mail_id);
+                        }
+                        UserEvent::OpenWriteMailWindow => {
+                            mail_attachments.set(Vec::new());
+
+                            if networking_system.start_writing_mail().is_ok() {
+                                interface.open_window(
+                                    &application,
+                                    &mut focus_state,
+                                    &WriteMailWindow::new(mail_attachments.new_remote()),
+                                );
+                            }
+                        }
+                        UserEvent::CancelWritingMail => {
+                            // Attached items are returned to the inventory by the server.
+                            let _ = networking_system.cancel_writing_mail();
+                            mail_attachments.set(Vec::new());
+                            pending_mail = None;
+                            interface.close_window_with_class(&mut focus_state, WriteMailWindow::WINDOW_CLASS);
+                        }
+                        UserEvent::SendMail(mail) => {
+                            // The server only accepts mail after looking up the receiver, so the mail
+                            // is sent once the receiver was found.
+                            if networking_system.check_mail_receiver(&mail.receiver_name).is_ok() {
+                                pending_mail = Some(mail);
+                            }
+                        }
+                        UserEvent::DetachMailItem { index, amount } => {
+                            let _ = networking_system.detach_mail_item(index, amount);
                         }
                         UserEvent::FocusChatWindow => {
                             interface.focus_window_with_class(&mut focus_state, ChatWindow::WINDOW_CLASS);
@@ -1366,6 +2939,47 @@ fn main() {
                     .iter_mut()
                     .for_each(|entity| entity.update(&map, delta_time as f32, client_tick));
 
+                hotbar.update_cooldowns(client_tick);
+
+                let minimap_icons: Vec<MinimapIcon> = entities
+                    .iter()
+                    .skip(1)
+                    .filter_map(|entity| {
+                        let color = match entity.get_entity_type() {
+                            EntityType::Player => Color::rgb_u8(120, 180, 255),
+                            EntityType::Npc => Color::rgb_u8(255, 200, 80),
+                            EntityType::Warp => Color::rgb_u8(230, 90, 230),
+                            EntityType::Monster => Color::rgb_u8(230, 60, 60),
+                            EntityType::Item | EntityType::Hidden => return None,
+                        };
+
+                        Some(MinimapIcon {
+                            position: entity.get_grid_position(),
+                            color,
+                        })
+                    })
+                    .chain(party_members.get().iter().filter_map(|information| {
+                        let position = information.position?;
+
+                        (information.member.map_name.trim_end_matches(".gat") == minimap.get().map_name).then_some(MinimapIcon {
+                            position: Vector2::new(position.x as usize, position.y as usize),
+                            color: Color::rgb_u8(80, 220, 80),
+                        })
+                    }))
+                    .collect();
+                let player_position = entities.first().map(|player| player.get_grid_position());
+
+                if minimap.get().icons != minimap_icons || minimap.get().player_position != player_position {
+                    minimap.mutate(|minimap| {
+                        minimap.icons = minimap_icons;
+                        minimap.player_position = player_position;
+                    });
+                }
+
+                if minimap.get().has_expired_markers(client_tick.0) {
+                    minimap.mutate(|minimap| minimap.remove_expired_markers(client_tick.0));
+                }
+
                 #[cfg(feature = "debug")]
                 update_entities_measurement.stop();
 
@@ -1759,6 +3373,55 @@ fn main() {
                     );
                 }
 
+                {
+                    #[cfg(feature = "debug")]
+                    profile_block!("render signboards");
+
+                    for entity in entities {
+                        entity.render_signboard(
+                            deferred_target,
+                            &mut screen_render_pass,
+                            &deferred_renderer,
+                            current_camera,
+                            application.get_game_theme(),
+                            window_size,
+                        );
+                    }
+                }
+
+                {
+                    #[cfg(feature = "debug")]
+                    profile_block!("render emotions");
+
+                    for entity in entities {
+                        entity.render_emotion(
+                            deferred_target,
+                            &mut screen_render_pass,
+                            &deferred_renderer,
+                            current_camera,
+                            &application,
+                            window_size,
+                        );
+                    }
+                }
+
+                {
+                    #[cfg(feature = "debug")]
+                    profile_block!("render cast bars");
+
+                    for entity in entities {
+                        entity.render_casting(
+                            deferred_target,
+                            &mut screen_render_pass,
+                            &deferred_renderer,
+                            current_camera,
+                            application.get_game_theme(),
+                            window_size,
+                            client_tick,
+                        );
+                    }
+                }
+
                 if let Some(PickerTarget::Entity(entity_id)) = mouse_target {
                     #[cfg(feature = "debug")]
                     profile_block!("render hovered entity status");
@@ -1817,6 +3480,17 @@ fn main() {
                         application.get_game_theme(),
                         window_size,
                     );
+
+                    if let Entity::Player(player) = &entities[0] {
+                        player.render_status_effects(
+                            deferred_target,
+                            &mut screen_render_pass,
+                            &deferred_renderer,
+                            &application,
+                            window_size,
+                            client_tick,
+                        );
+                    }
                 }
 
                 #[cfg(feature = "debug")]
@@ -1834,6 +3508,16 @@ fn main() {
                 }
 
                 if show_interface {
+                    if let Some(cut_in) = &cut_in {
+                        cut_in.render(
+                            deferred_target,
+                            &mut screen_render_pass,
+                            &deferred_renderer,
+                            &application,
+                            window_size,
+                        );
+                    }
+
                     deferred_renderer.overlay_interface(deferred_target, &mut screen_render_pass, &interface_target.texture);
 
                     mouse_cursor.render(
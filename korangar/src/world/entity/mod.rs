@@ -2,23 +2,24 @@ use std::sync::Arc;
 
 use cgmath::{Array, Vector2, Vector3, VectorSpace};
 use derive_new::new;
+use korangar_interface::application::FontSizeTrait;
 use korangar_interface::elements::PrototypeElement;
 use korangar_interface::windows::{PrototypeWindow, Window};
-use korangar_networking::EntityData;
+use korangar_networking::{BodyState, ChatRoomData, EntityData, EntityVisualState, GroundItemData, StoreData, StoreType};
 use ragnarok_formats::map::TileFlags;
-use ragnarok_packets::{AccountId, CharacterInformation, ClientTick, EntityId, Sex, StatusType, WorldPosition};
+use ragnarok_packets::{AccountId, CharacterInformation, ClientTick, EntityId, ItemId, Sex, StatusType, WorldPosition};
 #[cfg(feature = "debug")]
 use wgpu::Buffer;
 use wgpu::RenderPass;
 
 #[cfg(feature = "debug")]
 use crate::graphics::MarkerRenderer;
-use crate::graphics::{Camera, DeferredRenderer, EntityRenderer, Renderer};
+use crate::graphics::{Camera, Color, DeferredRenderer, EntityRenderer, Renderer, SpriteRenderer, Texture};
 use crate::interface::application::InterfaceSettings;
-use crate::interface::layout::{ScreenPosition, ScreenSize};
+use crate::interface::layout::{ScreenClip, ScreenPosition, ScreenSize};
 use crate::interface::theme::GameTheme;
 use crate::interface::windows::WindowCache;
-use crate::loaders::{ActionLoader, Actions, AnimationState, ScriptLoader, Sprite, SpriteLoader};
+use crate::loaders::{ActionLoader, Actions, AnimationState, FontSize, ScriptLoader, Sprite, SpriteLoader};
 use crate::world::Map;
 #[cfg(feature = "debug")]
 use crate::world::MarkerIdentifier;
@@ -56,6 +57,33 @@ pub enum EntityType {
     Player,
     Npc,
     Monster,
+    Item,
+}
+
+/// How long an emotion is shown over the head of an entity, in milliseconds.
+const EMOTION_DURATION: u32 = 3000;
+
+/// Emotion that is shown over the head of an entity.
+struct Emotion {
+    sprite: Arc<Sprite>,
+    actions: Arc<Actions>,
+    animation_state: AnimationState,
+    direction: usize,
+}
+
+/// Skill that an entity is currently casting.
+struct Casting {
+    start_tick: u32,
+    end_tick: u32,
+}
+
+/// Status effect that is currently active on an entity.
+pub struct StatusEffect {
+    pub status_id: u16,
+    pub icon: Option<Arc<Texture>>,
+    /// Client tick at which the status effect runs out. Effects without a
+    /// duration don't have one.
+    pub end_tick: Option<u32>,
 }
 
 #[derive(PrototypeElement)]
@@ -79,6 +107,18 @@ pub struct Common {
     details: ResourceState<String>,
     #[hidden_element]
     animation_state: AnimationState,
+    #[hidden_element]
+    chat_room: Option<ChatRoomData>,
+    #[hidden_element]
+    store: Option<StoreData>,
+    #[hidden_element]
+    emotion: Option<Emotion>,
+    #[hidden_element]
+    visual_state: EntityVisualState,
+    #[hidden_element]
+    status_effects: Vec<StatusEffect>,
+    #[hidden_element]
+    casting: Option<Casting>,
 }
 
 #[cfg_attr(feature = "debug", korangar_debug::profile)]
@@ -255,6 +295,11 @@ fn get_sprite_and_actions(
         EntityType::Npc => format!("npc\\{}", script_loader.get_job_name_from_id(job_id)),
         EntityType::Monster => format!("¸ó½ºÅÍ\\{}", script_loader.get_job_name_from_id(job_id)),
         EntityType::Warp | EntityType::Hidden => format!("npc\\{}", script_loader.get_job_name_from_id(job_id)), // TODO: change
+        // For ground items the job id is the item id.
+        EntityType::Item => format!(
+            "¾ÆÀÌÅÛ\\{}",
+            script_loader.get_item_resource_from_id(ItemId(job_id as u32), true)
+        ),
     };
 
     (
@@ -283,6 +328,7 @@ impl Common {
         let health_points = entity_data.health_points as usize;
         let maximum_health_points = entity_data.maximum_health_points as usize;
         let sex = entity_data.sex;
+        let visual_state = entity_data.visual_state;
 
         let active_movement = None;
 
@@ -316,6 +362,12 @@ impl Common {
             actions,
             details,
             animation_state,
+            chat_room: None,
+            store: None,
+            emotion: None,
+            visual_state,
+            status_effects: Vec::new(),
+            casting: None,
         };
 
         if let Some(destination) = entity_data.destination {
@@ -394,6 +446,78 @@ impl Common {
         }
 
         self.animation_state.update(client_tick);
+
+        if let Some(emotion) = &mut self.emotion {
+            match client_tick.0.saturating_sub(emotion.animation_state.start_time.0) > EMOTION_DURATION {
+                true => self.emotion = None,
+                false => emotion.animation_state.update(client_tick),
+            }
+        }
+
+        if let Some(casting) = &self.casting
+            && client_tick.0 > casting.end_tick
+        {
+            self.casting = None;
+        }
+    }
+
+    pub fn start_casting(&mut self, cast_time: u32, client_tick: ClientTick) {
+        // Skills without a cast time are used instantly, so there is nothing to show.
+        self.casting = (cast_time > 0).then_some(Casting {
+            start_tick: client_tick.0,
+            end_tick: client_tick.0.wrapping_add(cast_time),
+        });
+    }
+
+    pub fn stop_casting(&mut self) {
+        self.casting = None;
+    }
+
+    pub fn set_emotion(
+        &mut self,
+        sprite_loader: &mut SpriteLoader,
+        action_loader: &mut ActionLoader,
+        emotion: u8,
+        client_tick: ClientTick,
+    ) {
+        const EMOTION_PATH: &str = "ÀÌÆÑÆ®\\emotion";
+
+        let (Ok(sprite), Ok(actions)) = (
+            sprite_loader.get(&format!("{EMOTION_PATH}.spr")),
+            action_loader.get(&format!("{EMOTION_PATH}.act")),
+        ) else {
+            return;
+        };
+
+        // Unlike entity sprites, the emotion sprite only has a single direction per
+        // action, so we split the emotion into an action and a direction.
+        let mut animation_state = AnimationState::new(client_tick);
+        animation_state.action = emotion as usize / 8;
+
+        self.emotion = Some(Emotion {
+            sprite,
+            actions,
+            animation_state,
+            direction: emotion as usize % 8,
+        });
+    }
+
+    pub fn add_status_effect(&mut self, status_effect: StatusEffect) {
+        self.remove_status_effect(status_effect.status_id);
+        self.status_effects.push(status_effect);
+    }
+
+    pub fn remove_status_effect(&mut self, status_id: u16) {
+        self.status_effects.retain(|status_effect| status_effect.status_id != status_id);
+    }
+
+    fn get_color(&self) -> Color {
+        match (self.visual_state.body_state, self.visual_state.is_hidden) {
+            (BodyState::Stone, _) => Color::rgb_u8(150, 140, 130),
+            (BodyState::Frozen, _) => Color::rgb_u8(130, 170, 255),
+            (_, true) => Color::monochrome_u8(90),
+            _ => Color::monochrome_u8(255),
+        }
     }
 
     pub fn move_from_to(&mut self, map: &Map, from: Vector2<usize>, to: Vector2<usize>, starting_timestamp: ClientTick) {
@@ -730,6 +854,7 @@ impl Common {
             Vector2::new(1, 1),
             Vector2::new(0, 0),
             mirror,
+            self.get_color(),
             self.entity_id,
         );
     }
@@ -748,6 +873,154 @@ impl Common {
     {
         renderer.render_marker(render_target, render_pass, camera, marker_identifier, self.position, hovered);
     }
+
+    pub fn render_emotion(
+        &self,
+        render_target: &mut <DeferredRenderer as Renderer>::Target,
+        render_pass: &mut RenderPass,
+        renderer: &DeferredRenderer,
+        camera: &dyn Camera,
+        application: &InterfaceSettings,
+        window_size: ScreenSize,
+    ) {
+        const EMOTION_HEIGHT: Vector3<f32> = Vector3::new(0.0, 17.0, 0.0);
+
+        let Some(emotion) = &self.emotion else {
+            return;
+        };
+
+        let (view_matrix, projection_matrix) = camera.view_projection_matrices();
+        let clip_space_position = (projection_matrix * view_matrix) * (self.position + EMOTION_HEIGHT).extend(1.0);
+        let screen_position = camera.clip_to_screen_space(clip_space_position);
+        let position = ScreenPosition {
+            left: screen_position.x * window_size.width,
+            top: screen_position.y * window_size.height,
+        };
+
+        emotion.actions.render2(
+            render_target,
+            render_pass,
+            renderer,
+            &emotion.sprite,
+            &emotion.animation_state,
+            position,
+            emotion.direction,
+            Color::monochrome_u8(255),
+            application,
+        );
+    }
+
+    pub fn render_casting(
+        &self,
+        render_target: &mut <DeferredRenderer as Renderer>::Target,
+        render_pass: &mut RenderPass,
+        renderer: &DeferredRenderer,
+        camera: &dyn Camera,
+        theme: &GameTheme,
+        window_size: ScreenSize,
+        client_tick: ClientTick,
+    ) {
+        const CAST_BAR_HEIGHT: Vector3<f32> = Vector3::new(0.0, 14.0, 0.0);
+
+        let Some(casting) = &self.casting else {
+            return;
+        };
+
+        let (view_matrix, projection_matrix) = camera.view_projection_matrices();
+        let clip_space_position = (projection_matrix * view_matrix) * (self.position + CAST_BAR_HEIGHT).extend(1.0);
+        let screen_position = camera.clip_to_screen_space(clip_space_position);
+        let final_position = ScreenPosition {
+            left: screen_position.x * window_size.width,
+            top: screen_position.y * window_size.height,
+        };
+
+        let bar_width = theme.status_bar.enemy_bar_width.get();
+        let bar_size = ScreenSize {
+            width: bar_width,
+            height: theme.status_bar.cast_time_height.get(),
+        };
+
+        renderer.render_rectangle(
+            render_target,
+            render_pass,
+            final_position - theme.status_bar.border_size.get() - ScreenSize::only_width(bar_width / 2.0),
+            bar_size + (theme.status_bar.border_size.get() * 2.0),
+            theme.status_bar.background_color.get(),
+        );
+
+        let cast_time = casting.end_tick.wrapping_sub(casting.start_tick);
+        let elapsed = client_tick.0.saturating_sub(casting.start_tick).min(cast_time);
+
+        renderer.render_bar(
+            render_target,
+            render_pass,
+            final_position,
+            bar_size,
+            theme.status_bar.cast_time_color.get(),
+            cast_time as f32,
+            elapsed as f32,
+        );
+    }
+
+    pub fn render_signboard(
+        &self,
+        render_target: &mut <DeferredRenderer as Renderer>::Target,
+        render_pass: &mut RenderPass,
+        renderer: &DeferredRenderer,
+        camera: &dyn Camera,
+        theme: &GameTheme,
+        window_size: ScreenSize,
+    ) {
+        const SIGNBOARD_HEIGHT: Vector3<f32> = Vector3::new(0.0, 12.0, 0.0);
+
+        let text = match (&self.chat_room, &self.store) {
+            (Some(chat_room), _) => format!("{} ({}/{})", chat_room.title, chat_room.member_count, chat_room.limit),
+            (None, Some(store)) => match store.store_type {
+                StoreType::Vending => store.shop_name.clone(),
+                StoreType::Buying => format!("[Buying] {}", store.shop_name),
+            },
+            (None, None) => return,
+        };
+        let font_size = theme.signboard.font_size.get();
+        let text_offset = theme.signboard.text_offset.get();
+        let border_size = theme.signboard.border_size.get();
+
+        let (view_matrix, projection_matrix) = camera.view_projection_matrices();
+        let clip_space_position = (projection_matrix * view_matrix) * (self.position + SIGNBOARD_HEIGHT).extend(1.0);
+        let screen_position = camera.clip_to_screen_space(clip_space_position);
+
+        let size = ScreenSize {
+            width: text.len() as f32 * font_size.get_value() / 2.0 + text_offset.left * 2.0,
+            height: font_size.get_value() + text_offset.top * 2.0,
+        };
+        let position = ScreenPosition {
+            left: screen_position.x * window_size.width - size.width / 2.0,
+            top: screen_position.y * window_size.height - size.height,
+        };
+
+        renderer.render_rectangle(
+            render_target,
+            render_pass,
+            position - border_size,
+            size + border_size * 2.0,
+            theme.signboard.border_color.get(),
+        );
+        renderer.render_rectangle(
+            render_target,
+            render_pass,
+            position,
+            size,
+            theme.signboard.background_color.get(),
+        );
+        renderer.render_text(
+            render_target,
+            render_pass,
+            &text,
+            position + text_offset,
+            theme.signboard.foreground_color.get(),
+            font_size,
+        );
+    }
 }
 
 #[derive(PrototypeWindow)]
@@ -896,6 +1169,72 @@ impl Player {
             self.activity_points as f32,
         );
     }
+
+    pub fn render_status_effects(
+        &self,
+        render_target: &mut <DeferredRenderer as Renderer>::Target,
+        render_pass: &mut RenderPass,
+        renderer: &DeferredRenderer,
+        application: &InterfaceSettings,
+        window_size: ScreenSize,
+        client_tick: ClientTick,
+    ) {
+        const ICON_SIZE: f32 = 24.0;
+        const ICON_GAP: f32 = 4.0;
+        const FONT_SIZE: f32 = 12.0;
+
+        let scaling = application.get_scaling_factor();
+        let icon_size = ScreenSize::uniform(ICON_SIZE * scaling);
+        let screen_clip = ScreenClip {
+            left: 0.0,
+            top: 0.0,
+            right: f32::MAX,
+            bottom: f32::MAX,
+        };
+
+        let mut position = ScreenPosition {
+            left: window_size.width - (ICON_SIZE + ICON_GAP) * scaling,
+            top: ICON_GAP * scaling,
+        };
+
+        for status_effect in &self.common.status_effects {
+            let Some(icon) = &status_effect.icon else {
+                continue;
+            };
+
+            renderer.render_sprite(
+                render_target,
+                render_pass,
+                icon,
+                position,
+                icon_size,
+                screen_clip,
+                Color::monochrome_u8(255),
+                true,
+            );
+
+            if let Some(end_tick) = status_effect.end_tick {
+                // The server tells us when the effect ends, so we only clamp the countdown.
+                let remaining_seconds = end_tick.saturating_sub(client_tick.0) / 1000;
+                let text = match remaining_seconds {
+                    0..=59 => format!("{remaining_seconds}s"),
+                    60..=3599 => format!("{}m", remaining_seconds / 60),
+                    _ => format!("{}h", remaining_seconds / 3600),
+                };
+
+                renderer.render_text(
+                    render_target,
+                    render_pass,
+                    &text,
+                    position + ScreenPosition::only_top(icon_size.height),
+                    Color::monochrome_u8(255),
+                    FontSize::new(FONT_SIZE * scaling),
+                );
+            }
+
+            position.left -= (ICON_SIZE + ICON_GAP) * scaling;
+        }
+    }
 }
 
 #[derive(PrototypeWindow)]
@@ -974,11 +1313,84 @@ impl Npc {
     }
 }
 
+#[derive(PrototypeWindow)]
+pub struct GroundItem {
+    common: Common,
+    pub item_id: ItemId,
+    pub amount: u16,
+}
+
+impl GroundItem {
+    pub fn new(
+        sprite_loader: &mut SpriteLoader,
+        action_loader: &mut ActionLoader,
+        script_loader: &ScriptLoader,
+        map: &Map,
+        item_data: GroundItemData,
+        client_tick: ClientTick,
+    ) -> Self {
+        let entity_type = EntityType::Item;
+        let job_id = item_data.item_id.0 as usize;
+        let grid_position = Vector2::new(item_data.position.x as usize, item_data.position.y as usize);
+        let position = map.get_world_position(grid_position);
+        let sex = Sex::Male;
+
+        let (sprite, actions) = get_sprite_and_actions(sprite_loader, action_loader, script_loader, entity_type, job_id, sex);
+
+        // Ground items have no details that we could request from the server, so we
+        // use the name of the item instead.
+        let name = script_loader.get_item_name_from_id(item_data.item_id, item_data.is_identified);
+        let details = match item_data.amount {
+            1 => ResourceState::Available(name),
+            amount => ResourceState::Available(format!("{name} ({amount})")),
+        };
+
+        let common = Common {
+            entity_id: item_data.entity_id,
+            job_id,
+            health_points: 0,
+            maximum_health_points: 0,
+            movement_speed: 0,
+            head_direction: 0,
+            sex,
+            entity_type,
+            active_movement: None,
+            sprite,
+            actions,
+            grid_position,
+            position,
+            details,
+            animation_state: AnimationState::new(client_tick),
+            chat_room: None,
+            store: None,
+            emotion: None,
+            visual_state: EntityVisualState::default(),
+            status_effects: Vec::new(),
+            casting: None,
+        };
+
+        Self {
+            common,
+            item_id: item_data.item_id,
+            amount: item_data.amount,
+        }
+    }
+
+    pub fn get_common(&self) -> &Common {
+        &self.common
+    }
+
+    pub fn get_common_mut(&mut self) -> &mut Common {
+        &mut self.common
+    }
+}
+
 // TODO:
 //#[derive(PrototypeWindow)]
 pub enum Entity {
     Player(Player),
     Npc(Npc),
+    Item(GroundItem),
 }
 
 impl Entity {
@@ -986,6 +1398,7 @@ impl Entity {
         match self {
             Self::Player(player) => player.get_common(),
             Self::Npc(npc) => npc.get_common(),
+            Self::Item(item) => item.get_common(),
         }
     }
 
@@ -993,6 +1406,7 @@ impl Entity {
         match self {
             Self::Player(player) => player.get_common_mut(),
             Self::Npc(npc) => npc.get_common_mut(),
+            Self::Item(item) => item.get_common_mut(),
         }
     }
 
@@ -1031,6 +1445,22 @@ impl Entity {
         self.get_common().details.as_option()
     }
 
+    pub fn set_chat_room(&mut self, chat_room: Option<ChatRoomData>) {
+        self.get_common_mut().chat_room = chat_room;
+    }
+
+    pub fn get_chat_room(&self) -> Option<&ChatRoomData> {
+        self.get_common().chat_room.as_ref()
+    }
+
+    pub fn set_store(&mut self, store: Option<StoreData>) {
+        self.get_common_mut().store = store;
+    }
+
+    pub fn get_store(&self) -> Option<&StoreData> {
+        self.get_common().store.as_ref()
+    }
+
     pub fn get_grid_position(&self) -> Vector2<usize> {
         self.get_common().grid_position
     }
@@ -1043,6 +1473,26 @@ impl Entity {
         self.get_common_mut().set_position(map, position, client_tick);
     }
 
+    pub fn add_status_effect(&mut self, status_effect: StatusEffect) {
+        self.get_common_mut().add_status_effect(status_effect);
+    }
+
+    pub fn remove_status_effect(&mut self, status_id: u16) {
+        self.get_common_mut().remove_status_effect(status_id);
+    }
+
+    pub fn set_visual_state(&mut self, visual_state: EntityVisualState) {
+        self.get_common_mut().visual_state = visual_state;
+    }
+
+    pub fn start_casting(&mut self, cast_time: u32, client_tick: ClientTick) {
+        self.get_common_mut().start_casting(cast_time, client_tick);
+    }
+
+    pub fn stop_casting(&mut self) {
+        self.get_common_mut().stop_casting();
+    }
+
     pub fn update_health(&mut self, health_points: usize, maximum_health_points: usize) {
         let common = self.get_common_mut();
         common.health_points = health_points;
@@ -1057,6 +1507,17 @@ impl Entity {
         self.get_common_mut().move_from_to(map, from, to, starting_timestamp);
     }
 
+    pub fn set_emotion(
+        &mut self,
+        sprite_loader: &mut SpriteLoader,
+        action_loader: &mut ActionLoader,
+        emotion: u8,
+        client_tick: ClientTick,
+    ) {
+        self.get_common_mut()
+            .set_emotion(sprite_loader, action_loader, emotion, client_tick);
+    }
+
     /*#[cfg(feature = "debug")]
     pub fn generate_steps_vertex_buffer(&mut self, device: Arc<Device>, map: &Map) {
         self.get_common_mut().generate_steps_vertex_buffer(device, map);
@@ -1066,6 +1527,13 @@ impl Entity {
     where
         T: Renderer + EntityRenderer,
     {
+        // Hidden players can still see themselves, so only other entities vanish.
+        if let Self::Npc(npc) = self
+            && npc.common.visual_state.is_hidden
+        {
+            return;
+        }
+
         self.get_common().render(render_target, render_pass, renderer, camera);
     }
 
@@ -1097,8 +1565,49 @@ impl Entity {
         match self {
             Self::Player(player) => player.render_status(render_target, render_pass, renderer, camera, theme, window_size),
             Self::Npc(npc) => npc.render_status(render_target, render_pass, renderer, camera, theme, window_size),
+            Self::Item(_) => {}
         }
     }
+
+    pub fn render_casting(
+        &self,
+        render_target: &mut <DeferredRenderer as Renderer>::Target,
+        render_pass: &mut RenderPass,
+        renderer: &DeferredRenderer,
+        camera: &dyn Camera,
+        theme: &GameTheme,
+        window_size: ScreenSize,
+        client_tick: ClientTick,
+    ) {
+        self.get_common()
+            .render_casting(render_target, render_pass, renderer, camera, theme, window_size, client_tick);
+    }
+
+    pub fn render_signboard(
+        &self,
+        render_target: &mut <DeferredRenderer as Renderer>::Target,
+        render_pass: &mut RenderPass,
+        renderer: &DeferredRenderer,
+        camera: &dyn Camera,
+        theme: &GameTheme,
+        window_size: ScreenSize,
+    ) {
+        self.get_common()
+            .render_signboard(render_target, render_pass, renderer, camera, theme, window_size);
+    }
+
+    pub fn render_emotion(
+        &self,
+        render_target: &mut <DeferredRenderer as Renderer>::Target,
+        render_pass: &mut RenderPass,
+        renderer: &DeferredRenderer,
+        camera: &dyn Camera,
+        application: &InterfaceSettings,
+        window_size: ScreenSize,
+    ) {
+        self.get_common()
+            .render_emotion(render_target, render_pass, renderer, camera, application, window_size);
+    }
 }
 
 impl PrototypeWindow<InterfaceSettings> for Entity {
@@ -1111,6 +1620,7 @@ impl PrototypeWindow<InterfaceSettings> for Entity {
         match self {
             Entity::Player(player) => player.to_window(window_cache, application, available_space),
             Entity::Npc(npc) => npc.to_window(window_cache, application, available_space),
+            Entity::Item(item) => item.to_window(window_cache, application, available_space),
         }
     }
 }
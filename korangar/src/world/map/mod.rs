@@ -3,6 +3,7 @@ use std::collections::HashSet;
 
 use cgmath::{Array, EuclideanSpace, Matrix4, Point3, SquareMatrix, Vector2, Vector3};
 use derive_new::new;
+use image::{Rgba, RgbaImage};
 use korangar_audio::AudioEngine;
 #[cfg(feature = "debug")]
 use korangar_debug::profiling::Profiler;
@@ -128,6 +129,10 @@ pub struct Map {
     tile_vertex_buffer: Buffer<ModelVertex>,
     object_kdtree: KDTree<ObjectKey, AABB>,
     background_music_track_name: Option<String>,
+    /// Set when tile flags changed but the tile vertex buffers were not yet
+    /// regenerated.
+    #[new(default)]
+    tiles_changed: bool,
     #[cfg(feature = "debug")]
     map_data: MapData,
 }
@@ -151,6 +156,54 @@ impl Map {
         &self.tiles[position.x + position.y * self.width]
     }
 
+    pub fn get_tiles(&self) -> &[Tile] {
+        &self.tiles
+    }
+
+    pub fn set_tile_flags(&mut self, position: Vector2<usize>, flags: TileFlags) {
+        self.tiles[position.x + position.y * self.width].flags = flags;
+        self.tiles_changed = true;
+    }
+
+    pub fn tiles_changed(&self) -> bool {
+        self.tiles_changed
+    }
+
+    pub fn set_tile_vertex_buffers(&mut self, tile_vertex_buffer: Buffer<ModelVertex>, tile_picker_vertex_buffer: Buffer<TileVertex>) {
+        self.tile_vertex_buffer = tile_vertex_buffer;
+        self.tile_picker_vertex_buffer = tile_picker_vertex_buffer;
+        self.tiles_changed = false;
+    }
+
+    pub fn get_size(&self) -> Vector2<usize> {
+        Vector2::new(self.width, self.height)
+    }
+
+    /// Draw the walkable tiles of the map, for maps that don't come with a
+    /// minimap. Like the official minimaps, the image is square with the map
+    /// centered in it and north facing up.
+    pub fn generate_minimap_image(&self) -> RgbaImage {
+        let side = self.width.max(self.height);
+        let offset_x = (side - self.width) / 2;
+        let offset_y = (side - self.height) / 2;
+
+        let mut image = RgbaImage::new(side as u32, side as u32);
+
+        for y in 0..self.height {
+            for x in 0..self.width {
+                let color = match self.get_tile(Vector2::new(x, y)).flags.contains(TileFlags::WALKABLE) {
+                    true => Rgba([210, 210, 200, 230]),
+                    false => Rgba([40, 40, 40, 160]),
+                };
+
+                let image_y = side - 1 - (offset_y + y);
+                image.put_pixel((offset_x + x) as u32, image_y as u32, color);
+            }
+        }
+
+        image
+    }
+
     pub fn background_music_track_name(&self) -> Option<&str> {
         self.background_music_track_name.as_deref()
     }
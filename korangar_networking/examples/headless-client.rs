@@ -0,0 +1,356 @@
+use std::net::{SocketAddr, ToSocketAddrs};
+use std::process::ExitCode;
+use std::thread::sleep;
+use std::time::{Duration, Instant};
+
+use korangar_networking::{DisconnectReason, LoginServerLoginData, NetworkEvent, NetworkingSystem};
+use ragnarok_packets::handler::NoPacketCallback;
+use ragnarok_packets::{PacketProfile, PacketVersion, WorldPosition};
+use serde_json::json;
+
+const USAGE: &str =
+    "usage: headless-client <address> <username> <password> [--character <name>] [--packet-version <version>] [--script <file>]";
+const POLL_INTERVAL: Duration = Duration::from_millis(50);
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(10);
+
+struct Arguments {
+    address: SocketAddr,
+    username: String,
+    password: String,
+    character_name: Option<String>,
+    packet_profile: PacketProfile,
+    script: Option<String>,
+}
+
+impl Arguments {
+    fn parse() -> Result<Self, String> {
+        let mut arguments = std::env::args().skip(1);
+        let mut positional = Vec::new();
+        let mut character_name = None;
+        let mut packet_profile = PacketProfile::default();
+        let mut script = None;
+
+        while let Some(argument) = arguments.next() {
+            let mut value = || arguments.next().ok_or(format!("missing value for {argument}"));
+
+            match argument.as_str() {
+                "--character" => character_name = Some(value()?),
+                "--packet-version" => {
+                    let packet_version = value()?.parse().map_err(|_| "invalid packet version".to_owned())?;
+                    packet_profile = PacketProfile::new(PacketVersion(packet_version));
+                }
+                "--script" => script = Some(std::fs::read_to_string(value()?).map_err(|error| error.to_string())?),
+                _ => positional.push(argument),
+            }
+        }
+
+        let [address, username, password] = <[String; 3]>::try_from(positional).map_err(|_| USAGE.to_owned())?;
+        let address = address
+            .to_socket_addrs()
+            .map_err(|error| error.to_string())?
+            .next()
+            .ok_or("failed to resolve address".to_owned())?;
+
+        Ok(Self {
+            address,
+            username,
+            password,
+            character_name,
+            packet_profile,
+            script,
+        })
+    }
+}
+
+/// A single line of a script.
+#[derive(Debug)]
+enum Command {
+    /// Keep processing events for some time.
+    Wait(Duration),
+    /// Wait for an event with the given name, failing after the timeout.
+    Expect {
+        event_name: String,
+        timeout: Duration,
+    },
+    Move {
+        x: usize,
+        y: usize,
+    },
+    Chat(String),
+    /// Send a private message, written as `whisper <name>: <message>` so that
+    /// names may contain spaces.
+    Whisper {
+        recipient_name: String,
+        message: String,
+    },
+    Quit,
+}
+
+impl Command {
+    fn parse(line: &str) -> Result<Self, String> {
+        let (command, arguments) = line.split_once(' ').unwrap_or((line, ""));
+        let number = |text: &str| text.parse::<u64>().map_err(|_| format!("expected a number but got \"{text}\""));
+
+        match (command, arguments.split_whitespace().collect::<Vec<_>>().as_slice()) {
+            ("wait", [milliseconds]) => Ok(Self::Wait(Duration::from_millis(number(milliseconds)?))),
+            ("expect", [event_name]) => Ok(Self::Expect {
+                event_name: event_name.to_string(),
+                timeout: DEFAULT_TIMEOUT,
+            }),
+            ("expect", [event_name, milliseconds]) => Ok(Self::Expect {
+                event_name: event_name.to_string(),
+                timeout: Duration::from_millis(number(milliseconds)?),
+            }),
+            ("move", [x, y]) => Ok(Self::Move {
+                x: number(x)? as usize,
+                y: number(y)? as usize,
+            }),
+            ("chat", _) if !arguments.is_empty() => Ok(Self::Chat(arguments.to_owned())),
+            ("whisper", _) => match arguments.split_once(':') {
+                Some((recipient_name, message)) if !recipient_name.trim().is_empty() && !message.trim().is_empty() => Ok(Self::Whisper {
+                    recipient_name: recipient_name.trim().to_owned(),
+                    message: message.trim().to_owned(),
+                }),
+                _ => Err(format!("expected \"whisper <name>: <message>\" but got \"{line}\"")),
+            },
+            ("quit", []) => Ok(Self::Quit),
+            _ => Err(format!("invalid command \"{line}\"")),
+        }
+    }
+}
+
+fn parse_script(script: &str) -> Result<Vec<Command>, String> {
+    script
+        .lines()
+        .enumerate()
+        .map(|(index, line)| (index, line.trim()))
+        .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+        .map(|(index, line)| Command::parse(line).map_err(|error| format!("line {}: {error}", index + 1)))
+        .collect()
+}
+
+/// Splits the serialized event into the name of the variant and its fields.
+fn serialize_event(event: &NetworkEvent) -> (String, serde_json::Value) {
+    // Events don't contain any maps with non-string keys, so serializing them
+    // can't fail.
+    match serde_json::to_value(event).expect("failed to serialize event") {
+        serde_json::Value::String(event_name) => (event_name, serde_json::Value::Null),
+        serde_json::Value::Object(object) if object.len() == 1 => object.into_iter().next().unwrap(),
+        value => unreachable!("unexpected event representation {value}"),
+    }
+}
+
+fn event_name(event: &NetworkEvent) -> String {
+    serialize_event(event).0
+}
+
+/// Drives the [`NetworkingSystem`] from the login server into the game and
+/// prints every event as a JSON line.
+struct HeadlessClient {
+    networking_system: NetworkingSystem<NoPacketCallback>,
+    start: Instant,
+    character_name: Option<String>,
+    login_data: Option<LoginServerLoginData>,
+    player_name: Option<String>,
+    in_game: bool,
+    /// Events received while logging in, which are handed to the script once
+    /// it starts.
+    buffered_events: Vec<NetworkEvent>,
+}
+
+impl HeadlessClient {
+    fn print(&self, mut line: serde_json::Value) {
+        line["elapsed_ms"] = json!(self.start.elapsed().as_millis() as u64);
+        println!("{line}");
+    }
+
+    /// Process all new events and return them along with any buffered ones.
+    /// Fails if the client can't get into the game or loses the connection.
+    fn poll(&mut self) -> Result<Vec<NetworkEvent>, String> {
+        let new_events = self.networking_system.get_events();
+
+        for event in &new_events {
+            let (event_name, details) = serialize_event(event);
+            self.print(json!({ "event": event_name, "details": details }));
+
+            match event {
+                NetworkEvent::LoginServerConnected {
+                    character_servers,
+                    login_data,
+                } => {
+                    let character_server = character_servers.first().ok_or("no character server available")?;
+
+                    self.networking_system.disconnect_from_login_server();
+                    self.networking_system
+                        .connect_to_character_server(login_data, character_server.clone());
+                    self.login_data = Some(*login_data);
+                }
+                NetworkEvent::CharacterServerConnected { .. } => {
+                    let _ = self.networking_system.request_character_list();
+                }
+                NetworkEvent::CharacterList { characters } => {
+                    let character = match &self.character_name {
+                        Some(name) => characters.iter().find(|character| &character.name == name),
+                        None => characters.first(),
+                    }
+                    .ok_or("character not found")?;
+
+                    self.player_name = Some(character.name.clone());
+                    let _ = self.networking_system.select_character(character.character_number as usize);
+                }
+                NetworkEvent::CharacterSelected { login_data, .. } => {
+                    // Unwrapping is fine since the character server is only connected after the
+                    // login.
+                    let login_server_login_data = self.login_data.unwrap();
+
+                    self.networking_system.disconnect_from_character_server();
+                    self.networking_system.connect_to_map_server(&login_server_login_data, *login_data);
+                    let _ = self.networking_system.map_loaded();
+                }
+                NetworkEvent::SetPlayerPosition(..) => self.in_game = true,
+                NetworkEvent::LoginServerConnectionFailed { message, .. }
+                | NetworkEvent::CharacterServerConnectionFailed { message, .. }
+                | NetworkEvent::CharacterSelectionFailed { message, .. } => return Err(message.to_string()),
+                NetworkEvent::LoginServerDisconnected {
+                    reason: DisconnectReason::ConnectionError,
+                } => return Err("lost connection to the login server".to_owned()),
+                NetworkEvent::CharacterServerDisconnected {
+                    reason: DisconnectReason::ConnectionError,
+                } => return Err("lost connection to the character server".to_owned()),
+                NetworkEvent::MapServerDisconnected { .. } if self.in_game => return Err("lost connection to the map server".to_owned()),
+                _ => {}
+            }
+        }
+
+        let mut events = std::mem::take(&mut self.buffered_events);
+        events.extend(new_events);
+
+        Ok(events)
+    }
+
+    fn wait_until_in_game(&mut self) -> Result<(), String> {
+        let start = Instant::now();
+
+        while !self.in_game {
+            if start.elapsed() > DEFAULT_TIMEOUT {
+                return Err("timed out while logging in".to_owned());
+            }
+
+            let events = self.poll()?;
+            self.buffered_events.extend(events);
+            sleep(POLL_INTERVAL);
+        }
+
+        Ok(())
+    }
+
+    /// Returns `false` once the script should stop.
+    fn run_command(&mut self, command: Command) -> Result<bool, String> {
+        self.print(json!({ "command": format!("{command:?}") }));
+
+        let not_connected = |_| "not connected to the map server".to_owned();
+
+        match command {
+            Command::Wait(duration) => {
+                let start = Instant::now();
+
+                while start.elapsed() < duration {
+                    self.poll()?;
+                    sleep(POLL_INTERVAL);
+                }
+            }
+            Command::Expect { event_name, timeout } => {
+                let start = Instant::now();
+
+                loop {
+                    if self.poll()?.iter().any(|event| self::event_name(event) == event_name) {
+                        break;
+                    }
+
+                    if start.elapsed() > timeout {
+                        return Err(format!("expected {event_name} but it didn't arrive in time"));
+                    }
+
+                    sleep(POLL_INTERVAL);
+                }
+            }
+            Command::Move { x, y } => self
+                .networking_system
+                .player_move(WorldPosition::new(x, y))
+                .map_err(not_connected)?,
+            Command::Chat(message) => {
+                // Unwrapping is fine since we are in game, so a character was selected.
+                let player_name = self.player_name.clone().unwrap();
+
+                self.networking_system
+                    .send_chat_message(&player_name, &message)
+                    .map_err(not_connected)?;
+            }
+            Command::Whisper { recipient_name, message } => self
+                .networking_system
+                .send_whisper(&recipient_name, &message)
+                .map_err(not_connected)?,
+            Command::Quit => return Ok(false),
+        }
+
+        Ok(true)
+    }
+}
+
+fn run(arguments: Arguments, client: &mut HeadlessClient) -> Result<(), String> {
+    let script = arguments.script.as_deref().map(parse_script).transpose()?;
+
+    client.networking_system.connect_to_login_server(
+        arguments.address,
+        arguments.packet_profile,
+        arguments.username,
+        arguments.password,
+    );
+    client.wait_until_in_game()?;
+
+    match script {
+        Some(commands) => {
+            for command in commands {
+                if !client.run_command(command)? {
+                    break;
+                }
+            }
+
+            let _ = client.networking_system.log_out();
+            Ok(())
+        }
+        // Without a script, just report events until the process is killed.
+        None => loop {
+            client.poll()?;
+            sleep(POLL_INTERVAL);
+        },
+    }
+}
+
+fn main() -> ExitCode {
+    let arguments = match Arguments::parse() {
+        Ok(arguments) => arguments,
+        Err(error) => {
+            eprintln!("{error}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut client = HeadlessClient {
+        networking_system: NetworkingSystem::spawn(),
+        start: Instant::now(),
+        character_name: arguments.character_name.clone(),
+        login_data: None,
+        player_name: None,
+        in_game: false,
+        buffered_events: Vec::new(),
+    };
+
+    match run(arguments, &mut client) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            client.print(json!({ "error": error }));
+            ExitCode::FAILURE
+        }
+    }
+}
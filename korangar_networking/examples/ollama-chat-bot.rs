@@ -5,6 +5,7 @@ use std::time::Duration;
 
 use korangar_debug::logging::Colorize;
 use korangar_networking::{DisconnectReason, NetworkEvent, NetworkingSystem};
+use ragnarok_packets::PacketProfile;
 use reqwest::StatusCode;
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -78,7 +79,7 @@ async fn main() {
     let mut message_history = MessageHistory { hash_map: HashMap::new() };
 
     // Kick of the bot by connecting to the login server.
-    networking_system.connect_to_login_server(SOCKET_ADDR, USERNAME.to_owned(), PASSWORD.to_owned());
+    networking_system.connect_to_login_server(SOCKET_ADDR, PacketProfile::default(), USERNAME.to_owned(), PASSWORD.to_owned());
 
     loop {
         for event in networking_system.get_events() {
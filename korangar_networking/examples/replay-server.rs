@@ -0,0 +1,27 @@
+use korangar_debug::logging::Colorize;
+use korangar_networking::{load_recording, ReplayAddresses, ReplayServer};
+
+#[tokio::main(flavor = "current_thread")]
+async fn main() {
+    let path = std::env::args().nth(1).expect("usage: replay-server <recording>");
+    let connections = load_recording(&path).expect("failed to load recording");
+    let packet_count: usize = connections.iter().map(|connection| connection.records.len()).sum();
+    let addresses = ReplayAddresses::default();
+
+    println!(
+        "[{}] Loaded {} packets from {}",
+        "Replay".green(),
+        packet_count.yellow(),
+        path.yellow()
+    );
+    println!(
+        "[{}] Waiting for the client on {}:{}",
+        "Replay".green(),
+        addresses.ip.yellow(),
+        addresses.login_port.yellow()
+    );
+
+    ReplayServer::new(connections, addresses).run().await.expect("replay failed");
+
+    println!("[{}] Replay finished", "Replay".green());
+}
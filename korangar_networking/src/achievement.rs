@@ -0,0 +1,59 @@
+use ragnarok_packets::{AchievementData, AchievementListPacket, AchievementUpdatePacket};
+
+/// Achievement level and experience of the player.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct AchievementSummary {
+    pub total_score: u32,
+    pub level: u16,
+    pub experience: u32,
+    pub experience_to_next_level: u32,
+}
+
+impl From<&AchievementListPacket> for AchievementSummary {
+    fn from(packet: &AchievementListPacket) -> Self {
+        Self {
+            total_score: packet.total_score,
+            level: packet.level,
+            experience: packet.acheivement_experience,
+            experience_to_next_level: packet.acheivement_experience_to_next_level,
+        }
+    }
+}
+
+impl From<&AchievementUpdatePacket> for AchievementSummary {
+    fn from(packet: &AchievementUpdatePacket) -> Self {
+        Self {
+            total_score: packet.total_score,
+            level: packet.level,
+            experience: packet.acheivement_experience,
+            experience_to_next_level: packet.acheivement_experience_to_next_level,
+        }
+    }
+}
+
+/// Progress of the player on a single achievement.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct AchievementProgress {
+    pub achievement_id: u32,
+    pub is_completed: bool,
+    /// Progress on every objective, in the order they are listed in the
+    /// achievement table of the client.
+    pub objectives: [u32; 10],
+    /// Unix timestamp of when the achievement was completed.
+    pub completion_timestamp: u32,
+    pub got_rewarded: bool,
+}
+
+impl From<AchievementData> for AchievementProgress {
+    fn from(data: AchievementData) -> Self {
+        Self {
+            achievement_id: data.acheivement_id,
+            is_completed: data.is_completed != 0,
+            objectives: data.objectives,
+            completion_timestamp: data.completion_timestamp,
+            got_rewarded: data.got_rewarded != 0,
+        }
+    }
+}
@@ -1,6 +1,9 @@
 use ragnarok_packets::*;
 
+use crate::status::EntityVisualState;
+
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct EntityData {
     pub entity_id: EntityId,
     pub movement_speed: u16,
@@ -11,6 +14,7 @@ pub struct EntityData {
     pub maximum_health_points: i32,
     pub head_direction: usize,
     pub sex: Sex,
+    pub visual_state: EntityVisualState,
 }
 
 impl EntityData {
@@ -25,6 +29,10 @@ impl EntityData {
             maximum_health_points: character_information.maximum_health_points as i32,
             head_direction: 0, // TODO: get correct rotation
             sex: character_information.sex,
+            visual_state: EntityVisualState::new(
+                character_information.body_state as u16,
+                character_information.effect_state as u32,
+            ),
         }
     }
 }
@@ -41,6 +49,7 @@ impl From<EntityAppearedPacket> for EntityData {
             maximum_health_points: packet.maximum_health_points,
             head_direction: packet.head_direction as usize,
             sex: packet.sex,
+            visual_state: EntityVisualState::new(packet.body_state, packet.effect_state),
         }
     }
 }
@@ -57,6 +66,7 @@ impl From<EntityAppeared2Packet> for EntityData {
             maximum_health_points: packet.maximum_health_points,
             head_direction: packet.head_direction as usize,
             sex: packet.sex,
+            visual_state: EntityVisualState::new(packet.body_state, packet.effect_state),
         }
     }
 }
@@ -75,6 +85,95 @@ impl From<MovingEntityAppearedPacket> for EntityData {
             maximum_health_points: packet.maximum_health_points,
             head_direction: packet.head_direction as usize,
             sex: packet.sex,
+            visual_state: EntityVisualState::new(packet.body_state, packet.effect_state),
+        }
+    }
+}
+
+/// An item lying on the ground.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct GroundItemData {
+    pub entity_id: EntityId,
+    pub item_id: ItemId,
+    pub is_identified: bool,
+    pub amount: u16,
+    pub position: TilePosition,
+}
+
+impl From<GroundItemAppearedPacket> for GroundItemData {
+    fn from(packet: GroundItemAppearedPacket) -> Self {
+        Self {
+            entity_id: packet.entity_id,
+            item_id: packet.item_id,
+            is_identified: packet.is_identified != 0,
+            amount: packet.amount,
+            position: packet.position,
         }
     }
 }
+
+impl From<GroundItemDroppedPacket> for GroundItemData {
+    fn from(packet: GroundItemDroppedPacket) -> Self {
+        Self {
+            entity_id: packet.entity_id,
+            item_id: packet.item_id,
+            is_identified: packet.is_identified != 0,
+            amount: packet.amount,
+            position: packet.position,
+        }
+    }
+}
+
+/// A chat room, displayed as a signboard above its owner.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct ChatRoomData {
+    pub chat_room_id: ChatRoomId,
+    pub owner_id: EntityId,
+    pub title: String,
+    pub limit: u16,
+    pub member_count: u16,
+    pub chat_room_type: ChatRoomType,
+}
+
+impl From<ChatRoomAppearedPacket> for ChatRoomData {
+    fn from(packet: ChatRoomAppearedPacket) -> Self {
+        Self {
+            chat_room_id: packet.chat_room_id,
+            owner_id: packet.owner_id,
+            title: packet.title,
+            limit: packet.limit,
+            member_count: packet.member_count,
+            chat_room_type: packet.chat_room_type,
+        }
+    }
+}
+
+impl From<ChatRoomChangedPacket> for ChatRoomData {
+    fn from(packet: ChatRoomChangedPacket) -> Self {
+        Self {
+            chat_room_id: packet.chat_room_id,
+            owner_id: packet.owner_id,
+            title: packet.title,
+            limit: packet.limit,
+            member_count: packet.member_count,
+            chat_room_type: packet.chat_room_type,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum StoreType {
+    Vending,
+    Buying,
+}
+
+/// A vending shop or buying store, displayed as a signboard above its owner.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct StoreData {
+    pub store_type: StoreType,
+    pub shop_name: String,
+}
@@ -1,14 +1,21 @@
+use std::time::Duration;
+
 use ragnarok_packets::*;
 
+use crate::achievement::{AchievementProgress, AchievementSummary};
 use crate::hotkey::HotkeyState;
-use crate::items::ShopItem;
+use crate::items::{BuyingStoreItem, ShopItem, TradeItem, VendingItem};
+use crate::mail::{MailItem, MailSummary};
+use crate::quest::QuestData;
+use crate::status::{EntityVisualState, StatusEffectData};
 use crate::{
-    CharacterServerLoginData, EntityData, InventoryItem, LoginServerLoginData, MessageColor, NoMetadata,
-    UnifiedCharacterSelectionFailedReason, UnifiedLoginFailedReason,
+    CharacterServerLoginData, ChatRoomData, EntityData, GroundItemData, InventoryItem, LoginServerLoginData, MessageColor, NoMetadata,
+    StoreData, StoreType, UnifiedCharacterSelectionFailedReason, UnifiedLoginFailedReason, WhisperFailedReason,
 };
 
 /// An event triggered by one of the Ragnarok Online servers.
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum NetworkEvent {
     LoginServerConnected {
         character_servers: Vec<CharacterServerInformation>,
@@ -58,11 +65,26 @@ pub enum NetworkEvent {
     MapServerDisconnected {
         reason: DisconnectReason,
     },
+    /// The connection to the map server was lost and the client will try to
+    /// log in again after `delay`.
+    MapServerReconnecting {
+        attempt: u32,
+        maximum_attempts: u32,
+        delay: Duration,
+    },
+    /// Logged back in to the map server after the connection was lost. The
+    /// login data from the old login is no longer valid.
+    MapServerReconnected {
+        login_data: LoginServerLoginData,
+    },
     /// Add an entity to the list of entities that the client is aware of.
     AddEntity(EntityData),
     /// Remove an entity from the list of entities that the client is aware of
     /// by its id.
     RemoveEntity(EntityId),
+    /// Add an item on the ground to the list of entities that the client is
+    /// aware of. Ground items are removed with [`NetworkEvent::RemoveEntity`].
+    AddGroundItem(GroundItemData),
     /// The player is pathing to a new position.
     PlayerMove(WorldPosition, WorldPosition, ClientTick),
     /// An Entity nearby is pathing to a new position.
@@ -78,6 +100,15 @@ pub enum NetworkEvent {
         text: String,
         color: MessageColor,
     },
+    /// Private message sent to the player by another player.
+    Whisper {
+        sender: String,
+        text: String,
+    },
+    /// The last private message sent by the player could not be delivered.
+    WhisperFailed {
+        reason: WhisperFailedReason,
+    },
     CharacterSlotSwitched,
     CharacterSlotSwitchFailed,
     /// Update entity details. Mostly received when the client sends
@@ -100,6 +131,13 @@ pub enum NetworkEvent {
         item: InventoryItem<NoMetadata>,
     },
     SkillTree(Vec<SkillInformation>),
+    /// A single skill of the skill tree changed, usually after spending a skill
+    /// point on it.
+    SkillUpdated {
+        skill_id: SkillId,
+        skill_level: SkillLevel,
+        upgradable: bool,
+    },
     UpdateEquippedPosition {
         index: InventoryIndex,
         equipped_position: EquipPosition,
@@ -142,11 +180,425 @@ pub enum NetworkEvent {
     SellingCompleted {
         result: SellItemsResult,
     },
+    /// An item was used by an entity in the area.
+    ItemUsed {
+        index: InventoryIndex,
+        entity_id: EntityId,
+        remaining_amount: u16,
+        success: bool,
+    },
     InventoryItemRemoved {
         reason: RemoveItemReason,
         index: InventoryIndex,
         amount: u16,
     },
+    /// Another player invited the player to join their party.
+    PartyInvite {
+        party_id: PartyId,
+        party_name: String,
+    },
+    /// The player joined a party or the party changed. Replaces all known
+    /// party members.
+    SetPartyInformation {
+        party_name: String,
+        members: Vec<PartyMember>,
+    },
+    /// A party member joined the party or the information about them changed.
+    PartyMemberUpdated {
+        member: PartyMember,
+    },
+    /// A party member left or was kicked from the party. If the account id is
+    /// the one of the player, the player is no longer in a party.
+    PartyMemberRemoved {
+        account_id: AccountId,
+    },
+    PartyLeaderChanged {
+        old_leader_account_id: AccountId,
+        new_leader_account_id: AccountId,
+    },
+    UpdatePartyMemberPosition {
+        account_id: AccountId,
+        position: TilePosition,
+    },
+    UpdatePartyMemberHealth {
+        account_id: AccountId,
+        health_points: u32,
+        maximum_health_points: u32,
+    },
+    /// The player is a member of a guild.
+    SetGuild {
+        guild_id: GuildId,
+        emblem_id: u32,
+        guild_name: String,
+    },
+    SetGuildInformation {
+        information: GuildInformation,
+    },
+    SetGuildRelations {
+        relations: Vec<GuildRelation>,
+    },
+    GuildRelationRemoved {
+        guild_id: GuildId,
+        relation_type: GuildRelationType,
+    },
+    /// Replaces all known guild members. The names of the members need to be
+    /// requested separately and arrive as [`NetworkEvent::CharacterName`].
+    SetGuildMembers {
+        members: Vec<GuildMember>,
+    },
+    UpdateGuildMemberState {
+        account_id: AccountId,
+        character_id: CharacterId,
+        state: GuildMemberState,
+    },
+    SetGuildPositionNames {
+        positions: Vec<GuildPositionName>,
+    },
+    SetGuildPositions {
+        positions: Vec<GuildPosition>,
+    },
+    GuildNotice {
+        subject: String,
+        notice: String,
+    },
+    /// Compressed emblem data of a guild. The data can be decoded with
+    /// the texture loader.
+    GuildEmblem {
+        guild_id: GuildId,
+        emblem_id: u32,
+        emblem_data: Vec<u8>,
+    },
+    GuildEmblemChanged {
+        guild_id: GuildId,
+        emblem_id: u32,
+    },
+    CharacterName {
+        character_id: CharacterId,
+        name: String,
+    },
+    /// The storage was opened and these are all the items in it.
+    SetStorage {
+        items: Vec<InventoryItem<NoMetadata, StorageIndex>>,
+    },
+    StorageItemAdded {
+        item: InventoryItem<NoMetadata, StorageIndex>,
+    },
+    StorageItemRemoved {
+        index: StorageIndex,
+        amount: u16,
+    },
+    StorageClosed,
+    /// The cart was loaded and these are all the items in it.
+    SetCart {
+        items: Vec<InventoryItem<NoMetadata>>,
+    },
+    /// Another player wants to trade with the player.
+    TradeRequested {
+        name: String,
+        character_id: CharacterId,
+        base_level: u16,
+    },
+    /// The trade request was accepted by either side and the trade window
+    /// should be opened.
+    TradeStarted {
+        character_id: CharacterId,
+        base_level: u16,
+    },
+    TradeRequestFailed {
+        result: TradeResponse,
+    },
+    /// The trade partner added an item to the trade.
+    TradeItemAdded {
+        item: TradeItem<NoMetadata>,
+    },
+    /// The trade partner added zeny to the trade.
+    TradeZenyAdded {
+        amount: u32,
+    },
+    /// Response to adding an item to the trade. An index of `None` means that
+    /// zeny were added.
+    AddTradeItemResult {
+        index: Option<InventoryIndex>,
+        result: AddTradeItemResult,
+    },
+    TradeLocked {
+        party: TradeParty,
+    },
+    TradeCancelled,
+    TradeCompleted {
+        result: TradeResult,
+    },
+    /// The chat room created by the player was opened. The chat room
+    /// id is only known once the chat room appears through
+    /// [`NetworkEvent::ChatRoomUpdated`].
+    ChatRoomCreated,
+    ChatRoomCreationFailed {
+        result: CreateChatRoomResult,
+    },
+    /// A chat room appeared on screen or its settings changed.
+    ChatRoomUpdated {
+        chat_room: ChatRoomData,
+    },
+    ChatRoomDestroyed {
+        chat_room_id: ChatRoomId,
+    },
+    /// The player entered a chat room. Messages sent while in a chat room
+    /// only reach its members and arrive as regular chat messages.
+    ChatRoomEntered {
+        chat_room_id: ChatRoomId,
+        members: Vec<ChatRoomMember>,
+    },
+    EnterChatRoomFailed {
+        reason: EnterChatRoomFailedReason,
+    },
+    ChatRoomMemberEntered {
+        name: String,
+        member_count: u16,
+    },
+    /// A member left the chat room. This is also sent when the player left.
+    ChatRoomMemberExited {
+        name: String,
+        member_count: u16,
+        reason: ChatRoomExitReason,
+    },
+    ChatRoomRoleChanged {
+        name: String,
+        role: ChatRoomRole,
+    },
+    /// The player used the vending skill and may now open a vending shop with
+    /// up to `slot_count` items from their cart.
+    VendingShopSetup {
+        slot_count: u16,
+    },
+    /// The vending shop of the player was opened with these items.
+    VendingShopOpened {
+        items: Vec<VendingItem<NoMetadata>>,
+    },
+    VendingShopOpenFailed,
+    /// Another player bought an item from the vending shop of the player.
+    VendingItemSold {
+        index: InventoryIndex,
+        amount: u16,
+        zeny: u32,
+    },
+    /// A vending shop or buying store appeared, either because it was opened
+    /// or because its owner came into view.
+    StoreAppeared {
+        entity_id: EntityId,
+        store: StoreData,
+    },
+    StoreDisappeared {
+        entity_id: EntityId,
+        store_type: StoreType,
+    },
+    /// The items of a vending shop that the player is browsing.
+    VendingItemList {
+        entity_id: EntityId,
+        vendor_id: VendorId,
+        items: Vec<VendingItem<NoMetadata>>,
+    },
+    VendingPurchaseFailed {
+        index: InventoryIndex,
+        amount: u16,
+        result: VendingPurchaseResult,
+    },
+    /// The player used the buying store skill and may now open a buying store
+    /// looking for up to `slot_count` different items.
+    BuyingStoreSetup {
+        slot_count: u8,
+    },
+    /// The buying store of the player was opened with these items.
+    BuyingStoreOpened {
+        zeny_limit: u32,
+        items: Vec<BuyingStoreItem<NoMetadata>>,
+    },
+    BuyingStoreOpenFailed {
+        result: OpenBuyingStoreFailure,
+        weight: u32,
+    },
+    /// The buying store of the player was closed by the server.
+    BuyingStoreClosed {
+        reason: BuyingStoreClosedReason,
+    },
+    /// Another player sold an item to the buying store of the player.
+    BuyingStoreItemBought {
+        item_id: ItemId,
+        amount: u16,
+        zeny_limit: u32,
+    },
+    /// The items that a buying store that the player is browsing is looking
+    /// for.
+    BuyingStoreItemList {
+        entity_id: EntityId,
+        store_id: BuyingStoreId,
+        zeny_limit: u32,
+        items: Vec<BuyingStoreItem<NoMetadata>>,
+    },
+    /// The player sold an item to a buying store.
+    BuyingStoreItemSold {
+        index: InventoryIndex,
+        amount: u16,
+        zeny: u32,
+    },
+    SellToBuyingStoreFailed {
+        result: SellToBuyingStoreFailure,
+        item_id: ItemId,
+    },
+    /// The player has unread mail.
+    NewMail,
+    /// A page of the mailbox, newest mail first.
+    MailList {
+        mails: Vec<MailSummary>,
+        is_last_page: bool,
+    },
+    MailContents {
+        mail_id: MailId,
+        open_type: MailOpenType,
+        text: String,
+        zeny: u64,
+        items: Vec<MailItem<NoMetadata>>,
+    },
+    MailDeleted {
+        mail_id: MailId,
+    },
+    MailZenyRetrieved {
+        mail_id: MailId,
+    },
+    RetrieveMailZenyFailed {
+        result: RetrieveMailZenyResult,
+    },
+    MailItemsRetrieved {
+        mail_id: MailId,
+    },
+    RetrieveMailItemsFailed {
+        result: RetrieveMailItemsResult,
+    },
+    /// An item from the inventory was attached to the mail that the player is
+    /// writing.
+    MailItemAttached {
+        index: InventoryIndex,
+        item: MailItem<NoMetadata>,
+    },
+    AttachMailItemFailed {
+        result: AddMailItemResult,
+    },
+    /// An item was removed from the mail that the player is writing.
+    /// `remaining_amount` is the amount that is still attached.
+    MailItemDetached {
+        index: InventoryIndex,
+        remaining_amount: u16,
+    },
+    /// Response to checking the receiver of a mail. The character id is `None`
+    /// if there is no character with that name.
+    MailReceiverChecked {
+        name: String,
+        character_id: Option<CharacterId>,
+    },
+    MailSent,
+    SendMailFailed {
+        result: SendMailResult,
+    },
+    StatusEffectStarted {
+        entity_id: EntityId,
+        status_effect: StatusEffectData,
+    },
+    StatusEffectEnded {
+        entity_id: EntityId,
+        status_id: u16,
+    },
+    /// An entity was stone cursed, frozen, hidden or similar.
+    VisualStateChanged {
+        entity_id: EntityId,
+        visual_state: EntityVisualState,
+    },
+    /// The type of a cell changed, for example because an ice wall was
+    /// created on it.
+    ChangeMapCell {
+        map_name: String,
+        position: TilePosition,
+        cell_type: u16,
+    },
+    /// The server marked a position on the minimap, usually on behalf of an
+    /// NPC.
+    MinimapMarkerAdded {
+        npc_id: EntityId,
+        marker_id: u8,
+        position: LargeTilePosition,
+        color: ColorRGBA,
+        /// How long the marker is shown, `None` if it stays until the player
+        /// leaves the map.
+        duration: Option<Duration>,
+    },
+    MinimapMarkerRemoved {
+        npc_id: EntityId,
+        marker_id: u8,
+    },
+    /// The server points the player towards a position, for example where a
+    /// monster can be found.
+    NavigationHint {
+        map_name: String,
+        position: TilePosition,
+    },
+    /// An NPC shows an illustration (cut-in) on the screen.
+    DisplayCutIn {
+        image_name: String,
+        location: CutInLocation,
+    },
+    /// An NPC removed its illustration from the screen.
+    ClearCutIn,
+    /// An entity started casting a skill.
+    SkillCasting {
+        entity_id: EntityId,
+        skill_id: SkillId,
+        /// Cast time in milliseconds.
+        cast_time: u32,
+    },
+    /// An entity stopped casting a skill before the cast time was over.
+    SkillCastCancelled {
+        entity_id: EntityId,
+    },
+    /// The player failed to use a skill, so any ongoing cast is cancelled.
+    SkillFailed {
+        skill_id: SkillId,
+    },
+    /// A skill of the player can't be used again for some time.
+    SkillCooldown {
+        skill_id: SkillId,
+        /// Cooldown in milliseconds.
+        duration: u32,
+    },
+    /// An entity used an emotion.
+    Emotion {
+        entity_id: EntityId,
+        emotion: u8,
+    },
+    /// All quests in the quest log of the player, sent when logging in.
+    QuestList {
+        quests: Vec<QuestData>,
+    },
+    QuestAdded {
+        quest: QuestData,
+    },
+    /// The progress of hunting objectives changed.
+    QuestObjectivesUpdated {
+        objectives: Vec<HuntingObjective>,
+    },
+    QuestRemoved {
+        quest_id: u32,
+    },
+    /// All achievements the player made progress on, sent when logging in.
+    AchievementList {
+        summary: AchievementSummary,
+        achievements: Vec<AchievementProgress>,
+    },
+    AchievementUpdated {
+        summary: AchievementSummary,
+        achievement: AchievementProgress,
+    },
+    /// Reputation of the player with every faction.
+    ReputationList {
+        entries: Vec<ReputationEntry>,
+    },
 }
 
 /// New-type so we can implement some `From` traits. This will help when
@@ -189,7 +641,22 @@ impl From<NoNetworkEvents> for NetworkEventList {
     }
 }
 
+/// Where an NPC illustration is shown. Unlike [`ImageLocation`], this can't
+/// clear the illustration, since that is a separate event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum CutInLocation {
+    BottomLeft,
+    BottomMiddle,
+    BottomRight,
+    MiddleFloating,
+    /// Same as [`CutInLocation::MiddleFloating`] but the illustration is shown
+    /// in grayscale.
+    MiddleColorless,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum DisconnectReason {
     ClosedByClient,
     ConnectionError,
@@ -1,6 +1,7 @@
 use ragnarok_packets::HotkeyData;
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum HotkeyState {
     Bound(HotkeyData),
     Unbound,
@@ -1,9 +1,14 @@
-use ragnarok_packets::{EquipPosition, EquippableItemFlags, InventoryIndex, ItemId, ItemOptions, Price, RegularItemFlags};
+use ragnarok_packets::{
+    BuyingStoreItemListInformation, EquipPosition, EquippableItemFlags, InventoryIndex, ItemId, ItemOptions, OwnVendingItemInformation,
+    Price, RegularItemFlags, VendingItemInformation,
+};
 
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct NoMetadata;
 
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum InventoryItemDetails {
     Regular {
         amount: u16,
@@ -23,10 +28,14 @@ pub enum InventoryItemDetails {
     },
 }
 
+/// Item in the inventory, the cart or the storage. Storage items use a
+/// [`StorageIndex`](ragnarok_packets::StorageIndex) since their index has a
+/// different offset on the wire.
 #[derive(Clone, Debug)]
-pub struct InventoryItem<Meta> {
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct InventoryItem<Meta, Index = InventoryIndex> {
     pub metadata: Meta,
-    pub index: InventoryIndex,
+    pub index: Index,
     pub item_id: ItemId,
     pub item_type: u8,
     pub slot: [u32; 4], // card ?
@@ -34,7 +43,19 @@ pub struct InventoryItem<Meta> {
     pub details: InventoryItemDetails,
 }
 
-impl<Meta> InventoryItem<Meta> {
+impl<Meta, Index> InventoryItem<Meta, Index> {
+    pub fn with_index<NewIndex>(self, index: NewIndex) -> InventoryItem<Meta, NewIndex> {
+        InventoryItem {
+            metadata: self.metadata,
+            index,
+            item_id: self.item_id,
+            item_type: self.item_type,
+            slot: self.slot,
+            hire_expiration_date: self.hire_expiration_date,
+            details: self.details,
+        }
+    }
+
     pub fn is_identifed(&self) -> bool {
         match &self.details {
             InventoryItemDetails::Regular { flags, .. } => flags.contains(RegularItemFlags::IDENTIFIED),
@@ -44,6 +65,7 @@ impl<Meta> InventoryItem<Meta> {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum ItemQuantity {
     Fixed(u32),
     Infinite,
@@ -59,6 +81,7 @@ impl From<u32> for ItemQuantity {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct ShopItem<Meta> {
     pub metadata: Meta,
     pub item_id: ItemId,
@@ -76,3 +99,86 @@ pub struct SellItem<Meta> {
     pub price: Price,
     pub overcharge_price: Price,
 }
+
+/// Item in a vending shop.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct VendingItem<Meta> {
+    pub metadata: Meta,
+    /// Index of the item in the cart of the vendor.
+    pub index: InventoryIndex,
+    pub item_id: ItemId,
+    pub item_type: u8,
+    pub price: Price,
+    pub amount: u16,
+    pub is_identified: bool,
+    pub refinement_level: u8,
+    pub slot: [u32; 4], // card ?
+}
+
+impl From<VendingItemInformation> for VendingItem<NoMetadata> {
+    fn from(item: VendingItemInformation) -> Self {
+        Self {
+            metadata: NoMetadata,
+            index: item.index,
+            item_id: item.item_id,
+            item_type: item.item_type,
+            price: item.price,
+            amount: item.amount,
+            is_identified: item.identified != 0,
+            refinement_level: item.refinement_level,
+            slot: item.slot,
+        }
+    }
+}
+
+impl From<OwnVendingItemInformation> for VendingItem<NoMetadata> {
+    fn from(item: OwnVendingItemInformation) -> Self {
+        Self {
+            metadata: NoMetadata,
+            index: item.index,
+            item_id: item.item_id,
+            item_type: item.item_type,
+            price: item.price,
+            amount: item.amount,
+            is_identified: item.identified != 0,
+            refinement_level: item.refinement_level,
+            slot: item.slot,
+        }
+    }
+}
+
+/// Item that a buying store is looking for.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct BuyingStoreItem<Meta> {
+    pub metadata: Meta,
+    pub item_id: ItemId,
+    pub item_type: u8,
+    pub price: Price,
+    pub amount: u16,
+}
+
+impl From<BuyingStoreItemListInformation> for BuyingStoreItem<NoMetadata> {
+    fn from(item: BuyingStoreItemListInformation) -> Self {
+        Self {
+            metadata: NoMetadata,
+            item_id: item.item_id,
+            item_type: item.item_type,
+            price: item.price,
+            amount: item.amount,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct TradeItem<Meta> {
+    pub metadata: Meta,
+    pub item_id: ItemId,
+    pub item_type: u8,
+    pub amount: u32,
+    pub is_identified: bool,
+    pub refinement_level: u8,
+    pub slot: [u32; 4],
+}
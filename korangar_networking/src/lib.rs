@@ -1,22 +1,30 @@
+mod achievement;
 mod entity;
 mod event;
 mod hotkey;
 mod items;
+mod mail;
 mod message;
+mod quest;
+mod reconnect;
+mod recording;
+mod replay;
 mod server;
+mod status;
 
 use std::cell::RefCell;
 use std::net::{IpAddr, SocketAddr};
 use std::rc::Rc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use event::{
     CharacterServerDisconnectedEvent, DisconnectedEvent, LoginServerDisconnectedEvent, MapServerDisconnectedEvent, NetworkEventList,
     NoNetworkEvents,
 };
 use ragnarok_bytes::{ByteStream, FromBytes};
-use ragnarok_packets::handler::{DuplicateHandlerError, HandlerResult, NoPacketCallback, PacketCallback, PacketHandler};
+use ragnarok_packets::handler::{DuplicateHandlerError, HandlerResult, NoPacketCallback, PacketCallback, PacketHandler, ServerType};
 use ragnarok_packets::*;
+use reconnect::{LoginCredentials, MapServerSession, ReconnectStage, ReconnectState};
 use server::{ServerConnectCommand, ServerConnection};
 use tokio::io::{AsyncReadExt, AsyncWriteExt};
 use tokio::net::TcpStream;
@@ -24,14 +32,23 @@ use tokio::sync::mpsc::error::TryRecvError;
 use tokio::sync::mpsc::{UnboundedReceiver, UnboundedSender};
 use tokio::task::JoinHandle;
 
-pub use self::entity::EntityData;
-pub use self::event::{DisconnectReason, NetworkEvent};
+pub use self::achievement::{AchievementProgress, AchievementSummary};
+pub use self::entity::{ChatRoomData, EntityData, GroundItemData, StoreData, StoreType};
+pub use self::event::{CutInLocation, DisconnectReason, NetworkEvent};
 pub use self::hotkey::HotkeyState;
-pub use self::items::{InventoryItem, InventoryItemDetails, ItemQuantity, NoMetadata, SellItem, ShopItem};
-pub use self::message::MessageColor;
+pub use self::items::{
+    BuyingStoreItem, InventoryItem, InventoryItemDetails, ItemQuantity, NoMetadata, SellItem, ShopItem, TradeItem, VendingItem,
+};
+pub use self::mail::{MailItem, MailSummary};
+pub use self::message::{MessageColor, WhisperFailedReason};
+pub use self::quest::{QuestData, QuestObjectiveData};
+pub use self::reconnect::{InvalidReconnectPolicyError, ReconnectPolicy};
+pub use self::recording::{load_recording, read_recording, PacketDirection, PacketRecord, PacketRecorder, RecordedConnection};
+pub use self::replay::{ReplayAddresses, ReplayServer};
 pub use self::server::{
     CharacterServerLoginData, LoginServerLoginData, NotConnectedError, UnifiedCharacterSelectionFailedReason, UnifiedLoginFailedReason,
 };
+pub use self::status::{BodyState, EntityVisualState, StatusEffectData};
 use crate::server::NetworkTaskError;
 
 pub struct NetworkingSystem<Callback> {
@@ -39,7 +56,16 @@ pub struct NetworkingSystem<Callback> {
     login_server_connection: ServerConnection,
     character_server_connection: ServerConnection,
     map_server_connection: ServerConnection,
-    packet_callback: Callback,
+    login_credentials: Option<LoginCredentials>,
+    character_server: Option<CharacterServerInformation>,
+    character_slot: Option<usize>,
+    map_server_session: Option<MapServerSession>,
+    reconnect_policy: ReconnectPolicy,
+    reconnect_state: Option<ReconnectState>,
+    packet_profile: PacketProfile,
+    login_server_callback: Callback,
+    character_server_callback: Callback,
+    map_server_callback: Callback,
 }
 
 impl NetworkingSystem<NoPacketCallback> {
@@ -60,7 +86,16 @@ where
             login_server_connection: ServerConnection::Disconnected,
             character_server_connection: ServerConnection::Disconnected,
             map_server_connection: ServerConnection::Disconnected,
-            packet_callback,
+            login_credentials: None,
+            character_server: None,
+            character_slot: None,
+            map_server_session: None,
+            reconnect_policy: ReconnectPolicy::default(),
+            reconnect_state: None,
+            packet_profile: PacketProfile::default(),
+            login_server_callback: packet_callback.for_server(ServerType::Login),
+            character_server_callback: packet_callback.for_server(ServerType::Character),
+            map_server_callback: packet_callback.for_server(ServerType::Map),
         }
     }
 
@@ -88,6 +123,7 @@ where
                     match command {
                         ServerConnectCommand::Login {
                             address,
+                            packet_profile,
                             action_receiver,
                             event_sender,
                         } => {
@@ -96,7 +132,9 @@ where
                                 let _ = handle.await.unwrap();
                             }
 
-                            let packet_handler = Self::create_login_server_packet_handler(packet_callback.clone()).unwrap();
+                            let packet_handler =
+                                Self::create_login_server_packet_handler(packet_callback.for_server(ServerType::Login), packet_profile)
+                                    .unwrap();
                             let handle = local_set.spawn_local(Self::handle_server_connection(
                                 address,
                                 action_receiver,
@@ -111,6 +149,7 @@ where
                         }
                         ServerConnectCommand::Character {
                             address,
+                            packet_profile,
                             action_receiver,
                             event_sender,
                         } => {
@@ -119,7 +158,11 @@ where
                                 let _ = handle.await.unwrap();
                             }
 
-                            let packet_handler = Self::create_character_server_packet_handler(packet_callback.clone()).unwrap();
+                            let packet_handler = Self::create_character_server_packet_handler(
+                                packet_callback.for_server(ServerType::Character),
+                                packet_profile,
+                            )
+                            .unwrap();
                             let handle = local_set.spawn_local(Self::handle_server_connection(
                                 address,
                                 action_receiver,
@@ -134,6 +177,7 @@ where
                         }
                         ServerConnectCommand::Map {
                             address,
+                            packet_profile,
                             action_receiver,
                             event_sender,
                         } => {
@@ -142,7 +186,9 @@ where
                                 let _ = handle.await.unwrap();
                             }
 
-                            let packet_handler = Self::create_map_server_packet_handler(packet_callback.clone()).unwrap();
+                            let packet_handler =
+                                Self::create_map_server_packet_handler(packet_callback.for_server(ServerType::Map), packet_profile)
+                                    .unwrap();
                             let handle = local_set.spawn_local(Self::handle_server_connection(
                                 address,
                                 action_receiver,
@@ -205,16 +251,179 @@ where
 
         Self::handle_connection::<LoginServerDisconnectedEvent>(&mut self.login_server_connection, &mut events);
         Self::handle_connection::<CharacterServerDisconnectedEvent>(&mut self.character_server_connection, &mut events);
+
+        let map_server_events_start = events.len();
         Self::handle_connection::<MapServerDisconnectedEvent>(&mut self.map_server_connection, &mut events);
+        self.handle_map_server_reconnect(&mut events, map_server_events_start);
 
         events
     }
 
+    pub fn set_reconnect_policy(&mut self, reconnect_policy: ReconnectPolicy) {
+        self.reconnect_policy = reconnect_policy;
+    }
+
+    /// Replaces a lost map server connection with reconnect attempts as long as
+    /// the [`ReconnectPolicy`] allows it. Only once all attempts failed is the
+    /// [`NetworkEvent::MapServerDisconnected`] event passed on.
+    ///
+    /// Every attempt logs in to the login and character servers again to get
+    /// fresh login ids, so their events are consumed here while reconnecting.
+    fn handle_map_server_reconnect(&mut self, events: &mut Vec<NetworkEvent>, map_server_events_start: usize) {
+        let connection_lost = events[map_server_events_start..].iter().position(|event| {
+            matches!(event, NetworkEvent::MapServerDisconnected {
+                reason: DisconnectReason::ConnectionError
+            })
+        });
+
+        if let Some(offset) = connection_lost {
+            if self.map_server_session.is_some() {
+                events[map_server_events_start + offset] = self.schedule_reconnect_attempt();
+            }
+
+            return;
+        }
+
+        let Some(mut state) = self.reconnect_state.take() else {
+            return;
+        };
+        let now = Instant::now();
+
+        if let ReconnectStage::Waiting { attempt_at } = state.stage {
+            if now >= attempt_at {
+                // Unwrapping is fine since we never start reconnecting without a session.
+                let credentials = self.map_server_session.as_ref().unwrap().credentials.clone();
+
+                state.stage = ReconnectStage::LoggingIn;
+                state.deadline = Some(now + self.reconnect_policy.attempt_timeout);
+                self.connect_to_login_server(
+                    credentials.address,
+                    self.packet_profile.clone(),
+                    credentials.username,
+                    credentials.password,
+                );
+            }
+
+            self.reconnect_state = Some(state);
+            return;
+        }
+
+        if state.deadline.is_some_and(|deadline| now >= deadline) {
+            self.close_reconnect_connections();
+            events.push(self.schedule_reconnect_attempt_after(state.attempt));
+            return;
+        }
+
+        let session = self.map_server_session.clone().unwrap();
+        let mut attempt_failed = false;
+
+        // The client is not connected to the login or character server while in
+        // game, so all of their events belong to this attempt.
+        for event in events.drain(..map_server_events_start).collect::<Vec<_>>() {
+            match (event, state.stage) {
+                (NetworkEvent::LoginServerConnected { login_data, .. }, ReconnectStage::LoggingIn) => {
+                    self.login_server_connection = ServerConnection::Disconnected;
+                    self.connect_to_character_server(&login_data, session.character_server.clone());
+                    state.stage = ReconnectStage::SelectingCharacter { login_data };
+                }
+                (NetworkEvent::CharacterServerConnected { .. }, ReconnectStage::SelectingCharacter { .. }) => {
+                    let _ = self.request_character_list();
+                }
+                (NetworkEvent::CharacterList { .. }, ReconnectStage::SelectingCharacter { .. }) => {
+                    let _ = self.select_character(session.character_slot);
+                }
+                (
+                    NetworkEvent::CharacterSelected {
+                        login_data: character_server_login_data,
+                        ..
+                    },
+                    ReconnectStage::SelectingCharacter { login_data },
+                ) => {
+                    self.character_server_connection = ServerConnection::Disconnected;
+                    self.connect_to_map_server(&login_data, character_server_login_data);
+                    state.stage = ReconnectStage::EnteringMap { login_data };
+                }
+                (
+                    NetworkEvent::LoginServerConnectionFailed { .. }
+                    | NetworkEvent::LoginServerDisconnected { .. }
+                    | NetworkEvent::CharacterServerConnectionFailed { .. }
+                    | NetworkEvent::CharacterSelectionFailed { .. }
+                    | NetworkEvent::CharacterServerDisconnected { .. },
+                    _,
+                ) => attempt_failed = true,
+                _ => {}
+            }
+        }
+
+        if attempt_failed {
+            self.close_reconnect_connections();
+            events.push(self.schedule_reconnect_attempt_after(state.attempt));
+            return;
+        }
+
+        if let ReconnectStage::EnteringMap { login_data } = state.stage {
+            // The map server sends the player position once the login succeeded.
+            let logged_in = events.iter().any(|event| matches!(event, NetworkEvent::SetPlayerPosition(..)));
+
+            if logged_in {
+                events.insert(0, NetworkEvent::MapServerReconnected { login_data });
+                return;
+            }
+        }
+
+        self.reconnect_state = Some(state);
+    }
+
+    fn schedule_reconnect_attempt(&mut self) -> NetworkEvent {
+        let attempt = self.reconnect_state.take().map_or(0, |state| state.attempt);
+
+        self.schedule_reconnect_attempt_after(attempt)
+    }
+
+    /// Schedule the attempt after the given one, or give up if the
+    /// [`ReconnectPolicy`] doesn't allow any more attempts.
+    fn schedule_reconnect_attempt_after(&mut self, previous_attempt: u32) -> NetworkEvent {
+        let attempt = previous_attempt + 1;
+
+        if attempt > self.reconnect_policy.maximum_attempts {
+            self.map_server_session = None;
+            self.reconnect_state = None;
+
+            return NetworkEvent::MapServerDisconnected {
+                reason: DisconnectReason::ConnectionError,
+            };
+        }
+
+        let delay = self.reconnect_policy.delay_for_attempt(attempt);
+
+        self.reconnect_state = Some(ReconnectState {
+            attempt,
+            stage: ReconnectStage::Waiting {
+                attempt_at: Instant::now() + delay,
+            },
+            deadline: None,
+        });
+
+        NetworkEvent::MapServerReconnecting {
+            attempt,
+            maximum_attempts: self.reconnect_policy.maximum_attempts,
+            delay,
+        }
+    }
+
+    /// Drop the connections of an attempt without emitting any disconnect
+    /// events.
+    fn close_reconnect_connections(&mut self) {
+        self.login_server_connection = ServerConnection::Disconnected;
+        self.character_server_connection = ServerConnection::Disconnected;
+        self.map_server_connection = ServerConnection::Disconnected;
+    }
+
     async fn handle_server_connection<PingPacket>(
         address: SocketAddr,
         mut action_receiver: UnboundedReceiver<Vec<u8>>,
         event_sender: UnboundedSender<NetworkEvent>,
-        mut packet_handler: PacketHandler<NetworkEventList, (), Callback>,
+        mut packet_handler: PacketHandler<NetworkEventList, PacketVersion, Callback>,
         ping_factory: impl Fn() -> PingPacket,
         ping_frequency: Duration,
         // After logging in to the character server, it sends the account id without any packet.
@@ -256,7 +465,7 @@ where
                     }
 
                     let data = &buffer[..cut_off_buffer_base + received_bytes];
-                    let mut byte_stream = ByteStream::without_metadata(data);
+                    let mut byte_stream = ByteStream::with_metadata(data, packet_handler.profile().version());
                     let mut events = Vec::new();
 
                     if read_account_id {
@@ -303,36 +512,58 @@ where
                 }
                 // Send a keep-alive packet to the server.
                 _ = interval.tick() => {
-                    let packet_bytes = ping_factory().packet_to_bytes().unwrap();
+                    let packet_bytes = ping_factory().packet_to_bytes_with_profile(packet_handler.profile()).unwrap();
                     stream.write_all(&packet_bytes).await.map_err(|_| NetworkTaskError::ConnectionClosed)?;
                 }
             }
         }
     }
 
-    pub fn connect_to_login_server(&mut self, address: SocketAddr, username: impl Into<String>, password: impl Into<String>) {
+    /// Connect to a login server. The [`PacketProfile`] is used for this
+    /// connection and all character and map server connections that follow.
+    pub fn connect_to_login_server(
+        &mut self,
+        address: SocketAddr,
+        packet_profile: PacketProfile,
+        username: impl Into<String>,
+        password: impl Into<String>,
+    ) {
         if !matches!(self.login_server_connection, ServerConnection::Disconnected) {
             return;
         }
 
+        let username = username.into();
+        let password = password.into();
+
+        self.packet_profile = packet_profile;
+        self.login_credentials = Some(LoginCredentials {
+            address,
+            username: username.clone(),
+            password: password.clone(),
+        });
+
         let (action_sender, action_receiver) = tokio::sync::mpsc::unbounded_channel();
         let (event_sender, event_receiver) = tokio::sync::mpsc::unbounded_channel();
 
+        self.login_server_callback.connected(&self.packet_profile);
+
         self.command_sender
             .send(ServerConnectCommand::Login {
                 address,
+                packet_profile: self.packet_profile.clone(),
                 action_receiver,
                 event_sender,
             })
             .expect("network thread dropped");
 
-        let login_packet = LoginServerLoginPacket::new(username.into(), password.into());
+        let login_packet = LoginServerLoginPacket::new(username, password);
 
-        self.packet_callback.outgoing_packet(&login_packet);
+        let login_bytes = login_packet.packet_to_bytes_with_profile(&self.packet_profile).unwrap();
 
-        action_sender
-            .send(login_packet.packet_to_bytes().unwrap())
-            .expect("action receiver instantly dropped");
+        self.login_server_callback.outgoing_packet(&login_packet);
+        self.login_server_callback.outgoing_bytes(&login_bytes);
+
+        action_sender.send(login_bytes).expect("action receiver instantly dropped");
 
         self.login_server_connection = ServerConnection::Connected {
             action_sender,
@@ -349,10 +580,14 @@ where
         let (event_sender, event_receiver) = tokio::sync::mpsc::unbounded_channel();
 
         let address = SocketAddr::new(IpAddr::V4(server.server_ip.into()), server.server_port);
+        self.character_server = Some(server);
+
+        self.character_server_callback.connected(&self.packet_profile);
 
         self.command_sender
             .send(ServerConnectCommand::Character {
                 address,
+                packet_profile: self.packet_profile.clone(),
                 action_receiver,
                 event_sender,
             })
@@ -365,11 +600,12 @@ where
             login_data.sex,
         );
 
-        self.packet_callback.outgoing_packet(&login_packet);
+        let login_bytes = login_packet.packet_to_bytes_with_profile(&self.packet_profile).unwrap();
 
-        action_sender
-            .send(login_packet.packet_to_bytes().unwrap())
-            .expect("action receiver instantly dropped");
+        self.character_server_callback.outgoing_packet(&login_packet);
+        self.character_server_callback.outgoing_bytes(&login_bytes);
+
+        action_sender.send(login_bytes).expect("action receiver instantly dropped");
 
         self.character_server_connection = ServerConnection::Connected {
             action_sender,
@@ -391,9 +627,21 @@ where
 
         let address = SocketAddr::new(character_server_login_data.server_ip, character_server_login_data.server_port);
 
+        self.map_server_session = match (&self.login_credentials, &self.character_server, self.character_slot) {
+            (Some(credentials), Some(character_server), Some(character_slot)) => Some(MapServerSession {
+                credentials: credentials.clone(),
+                character_server: character_server.clone(),
+                character_slot,
+            }),
+            _ => None,
+        };
+
+        self.map_server_callback.connected(&self.packet_profile);
+
         self.command_sender
             .send(ServerConnectCommand::Map {
                 address,
+                packet_profile: self.packet_profile.clone(),
                 action_receiver,
                 event_sender,
             })
@@ -409,11 +657,12 @@ where
             login_server_login_data.sex,
         );
 
-        self.packet_callback.outgoing_packet(&login_packet);
+        let login_bytes = login_packet.packet_to_bytes_with_profile(&self.packet_profile).unwrap();
+
+        self.map_server_callback.outgoing_packet(&login_packet);
+        self.map_server_callback.outgoing_bytes(&login_bytes);
 
-        action_sender
-            .send(login_packet.packet_to_bytes().unwrap())
-            .expect("action receiver instantly dropped");
+        action_sender.send(login_bytes).expect("action receiver instantly dropped");
 
         self.map_server_connection = ServerConnection::Connected {
             action_sender,
@@ -431,15 +680,30 @@ where
 
     pub fn disconnect_from_map_server(&mut self) {
         self.map_server_connection = ServerConnection::ClosingManually;
+        self.map_server_session = None;
+
+        // Drop the login and character server connections of an attempt that is in
+        // progress.
+        if let Some(ReconnectState {
+            stage: ReconnectStage::LoggingIn | ReconnectStage::SelectingCharacter { .. },
+            ..
+        }) = self.reconnect_state.take()
+        {
+            self.login_server_connection = ServerConnection::Disconnected;
+            self.character_server_connection = ServerConnection::Disconnected;
+        }
     }
 
     pub fn send_login_server_packet(&mut self, packet: &(impl Packet + LoginServerPacket)) -> Result<(), NotConnectedError> {
         match &mut self.login_server_connection {
             ServerConnection::Connected { action_sender, .. } => {
-                self.packet_callback.outgoing_packet(packet);
-
                 // FIX: Don't unwrap.
-                action_sender.send(packet.packet_to_bytes().unwrap()).map_err(|_| NotConnectedError)
+                let bytes = packet.packet_to_bytes_with_profile(&self.packet_profile).unwrap();
+
+                self.login_server_callback.outgoing_packet(packet);
+                self.login_server_callback.outgoing_bytes(&bytes);
+
+                action_sender.send(bytes).map_err(|_| NotConnectedError)
             }
             _ => Err(NotConnectedError),
         }
@@ -448,10 +712,13 @@ where
     pub fn send_character_server_packet(&mut self, packet: &(impl Packet + CharacterServerPacket)) -> Result<(), NotConnectedError> {
         match &mut self.character_server_connection {
             ServerConnection::Connected { action_sender, .. } => {
-                self.packet_callback.outgoing_packet(packet);
-
                 // FIX: Don't unwrap.
-                action_sender.send(packet.packet_to_bytes().unwrap()).map_err(|_| NotConnectedError)
+                let bytes = packet.packet_to_bytes_with_profile(&self.packet_profile).unwrap();
+
+                self.character_server_callback.outgoing_packet(packet);
+                self.character_server_callback.outgoing_bytes(&bytes);
+
+                action_sender.send(bytes).map_err(|_| NotConnectedError)
             }
             _ => Err(NotConnectedError),
         }
@@ -460,10 +727,13 @@ where
     pub fn send_map_server_packet(&mut self, packet: &(impl Packet + MapServerPacket)) -> Result<(), NotConnectedError> {
         match &mut self.map_server_connection {
             ServerConnection::Connected { action_sender, .. } => {
-                self.packet_callback.outgoing_packet(packet);
-
                 // FIX: Don't unwrap.
-                action_sender.send(packet.packet_to_bytes().unwrap()).map_err(|_| NotConnectedError)
+                let bytes = packet.packet_to_bytes_with_profile(&self.packet_profile).unwrap();
+
+                self.map_server_callback.outgoing_packet(packet);
+                self.map_server_callback.outgoing_bytes(&bytes);
+
+                action_sender.send(bytes).map_err(|_| NotConnectedError)
             }
             _ => Err(NotConnectedError),
         }
@@ -471,8 +741,9 @@ where
 
     fn create_login_server_packet_handler(
         packet_callback: Callback,
-    ) -> Result<PacketHandler<NetworkEventList, (), Callback>, DuplicateHandlerError> {
-        let mut packet_handler = PacketHandler::<NetworkEventList, (), Callback>::with_callback(packet_callback);
+        packet_profile: PacketProfile,
+    ) -> Result<PacketHandler<NetworkEventList, PacketVersion, Callback>, DuplicateHandlerError> {
+        let mut packet_handler = PacketHandler::<NetworkEventList, PacketVersion, Callback>::with_profile(packet_callback, packet_profile);
 
         packet_handler.register(|packet: LoginServerLoginSuccessPacket| NetworkEvent::LoginServerConnected {
             character_servers: packet.character_server_information,
@@ -519,8 +790,9 @@ where
 
     fn create_character_server_packet_handler(
         packet_callback: Callback,
-    ) -> Result<PacketHandler<NetworkEventList, (), Callback>, DuplicateHandlerError> {
-        let mut packet_handler = PacketHandler::<NetworkEventList, (), Callback>::with_callback(packet_callback);
+        packet_profile: PacketProfile,
+    ) -> Result<PacketHandler<NetworkEventList, PacketVersion, Callback>, DuplicateHandlerError> {
+        let mut packet_handler = PacketHandler::<NetworkEventList, PacketVersion, Callback>::with_profile(packet_callback, packet_profile);
 
         packet_handler.register(|packet: LoginFailedPacket| {
             let reason = packet.reason;
@@ -602,8 +874,9 @@ where
 
     fn create_map_server_packet_handler(
         packet_callback: Callback,
-    ) -> Result<PacketHandler<NetworkEventList, (), Callback>, DuplicateHandlerError> {
-        let mut packet_handler = PacketHandler::<NetworkEventList, (), Callback>::with_callback(packet_callback);
+        packet_profile: PacketProfile,
+    ) -> Result<PacketHandler<NetworkEventList, PacketVersion, Callback>, DuplicateHandlerError> {
+        let mut packet_handler = PacketHandler::<NetworkEventList, PacketVersion, Callback>::with_profile(packet_callback, packet_profile);
 
         // This is a bit of a workaround for the way that the inventory is
         // sent. There is a single packet to start the inventory list,
@@ -612,7 +885,9 @@ where
         //
         // This variable provides some transient storage shared by all the inventory
         // handlers.
-        let inventory_items: Rc<RefCell<Option<Vec<InventoryItem<NoMetadata>>>>> = Rc::new(RefCell::new(None));
+        // Item indices are only converted once the whole list arrived, since their
+        // offset depends on the inventory type.
+        let inventory_items = Rc::new(RefCell::new(None::<Vec<InventoryItem<NoMetadata, u16>>>));
 
         packet_handler.register(|_: MapServerPingPacket| NoNetworkEvents)?;
         packet_handler.register(|packet: BroadcastMessagePacket| NetworkEvent::ChatMessage {
@@ -654,7 +929,10 @@ where
                 color,
             }
         })?;
-        packet_handler.register_noop::<DisplayEmotionPacket>()?;
+        packet_handler.register(|packet: DisplayEmotionPacket| NetworkEvent::Emotion {
+            entity_id: packet.entity_id,
+            emotion: packet.emotion,
+        })?;
         packet_handler.register(|packet: EntityMovePacket| {
             let (origin, destination) = packet.from_to.to_origin_destination();
             NetworkEvent::EntityMove(packet.entity_id, origin, destination, packet.timestamp)
@@ -669,14 +947,23 @@ where
         packet_handler.register(|packet: EntityAppeared2Packet| NetworkEvent::AddEntity(packet.into()))?;
         packet_handler.register(|packet: MovingEntityAppearedPacket| NetworkEvent::AddEntity(packet.into()))?;
         packet_handler.register(|packet: EntityDisappearedPacket| NetworkEvent::RemoveEntity(packet.entity_id))?;
+        packet_handler.register(|packet: GroundItemAppearedPacket| NetworkEvent::AddGroundItem(packet.into()))?;
+        packet_handler.register(|packet: GroundItemDroppedPacket| NetworkEvent::AddGroundItem(packet.into()))?;
+        packet_handler.register(|packet: GroundItemDisappearedPacket| NetworkEvent::RemoveEntity(packet.entity_id))?;
         packet_handler.register(|packet: UpdateStatusPacket| NetworkEvent::UpdateStatus(packet.status_type))?;
         packet_handler.register(|packet: UpdateStatusPacket1| NetworkEvent::UpdateStatus(packet.status_type))?;
         packet_handler.register(|packet: UpdateStatusPacket2| NetworkEvent::UpdateStatus(packet.status_type))?;
         packet_handler.register(|packet: UpdateStatusPacket3| NetworkEvent::UpdateStatus(packet.status_type))?;
         packet_handler.register_noop::<UpdateAttackRangePacket>()?;
-        packet_handler.register_noop::<NewMailStatusPacket>()?;
-        packet_handler.register_noop::<AchievementUpdatePacket>()?;
-        packet_handler.register_noop::<AchievementListPacket>()?;
+        packet_handler.register(|packet: NewMailStatusPacket| (packet.new_available != 0).then_some(NetworkEvent::NewMail))?;
+        packet_handler.register(|packet: AchievementUpdatePacket| NetworkEvent::AchievementUpdated {
+            summary: AchievementSummary::from(&packet),
+            achievement: AchievementProgress::from(packet.acheivement_data),
+        })?;
+        packet_handler.register(|packet: AchievementListPacket| NetworkEvent::AchievementList {
+            summary: AchievementSummary::from(&packet),
+            achievements: packet.acheivement_data.into_iter().map(AchievementProgress::from).collect(),
+        })?;
         packet_handler.register_noop::<CriticalWeightUpdatePacket>()?;
         packet_handler.register(|packet: SpriteChangePacket| {
             (packet.sprite_type == 0).then_some(NetworkEvent::ChangeJob(packet.account_id, packet.value))
@@ -774,14 +1061,39 @@ where
         packet_handler.register({
             let inventory_items = inventory_items.clone();
 
-            move |_: InventoyEndPacket| {
+            move |packet: InventoyEndPacket| {
                 let items = inventory_items.borrow_mut().take().expect("Unexpected inventory end packet");
-                NetworkEvent::SetInventory { items }
+
+                match packet.inventory_type {
+                    InventoryType::Inventory => Some(NetworkEvent::SetInventory {
+                        items: convert_item_indices(items, 2, InventoryIndex),
+                    }),
+                    InventoryType::Storage => Some(NetworkEvent::SetStorage {
+                        items: convert_item_indices(items, 1, StorageIndex),
+                    }),
+                    InventoryType::Cart => Some(NetworkEvent::SetCart {
+                        items: convert_item_indices(items, 2, InventoryIndex),
+                    }),
+                    InventoryType::GuildStorage => None,
+                }
             }
         })?;
         packet_handler.register_noop::<EquippableSwitchItemListPacket>()?;
         packet_handler.register_noop::<MapTypePacket>()?;
         packet_handler.register(|packet: UpdateSkillTreePacket| NetworkEvent::SkillTree(packet.skill_information))?;
+        packet_handler.register(|packet: UpdateSkillPacket| NetworkEvent::SkillUpdated {
+            skill_id: packet.skill_id,
+            skill_level: packet.skill_level,
+            upgradable: packet.upgraded != 0,
+        })?;
+        // On success the server follows up with an `UpdateStatusPacket` for the stat
+        // and the remaining status points, so we only need to handle failure here.
+        packet_handler.register(|packet: RequestStatUpResponsePacket| {
+            (packet.success == 0).then(|| NetworkEvent::ChatMessage {
+                text: "Not enough status points.".to_string(),
+                color: MessageColor::Error,
+            })
+        })?;
         packet_handler.register(|packet: UpdateHotkeysPacket| NetworkEvent::SetHotkeyData {
             tab: packet.tab,
             hotkeys: packet
@@ -797,8 +1109,23 @@ where
         packet_handler.register_noop::<UpdatePartyInvitationStatePacket>()?;
         packet_handler.register_noop::<UpdateShowEquipPacket>()?;
         packet_handler.register_noop::<UpdateConfigurationPacket>()?;
-        packet_handler.register_noop::<NavigateToMonsterPacket>()?;
-        packet_handler.register_noop::<MarkMinimapPositionPacket>()?;
+        packet_handler.register(|packet: NavigateToMonsterPacket| NetworkEvent::NavigationHint {
+            map_name: packet.map_name,
+            position: packet.target_position,
+        })?;
+        packet_handler.register(|packet: MarkMinimapPositionPacket| match packet.marker_type {
+            MarkerType::RemoveMark => NetworkEvent::MinimapMarkerRemoved {
+                npc_id: packet.npc_id,
+                marker_id: packet.id,
+            },
+            marker_type => NetworkEvent::MinimapMarkerAdded {
+                npc_id: packet.npc_id,
+                marker_id: packet.id,
+                position: packet.position,
+                color: packet.color,
+                duration: matches!(marker_type, MarkerType::DisplayFor15Seconds).then_some(Duration::from_secs(15)),
+            },
+        })?;
         packet_handler.register(|_: NextButtonPacket| NetworkEvent::AddNextButton)?;
         packet_handler.register(|_: CloseButtonPacket| NetworkEvent::AddCloseButton)?;
         packet_handler.register(|packet: DialogMenuPacket| {
@@ -812,18 +1139,42 @@ where
             NetworkEvent::AddChoiceButtons(choices)
         })?;
         packet_handler.register_noop::<DisplaySpecialEffectPacket>()?;
-        packet_handler.register_noop::<DisplaySkillCooldownPacket>()?;
+        // Despite the name, the server sends the remaining duration rather than a
+        // client tick.
+        packet_handler.register(|packet: DisplaySkillCooldownPacket| NetworkEvent::SkillCooldown {
+            skill_id: packet.skill_id,
+            duration: packet.until.0,
+        })?;
         packet_handler.register_noop::<DisplaySkillEffectAndDamagePacket>()?;
         packet_handler.register(|packet: DisplaySkillEffectNoDamagePacket| {
             NetworkEvent::HealEffect(packet.destination_entity_id, packet.heal_amount as usize)
         })?;
         packet_handler.register_noop::<DisplayPlayerHealEffect>()?;
-        packet_handler.register_noop::<StatusChangePacket>()?;
-        packet_handler.register_noop::<QuestNotificationPacket1>()?;
-        packet_handler.register_noop::<HuntingQuestNotificationPacket>()?;
-        packet_handler.register_noop::<HuntingQuestUpdateObjectivePacket>()?;
-        packet_handler.register_noop::<QuestRemovedPacket>()?;
-        packet_handler.register_noop::<QuestListPacket>()?;
+        packet_handler.register(|packet: StatusChangePacket| match packet.state {
+            0 => NetworkEvent::StatusEffectEnded {
+                entity_id: packet.entity_id,
+                status_id: packet.index,
+            },
+            _ => NetworkEvent::StatusEffectStarted {
+                entity_id: packet.entity_id,
+                status_effect: StatusEffectData::from(&packet),
+            },
+        })?;
+        packet_handler.register(|packet: QuestNotificationPacket1| NetworkEvent::QuestAdded {
+            quest: QuestData::from(packet),
+        })?;
+        packet_handler.register(|packet: HuntingQuestNotificationPacket| NetworkEvent::QuestObjectivesUpdated {
+            objectives: packet.objective_details,
+        })?;
+        packet_handler.register(
+            |packet: HuntingQuestUpdateObjectivePacket| NetworkEvent::QuestObjectivesUpdated {
+                objectives: packet.objective_details,
+            },
+        )?;
+        packet_handler.register(|packet: QuestRemovedPacket| NetworkEvent::QuestRemoved { quest_id: packet.quest_id })?;
+        packet_handler.register(|packet: QuestListPacket| NetworkEvent::QuestList {
+            quests: packet.quests.into_iter().map(QuestData::from).collect(),
+        })?;
         packet_handler.register(|packet: VisualEffectPacket| {
             let path = match packet.effect {
                 VisualEffect::BaseLevelUp => "angel.str",
@@ -841,8 +1192,25 @@ where
             NetworkEvent::VisualEffect(path, packet.entity_id)
         })?;
         packet_handler.register_noop::<DisplayGainedExperiencePacket>()?;
-        packet_handler.register_noop::<DisplayImagePacket>()?;
-        packet_handler.register_noop::<StateChangePacket>()?;
+        packet_handler.register(|packet: DisplayImagePacket| {
+            let location = match packet.location {
+                ImageLocation::BottomLeft => CutInLocation::BottomLeft,
+                ImageLocation::BottomMiddle => CutInLocation::BottomMiddle,
+                ImageLocation::BottomRight => CutInLocation::BottomRight,
+                ImageLocation::MiddleFloating => CutInLocation::MiddleFloating,
+                ImageLocation::MiddleColorless => CutInLocation::MiddleColorless,
+                ImageLocation::ClearAll => return NetworkEvent::ClearCutIn,
+            };
+
+            NetworkEvent::DisplayCutIn {
+                image_name: packet.image_name,
+                location,
+            }
+        })?;
+        packet_handler.register(|packet: StateChangePacket| NetworkEvent::VisualStateChanged {
+            entity_id: packet.entity_id,
+            visual_state: EntityVisualState::new(packet.body_state, packet.effect_state),
+        })?;
 
         packet_handler.register(|packet: QuestEffectPacket| match packet.effect {
             QuestEffect::None => NetworkEvent::RemoveQuestEffect(packet.entity_id),
@@ -869,7 +1237,18 @@ where
             } = packet;
 
             if result != ItemPickupResult::Success {
-                todo!();
+                let text = match result {
+                    ItemPickupResult::Overweight => "You can't carry that much weight",
+                    ItemPickupResult::NoSpace | ItemPickupResult::MaximumOfItem | ItemPickupResult::StackLimitation => {
+                        "You can't carry any more items"
+                    }
+                    _ => "Failed to pick up the item",
+                };
+
+                return NetworkEvent::ChatMessage {
+                    text: text.to_owned(),
+                    color: MessageColor::Error,
+                };
             }
 
             // TODO: Not sure where to store these, since the *InventoryItem packets are not
@@ -916,6 +1295,17 @@ where
 
             NetworkEvent::IventoryItemAdded { item }
         })?;
+        packet_handler.register(|packet: UseItemResultPacket| NetworkEvent::ItemUsed {
+            index: packet.index,
+            entity_id: packet.entity_id,
+            remaining_amount: packet.amount,
+            success: packet.success != 0,
+        })?;
+        packet_handler.register(|packet: DropItemResultPacket| NetworkEvent::InventoryItemRemoved {
+            reason: RemoveItemReason::Normal,
+            index: packet.index,
+            amount: packet.amount,
+        })?;
         packet_handler.register(|packet: RemoveItemFromInventoryPacket| NetworkEvent::InventoryItemRemoved {
             reason: packet.remove_reason,
             index: packet.index,
@@ -974,8 +1364,19 @@ where
                 color: MessageColor::Error,
             },
         })?;
-        packet_handler.register_noop::<UseSkillSuccessPacket>()?;
-        packet_handler.register_noop::<ToUseSkillSuccessPacket>()?;
+        packet_handler.register(|packet: UseSkillSuccessPacket| NetworkEvent::SkillCasting {
+            entity_id: packet.source_entity,
+            skill_id: packet.skill_id,
+            cast_time: packet.delay_time,
+        })?;
+        // The server only sends this packet with a flag of 0, when using the skill
+        // failed.
+        packet_handler.register(|packet: ToUseSkillSuccessPacket| {
+            (packet.flag == 0).then_some(NetworkEvent::SkillFailed { skill_id: packet.skill_id })
+        })?;
+        packet_handler.register(|packet: CastCancelPacket| NetworkEvent::SkillCastCancelled {
+            entity_id: packet.entity_id,
+        })?;
         packet_handler
             .register(|packet: NotifySkillUnitPacket| NetworkEvent::AddSkillUnit(packet.entity_id, packet.unit_id, packet.position))?;
         packet_handler.register(|packet: SkillUnitDisappearPacket| NetworkEvent::RemoveSkillUnit(packet.entity_id))?;
@@ -1008,12 +1409,440 @@ where
             account_id: packet.account_id,
             character_id: packet.character_id,
         })?;
-        packet_handler.register_noop::<PartyInvitePacket>()?;
-        packet_handler.register_noop::<StatusChangeSequencePacket>()?;
-        packet_handler.register_noop::<ReputationPacket>()?;
+        packet_handler.register(|packet: PartyCreationResultPacket| {
+            let (text, color) = match packet.result {
+                PartyCreationResult::Created => return None,
+                PartyCreationResult::NameAlreadyExists => ("That party name is already taken.", MessageColor::Error),
+                PartyCreationResult::AlreadyInParty => ("You are already in a party.", MessageColor::Error),
+                PartyCreationResult::NotAllowedOnMap => ("You cannot create a party on this map.", MessageColor::Error),
+            };
+
+            Some(NetworkEvent::ChatMessage {
+                text: text.to_owned(),
+                color,
+            })
+        })?;
+        packet_handler.register(|packet: PartyInvitePacket| NetworkEvent::PartyInvite {
+            party_id: packet.party_id,
+            party_name: packet.party_name,
+        })?;
+        packet_handler.register(|packet: PartyInviteResultPacket| {
+            let (text, color) = match packet.result {
+                PartyInviteResult::AlreadyInParty => (format!("{} is already in a party.", packet.name), MessageColor::Error),
+                PartyInviteResult::Rejected => (format!("{} rejected your party invitation.", packet.name), MessageColor::Error),
+                PartyInviteResult::Accepted => (format!("{} joined your party.", packet.name), MessageColor::Information),
+                PartyInviteResult::PartyFull => ("Your party is full.".to_owned(), MessageColor::Error),
+                PartyInviteResult::SameAccount => (
+                    "You cannot invite a character of the same account.".to_owned(),
+                    MessageColor::Error,
+                ),
+                PartyInviteResult::InvitesBlocked => (
+                    format!("{} is not accepting party invitations.", packet.name),
+                    MessageColor::Error,
+                ),
+                PartyInviteResult::UnknownError => ("Failed to invite to the party.".to_owned(), MessageColor::Error),
+                PartyInviteResult::CharacterOffline => (format!("{} is not online.", packet.name), MessageColor::Error),
+                PartyInviteResult::NotAllowedOnMap | PartyInviteResult::NotAllowedOnMapLevel => {
+                    ("Party invitations are not allowed on this map.".to_owned(), MessageColor::Error)
+                }
+            };
+
+            NetworkEvent::ChatMessage { text, color }
+        })?;
+        packet_handler.register(|packet: PartyInformationPacket| NetworkEvent::SetPartyInformation {
+            party_name: packet.party_name,
+            members: packet.members,
+        })?;
+        packet_handler.register(|packet: PartyMemberJoinedPacket| {
+            let role = match packet.role {
+                0 => PartyMemberRole::Leader,
+                _ => PartyMemberRole::Member,
+            };
+
+            let member = PartyMember {
+                account_id: packet.account_id,
+                character_id: packet.character_id,
+                name: packet.name,
+                map_name: packet.map_name,
+                role,
+                state: packet.state,
+                job: packet.job,
+                base_level: packet.base_level,
+            };
+
+            (
+                NetworkEvent::PartyMemberUpdated { member },
+                NetworkEvent::UpdatePartyMemberPosition {
+                    account_id: packet.account_id,
+                    position: packet.position,
+                },
+            )
+        })?;
+        packet_handler.register(|packet: PartyMemberLeftPacket| match packet.reason {
+            PartyLeaveReason::Left | PartyLeaveReason::Kicked => NetworkEvent::PartyMemberRemoved {
+                account_id: packet.account_id,
+            },
+            PartyLeaveReason::CannotLeave => NetworkEvent::ChatMessage {
+                text: "You cannot leave the party right now.".to_owned(),
+                color: MessageColor::Error,
+            },
+            PartyLeaveReason::CannotKick => NetworkEvent::ChatMessage {
+                text: format!("You cannot kick {} from the party right now.", packet.name),
+                color: MessageColor::Error,
+            },
+        })?;
+        packet_handler.register_noop::<PartySettingsPacket>()?;
+        packet_handler.register(|packet: PartyLeaderChangedPacket| NetworkEvent::PartyLeaderChanged {
+            old_leader_account_id: packet.old_leader_account_id,
+            new_leader_account_id: packet.new_leader_account_id,
+        })?;
+        packet_handler.register(|packet: PartyMemberPositionPacket| NetworkEvent::UpdatePartyMemberPosition {
+            account_id: packet.account_id,
+            position: packet.position,
+        })?;
+        packet_handler.register(|packet: PartyMemberHealthPacket| NetworkEvent::UpdatePartyMemberHealth {
+            account_id: packet.account_id,
+            health_points: packet.health_points,
+            maximum_health_points: packet.maximum_health_points,
+        })?;
+        packet_handler.register(|packet: NotifyPartyMessagePacket| NetworkEvent::ChatMessage {
+            text: packet.message,
+            color: MessageColor::Party,
+        })?;
+        packet_handler.register(|packet: NotifyWhisperMessagePacket| NetworkEvent::Whisper {
+            sender: packet.sender_name,
+            text: packet.message,
+        })?;
+        packet_handler.register(|packet: WhisperResultPacket| match packet.result {
+            WhisperResult::Success => None,
+            WhisperResult::RecipientOffline => Some(NetworkEvent::WhisperFailed {
+                reason: WhisperFailedReason::RecipientOffline,
+            }),
+            WhisperResult::IgnoredByRecipient => Some(NetworkEvent::WhisperFailed {
+                reason: WhisperFailedReason::IgnoredByRecipient,
+            }),
+            WhisperResult::RecipientIgnoresEveryone => Some(NetworkEvent::WhisperFailed {
+                reason: WhisperFailedReason::RecipientIgnoresEveryone,
+            }),
+        })?;
+        packet_handler.register(|packet: UpdateGuildIdPacket| NetworkEvent::SetGuild {
+            guild_id: packet.guild_id,
+            emblem_id: packet.emblem_id,
+            guild_name: packet.guild_name,
+        })?;
+        packet_handler.register(|packet: GuildInformationPacket| NetworkEvent::SetGuildInformation {
+            information: packet.information,
+        })?;
+        packet_handler.register(|packet: GuildRelationsPacket| NetworkEvent::SetGuildRelations {
+            relations: packet.relations,
+        })?;
+        packet_handler.register(|packet: GuildRelationRemovedPacket| NetworkEvent::GuildRelationRemoved {
+            guild_id: packet.guild_id,
+            relation_type: packet.relation_type,
+        })?;
+        packet_handler.register(|packet: GuildMemberListPacket| NetworkEvent::SetGuildMembers { members: packet.members })?;
+        packet_handler.register(|packet: GuildMemberStatePacket| NetworkEvent::UpdateGuildMemberState {
+            account_id: packet.account_id,
+            character_id: packet.character_id,
+            state: packet.state,
+        })?;
+        packet_handler.register(|packet: CharacterNamePacket| NetworkEvent::CharacterName {
+            character_id: packet.character_id,
+            name: packet.name,
+        })?;
+        packet_handler.register(|packet: GuildPositionNamesPacket| NetworkEvent::SetGuildPositionNames {
+            positions: packet.positions,
+        })?;
+        packet_handler.register(|packet: GuildPositionsPacket| NetworkEvent::SetGuildPositions {
+            positions: packet.positions,
+        })?;
+        packet_handler.register(|packet: GuildNoticePacket| NetworkEvent::GuildNotice {
+            subject: packet.subject,
+            notice: packet.notice,
+        })?;
+        packet_handler.register(|packet: GuildEmblemPacket| NetworkEvent::GuildEmblem {
+            guild_id: packet.guild_id,
+            emblem_id: packet.emblem_id,
+            emblem_data: packet.emblem_data,
+        })?;
+        packet_handler.register(|packet: GuildEmblemChangedPacket| NetworkEvent::GuildEmblemChanged {
+            guild_id: packet.guild_id,
+            emblem_id: packet.emblem_id as u32,
+        })?;
+        packet_handler.register_noop::<StorageCapacityPacket>()?;
+        packet_handler.register(|packet: StorageItemAddedPacket| {
+            let StorageItemAddedPacket {
+                index,
+                amount,
+                item_id,
+                item_type,
+                is_identified,
+                is_broken,
+                refinement_level,
+                cards,
+                option_data,
+                enchantment_level,
+            } = packet;
+
+            // The packet doesn't tell us where an item can be equipped, so we go by the
+            // item type (armor, weapon and shadow gear).
+            let details = match item_type {
+                4 | 5 | 12 => InventoryItemDetails::Equippable {
+                    equip_position: EquipPosition::empty(),
+                    equipped_position: EquipPosition::empty(),
+                    bind_on_equip_type: 0,
+                    w_item_sprite_number: 0,
+                    option_count: option_data.len() as u8,
+                    option_data,
+                    refinement_level,
+                    enchantment_level,
+                    flags: {
+                        let mut flags = EquippableItemFlags::empty();
+                        flags.set(EquippableItemFlags::IDENTIFIED, is_identified != 0);
+                        flags.set(EquippableItemFlags::IS_BROKEN, is_broken != 0);
+                        flags
+                    },
+                },
+                _ => InventoryItemDetails::Regular {
+                    amount: amount as u16,
+                    equipped_position: EquipPosition::empty(),
+                    flags: {
+                        let mut flags = RegularItemFlags::empty();
+                        flags.set(RegularItemFlags::IDENTIFIED, is_identified != 0);
+                        flags
+                    },
+                },
+            };
+
+            let item = InventoryItem {
+                metadata: NoMetadata,
+                index,
+                item_id,
+                item_type,
+                slot: cards,
+                hire_expiration_date: 0,
+                details,
+            };
+
+            NetworkEvent::StorageItemAdded { item }
+        })?;
+        packet_handler.register(|packet: StorageItemRemovedPacket| NetworkEvent::StorageItemRemoved {
+            index: packet.index,
+            amount: packet.amount as u16,
+        })?;
+        packet_handler.register(|_: StorageClosedPacket| NetworkEvent::StorageClosed)?;
+        packet_handler.register(|packet: TradeRequestPacket| NetworkEvent::TradeRequested {
+            name: packet.name,
+            character_id: packet.character_id,
+            base_level: packet.base_level,
+        })?;
+        packet_handler.register(|packet: TradeResponseResultPacket| match packet.result {
+            TradeResponse::Accept => NetworkEvent::TradeStarted {
+                character_id: packet.character_id,
+                base_level: packet.base_level,
+            },
+            result => NetworkEvent::TradeRequestFailed { result },
+        })?;
+        packet_handler.register(|packet: TradeItemAddedPacket| match packet.item_id {
+            ItemId(0) => NetworkEvent::TradeZenyAdded { amount: packet.amount },
+            item_id => NetworkEvent::TradeItemAdded {
+                item: TradeItem {
+                    metadata: NoMetadata,
+                    item_id,
+                    item_type: packet.item_type,
+                    amount: packet.amount,
+                    is_identified: packet.is_identified != 0,
+                    refinement_level: packet.refinement_level,
+                    slot: packet.cards,
+                },
+            },
+        })?;
+        packet_handler.register(|packet: AddTradeItemResultPacket| NetworkEvent::AddTradeItemResult {
+            index: packet.index.checked_sub(2).map(InventoryIndex),
+            result: packet.result,
+        })?;
+        packet_handler.register(|packet: TradeLockedPacket| NetworkEvent::TradeLocked { party: packet.party })?;
+        packet_handler.register(|_: TradeCancelledPacket| NetworkEvent::TradeCancelled)?;
+        packet_handler.register(|packet: TradeCompletedPacket| NetworkEvent::TradeCompleted { result: packet.result })?;
+        packet_handler.register(|packet: CreateChatRoomResultPacket| match packet.result {
+            CreateChatRoomResult::Success => NetworkEvent::ChatRoomCreated,
+            result => NetworkEvent::ChatRoomCreationFailed { result },
+        })?;
+        packet_handler.register(|packet: ChatRoomAppearedPacket| NetworkEvent::ChatRoomUpdated { chat_room: packet.into() })?;
+        packet_handler.register(|packet: ChatRoomChangedPacket| NetworkEvent::ChatRoomUpdated { chat_room: packet.into() })?;
+        packet_handler.register(|packet: ChatRoomDestroyedPacket| NetworkEvent::ChatRoomDestroyed {
+            chat_room_id: packet.chat_room_id,
+        })?;
+        packet_handler.register(|packet: ChatRoomEnteredPacket| NetworkEvent::ChatRoomEntered {
+            chat_room_id: packet.chat_room_id,
+            members: packet.members,
+        })?;
+        packet_handler.register(|packet: EnterChatRoomFailedPacket| NetworkEvent::EnterChatRoomFailed { reason: packet.reason })?;
+        packet_handler.register(|packet: ChatRoomMemberEnteredPacket| NetworkEvent::ChatRoomMemberEntered {
+            name: packet.name,
+            member_count: packet.member_count,
+        })?;
+        packet_handler.register(|packet: ChatRoomMemberExitPacket| NetworkEvent::ChatRoomMemberExited {
+            name: packet.name,
+            member_count: packet.member_count,
+            reason: packet.reason,
+        })?;
+        packet_handler.register(|packet: ChatRoomRoleChangedPacket| NetworkEvent::ChatRoomRoleChanged {
+            name: packet.name,
+            role: packet.role,
+        })?;
+        packet_handler.register(|packet: OpenVendingShopSetupPacket| NetworkEvent::VendingShopSetup {
+            slot_count: packet.slot_count,
+        })?;
+        packet_handler.register(|packet: OpenVendingShopResultPacket| {
+            // On success the server sends the items of the shop, which is handled below.
+            (packet.result == OpenVendingShopResult::Failed).then_some(NetworkEvent::VendingShopOpenFailed)
+        })?;
+        packet_handler.register(|packet: OwnVendingItemListPacket| NetworkEvent::VendingShopOpened {
+            items: packet.items.into_iter().map(VendingItem::from).collect(),
+        })?;
+        packet_handler.register(|packet: VendingItemSoldPacket| NetworkEvent::VendingItemSold {
+            index: packet.index,
+            amount: packet.amount,
+            zeny: packet.zeny,
+        })?;
+        packet_handler.register(|packet: VendingShopAppearedPacket| NetworkEvent::StoreAppeared {
+            entity_id: packet.entity_id,
+            store: StoreData {
+                store_type: StoreType::Vending,
+                shop_name: packet.shop_name,
+            },
+        })?;
+        packet_handler.register(|packet: VendingShopDisappearedPacket| NetworkEvent::StoreDisappeared {
+            entity_id: packet.entity_id,
+            store_type: StoreType::Vending,
+        })?;
+        packet_handler.register(|packet: VendingItemListPacket| NetworkEvent::VendingItemList {
+            entity_id: packet.entity_id,
+            vendor_id: packet.vendor_id,
+            items: packet.items.into_iter().map(VendingItem::from).collect(),
+        })?;
+        packet_handler.register(|packet: VendingPurchaseResultPacket| {
+            (packet.result != VendingPurchaseResult::Success).then_some(NetworkEvent::VendingPurchaseFailed {
+                index: packet.index,
+                amount: packet.amount,
+                result: packet.result,
+            })
+        })?;
+        packet_handler.register(|packet: OpenBuyingStoreSetupPacket| NetworkEvent::BuyingStoreSetup {
+            slot_count: packet.slot_count,
+        })?;
+        packet_handler.register(|packet: OwnBuyingStoreItemListPacket| NetworkEvent::BuyingStoreOpened {
+            zeny_limit: packet.zeny_limit,
+            items: packet.items.into_iter().map(BuyingStoreItem::from).collect(),
+        })?;
+        packet_handler.register(|packet: OpenBuyingStoreFailedPacket| NetworkEvent::BuyingStoreOpenFailed {
+            result: packet.result,
+            weight: packet.weight,
+        })?;
+        packet_handler.register(|packet: BuyingStoreClosedPacket| NetworkEvent::BuyingStoreClosed { reason: packet.reason })?;
+        packet_handler.register(|packet: BuyingStoreItemBoughtPacket| NetworkEvent::BuyingStoreItemBought {
+            item_id: packet.item_id,
+            amount: packet.amount,
+            zeny_limit: packet.zeny_limit,
+        })?;
+        packet_handler.register(|packet: BuyingStoreAppearedPacket| NetworkEvent::StoreAppeared {
+            entity_id: packet.entity_id,
+            store: StoreData {
+                store_type: StoreType::Buying,
+                shop_name: packet.shop_name,
+            },
+        })?;
+        packet_handler.register(|packet: BuyingStoreDisappearedPacket| NetworkEvent::StoreDisappeared {
+            entity_id: packet.entity_id,
+            store_type: StoreType::Buying,
+        })?;
+        packet_handler.register(|packet: BuyingStoreItemListPacket| NetworkEvent::BuyingStoreItemList {
+            entity_id: packet.entity_id,
+            store_id: packet.store_id,
+            zeny_limit: packet.zeny_limit,
+            items: packet.items.into_iter().map(BuyingStoreItem::from).collect(),
+        })?;
+        packet_handler.register(|packet: BuyingStoreItemSoldPacket| NetworkEvent::BuyingStoreItemSold {
+            index: packet.index,
+            amount: packet.amount,
+            zeny: packet.zeny,
+        })?;
+        packet_handler.register(|packet: SellToBuyingStoreFailedPacket| NetworkEvent::SellToBuyingStoreFailed {
+            result: packet.result,
+            item_id: packet.item_id,
+        })?;
+        packet_handler.register(|packet: MailListPacket| NetworkEvent::MailList {
+            mails: packet.mails.into_iter().map(MailSummary::from).collect(),
+            is_last_page: packet.is_end != 0,
+        })?;
+        packet_handler.register(|packet: MailContentsPacket| NetworkEvent::MailContents {
+            mail_id: packet.mail_id,
+            open_type: packet.open_type,
+            text: packet.text,
+            zeny: packet.zeny,
+            items: packet.items.into_iter().map(MailItem::from).collect(),
+        })?;
+        packet_handler.register(|packet: MailDeletedPacket| NetworkEvent::MailDeleted { mail_id: packet.mail_id })?;
+        packet_handler.register(|packet: RetrieveMailZenyResultPacket| match packet.result {
+            RetrieveMailZenyResult::Success => NetworkEvent::MailZenyRetrieved { mail_id: packet.mail_id },
+            result => NetworkEvent::RetrieveMailZenyFailed { result },
+        })?;
+        packet_handler.register(|packet: RetrieveMailItemsResultPacket| match packet.result {
+            RetrieveMailItemsResult::Success => NetworkEvent::MailItemsRetrieved { mail_id: packet.mail_id },
+            result => NetworkEvent::RetrieveMailItemsFailed { result },
+        })?;
+        packet_handler.register_noop::<StartWritingMailResultPacket>()?;
+        packet_handler.register(|packet: AddMailItemResultPacket| match packet.result {
+            AddMailItemResult::Success => NetworkEvent::MailItemAttached {
+                index: packet.index,
+                item: MailItem::from(&packet),
+            },
+            result => NetworkEvent::AttachMailItemFailed { result },
+        })?;
+        packet_handler.register(|packet: RemoveMailItemResultPacket| {
+            (packet.result == 0).then_some(NetworkEvent::MailItemDetached {
+                index: packet.index,
+                remaining_amount: packet.amount,
+            })
+        })?;
+        packet_handler.register(|packet: MailReceiverPacket| NetworkEvent::MailReceiverChecked {
+            name: packet.name,
+            character_id: (packet.character_id != CharacterId(0)).then_some(packet.character_id),
+        })?;
+        packet_handler.register(|packet: SendMailResultPacket| match packet.result {
+            SendMailResult::Success => NetworkEvent::MailSent,
+            result => NetworkEvent::SendMailFailed { result },
+        })?;
+        packet_handler.register(|packet: NotifyGuildMessagePacket| NetworkEvent::ChatMessage {
+            text: packet.message,
+            color: MessageColor::Guild,
+        })?;
+        packet_handler.register(|packet: StatusChangeSequencePacket| match packet.state {
+            0 => NetworkEvent::StatusEffectEnded {
+                entity_id: EntityId(packet.id),
+                status_id: packet.index,
+            },
+            _ => NetworkEvent::StatusEffectStarted {
+                entity_id: EntityId(packet.id),
+                status_effect: StatusEffectData {
+                    status_id: packet.index,
+                    duration: None,
+                    remaining: None,
+                    values: [0; 3],
+                },
+            },
+        })?;
+        packet_handler.register(|packet: ReputationPacket| {
+            (packet.success != 0).then_some(NetworkEvent::ReputationList { entries: packet.entries })
+        })?;
         packet_handler.register_noop::<ClanInfoPacket>()?;
         packet_handler.register_noop::<ClanOnlineCountPacket>()?;
-        packet_handler.register_noop::<ChangeMapCellPacket>()?;
+        packet_handler.register(|packet: ChangeMapCellPacket| NetworkEvent::ChangeMapCell {
+            map_name: packet.map_name.replace(".gat", ""),
+            position: packet.position,
+            cell_type: packet.cell_type,
+        })?;
         packet_handler.register_noop::<OpenMarketPacket>()?;
         packet_handler.register(|packet: BuyOrSellPacket| NetworkEvent::AskBuyOrSell { shop_id: packet.shop_id })?;
         packet_handler.register(|packet: ShopItemListPacket| {
@@ -1046,6 +1875,7 @@ where
     }
 
     pub fn select_character(&mut self, character_slot: usize) -> Result<(), NotConnectedError> {
+        self.character_slot = Some(character_slot);
         self.send_character_server_packet(&SelectCharacterPacket::new(character_slot as u8))
     }
 
@@ -1079,6 +1909,10 @@ where
         self.send_map_server_packet(&GlobalMessagePacket::new(complete_message))
     }
 
+    pub fn send_emotion(&mut self, emotion: u8) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&RequestEmotionPacket::new(emotion))
+    }
+
     pub fn start_dialog(&mut self, npc_id: EntityId) -> Result<(), NotConnectedError> {
         self.send_map_server_packet(&StartDialogPacket::new(npc_id))
     }
@@ -1153,6 +1987,76 @@ where
         ))
     }
 
+    pub fn create_party(&mut self, party_name: String) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&CreatePartyPacket::new(
+            party_name,
+            PartyItemPickupRule::EachTake,
+            PartyItemShareRule::EachTake,
+        ))
+    }
+
+    pub fn invite_to_party(&mut self, name: String) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&InviteToPartyPacket::new(name))
+    }
+
+    pub fn reject_party_invite(&mut self, party_id: PartyId) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&PartyInviteResponsePacket::new(party_id, PartyInviteResponse::Reject))
+    }
+
+    pub fn accept_party_invite(&mut self, party_id: PartyId) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&PartyInviteResponsePacket::new(party_id, PartyInviteResponse::Accept))
+    }
+
+    pub fn leave_party(&mut self) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&LeavePartyPacket::default())
+    }
+
+    pub fn kick_party_member(&mut self, account_id: AccountId, name: String) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&KickPartyMemberPacket::new(account_id, name))
+    }
+
+    pub fn send_party_message(&mut self, player_name: &str, message: &str) -> Result<(), NotConnectedError> {
+        let complete_message = format!("{} : {}", player_name, message);
+
+        self.send_map_server_packet(&PartyMessagePacket::new(complete_message))
+    }
+
+    pub fn send_whisper(&mut self, recipient_name: &str, message: &str) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&WhisperMessagePacket::new(recipient_name.to_owned(), message.to_owned()))
+    }
+
+    pub fn request_guild_information(&mut self, information_type: GuildInformationType) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&RequestGuildInformationPacket::new(information_type))
+    }
+
+    pub fn request_character_name(&mut self, character_id: CharacterId) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&RequestCharacterNamePacket::new(character_id))
+    }
+
+    pub fn change_guild_notice(&mut self, guild_id: GuildId, subject: String, notice: String) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&ChangeGuildNoticePacket::new(guild_id, subject, notice))
+    }
+
+    pub fn remove_guild_relation(&mut self, guild_id: GuildId, relation_type: GuildRelationType) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&RemoveGuildRelationPacket::new(guild_id, relation_type))
+    }
+
+    pub fn request_guild_emblem(&mut self, guild_id: GuildId) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&RequestGuildEmblemPacket::new(guild_id))
+    }
+
+    /// Upload a new guild emblem. The emblem data needs to be a zlib compressed
+    /// BMP or GIF image.
+    pub fn upload_guild_emblem(&mut self, emblem_data: Vec<u8>) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&UploadGuildEmblemPacket::new(emblem_data))
+    }
+
+    pub fn send_guild_message(&mut self, player_name: &str, message: &str) -> Result<(), NotConnectedError> {
+        let complete_message = format!("{} : {}", player_name, message);
+
+        self.send_map_server_packet(&GuildMessagePacket::new(complete_message))
+    }
+
     pub fn create_character(&mut self, slot: usize, name: String) -> Result<(), NotConnectedError> {
         let hair_color = 0;
         let hair_style = 0;
@@ -1201,29 +2105,284 @@ where
     pub fn sell_items(&mut self, items: Vec<SoldItemInformation>) -> Result<(), NotConnectedError> {
         self.send_map_server_packet(&SellItemsPacket { items })
     }
+
+    pub fn use_item(&mut self, account_id: AccountId, index: InventoryIndex) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&UseItemPacket::new(index, account_id))
+    }
+
+    pub fn drop_item(&mut self, index: InventoryIndex, amount: u16) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&DropItemPacket::new(index, amount))
+    }
+
+    pub fn pick_up_item(&mut self, entity_id: EntityId) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&PickUpItemPacket::new(entity_id))
+    }
+
+    pub fn storage_add_item(&mut self, index: InventoryIndex, amount: u32) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&MoveItemToStoragePacket::new(index, amount))
+    }
+
+    pub fn storage_remove_item(&mut self, index: StorageIndex, amount: u32) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&MoveItemFromStoragePacket::new(index, amount))
+    }
+
+    pub fn storage_close(&mut self) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&CloseStoragePacket::new())
+    }
+
+    pub fn request_trade(&mut self, entity_id: EntityId) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&RequestTradePacket::new(entity_id))
+    }
+
+    pub fn accept_trade_request(&mut self) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&TradeResponsePacket::new(TradeResponse::Accept))
+    }
+
+    pub fn reject_trade_request(&mut self) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&TradeResponsePacket::new(TradeResponse::Cancel))
+    }
+
+    pub fn add_trade_item(&mut self, index: InventoryIndex, amount: u32) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&AddTradeItemPacket::new(index.0 + 2, amount))
+    }
+
+    pub fn add_trade_zeny(&mut self, amount: u32) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&AddTradeItemPacket::new(0, amount))
+    }
+
+    pub fn lock_trade(&mut self) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&LockTradePacket::new())
+    }
+
+    pub fn cancel_trade(&mut self) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&CancelTradePacket::new())
+    }
+
+    pub fn commit_trade(&mut self) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&CommitTradePacket::new())
+    }
+
+    pub fn create_chat_room(
+        &mut self,
+        title: String,
+        limit: u16,
+        chat_room_type: ChatRoomType,
+        password: String,
+    ) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&CreateChatRoomPacket::new(limit, chat_room_type, password, title))
+    }
+
+    pub fn enter_chat_room(&mut self, chat_room_id: ChatRoomId, password: String) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&EnterChatRoomPacket::new(chat_room_id, password))
+    }
+
+    pub fn change_chat_room(
+        &mut self,
+        title: String,
+        limit: u16,
+        chat_room_type: ChatRoomType,
+        password: String,
+    ) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&ChangeChatRoomPacket::new(limit, chat_room_type, password, title))
+    }
+
+    pub fn transfer_chat_room_ownership(&mut self, name: String) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&ChangeChatRoomRolePacket::new(ChatRoomRole::Owner, name))
+    }
+
+    pub fn kick_from_chat_room(&mut self, name: String) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&KickFromChatRoomPacket::new(name))
+    }
+
+    pub fn leave_chat_room(&mut self) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&LeaveChatRoomPacket::new())
+    }
+
+    pub fn open_vending_shop(&mut self, shop_name: String, items: Vec<VendingShopItemInformation>) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&OpenVendingShopPacket::new(shop_name, 1, items))
+    }
+
+    pub fn close_vending_shop(&mut self) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&CloseVendingShopPacket::new())
+    }
+
+    pub fn request_vending_item_list(&mut self, entity_id: EntityId) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&RequestVendingItemListPacket::new(entity_id))
+    }
+
+    pub fn purchase_vending_items(
+        &mut self,
+        entity_id: EntityId,
+        vendor_id: VendorId,
+        items: Vec<VendingItem<u16>>,
+    ) -> Result<(), NotConnectedError> {
+        let item_information = items
+            .into_iter()
+            .map(|item| PurchaseVendingItemInformation {
+                amount: item.metadata,
+                index: item.index,
+            })
+            .collect();
+
+        self.send_map_server_packet(&PurchaseVendingItemsPacket::new(entity_id, vendor_id, item_information))
+    }
+
+    pub fn open_buying_store(
+        &mut self,
+        shop_name: String,
+        zeny_limit: u32,
+        items: Vec<BuyingStoreItemInformation>,
+    ) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&OpenBuyingStorePacket::new(zeny_limit, 1, shop_name, items))
+    }
+
+    pub fn close_buying_store(&mut self) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&CloseBuyingStorePacket::new())
+    }
+
+    pub fn request_buying_store_item_list(&mut self, entity_id: EntityId) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&RequestBuyingStoreItemListPacket::new(entity_id))
+    }
+
+    pub fn sell_to_buying_store(
+        &mut self,
+        entity_id: EntityId,
+        store_id: BuyingStoreId,
+        items: Vec<SellToBuyingStoreItemInformation>,
+    ) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&SellToBuyingStorePacket::new(entity_id, store_id, items))
+    }
+
+    /// Open the mailbox and request the newest mail.
+    pub fn open_mailbox(&mut self) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&OpenMailboxPacket::new(MailId(0)))
+    }
+
+    /// Request the mail that is older than `mail_id`.
+    pub fn request_next_mail_page(&mut self, mail_id: MailId) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&RequestNextMailPagePacket::new(mail_id))
+    }
+
+    pub fn close_mailbox(&mut self) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&CloseMailboxPacket::default())
+    }
+
+    pub fn read_mail(&mut self, open_type: MailOpenType, mail_id: MailId) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&ReadMailPacket::new(open_type, mail_id))
+    }
+
+    pub fn delete_mail(&mut self, open_type: MailOpenType, mail_id: MailId) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&DeleteMailPacket::new(open_type, mail_id))
+    }
+
+    pub fn retrieve_mail_zeny(&mut self, open_type: MailOpenType, mail_id: MailId) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&RetrieveMailZenyPacket::new(mail_id, open_type))
+    }
+
+    pub fn retrieve_mail_items(&mut self, open_type: MailOpenType, mail_id: MailId) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&RetrieveMailItemsPacket::new(mail_id, open_type))
+    }
+
+    pub fn start_writing_mail(&mut self) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&StartWritingMailPacket::new(String::new()))
+    }
+
+    pub fn cancel_writing_mail(&mut self) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&CancelWritingMailPacket::default())
+    }
+
+    pub fn attach_mail_item(&mut self, index: InventoryIndex, amount: u16) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&AddMailItemPacket::new(index, amount))
+    }
+
+    pub fn detach_mail_item(&mut self, index: InventoryIndex, amount: u16) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&RemoveMailItemPacket::new(index, amount))
+    }
+
+    /// The receiver of a mail needs to be checked before the mail can be sent.
+    pub fn check_mail_receiver(&mut self, receiver_name: &str) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&CheckMailReceiverPacket::new(receiver_name.to_owned()))
+    }
+
+    pub fn send_mail(
+        &mut self,
+        receiver_name: &str,
+        receiver_id: CharacterId,
+        sender_name: &str,
+        title: &str,
+        text: &str,
+        zeny: u64,
+    ) -> Result<(), NotConnectedError> {
+        // The lengths include the null terminator.
+        let title_length = title.len() as u16 + 1;
+        let text_length = text.len() as u16 + 1;
+
+        self.send_map_server_packet(&SendMailPacket::new(
+            receiver_name.to_owned(),
+            sender_name.to_owned(),
+            zeny,
+            title_length,
+            text_length,
+            receiver_id,
+            title.to_owned(),
+            text.to_owned(),
+        ))
+    }
+
+    pub fn request_stat_up(&mut self, stat_type: StatType) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&RequestStatUpPacket::new(stat_type, 1))
+    }
+
+    pub fn request_skill_up(&mut self, skill_id: SkillId) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&RequestSkillUpPacket::new(skill_id))
+    }
+}
+
+/// Remove the wire offset from the indices of a received item list. Items with
+/// an index below the offset are dropped.
+fn convert_item_indices<Index>(
+    items: Vec<InventoryItem<NoMetadata, u16>>,
+    offset: u16,
+    index: impl Fn(u16) -> Index,
+) -> Vec<InventoryItem<NoMetadata, Index>> {
+    items
+        .into_iter()
+        .filter_map(|item| {
+            let raw_index = item.index.checked_sub(offset)?;
+            Some(item.with_index(index(raw_index)))
+        })
+        .collect()
 }
 
 #[cfg(test)]
 mod packet_handlers {
     use ragnarok_packets::handler::NoPacketCallback;
+    use ragnarok_packets::{PacketProfile, PacketVersion};
 
     use crate::NetworkingSystem;
 
     #[test]
     fn login_server() {
-        let result = NetworkingSystem::create_login_server_packet_handler(NoPacketCallback);
+        let result = NetworkingSystem::create_login_server_packet_handler(NoPacketCallback, PacketProfile::default());
         assert!(result.is_ok());
     }
 
     #[test]
     fn character_server() {
-        let result = NetworkingSystem::create_character_server_packet_handler(NoPacketCallback);
+        let result = NetworkingSystem::create_character_server_packet_handler(NoPacketCallback, PacketProfile::default());
         assert!(result.is_ok());
     }
 
     #[test]
     fn map_server() {
-        let result = NetworkingSystem::create_map_server_packet_handler(NoPacketCallback);
+        let result = NetworkingSystem::create_map_server_packet_handler(NoPacketCallback, PacketProfile::default());
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn map_server_old_packet_version() {
+        let packet_profile = PacketProfile::new(PacketVersion(20071106));
+        let result = NetworkingSystem::create_map_server_packet_handler(NoPacketCallback, packet_profile);
         assert!(result.is_ok());
     }
 }
@@ -0,0 +1,75 @@
+use ragnarok_packets::{AddMailItemResultPacket, ItemId, MailAttachmentFlags, MailId, MailInformation, MailItemInformation, MailOpenType};
+
+use crate::items::NoMetadata;
+
+/// Entry in the mailbox of the player. The contents of a mail are only sent
+/// once it is read.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct MailSummary {
+    pub mail_id: MailId,
+    pub open_type: MailOpenType,
+    pub sender_name: String,
+    pub title: String,
+    pub is_read: bool,
+    pub has_zeny: bool,
+    pub has_items: bool,
+    /// Seconds until the mail expires.
+    pub expires_in: u32,
+}
+
+impl From<MailInformation> for MailSummary {
+    fn from(mail: MailInformation) -> Self {
+        Self {
+            mail_id: mail.mail_id,
+            open_type: mail.open_type,
+            sender_name: mail.sender_name,
+            title: mail.title,
+            is_read: mail.is_read != 0,
+            has_zeny: mail.attachments.contains(MailAttachmentFlags::ZENY),
+            has_items: mail.attachments.contains(MailAttachmentFlags::ITEM),
+            expires_in: mail.expires_in,
+        }
+    }
+}
+
+/// Item attached to a mail.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct MailItem<Meta> {
+    pub metadata: Meta,
+    pub item_id: ItemId,
+    pub item_type: u8,
+    pub amount: u16,
+    pub is_identified: bool,
+    pub refinement_level: u8,
+    pub slot: [u32; 4], // card ?
+}
+
+impl From<MailItemInformation> for MailItem<NoMetadata> {
+    fn from(item: MailItemInformation) -> Self {
+        Self {
+            metadata: NoMetadata,
+            item_id: item.item_id,
+            item_type: item.item_type,
+            amount: item.amount,
+            is_identified: item.identified != 0,
+            refinement_level: item.refinement_level,
+            slot: item.slot,
+        }
+    }
+}
+
+impl From<&AddMailItemResultPacket> for MailItem<NoMetadata> {
+    fn from(packet: &AddMailItemResultPacket) -> Self {
+        Self {
+            metadata: NoMetadata,
+            item_id: packet.item_id,
+            item_type: packet.item_type,
+            amount: packet.amount,
+            is_identified: packet.identified != 0,
+            refinement_level: packet.refinement_level,
+            slot: packet.slot,
+        }
+    }
+}
@@ -1,8 +1,21 @@
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum MessageColor {
     Rgb { red: u8, green: u8, blue: u8 },
     Broadcast,
     Server,
     Error,
     Information,
+    Party,
+    Guild,
+    Whisper,
+}
+
+/// Reason why a private message could not be delivered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum WhisperFailedReason {
+    RecipientOffline,
+    IgnoredByRecipient,
+    RecipientIgnoresEveryone,
 }
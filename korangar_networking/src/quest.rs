@@ -0,0 +1,75 @@
+use ragnarok_packets::{ObjectiveDetails1, Quest, QuestDetails, QuestNotificationPacket1};
+
+/// Monster that has to be hunted to complete a quest.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct QuestObjectiveData {
+    pub hunt_identification: u32,
+    pub mob_id: u32,
+    pub mob_name: String,
+    pub current_count: u16,
+    /// Zero until the server sends the amount of monsters to hunt.
+    pub total_count: u16,
+}
+
+impl From<QuestDetails> for QuestObjectiveData {
+    fn from(details: QuestDetails) -> Self {
+        Self {
+            hunt_identification: details.hunt_identification,
+            mob_id: details.mob_id,
+            mob_name: details.mob_name,
+            current_count: details.kill_count,
+            total_count: details.total_count,
+        }
+    }
+}
+
+impl From<ObjectiveDetails1> for QuestObjectiveData {
+    fn from(details: ObjectiveDetails1) -> Self {
+        Self {
+            hunt_identification: details.hunt_identification,
+            mob_id: details.mob_id,
+            mob_name: details.mob_name,
+            current_count: details.mob_count,
+            total_count: 0,
+        }
+    }
+}
+
+/// Quest in the quest log of the player.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct QuestData {
+    pub quest_id: u32,
+    pub active: bool,
+    /// Unix timestamp of when the quest expires, zero if it doesn't.
+    pub expire_time: u32,
+    pub objectives: Vec<QuestObjectiveData>,
+}
+
+impl From<Quest> for QuestData {
+    fn from(quest: Quest) -> Self {
+        Self {
+            quest_id: quest.quest_id,
+            active: quest.active != 0,
+            expire_time: quest.expire_time,
+            objectives: quest.objective_details.into_iter().map(QuestObjectiveData::from).collect(),
+        }
+    }
+}
+
+impl From<QuestNotificationPacket1> for QuestData {
+    fn from(packet: QuestNotificationPacket1) -> Self {
+        Self {
+            quest_id: packet.quest_id,
+            active: packet.active != 0,
+            expire_time: packet.expire_time,
+            objectives: packet
+                .objective_details
+                .into_iter()
+                .take(packet.objective_count as usize)
+                .map(QuestObjectiveData::from)
+                .collect(),
+        }
+    }
+}
@@ -0,0 +1,162 @@
+use std::net::SocketAddr;
+use std::time::{Duration, Instant};
+
+use ragnarok_packets::CharacterServerInformation;
+
+use crate::server::LoginServerLoginData;
+
+/// Controls how the [`NetworkingSystem`](crate::NetworkingSystem) tries to
+/// get back into the game after the connection to the map server was lost.
+///
+/// Every failed attempt multiplies the delay before the next one by
+/// `backoff_factor`, up to `maximum_delay`. An attempt that didn't make it back
+/// into the game after `attempt_timeout` counts as failed.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ReconnectPolicy {
+    pub(crate) maximum_attempts: u32,
+    pub(crate) initial_delay: Duration,
+    pub(crate) maximum_delay: Duration,
+    pub(crate) backoff_factor: f32,
+    pub(crate) attempt_timeout: Duration,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InvalidReconnectPolicyError {
+    /// The backoff factor is not a finite number of at least 1.
+    BackoffFactor,
+    /// Attempts would time out before they could possibly succeed.
+    ZeroAttemptTimeout,
+}
+
+impl ReconnectPolicy {
+    pub const DISABLED: Self = Self {
+        maximum_attempts: 0,
+        initial_delay: Duration::ZERO,
+        maximum_delay: Duration::ZERO,
+        backoff_factor: 1.0,
+        attempt_timeout: Duration::ZERO,
+    };
+
+    /// Create a new policy. A `maximum_attempts` of 0 disables reconnecting.
+    pub fn new(
+        maximum_attempts: u32,
+        initial_delay: Duration,
+        maximum_delay: Duration,
+        backoff_factor: f32,
+        attempt_timeout: Duration,
+    ) -> Result<Self, InvalidReconnectPolicyError> {
+        if !backoff_factor.is_finite() || backoff_factor < 1.0 {
+            return Err(InvalidReconnectPolicyError::BackoffFactor);
+        }
+
+        if maximum_attempts > 0 && attempt_timeout.is_zero() {
+            return Err(InvalidReconnectPolicyError::ZeroAttemptTimeout);
+        }
+
+        Ok(Self {
+            maximum_attempts,
+            initial_delay,
+            maximum_delay,
+            backoff_factor,
+            attempt_timeout,
+        })
+    }
+
+    /// Delay before the given attempt, starting at 1.
+    pub fn delay_for_attempt(&self, attempt: u32) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(i32::MAX as u32) as i32;
+        let delay = self.initial_delay.as_secs_f32() * self.backoff_factor.powi(exponent);
+
+        Duration::from_secs_f32(delay.min(self.maximum_delay.as_secs_f32()))
+    }
+}
+
+impl Default for ReconnectPolicy {
+    fn default() -> Self {
+        Self {
+            maximum_attempts: 5,
+            initial_delay: Duration::from_millis(500),
+            maximum_delay: Duration::from_secs(8),
+            backoff_factor: 2.0,
+            attempt_timeout: Duration::from_secs(10),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct LoginCredentials {
+    pub address: SocketAddr,
+    pub username: String,
+    pub password: String,
+}
+
+/// Everything needed to go through the login and character servers again.
+/// The login ids handed out by the login server are only valid once, so the
+/// map server can't be entered with the old ones.
+#[derive(Debug, Clone)]
+pub(crate) struct MapServerSession {
+    pub credentials: LoginCredentials,
+    pub character_server: CharacterServerInformation,
+    pub character_slot: usize,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum ReconnectStage {
+    /// Waiting for the delay before the next attempt to pass.
+    Waiting {
+        attempt_at: Instant,
+    },
+    LoggingIn,
+    SelectingCharacter {
+        login_data: LoginServerLoginData,
+    },
+    EnteringMap {
+        login_data: LoginServerLoginData,
+    },
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ReconnectState {
+    pub attempt: u32,
+    pub stage: ReconnectStage,
+    /// When the attempt in progress is given up. `None` while waiting.
+    pub deadline: Option<Instant>,
+}
+
+#[cfg(test)]
+mod backoff {
+    use std::time::Duration;
+
+    use super::{InvalidReconnectPolicyError, ReconnectPolicy};
+
+    #[test]
+    fn grows_exponentially() {
+        let policy = ReconnectPolicy::new(5, Duration::from_secs(1), Duration::from_secs(60), 2.0, Duration::from_secs(10)).unwrap();
+
+        assert_eq!(policy.delay_for_attempt(1), Duration::from_secs(1));
+        assert_eq!(policy.delay_for_attempt(2), Duration::from_secs(2));
+        assert_eq!(policy.delay_for_attempt(4), Duration::from_secs(8));
+    }
+
+    #[test]
+    fn capped_at_maximum_delay() {
+        let policy = ReconnectPolicy::default();
+
+        assert_eq!(policy.delay_for_attempt(u32::MAX), policy.maximum_delay);
+    }
+
+    #[test]
+    fn rejects_invalid_backoff_factor() {
+        for backoff_factor in [-2.0, 0.5, f32::NAN, f32::INFINITY] {
+            let policy = ReconnectPolicy::new(
+                5,
+                Duration::from_secs(1),
+                Duration::from_secs(60),
+                backoff_factor,
+                Duration::from_secs(10),
+            );
+
+            assert_eq!(policy, Err(InvalidReconnectPolicyError::BackoffFactor));
+        }
+    }
+}
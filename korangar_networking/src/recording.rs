@@ -0,0 +1,418 @@
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter, Read, Write};
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use ragnarok_bytes::ConversionError;
+use ragnarok_packets::handler::{PacketCallback, ServerType};
+use ragnarok_packets::{PacketHeader, PacketProfile, PacketVersion};
+
+const MAGIC: &[u8; 4] = b"KPRC";
+const VERSION: u8 = 2;
+
+const CONNECTION_ENTRY: u8 = 0;
+const PACKET_ENTRY: u8 = 1;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PacketDirection {
+    /// Sent by the server to the client.
+    Incoming,
+    /// Sent by the client to the server.
+    Outgoing,
+}
+
+/// A single packet captured by the [`PacketRecorder`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PacketRecord {
+    /// Time since the recording started.
+    pub timestamp: Duration,
+    pub server_type: ServerType,
+    pub direction: PacketDirection,
+    /// The packet including its header.
+    pub bytes: Vec<u8>,
+}
+
+/// A single connection captured by the [`PacketRecorder`] and all the packets
+/// that were sent and received over it.
+#[derive(Debug, Clone)]
+pub struct RecordedConnection {
+    /// Time since the recording started.
+    pub timestamp: Duration,
+    pub server_type: ServerType,
+    /// The profile that the client used for the connection.
+    pub packet_profile: PacketProfile,
+    pub records: Vec<PacketRecord>,
+}
+
+enum RecordingEntry {
+    Connection(RecordedConnection),
+    Packet(PacketRecord),
+}
+
+fn write_connection(
+    writer: &mut impl Write,
+    timestamp: Duration,
+    server_type: ServerType,
+    packet_profile: &PacketProfile,
+) -> io::Result<()> {
+    let header_overrides = packet_profile.header_overrides();
+
+    writer.write_all(&[CONNECTION_ENTRY])?;
+    writer.write_all(&(timestamp.as_micros() as u64).to_le_bytes())?;
+    writer.write_all(&[server_type_to_byte(server_type)])?;
+    writer.write_all(&packet_profile.version().0.to_le_bytes())?;
+    writer.write_all(&(header_overrides.len() as u16).to_le_bytes())?;
+
+    for (original, header) in header_overrides {
+        writer.write_all(&original.0.to_le_bytes())?;
+        writer.write_all(&header.0.to_le_bytes())?;
+    }
+
+    Ok(())
+}
+
+fn write_packet(writer: &mut impl Write, record: &PacketRecord) -> io::Result<()> {
+    let direction = match record.direction {
+        PacketDirection::Incoming => 0u8,
+        PacketDirection::Outgoing => 1,
+    };
+
+    writer.write_all(&[PACKET_ENTRY])?;
+    writer.write_all(&(record.timestamp.as_micros() as u64).to_le_bytes())?;
+    writer.write_all(&[server_type_to_byte(record.server_type), direction])?;
+    writer.write_all(&(record.bytes.len() as u32).to_le_bytes())?;
+    writer.write_all(&record.bytes)
+}
+
+fn server_type_to_byte(server_type: ServerType) -> u8 {
+    match server_type {
+        ServerType::Login => 0,
+        ServerType::Character => 1,
+        ServerType::Map => 2,
+    }
+}
+
+fn server_type_from_byte(byte: u8) -> io::Result<ServerType> {
+    match byte {
+        0 => Ok(ServerType::Login),
+        1 => Ok(ServerType::Character),
+        2 => Ok(ServerType::Map),
+        invalid => Err(invalid_data(format!("invalid server type {invalid}"))),
+    }
+}
+
+fn read_array<const SIZE: usize>(reader: &mut impl Read) -> io::Result<[u8; SIZE]> {
+    let mut bytes = [0; SIZE];
+    reader.read_exact(&mut bytes)?;
+    Ok(bytes)
+}
+
+/// Returns `Ok(None)` at the end of the recording.
+fn read_entry(reader: &mut impl Read) -> io::Result<Option<RecordingEntry>> {
+    let mut kind = [0; 1];
+
+    match reader.read_exact(&mut kind) {
+        Ok(()) => {}
+        Err(error) if error.kind() == io::ErrorKind::UnexpectedEof => return Ok(None),
+        Err(error) => return Err(error),
+    }
+
+    let timestamp = Duration::from_micros(u64::from_le_bytes(read_array(reader)?));
+    let server_type = server_type_from_byte(read_array::<1>(reader)?[0])?;
+
+    match kind[0] {
+        CONNECTION_ENTRY => {
+            let version = PacketVersion(u32::from_le_bytes(read_array(reader)?));
+            let override_count = u16::from_le_bytes(read_array(reader)?);
+            let mut packet_profile = PacketProfile::new(version);
+
+            for _ in 0..override_count {
+                let original = PacketHeader(u16::from_le_bytes(read_array(reader)?));
+                let header = PacketHeader(u16::from_le_bytes(read_array(reader)?));
+                packet_profile = packet_profile.with_raw_header_override(original, header);
+            }
+
+            Ok(Some(RecordingEntry::Connection(RecordedConnection {
+                timestamp,
+                server_type,
+                packet_profile,
+                records: Vec::new(),
+            })))
+        }
+        PACKET_ENTRY => {
+            let direction = match read_array::<1>(reader)?[0] {
+                0 => PacketDirection::Incoming,
+                1 => PacketDirection::Outgoing,
+                invalid => return Err(invalid_data(format!("invalid packet direction {invalid}"))),
+            };
+
+            let length = u32::from_le_bytes(read_array(reader)?);
+            let mut bytes = vec![0; length as usize];
+            reader.read_exact(&mut bytes)?;
+
+            Ok(Some(RecordingEntry::Packet(PacketRecord {
+                timestamp,
+                server_type,
+                direction,
+                bytes,
+            })))
+        }
+        invalid => Err(invalid_data(format!("invalid entry kind {invalid}"))),
+    }
+}
+
+fn invalid_data(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+/// Read all connections of a recording created by the [`PacketRecorder`], in
+/// the order they were made.
+pub fn read_recording(reader: impl Read) -> io::Result<Vec<RecordedConnection>> {
+    let mut reader = BufReader::new(reader);
+    let mut header = [0; 5];
+
+    reader.read_exact(&mut header)?;
+
+    if &header[..4] != MAGIC {
+        return Err(invalid_data("not a packet recording".to_owned()));
+    }
+
+    if header[4] != VERSION {
+        return Err(invalid_data(format!("unsupported recording version {}", header[4])));
+    }
+
+    let mut connections: Vec<RecordedConnection> = Vec::new();
+
+    while let Some(entry) = read_entry(&mut reader)? {
+        match entry {
+            RecordingEntry::Connection(connection) => connections.push(connection),
+            RecordingEntry::Packet(record) => {
+                // Packets belong to the most recent connection to their server.
+                let connection = connections
+                    .iter_mut()
+                    .rev()
+                    .find(|connection| connection.server_type == record.server_type)
+                    .ok_or_else(|| invalid_data("packet recorded before connecting".to_owned()))?;
+
+                connection.records.push(record);
+            }
+        }
+    }
+
+    Ok(connections)
+}
+
+/// Load a recording from a file.
+pub fn load_recording(path: impl AsRef<Path>) -> io::Result<Vec<RecordedConnection>> {
+    read_recording(File::open(path)?)
+}
+
+/// [`PacketCallback`] that writes every incoming and outgoing packet to a
+/// recording, which can be played back with the
+/// [`ReplayServer`](crate::ReplayServer).
+///
+/// Packets are recorded exactly as they were sent over the wire, including
+/// unknown and failed ones, so replaying them will trigger the same errors.
+/// Every connection is recorded together with the [`PacketProfile`] that the
+/// client used for it.
+#[derive(Clone)]
+pub struct PacketRecorder {
+    writer: Arc<Mutex<Box<dyn Write + Send>>>,
+    start: Instant,
+    /// Only set on the copies handed out by [`PacketCallback::for_server`].
+    server_type: Option<ServerType>,
+}
+
+impl PacketRecorder {
+    pub fn new(writer: impl Write + Send + 'static) -> io::Result<Self> {
+        let mut writer: Box<dyn Write + Send> = Box::new(writer);
+
+        writer.write_all(MAGIC)?;
+        writer.write_all(&[VERSION])?;
+
+        Ok(Self {
+            writer: Arc::new(Mutex::new(writer)),
+            start: Instant::now(),
+            server_type: None,
+        })
+    }
+
+    pub fn create(path: impl AsRef<Path>) -> io::Result<Self> {
+        Self::new(BufWriter::new(File::create(path)?))
+    }
+
+    fn write_entry(&self, write: impl FnOnce(&mut dyn Write, ServerType, Duration) -> io::Result<()>) {
+        let Some(server_type) = self.server_type else {
+            return;
+        };
+
+        let mut writer = self.writer.lock().unwrap();
+
+        // A callback has no way of reporting errors, so a failed write just leaves
+        // a truncated recording. Flushing after every entry makes sure that we
+        // still have everything up to a crash.
+        let _ = write(&mut *writer, server_type, self.start.elapsed()).and_then(|_| writer.flush());
+    }
+
+    fn record(&self, direction: PacketDirection, bytes: Vec<u8>) {
+        self.write_entry(|mut writer, server_type, timestamp| {
+            let record = PacketRecord {
+                timestamp,
+                server_type,
+                direction,
+                bytes,
+            };
+
+            write_packet(&mut writer, &record)
+        });
+    }
+}
+
+impl PacketCallback for PacketRecorder {
+    fn for_server(&self, server_type: ServerType) -> Self {
+        Self {
+            server_type: Some(server_type),
+            ..self.clone()
+        }
+    }
+
+    fn incoming_bytes(&self, bytes: &[u8]) {
+        self.record(PacketDirection::Incoming, bytes.to_vec());
+    }
+
+    fn connected(&self, packet_profile: &PacketProfile) {
+        self.write_entry(|mut writer, server_type, timestamp| write_connection(&mut writer, timestamp, server_type, packet_profile));
+    }
+
+    fn outgoing_bytes(&self, bytes: &[u8]) {
+        self.record(PacketDirection::Outgoing, bytes.to_vec());
+    }
+
+    fn unknown_packet(&self, bytes: Vec<u8>) {
+        self.record(PacketDirection::Incoming, bytes);
+    }
+
+    fn failed_packet(&self, bytes: Vec<u8>, error: Box<ConversionError>) {
+        let _ = error;
+        self.record(PacketDirection::Incoming, bytes);
+    }
+}
+
+#[cfg(test)]
+mod round_trip {
+    use std::io::Write;
+    use std::sync::{Arc, Mutex};
+
+    use ragnarok_bytes::ByteStream;
+    use ragnarok_packets::handler::{HandlerResult, PacketCallback, PacketHandler, ServerType};
+    use ragnarok_packets::{
+        ClientTick, MapServerLoginSuccessPacket, PacketExt, PacketHeader, PacketProfile, PacketVersion, RequestServerTickPacket,
+    };
+
+    use super::{read_recording, PacketDirection, PacketRecorder};
+
+    #[derive(Clone, Default)]
+    struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for SharedBuffer {
+        fn write(&mut self, bytes: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().write(bytes)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn records_are_read_back() {
+        let buffer = SharedBuffer::default();
+        let recorder = PacketRecorder::new(buffer.clone()).unwrap();
+        let map_recorder = recorder.for_server(ServerType::Map);
+        let login_recorder = recorder.for_server(ServerType::Login);
+        let bytes = RequestServerTickPacket::new(ClientTick(100)).packet_to_bytes().unwrap();
+
+        map_recorder.connected(&PacketProfile::default());
+        map_recorder.outgoing_bytes(&bytes);
+        login_recorder.connected(&PacketProfile::default());
+        login_recorder.unknown_packet(vec![1, 2, 3]);
+
+        let connections = read_recording(buffer.0.lock().unwrap().as_slice()).unwrap();
+
+        assert_eq!(connections.len(), 2);
+        assert_eq!(connections[0].server_type, ServerType::Map);
+        assert_eq!(connections[0].records.len(), 1);
+        assert_eq!(connections[0].records[0].direction, PacketDirection::Outgoing);
+        assert_eq!(connections[0].records[0].bytes, bytes);
+        assert_eq!(connections[1].server_type, ServerType::Login);
+        assert_eq!(connections[1].records.len(), 1);
+        assert_eq!(connections[1].records[0].direction, PacketDirection::Incoming);
+        assert_eq!(connections[1].records[0].bytes, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn packets_without_server_are_ignored() {
+        let buffer = SharedBuffer::default();
+        let recorder = PacketRecorder::new(buffer.clone()).unwrap();
+
+        recorder.connected(&PacketProfile::default());
+        recorder.unknown_packet(vec![1, 2, 3]);
+
+        let connections = read_recording(buffer.0.lock().unwrap().as_slice()).unwrap();
+        assert!(connections.is_empty());
+    }
+
+    #[test]
+    fn connections_keep_packet_profile() {
+        let buffer = SharedBuffer::default();
+        let recorder = PacketRecorder::new(buffer.clone()).unwrap();
+        let packet_profile =
+            PacketProfile::new(PacketVersion(20071106)).with_header_override::<RequestServerTickPacket>(PacketHeader(0x1234));
+
+        recorder.for_server(ServerType::Map).connected(&packet_profile);
+
+        let connections = read_recording(buffer.0.lock().unwrap().as_slice()).unwrap();
+        let recorded_profile = &connections[0].packet_profile;
+
+        assert_eq!(recorded_profile.version(), PacketVersion(20071106));
+        assert_eq!(recorded_profile.header::<RequestServerTickPacket>(), PacketHeader(0x1234));
+        assert_eq!(
+            recorded_profile.header::<MapServerLoginSuccessPacket>(),
+            packet_profile.header::<MapServerLoginSuccessPacket>()
+        );
+    }
+
+    #[test]
+    fn packets_before_connecting_are_rejected() {
+        let buffer = SharedBuffer::default();
+        let recorder = PacketRecorder::new(buffer.clone()).unwrap();
+
+        recorder.for_server(ServerType::Map).unknown_packet(vec![1, 2, 3]);
+
+        assert!(read_recording(buffer.0.lock().unwrap().as_slice()).is_err());
+    }
+
+    #[test]
+    fn incoming_packets_are_recorded_as_received() {
+        let buffer = SharedBuffer::default();
+        let packet_profile = PacketProfile::new(PacketVersion(20071106));
+        let recorder = PacketRecorder::new(buffer.clone()).unwrap().for_server(ServerType::Map);
+        recorder.connected(&packet_profile);
+        let mut packet_handler = PacketHandler::<(), PacketVersion, _>::with_profile(recorder, packet_profile);
+        packet_handler.register_noop::<MapServerLoginSuccessPacket>().unwrap();
+
+        // The low nibble of the last position byte is the direction, which is not
+        // kept when parsing the packet.
+        let bytes = [0x73, 0x00, 100, 0, 0, 0, 2, 128, 0x03, 5, 5];
+        let mut byte_stream = ByteStream::with_metadata(&bytes, PacketVersion(20071106));
+
+        assert!(matches!(packet_handler.process_one(&mut byte_stream), HandlerResult::Ok(())));
+
+        let records = &read_recording(buffer.0.lock().unwrap().as_slice()).unwrap()[0].records;
+
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].direction, PacketDirection::Incoming);
+        assert_eq!(records[0].bytes, bytes);
+    }
+}
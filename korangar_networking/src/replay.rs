@@ -0,0 +1,265 @@
+use std::io;
+use std::net::{Ipv4Addr, SocketAddr};
+use std::time::Duration;
+
+use ragnarok_bytes::{ByteStream, FromBytes, ToBytes};
+use ragnarok_packets::handler::ServerType;
+use ragnarok_packets::{
+    AccountId, CharacterSelectionSuccessPacket, LoginServerLoginSuccessPacket, Packet, PacketExt, PacketHeader, PacketProfile,
+    ServerAddress,
+};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::task::JoinSet;
+use tokio::time::Instant;
+
+use crate::recording::{PacketDirection, PacketRecord, RecordedConnection};
+
+/// Where the [`ReplayServer`] listens for the client.
+#[derive(Debug, Clone, Copy)]
+pub struct ReplayAddresses {
+    pub ip: Ipv4Addr,
+    pub login_port: u16,
+    pub character_port: u16,
+    pub map_port: u16,
+}
+
+impl Default for ReplayAddresses {
+    fn default() -> Self {
+        Self {
+            ip: Ipv4Addr::LOCALHOST,
+            login_port: 6900,
+            character_port: 6121,
+            map_port: 5121,
+        }
+    }
+}
+
+/// Serves a recording created by the
+/// [`PacketRecorder`](crate::PacketRecorder) back to a client.
+///
+/// The server accepts one connection for every recorded connection and
+/// replays its incoming packets with their original timing, starting once the
+/// client sent its login packet. Packets sent by the client are otherwise
+/// ignored, so the replay is the same no matter what the client does. A
+/// connection that was followed by another one to the same server is closed
+/// by the server when the next connection was made in the recording, so
+/// reconnects play out like they did while recording.
+///
+/// Server addresses in the recorded packets are replaced with the addresses
+/// of the replay server, so the client connects to the replay server for
+/// every step.
+pub struct ReplayServer {
+    connections: Vec<RecordedConnection>,
+    addresses: ReplayAddresses,
+}
+
+impl ReplayServer {
+    pub fn new(connections: Vec<RecordedConnection>, addresses: ReplayAddresses) -> Self {
+        Self { connections, addresses }
+    }
+
+    pub async fn run(self) -> io::Result<()> {
+        let Self { connections, addresses } = self;
+
+        let login_listener = TcpListener::bind(SocketAddr::from((addresses.ip, addresses.login_port))).await?;
+        let character_listener = TcpListener::bind(SocketAddr::from((addresses.ip, addresses.character_port))).await?;
+        let map_listener = TcpListener::bind(SocketAddr::from((addresses.ip, addresses.map_port))).await?;
+
+        // After logging in, the character server sends the account id without any
+        // packet, so the recorder never sees it. We can take it from the login
+        // server response instead.
+        let account_id = connections
+            .iter()
+            .filter(|connection| connection.server_type == ServerType::Login)
+            .find_map(|connection| {
+                connection
+                    .records
+                    .iter()
+                    .find_map(|record| parse_packet::<LoginServerLoginSuccessPacket>(&record.bytes, &connection.packet_profile))
+            })
+            .map(|packet| packet.account_id)
+            .unwrap_or(AccountId(0));
+
+        let sessions_for = |server_type: ServerType| {
+            connections
+                .iter()
+                .filter(|connection| connection.server_type == server_type)
+                .map(|connection| {
+                    let records = connection
+                        .records
+                        .iter()
+                        .map(|record| PacketRecord {
+                            bytes: rewrite_addresses(&record.bytes, &addresses, &connection.packet_profile),
+                            ..record.clone()
+                        })
+                        .collect();
+
+                    RecordedConnection {
+                        records,
+                        ..connection.clone()
+                    }
+                })
+                .collect()
+        };
+
+        tokio::try_join!(
+            serve(login_listener, sessions_for(ServerType::Login), Vec::new()),
+            serve(
+                character_listener,
+                sessions_for(ServerType::Character),
+                account_id.to_bytes().unwrap()
+            ),
+            serve(map_listener, sessions_for(ServerType::Map), Vec::new()),
+        )?;
+
+        Ok(())
+    }
+}
+
+fn parse_packet<P: Packet>(bytes: &[u8], packet_profile: &PacketProfile) -> Option<P> {
+    let mut byte_stream = ByteStream::with_metadata(bytes, packet_profile.version());
+
+    match PacketHeader::from_bytes(&mut byte_stream) {
+        Ok(header) if header == packet_profile.header::<P>() => P::payload_from_bytes(&mut byte_stream).ok(),
+        _ => None,
+    }
+}
+
+fn rewrite_addresses(bytes: &[u8], addresses: &ReplayAddresses, packet_profile: &PacketProfile) -> Vec<u8> {
+    let server_address = ServerAddress(addresses.ip.octets());
+
+    if let Some(mut packet) = parse_packet::<LoginServerLoginSuccessPacket>(bytes, packet_profile) {
+        for information in &mut packet.character_server_information {
+            information.server_ip = server_address;
+            information.server_port = addresses.character_port;
+        }
+
+        return packet.packet_to_bytes_with_profile(packet_profile).unwrap();
+    }
+
+    if let Some(mut packet) = parse_packet::<CharacterSelectionSuccessPacket>(bytes, packet_profile) {
+        packet.map_server_ip = server_address;
+        packet.map_server_port = addresses.map_port;
+
+        return packet.packet_to_bytes_with_profile(packet_profile).unwrap();
+    }
+
+    bytes.to_vec()
+}
+
+async fn serve(listener: TcpListener, sessions: Vec<RecordedConnection>, greeting: Vec<u8>) -> io::Result<()> {
+    let mut connections = JoinSet::new();
+
+    // If nothing was recorded for this server, the client never connected to it.
+    for (index, session) in sessions.iter().enumerate() {
+        let (stream, _) = listener.accept().await?;
+        let base_timestamp = session_start(session);
+        let close_after = sessions
+            .get(index + 1)
+            .map(|next_session| session_start(next_session).saturating_sub(base_timestamp));
+
+        connections.spawn(replay_session(stream, session.clone(), greeting.clone(), close_after));
+    }
+
+    while let Some(result) = connections.join_next().await {
+        result.expect("replay task panicked")?;
+    }
+
+    Ok(())
+}
+
+/// Every session starts with the login packet of the client.
+fn session_start(session: &RecordedConnection) -> Duration {
+    session
+        .records
+        .iter()
+        .find(|record| record.direction == PacketDirection::Outgoing)
+        .map_or(session.timestamp, |record| record.timestamp)
+}
+
+async fn replay_session(
+    mut stream: TcpStream,
+    session: RecordedConnection,
+    greeting: Vec<u8>,
+    close_after: Option<Duration>,
+) -> io::Result<()> {
+    let mut buffer = [0u8; 8192];
+
+    // Wait for the login packet of the client before replaying anything.
+    if stream.read(&mut buffer).await? == 0 {
+        return Ok(());
+    }
+
+    let start = Instant::now();
+    let base_timestamp = session_start(&session);
+
+    stream.write_all(&greeting).await?;
+
+    for record in session
+        .records
+        .iter()
+        .filter(|record| record.direction == PacketDirection::Incoming)
+    {
+        let deadline = start + record.timestamp.saturating_sub(base_timestamp);
+
+        if !wait_until(&mut stream, &mut buffer, deadline).await? {
+            return Ok(());
+        }
+
+        stream.write_all(&record.bytes).await?;
+    }
+
+    match close_after {
+        // Dropping the stream closes the connection.
+        Some(close_after) => {
+            wait_until(&mut stream, &mut buffer, start + close_after).await?;
+        }
+        // Keep the connection open until the client closes it.
+        None => while stream.read(&mut buffer).await? != 0 {},
+    }
+
+    Ok(())
+}
+
+/// Discard everything the client sends until the deadline. Returns `false` if
+/// the client closed the connection.
+async fn wait_until(stream: &mut TcpStream, buffer: &mut [u8], deadline: Instant) -> io::Result<bool> {
+    loop {
+        tokio::select! {
+            _ = tokio::time::sleep_until(deadline) => return Ok(true),
+            received_bytes = stream.read(buffer) => {
+                if received_bytes? == 0 {
+                    return Ok(false);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod address_rewrite {
+    use std::net::Ipv4Addr;
+
+    use ragnarok_packets::{CharacterId, CharacterSelectionSuccessPacket, PacketExt, PacketHeader, PacketProfile, ServerAddress};
+
+    use super::{parse_packet, rewrite_addresses, ReplayAddresses};
+
+    #[test]
+    fn rewrite_with_packet_profile() {
+        let packet_profile = PacketProfile::default().with_header_override::<CharacterSelectionSuccessPacket>(PacketHeader(0x1234));
+        let addresses = ReplayAddresses {
+            ip: Ipv4Addr::new(10, 0, 0, 1),
+            ..Default::default()
+        };
+        let packet = CharacterSelectionSuccessPacket::new(CharacterId(1), "prontera.gat".to_owned(), ServerAddress([1, 2, 3, 4]), 5000);
+        let bytes = packet.packet_to_bytes_with_profile(&packet_profile).unwrap();
+
+        let rewritten = rewrite_addresses(&bytes, &addresses, &packet_profile);
+        let parsed = parse_packet::<CharacterSelectionSuccessPacket>(&rewritten, &packet_profile).unwrap();
+
+        assert_eq!(rewritten[..2], [0x34, 0x12]);
+        assert_eq!(parsed.map_server_ip.0, [10, 0, 0, 1]);
+        assert_eq!(parsed.map_server_port, addresses.map_port);
+    }
+}
@@ -1,11 +1,12 @@
 use std::net::{IpAddr, SocketAddr};
 
-use ragnarok_packets::{AccountId, CharacterId, Sex};
+use ragnarok_packets::{AccountId, CharacterId, PacketProfile, Sex};
 use tokio::sync::mpsc::{UnboundedReceiver, UnboundedSender};
 
 use crate::event::NetworkEvent;
 
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct LoginServerLoginData {
     pub account_id: AccountId,
     pub login_id1: u32,
@@ -14,6 +15,7 @@ pub struct LoginServerLoginData {
 }
 
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum UnifiedLoginFailedReason {
     ServerClosed,
     AlreadyLoggedIn,
@@ -30,12 +32,14 @@ pub enum UnifiedLoginFailedReason {
 }
 
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum UnifiedCharacterSelectionFailedReason {
     RejectedFromServer,
     MapServerUnavailable,
 }
 
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct CharacterServerLoginData {
     pub server_ip: IpAddr,
     pub server_port: u16,
@@ -45,16 +49,19 @@ pub struct CharacterServerLoginData {
 pub(crate) enum ServerConnectCommand {
     Login {
         address: SocketAddr,
+        packet_profile: PacketProfile,
         action_receiver: UnboundedReceiver<Vec<u8>>,
         event_sender: UnboundedSender<NetworkEvent>,
     },
     Character {
         address: SocketAddr,
+        packet_profile: PacketProfile,
         action_receiver: UnboundedReceiver<Vec<u8>>,
         event_sender: UnboundedSender<NetworkEvent>,
     },
     Map {
         address: SocketAddr,
+        packet_profile: PacketProfile,
         action_receiver: UnboundedReceiver<Vec<u8>>,
         event_sender: UnboundedSender<NetworkEvent>,
     },
@@ -0,0 +1,82 @@
+use ragnarok_packets::StatusChangePacket;
+
+/// Buff or debuff on an entity.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct StatusEffectData {
+    /// Index into the status effect tables of the client (`EFST_*`).
+    pub status_id: u16,
+    /// Total duration in milliseconds, `None` if the effect lasts until it is
+    /// removed.
+    pub duration: Option<u32>,
+    /// Remaining duration in milliseconds, `None` if the effect lasts until it
+    /// is removed.
+    pub remaining: Option<u32>,
+    pub values: [u32; 3],
+}
+
+impl From<&StatusChangePacket> for StatusEffectData {
+    fn from(packet: &StatusChangePacket) -> Self {
+        // Effects without a time limit are sent with a duration of zero.
+        let duration = (packet.duration_in_milliseconds != 0).then_some(packet.duration_in_milliseconds);
+        let remaining = duration.map(|_| packet.remaining_in_milliseconds);
+
+        Self {
+            status_id: packet.index,
+            duration,
+            remaining,
+            values: packet.value,
+        }
+    }
+}
+
+/// Body state of an entity, which is set by status effects that stop the
+/// entity from moving.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum BodyState {
+    #[default]
+    Normal,
+    Stone,
+    Frozen,
+    Stunned,
+    Sleeping,
+    /// Any other state that doesn't change how the entity is displayed.
+    Other,
+}
+
+impl From<u16> for BodyState {
+    fn from(value: u16) -> Self {
+        match value {
+            0 => Self::Normal,
+            1 => Self::Stone,
+            2 => Self::Frozen,
+            3 => Self::Stunned,
+            4 => Self::Sleeping,
+            _ => Self::Other,
+        }
+    }
+}
+
+/// Parts of the state of an entity that change how it is displayed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct EntityVisualState {
+    pub body_state: BodyState,
+    /// Set by hiding, cloaking and similar skills.
+    pub is_hidden: bool,
+}
+
+impl EntityVisualState {
+    const CHASE_WALK: u32 = 0x4000;
+    const CLOAK: u32 = 0x4;
+    const HIDE: u32 = 0x2;
+    const INVISIBLE: u32 = 0x40;
+
+    pub fn new(body_state: u16, effect_state: u32) -> Self {
+        Self {
+            body_state: BodyState::from(body_state),
+            is_hidden: effect_state & (Self::HIDE | Self::CLOAK | Self::INVISIBLE | Self::CHASE_WALK) != 0,
+        }
+    }
+}
@@ -0,0 +1,389 @@
+mod mock_server;
+
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+use std::time::Duration;
+
+use korangar_networking::{
+    CharacterServerLoginData, DisconnectReason, LoginServerLoginData, NetworkEvent, NetworkingSystem, ReconnectPolicy,
+    UnifiedLoginFailedReason,
+};
+use ragnarok_bytes::ToBytes;
+use ragnarok_packets::handler::NoPacketCallback;
+use ragnarok_packets::*;
+
+use self::mock_server::{wait_for_event, wait_for_events, MockServer, Script};
+
+const ACCOUNT_ID: AccountId = AccountId(2000000);
+const CHARACTER_ID: CharacterId = CharacterId(150000);
+
+fn login_data() -> LoginServerLoginData {
+    LoginServerLoginData {
+        account_id: ACCOUNT_ID,
+        login_id1: 1,
+        login_id2: 2,
+        sex: Sex::Male,
+    }
+}
+
+fn character_server_information(address: SocketAddr) -> CharacterServerInformation {
+    let SocketAddr::V4(address) = address else {
+        unreachable!("mock servers always listen on IPv4");
+    };
+
+    CharacterServerInformation {
+        server_ip: ServerAddress(address.ip().octets()),
+        server_port: address.port(),
+        server_name: "Mock".to_owned(),
+        user_count: 0,
+        server_type: 0,
+        display_new: 0,
+        unknown: [0; 128],
+    }
+}
+
+fn character_server_login_data(address: SocketAddr) -> CharacterServerLoginData {
+    CharacterServerLoginData {
+        server_ip: address.ip(),
+        server_port: address.port(),
+        character_id: CHARACTER_ID,
+    }
+}
+
+fn login_server_login(character_server_address: SocketAddr, login_id1: u32) -> Script {
+    Script::new()
+        .expect::<LoginServerLoginPacket>()
+        .respond(LoginServerLoginSuccessPacket::new(
+            login_id1,
+            ACCOUNT_ID,
+            2,
+            Sex::Male,
+            AuthToken([0; 17]),
+            vec![character_server_information(character_server_address)],
+        ))
+}
+
+fn character_server_selection(map_server_address: SocketAddr) -> Script {
+    Script::new()
+        .expect::<CharacterServerLoginPacket>()
+        .respond_raw(ACCOUNT_ID.to_bytes().unwrap())
+        .respond(CharacterServerLoginSuccessPacket::new(29, 9, 0, 0, 0, 9))
+        .expect::<RequestCharacterListPacket>()
+        .respond(RequestCharacterListSuccessPacket::new(Vec::new()))
+        .expect::<SelectCharacterPacket>()
+        .respond(CharacterSelectionSuccessPacket::new(
+            CHARACTER_ID,
+            "prontera.gat".to_owned(),
+            ServerAddress([127, 0, 0, 1]),
+            map_server_address.port(),
+        ))
+}
+
+fn map_server_login() -> Script {
+    Script::new()
+        .expect::<MapServerLoginPacket>()
+        .respond(MapServerLoginSuccessPacket::new(
+            ClientTick(1234),
+            WorldPosition::new(50, 60),
+            Some(0),
+        ))
+}
+
+/// Go through the login, character, and map servers the same way the client
+/// does and wait until the player is on the map.
+fn enter_map(networking_system: &mut NetworkingSystem<NoPacketCallback>, login_server_address: SocketAddr) {
+    networking_system.connect_to_login_server(login_server_address, PacketProfile::default(), "username", "password");
+
+    let events = wait_for_events(networking_system, 1);
+    let Some(NetworkEvent::LoginServerConnected {
+        character_servers,
+        login_data,
+    }) = events.into_iter().next()
+    else {
+        panic!("expected login server connected event");
+    };
+
+    networking_system.disconnect_from_login_server();
+    networking_system.connect_to_character_server(&login_data, character_servers[0].clone());
+
+    wait_for_event(networking_system, |event| {
+        matches!(event, NetworkEvent::CharacterServerConnected { .. })
+    });
+    networking_system.request_character_list().unwrap();
+    wait_for_event(networking_system, |event| matches!(event, NetworkEvent::CharacterList { .. }));
+    networking_system.select_character(0).unwrap();
+
+    let events = wait_for_event(networking_system, |event| {
+        matches!(event, NetworkEvent::CharacterSelected { .. })
+    });
+    let Some(NetworkEvent::CharacterSelected {
+        login_data: character_server_login_data,
+        ..
+    }) = events.into_iter().last()
+    else {
+        unreachable!();
+    };
+
+    networking_system.disconnect_from_character_server();
+    networking_system.connect_to_map_server(&login_data, character_server_login_data);
+
+    wait_for_event(networking_system, |event| matches!(event, NetworkEvent::SetPlayerPosition(..)));
+}
+
+/// Connect to a mock map server and wait until the login went through.
+fn log_in_to_map_server(networking_system: &mut NetworkingSystem<NoPacketCallback>, script: Script) -> MockServer {
+    let script = Script::new()
+        .expect::<MapServerLoginPacket>()
+        .respond(MapServerLoginSuccessPacket::new(
+            ClientTick(1234),
+            WorldPosition::new(50, 60),
+            Some(0),
+        ))
+        .chain(script);
+    let map_server = MockServer::start(script);
+
+    networking_system.connect_to_map_server(&login_data(), character_server_login_data(map_server.address()));
+
+    let events = wait_for_events(networking_system, 2);
+    assert!(matches!(events[0], NetworkEvent::UpdateClientTick(ClientTick(1234))));
+    assert!(matches!(
+        events[1],
+        NetworkEvent::SetPlayerPosition(WorldPosition { x: 50, y: 60 })
+    ));
+
+    map_server
+}
+
+#[test]
+fn login_server_login_succeeds() {
+    let character_server_address = SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 6121);
+    let login_server = MockServer::start(
+        Script::new()
+            .expect::<LoginServerLoginPacket>()
+            .respond(LoginServerLoginSuccessPacket::new(
+                1,
+                ACCOUNT_ID,
+                2,
+                Sex::Male,
+                AuthToken([0; 17]),
+                vec![character_server_information(character_server_address)],
+            )),
+    );
+    let mut networking_system = NetworkingSystem::spawn();
+
+    networking_system.connect_to_login_server(login_server.address(), PacketProfile::default(), "username", "password");
+
+    let events = wait_for_events(&mut networking_system, 1);
+    login_server.finish();
+
+    let NetworkEvent::LoginServerConnected {
+        character_servers,
+        login_data,
+    } = &events[0]
+    else {
+        panic!("expected login server connected event but got {events:?}");
+    };
+
+    assert_eq!(character_servers.len(), 1);
+    assert_eq!(character_servers[0].server_name, "Mock");
+    assert_eq!(character_servers[0].server_port, 6121);
+    assert_eq!(login_data.account_id, ACCOUNT_ID);
+    assert_eq!(login_data.login_id1, 1);
+    assert_eq!(login_data.login_id2, 2);
+}
+
+#[test]
+fn login_server_login_fails() {
+    let login_server = MockServer::start(
+        Script::new()
+            .expect::<LoginServerLoginPacket>()
+            .respond(LoginFailedPacket2::new(LoginFailedReason2::IncorrectPassword)),
+    );
+    let mut networking_system = NetworkingSystem::spawn();
+
+    networking_system.connect_to_login_server(login_server.address(), PacketProfile::default(), "username", "wrong password");
+
+    let events = wait_for_events(&mut networking_system, 1);
+    login_server.finish();
+
+    assert!(matches!(events[0], NetworkEvent::LoginServerConnectionFailed {
+        reason: UnifiedLoginFailedReason::IncorrectPassword,
+        ..
+    }));
+}
+
+#[test]
+fn character_server_select_character() {
+    let character_server = MockServer::start(
+        Script::new()
+            .expect::<CharacterServerLoginPacket>()
+            .respond_raw(ACCOUNT_ID.to_bytes().unwrap())
+            .respond(CharacterServerLoginSuccessPacket::new(29, 9, 0, 0, 0, 9))
+            .expect::<RequestCharacterListPacket>()
+            .respond(RequestCharacterListSuccessPacket::new(Vec::new()))
+            .expect::<SelectCharacterPacket>()
+            .respond(CharacterSelectionSuccessPacket::new(
+                CHARACTER_ID,
+                "prontera.gat".to_owned(),
+                ServerAddress([127, 0, 0, 1]),
+                5121,
+            )),
+    );
+    let mut networking_system = NetworkingSystem::spawn();
+
+    networking_system.connect_to_character_server(&login_data(), character_server_information(character_server.address()));
+
+    let events = wait_for_events(&mut networking_system, 2);
+    assert!(matches!(events[0], NetworkEvent::AccountId(ACCOUNT_ID)));
+    assert!(matches!(events[1], NetworkEvent::CharacterServerConnected {
+        normal_slot_count: 9
+    }));
+
+    networking_system.request_character_list().unwrap();
+
+    let events = wait_for_events(&mut networking_system, 1);
+    assert!(matches!(&events[0], NetworkEvent::CharacterList { characters } if characters.is_empty()));
+
+    networking_system.select_character(0).unwrap();
+
+    let events = wait_for_events(&mut networking_system, 1);
+    character_server.finish();
+
+    let NetworkEvent::CharacterSelected { login_data, map_name } = &events[0] else {
+        panic!("expected character selected event but got {events:?}");
+    };
+
+    assert_eq!(map_name, "prontera");
+    assert_eq!(login_data.character_id, CHARACTER_ID);
+    assert_eq!(login_data.server_ip, IpAddr::V4(Ipv4Addr::LOCALHOST));
+    assert_eq!(login_data.server_port, 5121);
+}
+
+#[test]
+fn map_server_player_move() {
+    let mut networking_system = NetworkingSystem::spawn();
+    let map_server = log_in_to_map_server(
+        &mut networking_system,
+        Script::new()
+            .expect::<RequestPlayerMovePacket>()
+            .respond(PlayerMovePacket::new(ClientTick(2000), WorldPosition2 {
+                x1: 50,
+                y1: 60,
+                x2: 55,
+                y2: 62,
+            })),
+    );
+
+    networking_system.player_move(WorldPosition::new(55, 62)).unwrap();
+
+    let events = wait_for_events(&mut networking_system, 1);
+    map_server.finish();
+
+    assert!(matches!(
+        events[0],
+        NetworkEvent::PlayerMove(WorldPosition { x: 50, y: 60 }, WorldPosition { x: 55, y: 62 }, ClientTick(2000))
+    ));
+}
+
+#[test]
+fn map_server_connection_lost() {
+    let mut networking_system = NetworkingSystem::spawn();
+    networking_system.set_reconnect_policy(ReconnectPolicy::DISABLED);
+
+    // Closing the connection on the next packet makes sure that the login events
+    // are already consumed.
+    let map_server = log_in_to_map_server(
+        &mut networking_system,
+        Script::new().expect::<RequestPlayerMovePacket>().close(),
+    );
+
+    networking_system.player_move(WorldPosition::new(55, 62)).unwrap();
+
+    let events = wait_for_events(&mut networking_system, 1);
+    map_server.finish();
+
+    assert!(matches!(events[0], NetworkEvent::MapServerDisconnected {
+        reason: DisconnectReason::ConnectionError
+    }));
+}
+
+#[test]
+fn map_server_reconnects_after_connection_loss() {
+    let map_server = MockServer::start_sequence(vec![
+        map_server_login().expect::<RequestPlayerMovePacket>().close(),
+        map_server_login(),
+    ]);
+    let character_server = MockServer::start_sequence(vec![
+        character_server_selection(map_server.address()),
+        character_server_selection(map_server.address()),
+    ]);
+    let login_server = MockServer::start_sequence(vec![
+        login_server_login(character_server.address(), 1),
+        login_server_login(character_server.address(), 11),
+    ]);
+    let mut networking_system = NetworkingSystem::spawn();
+    networking_system.set_reconnect_policy(
+        ReconnectPolicy::new(
+            2,
+            Duration::from_millis(10),
+            Duration::from_millis(10),
+            1.0,
+            Duration::from_secs(2),
+        )
+        .unwrap(),
+    );
+
+    enter_map(&mut networking_system, login_server.address());
+    networking_system.player_move(WorldPosition::new(55, 62)).unwrap();
+
+    let events = wait_for_events(&mut networking_system, 1);
+    assert!(matches!(events[0], NetworkEvent::MapServerReconnecting {
+        attempt: 1,
+        maximum_attempts: 2,
+        ..
+    }));
+
+    // The old login ids are only valid once, so the client has to log in again.
+    let events = wait_for_event(&mut networking_system, |event| {
+        matches!(event, NetworkEvent::MapServerReconnected { .. })
+    });
+    login_server.finish();
+    character_server.finish();
+    map_server.finish();
+
+    assert!(matches!(events.last(), Some(NetworkEvent::MapServerReconnected { login_data }) if login_data.login_id1 == 11));
+    assert!(!events.iter().any(|event| matches!(event, NetworkEvent::CharacterList { .. })));
+}
+
+#[test]
+fn map_server_reconnect_attempt_times_out() {
+    let map_server = MockServer::start(map_server_login().expect::<RequestPlayerMovePacket>().close());
+    let character_server = MockServer::start(character_server_selection(map_server.address()));
+    // The second login is never answered but the connection stays open.
+    let login_server = MockServer::start_sequence(vec![
+        login_server_login(character_server.address(), 1),
+        Script::new().expect::<LoginServerLoginPacket>(),
+    ]);
+    let mut networking_system = NetworkingSystem::spawn();
+    networking_system.set_reconnect_policy(
+        ReconnectPolicy::new(
+            2,
+            Duration::from_millis(10),
+            Duration::from_millis(10),
+            1.0,
+            Duration::from_millis(300),
+        )
+        .unwrap(),
+    );
+
+    enter_map(&mut networking_system, login_server.address());
+    networking_system.player_move(WorldPosition::new(55, 62)).unwrap();
+
+    let events = wait_for_event(&mut networking_system, |event| {
+        matches!(event, NetworkEvent::MapServerReconnecting { attempt: 2, .. })
+    });
+    login_server.finish();
+    character_server.finish();
+    map_server.finish();
+
+    assert!(matches!(events[0], NetworkEvent::MapServerReconnecting { attempt: 1, .. }));
+}
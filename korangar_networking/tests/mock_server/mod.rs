@@ -0,0 +1,266 @@
+//! Scriptable stand-in for the login, character, and map servers, so the
+//! [`NetworkingSystem`] can be tested without a real rAthena instance.
+
+use std::io::{Read, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::sync::mpsc::{channel, Receiver};
+use std::time::{Duration, Instant};
+
+use korangar_networking::{NetworkEvent, NetworkingSystem};
+use ragnarok_bytes::ByteStream;
+use ragnarok_packets::handler::{HandlerResult, NoPacketCallback, PacketCallback, PacketHandler};
+use ragnarok_packets::{
+    CharacterServerKeepalivePacket, ClientPacket, LoginServerKeepalivePacket, PacketExt, PacketHeader, RequestServerTickPacket,
+    ServerPacket,
+};
+
+const TIMEOUT: Duration = Duration::from_secs(5);
+
+type ClientPacketHandler = PacketHandler<Vec<PacketHeader>, (), NoPacketCallback>;
+
+enum Step {
+    /// Wait for the client to send a packet with this header.
+    Expect(PacketHeader),
+    /// Send some bytes to the client.
+    Send(Vec<u8>),
+    /// Close the connection.
+    Close,
+}
+
+/// The steps a [`MockServer`] goes through after the client connected.
+/// Keep-alive packets sent by the client are ignored.
+pub struct Script {
+    steps: Vec<Step>,
+    registrations: Vec<fn(&mut ClientPacketHandler)>,
+}
+
+impl Script {
+    pub fn new() -> Self {
+        Self {
+            steps: Vec::new(),
+            registrations: Vec::new(),
+        }
+    }
+
+    pub fn expect<P: ClientPacket + 'static>(mut self) -> Self {
+        fn register<P: ClientPacket + 'static>(packet_handler: &mut ClientPacketHandler) {
+            // The same packet might be expected multiple times.
+            let _ = packet_handler.register(|_: P| vec![P::HEADER]);
+        }
+
+        self.steps.push(Step::Expect(P::HEADER));
+        self.registrations.push(register::<P>);
+        self
+    }
+
+    pub fn respond(mut self, packet: impl ServerPacket) -> Self {
+        self.steps.push(Step::Send(packet.packet_to_bytes().unwrap()));
+        self
+    }
+
+    /// Send bytes that are not a packet, like the account id sent by the
+    /// character server after logging in.
+    pub fn respond_raw(mut self, bytes: Vec<u8>) -> Self {
+        self.steps.push(Step::Send(bytes));
+        self
+    }
+
+    pub fn close(mut self) -> Self {
+        self.steps.push(Step::Close);
+        self
+    }
+
+    /// Continue with the steps of another script.
+    pub fn chain(mut self, other: Script) -> Self {
+        self.steps.extend(other.steps);
+        self.registrations.extend(other.registrations);
+        self
+    }
+}
+
+pub struct MockServer {
+    address: SocketAddr,
+    connection_count: usize,
+    result_receiver: Receiver<Result<(), String>>,
+}
+
+impl MockServer {
+    /// Listen on a free localhost port and run the script for the first client
+    /// that connects.
+    pub fn start(script: Script) -> Self {
+        Self::start_sequence(vec![script])
+    }
+
+    /// Listen on a free localhost port and run one script per client that
+    /// connects, in order.
+    pub fn start_sequence(scripts: Vec<Script>) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        let connection_count = scripts.len();
+        let (result_sender, result_receiver) = channel();
+
+        std::thread::spawn(move || {
+            for script in scripts {
+                let Script { steps, registrations } = script;
+
+                let mut packet_handler = ClientPacketHandler::default();
+                let _ = packet_handler.register_noop::<LoginServerKeepalivePacket>();
+                let _ = packet_handler.register_noop::<CharacterServerKeepalivePacket>();
+                let _ = packet_handler.register_noop::<RequestServerTickPacket>();
+                registrations.iter().for_each(|register| register(&mut packet_handler));
+
+                let (stream, _) = listener.accept().unwrap();
+                stream.set_read_timeout(Some(TIMEOUT)).unwrap();
+
+                let mut connection = Connection {
+                    stream,
+                    packet_handler,
+                    buffer: Vec::new(),
+                    received: Vec::new(),
+                };
+
+                let result = connection.run(steps);
+                let _ = result_sender.send(result);
+
+                // Keep the connection open until the client closes it.
+                std::thread::spawn(move || {
+                    let mut buffer = [0; 1024];
+                    while matches!(connection.stream.read(&mut buffer), Ok(received_bytes) if received_bytes > 0) {}
+                });
+            }
+        });
+
+        Self {
+            address,
+            connection_count,
+            result_receiver,
+        }
+    }
+
+    pub fn address(&self) -> SocketAddr {
+        self.address
+    }
+
+    /// Wait for the script to finish and panic if the client didn't behave as
+    /// expected.
+    pub fn finish(self) {
+        for _ in 0..self.connection_count {
+            match self.result_receiver.recv_timeout(TIMEOUT) {
+                Ok(Ok(())) => {}
+                Ok(Err(message)) => panic!("mock server failed: {message}"),
+                Err(..) => panic!("mock server timed out"),
+            }
+        }
+    }
+}
+
+struct Connection {
+    stream: TcpStream,
+    packet_handler: ClientPacketHandler,
+    buffer: Vec<u8>,
+    received: Vec<PacketHeader>,
+}
+
+impl Connection {
+    fn run(&mut self, steps: Vec<Step>) -> Result<(), String> {
+        for step in steps {
+            match step {
+                Step::Expect(header) => {
+                    let received = self.next_packet()?;
+
+                    if received != header {
+                        return Err(format!("expected packet 0x{:04x} but got 0x{:04x}", header.0, received.0));
+                    }
+                }
+                Step::Send(bytes) => self.stream.write_all(&bytes).map_err(|error| error.to_string())?,
+                Step::Close => {
+                    let _ = self.stream.shutdown(std::net::Shutdown::Both);
+                    return Ok(());
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn next_packet(&mut self) -> Result<PacketHeader, String> {
+        loop {
+            if !self.received.is_empty() {
+                return Ok(self.received.remove(0));
+            }
+
+            let mut chunk = [0; 4096];
+            let received_bytes = self.stream.read(&mut chunk).map_err(|error| error.to_string())?;
+
+            if received_bytes == 0 {
+                return Err("client closed the connection".to_owned());
+            }
+
+            self.buffer.extend_from_slice(&chunk[..received_bytes]);
+
+            let mut byte_stream = ByteStream::<()>::without_metadata(&self.buffer);
+
+            while !byte_stream.is_empty() {
+                match self.packet_handler.process_one(&mut byte_stream) {
+                    HandlerResult::Ok(headers) => self.received.extend(headers),
+                    HandlerResult::PacketCutOff => break,
+                    HandlerResult::UnhandledPacket => {
+                        let bytes = byte_stream.remaining_bytes();
+                        return Err(format!("unexpected packet 0x{:02x}{:02x}", bytes[1], bytes[0]));
+                    }
+                    HandlerResult::InternalError(error) => return Err(format!("{error:?}")),
+                }
+            }
+
+            let consumed = byte_stream.get_offset();
+            self.buffer.drain(..consumed);
+        }
+    }
+}
+
+/// Poll the networking system until it produced an event matching the
+/// predicate. Returns all events up to and including that one.
+pub fn wait_for_event<Callback>(
+    networking_system: &mut NetworkingSystem<Callback>,
+    predicate: impl Fn(&NetworkEvent) -> bool,
+) -> Vec<NetworkEvent>
+where
+    Callback: PacketCallback + Send,
+{
+    let start = Instant::now();
+    let mut events = Vec::new();
+
+    loop {
+        if let Some(position) = events.iter().position(&predicate) {
+            events.truncate(position + 1);
+            return events;
+        }
+
+        if start.elapsed() > TIMEOUT {
+            panic!("expected a matching event but only got {events:?}");
+        }
+
+        events.extend(networking_system.get_events());
+        std::thread::sleep(Duration::from_millis(10));
+    }
+}
+
+/// Poll the networking system until it produced the given number of events.
+pub fn wait_for_events<Callback>(networking_system: &mut NetworkingSystem<Callback>, count: usize) -> Vec<NetworkEvent>
+where
+    Callback: PacketCallback + Send,
+{
+    let start = Instant::now();
+    let mut events = Vec::new();
+
+    while events.len() < count {
+        if start.elapsed() > TIMEOUT {
+            panic!("expected {count} events but only got {events:?}");
+        }
+
+        events.extend(networking_system.get_events());
+        std::thread::sleep(Duration::from_millis(10));
+    }
+
+    events
+}
@@ -89,6 +89,11 @@ where
         self.limit = save_point.limit;
     }
 
+    /// All bytes that were read since the save point was created.
+    pub fn bytes_since(&self, save_point: &SavePoint) -> &'a [u8] {
+        &self.data[save_point.offset..self.offset]
+    }
+
     pub(crate) fn install_limit<Caller>(&mut self, size: usize) -> ConversionResult<TemporaryLimit> {
         let frame_limit = self.offset + size;
         let old_limit = self.limit;
@@ -200,6 +205,18 @@ mod save_point {
         assert_eq!(byte_stream.offset, 0);
         assert_eq!(byte_stream.limit, TEST_BYTE_SIZE);
     }
+
+    #[test]
+    fn bytes_since() {
+        let mut byte_stream = ByteStream::<()>::without_metadata(&[1, 2, 3, 4]);
+        byte_stream.offset = 1;
+
+        let save_point = byte_stream.create_save_point();
+
+        byte_stream.offset = 3;
+
+        assert_eq!(byte_stream.bytes_since(&save_point), &[2, 3]);
+    }
 }
 
 #[cfg(test)]
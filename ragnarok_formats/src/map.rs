@@ -56,18 +56,27 @@ bitflags::bitflags! {
     }
 }
 
+impl TileFlags {
+    /// Convert a cell type, as stored in GAT files and sent by the map server.
+    pub fn from_cell_type(cell_type: u8) -> Option<Self> {
+        match cell_type {
+            0 => Some(Self::WALKABLE),
+            1 => Some(Self::empty()),
+            2 => Some(Self::WATER),
+            3 => Some(Self::WATER | Self::WALKABLE),
+            4 => Some(Self::WATER | Self::SNIPABLE),
+            5 => Some(Self::CLIFF | Self::SNIPABLE),
+            6 => Some(Self::CLIFF),
+            _ => None,
+        }
+    }
+}
+
 impl FromBytes for TileFlags {
     fn from_bytes<Meta>(byte_stream: &mut ByteStream<Meta>) -> ConversionResult<Self> {
-        match <Self as bitflags::Flags>::Bits::from_bytes(byte_stream).trace::<Self>()? {
-            0 => Ok(Self::WALKABLE),
-            1 => Ok(Self::empty()),
-            2 => Ok(Self::WATER),
-            3 => Ok(Self::WATER | Self::WALKABLE),
-            4 => Ok(Self::WATER | Self::SNIPABLE),
-            5 => Ok(Self::CLIFF | Self::SNIPABLE),
-            6 => Ok(Self::CLIFF),
-            invalid => Err(ConversionError::from_message(format!("invalid tile type {invalid}"))),
-        }
+        let cell_type = <Self as bitflags::Flags>::Bits::from_bytes(byte_stream).trace::<Self>()?;
+
+        Self::from_cell_type(cell_type).ok_or_else(|| ConversionError::from_message(format!("invalid tile type {cell_type}")))
     }
 }
 
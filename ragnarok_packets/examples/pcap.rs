@@ -1,32 +1,24 @@
-#![feature(const_type_id)]
+use std::collections::{BTreeMap, HashMap};
+use std::net::{IpAddr, SocketAddr};
+use std::process::ExitCode;
 
-use std::any::TypeId;
-
-use etherparse::{SlicedPacket, TransportSlice};
+use etherparse::{NetSlice, SlicedPacket, TransportSlice};
 use korangar_debug::logging::symbols::ARROW;
 use korangar_debug::logging::{Colorize, Colorized};
-use ragnarok_bytes::{ByteStream, ConversionError};
-use ragnarok_packets::handler::{HandlerResult, PacketCallback, PacketHandler};
+use pcap::{Activated, Capture, Linktype};
+use ragnarok_bytes::ByteStream;
+use ragnarok_packets::handler::{HandlerResult, NoPacketCallback, PacketHandler};
 use ragnarok_packets::*;
+use serde_json::json;
 
-// Adjust this to change which packets will print their content!
-const EXPAND_BEHAVIOUR: ExpandBehaviour = ExpandBehaviour::Blacklist(&[
-    TypeId::of::<LoginServerKeepalivePacket>(),
-    TypeId::of::<CharacterServerKeepalivePacket>(),
-    TypeId::of::<RequestServerTickPacket>(),
-    TypeId::of::<ServerTickPacket>(),
-    TypeId::of::<MapServerPingPacket>(),
-]);
-
-#[allow(dead_code)]
-enum ExpandBehaviour {
-    None,
-    All,
-    Whitelist(&'static [TypeId]),
-    Blacklist(&'static [TypeId]),
-}
+const USAGE: &str = "usage: pcap (--file <capture> | --device <device>) [--filter <bpf filter>] [--header <header>]... [--type <packet \
+                     type>]... [--packet-version <version>] [--expand] [--quiet] [--json]";
 
-#[derive(Clone)]
+const LOGIN_SERVER_PORT: u16 = 6900;
+const CHARACTER_SERVER_PORT: u16 = 6121;
+const MAP_SERVER_PORT: u16 = 5121;
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 enum ServerType {
     Login,
     Character,
@@ -41,9 +33,17 @@ impl ServerType {
             ServerType::Map => "Map".cyan(),
         }
     }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            ServerType::Login => "Login",
+            ServerType::Character => "Character",
+            ServerType::Map => "Map",
+        }
+    }
 }
 
-#[derive(Clone)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 enum Direction {
     Incoming,
     Outgoing,
@@ -56,324 +56,876 @@ impl Direction {
             Direction::Outgoing => "Outgoing".red(),
         }
     }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Direction::Incoming => "Incoming",
+            Direction::Outgoing => "Outgoing",
+        }
+    }
 }
 
-#[derive(Clone)]
-struct PrintCallback {
-    server_type: ServerType,
-    direction: Direction,
+enum Source {
+    File(String),
+    Device(String),
 }
 
-impl PrintCallback {
-    pub fn new(server_type: ServerType, direction: Direction) -> Self {
-        Self { server_type, direction }
+struct Options {
+    source: Source,
+    filter: Option<String>,
+    /// Only packets with one of these headers are printed and counted.
+    headers: Vec<PacketHeader>,
+    /// Only packets of one of these types are printed and counted.
+    packet_types: Vec<String>,
+    packet_profile: PacketProfile,
+    /// Print the content of every packet except pings.
+    expand: bool,
+    /// Only print the report.
+    quiet: bool,
+    /// Print the report as JSON.
+    json: bool,
+}
+
+impl Options {
+    fn parse() -> Result<Self, String> {
+        let mut arguments = std::env::args().skip(1);
+        let mut source = None;
+        let mut filter = None;
+        let mut headers = Vec::new();
+        let mut packet_types = Vec::new();
+        let mut packet_profile = PacketProfile::default();
+        let mut expand = false;
+        let mut quiet = false;
+        let mut json = false;
+
+        while let Some(argument) = arguments.next() {
+            let mut value = || arguments.next().ok_or(format!("missing value for {argument}"));
+
+            match argument.as_str() {
+                "--file" => source = Some(Source::File(value()?)),
+                "--device" => source = Some(Source::Device(value()?)),
+                "--filter" => filter = Some(value()?),
+                "--header" => {
+                    let header = value()?;
+                    let header =
+                        u16::from_str_radix(header.trim_start_matches("0x"), 16).map_err(|_| format!("invalid header {header}"))?;
+                    headers.push(PacketHeader(header));
+                }
+                "--type" => packet_types.push(value()?),
+                "--packet-version" => {
+                    let packet_version = value()?.parse().map_err(|_| "invalid packet version".to_owned())?;
+                    packet_profile = PacketProfile::new(PacketVersion(packet_version));
+                }
+                "--expand" => expand = true,
+                "--quiet" => quiet = true,
+                // The report has to be the only output to be valid JSON.
+                "--json" => (json, quiet) = (true, true),
+                _ => return Err(USAGE.to_owned()),
+            }
+        }
+
+        Ok(Self {
+            source: source.ok_or(USAGE.to_owned())?,
+            filter,
+            headers,
+            packet_types,
+            packet_profile,
+            expand,
+            quiet,
+            json,
+        })
+    }
+
+    fn includes_header(&self, header: PacketHeader) -> bool {
+        self.headers.is_empty() || self.headers.contains(&header)
+    }
+
+    fn includes_packet(&self, header: PacketHeader, packet_type: &str) -> bool {
+        self.includes_header(header) && (self.packet_types.is_empty() || self.packet_types.iter().any(|name| name == packet_type))
     }
 }
 
-impl PacketCallback for PrintCallback {
-    fn incoming_packet<Packet>(&self, _packet: &Packet)
-    where
-        Packet: ragnarok_packets::Packet,
-    {
+/// Output of the packet handlers.
+struct DecodedPacket {
+    packet_type: &'static str,
+    is_ping: bool,
+    details: String,
+}
+
+fn handler<P: Packet + 'static>(packet: P) -> DecodedPacket {
+    let type_name = std::any::type_name::<P>();
+
+    DecodedPacket {
+        packet_type: type_name.rsplit("::").next().unwrap_or(type_name),
+        is_ping: P::IS_PING,
+        details: format!("{packet:?}"),
     }
+}
 
-    fn unknown_packet(&self, bytes: Vec<u8>) {
-        if bytes.len() > 2 {
-            let header = format!("0x{:0>4x}", bytes[0] as u16 | (bytes[1] as u16) << 8);
+type Handler = PacketHandler<Option<DecodedPacket>, PacketVersion, NoPacketCallback>;
 
-            println!(
-                "Unknown {} packet on {} server with header {}: {:?}",
-                self.direction.convert(),
-                self.server_type.convert(),
-                header.red(),
-                &bytes[2..]
-            );
-        } else {
-            println!(
-                "Trailing {} bytes on {} server: {:?}",
-                self.direction.convert(),
-                self.server_type.convert(),
-                bytes
-            );
+macro_rules! create_handler {
+    ($packet_profile:expr, [$($packet:ty),* $(,)?]) => {
+        {
+            let mut new_handler = Handler::with_profile(NoPacketCallback, $packet_profile.clone());
+            $(
+                new_handler.register(handler::<$packet>).unwrap();
+            )*
+
+            new_handler
+        }
+    };
+}
+
+struct Handlers {
+    login_incoming: Handler,
+    login_outgoing: Handler,
+    character_incoming: Handler,
+    character_outgoing: Handler,
+    map_incoming: Handler,
+    map_outgoing: Handler,
+}
+
+impl Handlers {
+    fn new(packet_profile: &PacketProfile) -> Self {
+        let login_incoming = create_handler!(packet_profile, [
+            LoginServerLoginSuccessPacket,
+            LoginFailedPacket,
+            LoginFailedPacket2
+        ]);
+
+        let login_outgoing = create_handler!(packet_profile, [LoginServerLoginPacket, LoginServerKeepalivePacket,]);
+
+        let character_incoming = create_handler!(packet_profile, [
+            LoginFailedPacket,
+            CharacterServerLoginSuccessPacket,
+            RequestCharacterListSuccessPacket,
+            Packet006b,
+            Packet0b18,
+            CharacterSelectionSuccessPacket,
+            CharacterSelectionFailedPacket,
+            MapServerUnavailablePacket,
+            CreateCharacterSuccessPacket,
+            CharacterCreationFailedPacket,
+            CharacterDeletionSuccessPacket,
+            CharacterDeletionFailedPacket,
+            SwitchCharacterSlotResponsePacket,
+        ]);
+
+        let character_outgoing = create_handler!(packet_profile, [
+            CharacterServerKeepalivePacket,
+            RequestCharacterListPacket,
+            SelectCharacterPacket,
+            CreateCharacterPacket,
+            DeleteCharacterPacket,
+            SwitchCharacterSlotPacket,
+            CharacterServerLoginPacket,
+        ]);
+
+        let map_incoming = create_handler!(packet_profile, [
+            MapServerPingPacket,
+            BroadcastMessagePacket,
+            Broadcast2MessagePacket,
+            OverheadMessagePacket,
+            ServerMessagePacket,
+            EntityMessagePacket,
+            DisplayEmotionPacket,
+            EntityMovePacket,
+            EntityStopMovePacket,
+            PlayerMovePacket,
+            ChangeMapPacket,
+            EntityAppearedPacket,
+            EntityAppeared2Packet,
+            MovingEntityAppearedPacket,
+            EntityDisappearedPacket,
+            UpdateStatusPacket,
+            UpdateStatusPacket1,
+            UpdateStatusPacket2,
+            UpdateStatusPacket3,
+            UpdateAttackRangePacket,
+            NewMailStatusPacket,
+            AchievementUpdatePacket,
+            AchievementListPacket,
+            CriticalWeightUpdatePacket,
+            SpriteChangePacket,
+            InventoyStartPacket,
+            RegularItemListPacket,
+            EquippableItemListPacket,
+            InventoyEndPacket,
+            EquippableSwitchItemListPacket,
+            MapTypePacket,
+            UpdateSkillTreePacket,
+            UpdateHotkeysPacket,
+            InitialStatusPacket,
+            UpdatePartyInvitationStatePacket,
+            UpdateShowEquipPacket,
+            UpdateConfigurationPacket,
+            NavigateToMonsterPacket,
+            MarkMinimapPositionPacket,
+            NextButtonPacket,
+            CloseButtonPacket,
+            DialogMenuPacket,
+            DisplaySpecialEffectPacket,
+            DisplaySkillCooldownPacket,
+            DisplaySkillEffectAndDamagePacket,
+            DisplaySkillEffectNoDamagePacket,
+            DisplayPlayerHealEffect,
+            StatusChangePacket,
+            QuestNotificationPacket1,
+            HuntingQuestNotificationPacket,
+            HuntingQuestUpdateObjectivePacket,
+            QuestRemovedPacket,
+            QuestListPacket,
+            VisualEffectPacket,
+            DisplayGainedExperiencePacket,
+            DisplayImagePacket,
+            StateChangePacket,
+            QuestEffectPacket,
+            ItemPickupPacket,
+            RemoveItemFromInventoryPacket,
+            ServerTickPacket,
+            RequestPlayerDetailsSuccessPacket,
+            RequestEntityDetailsSuccessPacket,
+            UpdateEntityHealthPointsPacket,
+            RequestPlayerAttackFailedPacket,
+            DamagePacket,
+            NpcDialogPacket,
+            RequestEquipItemStatusPacket,
+            RequestUnequipItemStatusPacket,
+            Packet8302,
+            Packet0b18,
+            MapServerLoginSuccessPacket,
+            RestartResponsePacket,
+            DisconnectResponsePacket,
+            UseSkillSuccessPacket,
+            ToUseSkillSuccessPacket,
+            CastCancelPacket,
+            NotifySkillUnitPacket,
+            SkillUnitDisappearPacket,
+            NotifyGroundSkillPacket,
+            FriendListPacket,
+            FriendOnlineStatusPacket,
+            FriendRequestPacket,
+            FriendRequestResultPacket,
+            NotifyFriendRemovedPacket,
+            PartyInvitePacket,
+            StatusChangeSequencePacket,
+            ReputationPacket,
+            ClanInfoPacket,
+            ClanOnlineCountPacket,
+            ChangeMapCellPacket,
+            OpenMarketPacket,
+            BuyOrSellPacket,
+            ShopItemListPacket,
+            BuyShopItemsResultPacket,
+            ParameterChangePacket,
+            SellListPacket,
+            SellItemsPacket,
+            SellItemsResultPacket,
+            UseItemResultPacket,
+            DropItemResultPacket,
+            GroundItemAppearedPacket,
+            GroundItemDroppedPacket,
+            GroundItemDisappearedPacket,
+            PartyCreationResultPacket,
+            PartyInviteResultPacket,
+            PartyMemberLeftPacket,
+            PartyInformationPacket,
+            PartyMemberJoinedPacket,
+            PartySettingsPacket,
+            PartyLeaderChangedPacket,
+            PartyMemberPositionPacket,
+            PartyMemberHealthPacket,
+            NotifyPartyMessagePacket,
+            UpdateGuildIdPacket,
+            GuildInformationPacket,
+            GuildRelationsPacket,
+            GuildRelationRemovedPacket,
+            GuildMemberListPacket,
+            GuildMemberStatePacket,
+            CharacterNamePacket,
+            GuildPositionNamesPacket,
+            GuildPositionsPacket,
+            GuildNoticePacket,
+            GuildEmblemPacket,
+            GuildEmblemChangedPacket,
+            NotifyGuildMessagePacket,
+            TradeRequestPacket,
+            TradeResponseResultPacket,
+            TradeItemAddedPacket,
+            AddTradeItemResultPacket,
+            TradeLockedPacket,
+            TradeCancelledPacket,
+            TradeCompletedPacket,
+            StorageCapacityPacket,
+            StorageItemAddedPacket,
+            StorageItemRemovedPacket,
+            StorageClosedPacket,
+            RequestStatUpResponsePacket,
+            UpdateSkillPacket,
+            NotifyWhisperMessagePacket,
+            WhisperResultPacket,
+            CreateChatRoomResultPacket,
+            ChatRoomAppearedPacket,
+            ChatRoomDestroyedPacket,
+            EnterChatRoomFailedPacket,
+            ChatRoomEnteredPacket,
+            ChatRoomMemberEnteredPacket,
+            ChatRoomMemberExitPacket,
+            ChatRoomChangedPacket,
+            ChatRoomRoleChangedPacket,
+            OpenVendingShopSetupPacket,
+            OpenVendingShopResultPacket,
+            OwnVendingItemListPacket,
+            VendingShopAppearedPacket,
+            VendingShopDisappearedPacket,
+            VendingItemListPacket,
+            VendingPurchaseResultPacket,
+            VendingItemSoldPacket,
+            OpenBuyingStoreSetupPacket,
+            OpenBuyingStoreFailedPacket,
+            OwnBuyingStoreItemListPacket,
+            BuyingStoreAppearedPacket,
+            BuyingStoreDisappearedPacket,
+            BuyingStoreItemListPacket,
+            BuyingStoreClosedPacket,
+            BuyingStoreItemBoughtPacket,
+            BuyingStoreItemSoldPacket,
+            SellToBuyingStoreFailedPacket,
+            MailListPacket,
+            MailContentsPacket,
+            MailDeletedPacket,
+            RetrieveMailZenyResultPacket,
+            RetrieveMailItemsResultPacket,
+            StartWritingMailResultPacket,
+            AddMailItemResultPacket,
+            RemoveMailItemResultPacket,
+            MailReceiverPacket,
+            SendMailResultPacket,
+        ]);
+
+        let map_outgoing = create_handler!(packet_profile, [
+            MapLoadedPacket,
+            RestartPacket,
+            RequestPlayerMovePacket,
+            RequestWarpToMapPacket,
+            RequestDetailsPacket,
+            RequestActionPacket,
+            GlobalMessagePacket,
+            StartDialogPacket,
+            NextDialogPacket,
+            CloseDialogPacket,
+            ChooseDialogOptionPacket,
+            RequestEquipItemPacket,
+            RequestUnequipItemPacket,
+            UseSkillAtIdPacket,
+            UseSkillOnGroundPacket,
+            StartUseSkillPacket,
+            EndUseSkillPacket,
+            AddFriendPacket,
+            RemoveFriendPacket,
+            FriendRequestResponsePacket,
+            SetHotkeyData2Packet,
+            SelectBuyOrSellPacket,
+            BuyShopItemsPacket,
+            CloseShopPacket,
+            SellItemsPacket,
+            RequestServerTickPacket,
+            MapServerLoginPacket,
+            RequestEmotionPacket,
+            SetHotkeyData1Packet,
+            UseItemPacket,
+            DropItemPacket,
+            PickUpItemPacket,
+            CreatePartyPacket,
+            InviteToPartyPacket,
+            PartyInviteResponsePacket,
+            LeavePartyPacket,
+            KickPartyMemberPacket,
+            PartyMessagePacket,
+            RequestGuildInformationPacket,
+            RemoveGuildRelationPacket,
+            RequestCharacterNamePacket,
+            ChangeGuildNoticePacket,
+            RequestGuildEmblemPacket,
+            UploadGuildEmblemPacket,
+            GuildMessagePacket,
+            BuyItemsPacket,
+            RequestTradePacket,
+            TradeResponsePacket,
+            AddTradeItemPacket,
+            LockTradePacket,
+            CancelTradePacket,
+            CommitTradePacket,
+            MoveItemToStoragePacket,
+            MoveItemFromStoragePacket,
+            CloseStoragePacket,
+            RequestStatUpPacket,
+            RequestSkillUpPacket,
+            WhisperMessagePacket,
+            CreateChatRoomPacket,
+            EnterChatRoomPacket,
+            ChangeChatRoomPacket,
+            ChangeChatRoomRolePacket,
+            KickFromChatRoomPacket,
+            LeaveChatRoomPacket,
+            OpenVendingShopPacket,
+            CloseVendingShopPacket,
+            RequestVendingItemListPacket,
+            PurchaseVendingItemsPacket,
+            OpenBuyingStorePacket,
+            CloseBuyingStorePacket,
+            RequestBuyingStoreItemListPacket,
+            SellToBuyingStorePacket,
+            OpenMailboxPacket,
+            RequestNextMailPagePacket,
+            CloseMailboxPacket,
+            ReadMailPacket,
+            DeleteMailPacket,
+            RetrieveMailZenyPacket,
+            RetrieveMailItemsPacket,
+            StartWritingMailPacket,
+            CancelWritingMailPacket,
+            AddMailItemPacket,
+            RemoveMailItemPacket,
+            CheckMailReceiverPacket,
+            SendMailPacket,
+        ]);
+
+        Self {
+            login_incoming,
+            login_outgoing,
+            character_incoming,
+            character_outgoing,
+            map_incoming,
+            map_outgoing,
         }
     }
 
-    fn failed_packet(&self, bytes: Vec<u8>, error: Box<ConversionError>) {
-        if bytes.len() > 2 {
-            let header = format!("0x{:0>4x}", bytes[0] as u16 | (bytes[1] as u16) << 8);
+    fn get(&mut self, server_type: ServerType, direction: Direction) -> &mut Handler {
+        match (server_type, direction) {
+            (ServerType::Login, Direction::Incoming) => &mut self.login_incoming,
+            (ServerType::Login, Direction::Outgoing) => &mut self.login_outgoing,
+            (ServerType::Character, Direction::Incoming) => &mut self.character_incoming,
+            (ServerType::Character, Direction::Outgoing) => &mut self.character_outgoing,
+            (ServerType::Map, Direction::Incoming) => &mut self.map_incoming,
+            (ServerType::Map, Direction::Outgoing) => &mut self.map_outgoing,
+        }
+    }
+}
 
-            println!(
-                "Error {} packet on {} server with header {} and error {:?}: {:?}",
-                self.direction.convert(),
-                self.server_type.convert(),
-                header.red(),
-                error.red(),
-                &bytes[2..]
-            );
-        } else {
-            println!(
-                "Trailing {} bytes on {} server with error {:?}: {:?}",
-                self.direction.convert(),
-                self.server_type.convert(),
-                error.red(),
-                bytes
-            );
+/// One direction of a TCP connection, reassembled from its segments.
+#[derive(Default)]
+struct TcpStream {
+    next_sequence_number: Option<u32>,
+    /// Segments that arrived before the data preceding them.
+    out_of_order: HashMap<u32, Vec<u8>>,
+    /// Data that has not been processed yet.
+    buffer: Vec<u8>,
+    /// Set after an unknown or failed packet. Without knowing where the next
+    /// packet starts, nothing can be decoded until the connection is reset.
+    desynced: bool,
+}
+
+/// Compare sequence numbers the way TCP does, so the comparison still works
+/// after they wrapped around.
+fn is_after(sequence_number: u32, other: u32) -> bool {
+    (sequence_number.wrapping_sub(other) as i32) > 0
+}
+
+impl TcpStream {
+    fn add_segment(&mut self, sequence_number: u32, syn: bool, payload: &[u8]) {
+        if syn {
+            // A new connection on the same ports.
+            *self = Self {
+                next_sequence_number: Some(sequence_number.wrapping_add(1)),
+                ..Default::default()
+            };
+            return;
+        }
+
+        if payload.is_empty() {
+            return;
+        }
+
+        // The capture might have started in the middle of the connection.
+        let next_sequence_number = *self.next_sequence_number.get_or_insert(sequence_number);
+
+        match is_after(sequence_number, next_sequence_number) {
+            true => {
+                self.out_of_order.insert(sequence_number, payload.to_vec());
+            }
+            false => {
+                self.append(sequence_number, payload);
+
+                // The map can't be ordered by the raw sequence numbers since they might wrap
+                // around, so we look for any segment that can be appended.
+                while let Some(sequence_number) = self
+                    .out_of_order
+                    .keys()
+                    .copied()
+                    .find(|sequence_number| !is_after(*sequence_number, self.next_sequence_number.unwrap()))
+                {
+                    let payload = self.out_of_order.remove(&sequence_number).unwrap();
+                    self.append(sequence_number, &payload);
+                }
+            }
+        }
+    }
+
+    /// Append a segment that starts at or before the next sequence number.
+    /// Retransmitted data is skipped.
+    fn append(&mut self, sequence_number: u32, payload: &[u8]) {
+        let next_sequence_number = self.next_sequence_number.unwrap();
+        let duplicate_bytes = next_sequence_number.wrapping_sub(sequence_number) as usize;
+
+        if duplicate_bytes >= payload.len() {
+            return;
         }
+
+        self.buffer.extend_from_slice(&payload[duplicate_bytes..]);
+        self.next_sequence_number = Some(sequence_number.wrapping_add(payload.len() as u32));
     }
 }
 
-fn handler<P: Packet + 'static>(server_type: ServerType, direction: Direction) -> impl Fn(P) {
-    move |packet: P| {
-        let header = format!("0x{:0>4x}", P::HEADER.0);
+#[derive(Default)]
+struct PacketStatistics {
+    count: usize,
+    bytes: usize,
+}
+
+#[derive(Default)]
+struct FailureStatistics {
+    count: usize,
+    last_error: String,
+}
+
+#[derive(Default)]
+struct Statistics {
+    packets: BTreeMap<(ServerType, Direction, PacketHeader, &'static str), PacketStatistics>,
+    unknown_headers: BTreeMap<(ServerType, Direction, PacketHeader), PacketStatistics>,
+    failures: BTreeMap<(ServerType, Direction, PacketHeader), FailureStatistics>,
+    /// Bytes that couldn't be assigned to a packet because the stream was
+    /// out of sync after an unknown or failed packet.
+    skipped_bytes: usize,
+}
 
+impl Statistics {
+    fn print_table(&self) {
         println!(
-            "{} packet on {} server: {} ({})",
-            direction.convert(),
-            server_type.convert(),
-            std::any::type_name::<P>().cyan(),
-            header.green()
+            "{:<10} {:<9} {:<7} {:<40} {:>8} {:>10}",
+            "Server", "Direction", "Header", "Packet", "Count", "Bytes"
         );
 
-        match EXPAND_BEHAVIOUR {
-            ExpandBehaviour::None => {}
-            ExpandBehaviour::Whitelist(types) if !types.contains(&TypeId::of::<P>()) => {}
-            ExpandBehaviour::Blacklist(types) if types.contains(&TypeId::of::<P>()) => {}
-            _ => {
-                let arrow = match direction {
-                    Direction::Incoming => ARROW.green(),
-                    Direction::Outgoing => ARROW.red(),
-                };
-                println!(" {arrow} {packet:?}")
+        for ((server_type, direction, header, packet_type), statistics) in &self.packets {
+            println!(
+                "{:<10} {:<9} 0x{:0>4x}  {:<40} {:>8} {:>10}",
+                server_type.name(),
+                direction.name(),
+                header.0,
+                packet_type,
+                statistics.count,
+                statistics.bytes
+            );
+        }
+
+        if !self.unknown_headers.is_empty() {
+            println!("\nUnknown headers");
+
+            for ((server_type, direction, header), statistics) in &self.unknown_headers {
+                println!(
+                    "{:<10} {:<9} 0x{:0>4x}  {:<40} {:>8} {:>10}",
+                    server_type.name(),
+                    direction.name(),
+                    header.0,
+                    "",
+                    statistics.count,
+                    statistics.bytes
+                );
+            }
+        }
+
+        if !self.failures.is_empty() {
+            println!("\nDecode failures");
+
+            for ((server_type, direction, header), statistics) in &self.failures {
+                println!(
+                    "{:<10} {:<9} 0x{:0>4x}  {:>8}  {}",
+                    server_type.name(),
+                    direction.name(),
+                    header.0,
+                    statistics.count,
+                    statistics.last_error
+                );
             }
         }
+
+        println!("\nSkipped bytes: {}", self.skipped_bytes);
+    }
+
+    fn print_json(&self) {
+        let packets: Vec<_> = self
+            .packets
+            .iter()
+            .map(|((server_type, direction, header, packet_type), statistics)| {
+                json!({
+                    "server": server_type.name(),
+                    "direction": direction.name(),
+                    "header": format!("0x{:0>4x}", header.0),
+                    "type": packet_type,
+                    "count": statistics.count,
+                    "bytes": statistics.bytes,
+                })
+            })
+            .collect();
+        let unknown_headers: Vec<_> = self
+            .unknown_headers
+            .iter()
+            .map(|((server_type, direction, header), statistics)| {
+                json!({
+                    "server": server_type.name(),
+                    "direction": direction.name(),
+                    "header": format!("0x{:0>4x}", header.0),
+                    "count": statistics.count,
+                    "bytes": statistics.bytes,
+                })
+            })
+            .collect();
+        let failures: Vec<_> = self
+            .failures
+            .iter()
+            .map(|((server_type, direction, header), statistics)| {
+                json!({
+                    "server": server_type.name(),
+                    "direction": direction.name(),
+                    "header": format!("0x{:0>4x}", header.0),
+                    "count": statistics.count,
+                    "last_error": statistics.last_error,
+                })
+            })
+            .collect();
+
+        let report = json!({
+            "packets": packets,
+            "unknown_headers": unknown_headers,
+            "failures": failures,
+            "skipped_bytes": self.skipped_bytes,
+        });
+
+        println!("{report:#}");
     }
 }
 
-macro_rules! create_handler {
-    ($server_type:expr, $direction:expr, [$($packet:ty),* $(,)?]) => {
-        {
-            let mut new_handler = PacketHandler::<(), (), _>::with_callback(PrintCallback::new($server_type, $direction));
-            $(
-                new_handler.register(handler::<$packet>($server_type, $direction)).unwrap();
-            )*
+struct Analyzer {
+    options: Options,
+    handlers: Handlers,
+    streams: HashMap<(SocketAddr, SocketAddr), TcpStream>,
+    statistics: Statistics,
+}
 
-            new_handler
+impl Analyzer {
+    fn process_segment(&mut self, source: SocketAddr, destination: SocketAddr, sequence_number: u32, syn: bool, payload: &[u8]) {
+        // FIX: Obviously this will break if the local port is one of the server ports.
+        // Check if the packet in incoming or outgoing beforehand.
+        let (server_type, direction) = match (source.port(), destination.port()) {
+            (LOGIN_SERVER_PORT, _) => (ServerType::Login, Direction::Incoming),
+            (_, LOGIN_SERVER_PORT) => (ServerType::Login, Direction::Outgoing),
+            (CHARACTER_SERVER_PORT, _) => (ServerType::Character, Direction::Incoming),
+            (_, CHARACTER_SERVER_PORT) => (ServerType::Character, Direction::Outgoing),
+            (MAP_SERVER_PORT, _) => (ServerType::Map, Direction::Incoming),
+            (_, MAP_SERVER_PORT) => (ServerType::Map, Direction::Outgoing),
+            _ => return,
+        };
+
+        let stream = self.streams.entry((source, destination)).or_default();
+        stream.add_segment(sequence_number, syn, payload);
+
+        if stream.desynced {
+            self.statistics.skipped_bytes += stream.buffer.len();
+            stream.buffer.clear();
+            return;
         }
-    };
-}
 
-fn main() {
-    const DEVICE: &str = "wlp5s0";
-
-    const LOGIN_SERVER_PORT: u16 = 6900;
-    const CHARACTER_SERVER_PORT: u16 = 6121;
-    const MAP_SERVER_PORT: u16 = 5121;
-
-    let mut cap = pcap::Capture::from_device(DEVICE).unwrap().immediate_mode(true).open().unwrap();
-    cap.filter("host 49.12.109.207", true).unwrap();
-
-    let mut client_login_handler = create_handler!(ServerType::Login, Direction::Incoming, [
-        LoginServerLoginSuccessPacket,
-        LoginFailedPacket,
-        LoginFailedPacket2
-    ]);
-
-    let mut server_login_handler = create_handler!(ServerType::Login, Direction::Outgoing, [
-        LoginServerLoginPacket,
-        LoginServerKeepalivePacket,
-    ]);
-
-    let mut client_character_handler = create_handler!(ServerType::Character, Direction::Incoming, [
-        LoginFailedPacket,
-        CharacterServerLoginSuccessPacket,
-        RequestCharacterListSuccessPacket,
-        Packet006b,
-        Packet0b18,
-        CharacterSelectionSuccessPacket,
-        CharacterSelectionFailedPacket,
-        MapServerUnavailablePacket,
-        CreateCharacterSuccessPacket,
-        CharacterCreationFailedPacket,
-        CharacterDeletionSuccessPacket,
-        CharacterDeletionFailedPacket,
-        SwitchCharacterSlotResponsePacket,
-    ]);
-
-    let mut server_character_handler = create_handler!(ServerType::Character, Direction::Outgoing, [
-        CharacterServerKeepalivePacket,
-        RequestCharacterListPacket,
-        SelectCharacterPacket,
-        CreateCharacterPacket,
-        DeleteCharacterPacket,
-        SwitchCharacterSlotPacket,
-    ]);
-
-    let mut client_map_handler = create_handler!(ServerType::Map, Direction::Incoming, [
-        MapServerPingPacket,
-        BroadcastMessagePacket,
-        Broadcast2MessagePacket,
-        OverheadMessagePacket,
-        ServerMessagePacket,
-        EntityMessagePacket,
-        DisplayEmotionPacket,
-        EntityMovePacket,
-        EntityStopMovePacket,
-        PlayerMovePacket,
-        ChangeMapPacket,
-        EntityAppearedPacket,
-        EntityAppeared2Packet,
-        MovingEntityAppearedPacket,
-        EntityDisappearedPacket,
-        UpdateStatusPacket,
-        UpdateStatusPacket1,
-        UpdateStatusPacket2,
-        UpdateStatusPacket3,
-        UpdateAttackRangePacket,
-        NewMailStatusPacket,
-        AchievementUpdatePacket,
-        AchievementListPacket,
-        CriticalWeightUpdatePacket,
-        SpriteChangePacket,
-        InventoyStartPacket,
-        RegularItemListPacket,
-        EquippableItemListPacket,
-        InventoyEndPacket,
-        EquippableSwitchItemListPacket,
-        MapTypePacket,
-        UpdateSkillTreePacket,
-        UpdateHotkeysPacket,
-        InitialStatusPacket,
-        UpdatePartyInvitationStatePacket,
-        UpdateShowEquipPacket,
-        UpdateConfigurationPacket,
-        NavigateToMonsterPacket,
-        MarkMinimapPositionPacket,
-        NextButtonPacket,
-        CloseButtonPacket,
-        DialogMenuPacket,
-        DisplaySpecialEffectPacket,
-        DisplaySkillCooldownPacket,
-        DisplaySkillEffectAndDamagePacket,
-        DisplaySkillEffectNoDamagePacket,
-        DisplayPlayerHealEffect,
-        StatusChangePacket,
-        QuestNotificationPacket1,
-        HuntingQuestNotificationPacket,
-        HuntingQuestUpdateObjectivePacket,
-        QuestRemovedPacket,
-        QuestListPacket,
-        VisualEffectPacket,
-        DisplayGainedExperiencePacket,
-        DisplayImagePacket,
-        StateChangePacket,
-        QuestEffectPacket,
-        ItemPickupPacket,
-        RemoveItemFromInventoryPacket,
-        ServerTickPacket,
-        RequestPlayerDetailsSuccessPacket,
-        RequestEntityDetailsSuccessPacket,
-        UpdateEntityHealthPointsPacket,
-        RequestPlayerAttackFailedPacket,
-        DamagePacket,
-        NpcDialogPacket,
-        RequestEquipItemStatusPacket,
-        RequestUnequipItemStatusPacket,
-        Packet8302,
-        Packet0b18,
-        MapServerLoginSuccessPacket,
-        RestartResponsePacket,
-        DisconnectResponsePacket,
-        UseSkillSuccessPacket,
-        ToUseSkillSuccessPacket,
-        NotifySkillUnitPacket,
-        SkillUnitDisappearPacket,
-        NotifyGroundSkillPacket,
-        FriendListPacket,
-        FriendOnlineStatusPacket,
-        FriendRequestPacket,
-        FriendRequestResultPacket,
-        NotifyFriendRemovedPacket,
-        PartyInvitePacket,
-        StatusChangeSequencePacket,
-        ReputationPacket,
-        ClanInfoPacket,
-        ClanOnlineCountPacket,
-        ChangeMapCellPacket,
-        OpenMarketPacket,
-        BuyOrSellPacket,
-        ShopItemListPacket,
-        BuyShopItemsResultPacket,
-        ParameterChangePacket,
-        SellListPacket,
-        SellItemsPacket,
-        SellItemsResultPacket,
-    ]);
-
-    let mut server_map_handler = create_handler!(ServerType::Map, Direction::Outgoing, [
-        MapLoadedPacket,
-        RestartPacket,
-        RequestPlayerMovePacket,
-        RequestWarpToMapPacket,
-        RequestDetailsPacket,
-        RequestActionPacket,
-        GlobalMessagePacket,
-        StartDialogPacket,
-        NextDialogPacket,
-        CloseDialogPacket,
-        ChooseDialogOptionPacket,
-        RequestEquipItemPacket,
-        RequestUnequipItemPacket,
-        UseSkillAtIdPacket,
-        UseSkillOnGroundPacket,
-        StartUseSkillPacket,
-        EndUseSkillPacket,
-        AddFriendPacket,
-        RemoveFriendPacket,
-        FriendRequestResponsePacket,
-        SetHotkeyData2Packet,
-        SelectBuyOrSellPacket,
-        BuyShopItemsPacket,
-        CloseShopPacket,
-        SellItemsPacket,
-        RequestServerTickPacket,
-    ]);
-
-    println!("{}", "Listening for packets".green());
-
-    while let Ok(packet) = cap.next_packet() {
-        match SlicedPacket::from_ethernet(packet.data) {
-            Err(value) => println!("Err {:?}", value),
-            Ok(value) => {
-                if let Some(TransportSlice::Tcp(tcp_slice)) = value.transport {
-                    let source_port = tcp_slice.source_port();
-                    let destination_port = tcp_slice.destination_port();
-
-                    // FIX: Obviously this will break if the local port is one of the server ports.
-                    // Check if the packet in incoming or outgoing beforehand.
-
-                    // FIX: Handle cut off packets.
-
-                    if source_port == LOGIN_SERVER_PORT {
-                        let mut byte_stream = ByteStream::without_metadata(tcp_slice.payload());
-                        while let HandlerResult::Ok(_) = client_login_handler.process_one(&mut byte_stream) {}
-                    } else if destination_port == LOGIN_SERVER_PORT {
-                        let mut byte_stream = ByteStream::without_metadata(tcp_slice.payload());
-                        while let HandlerResult::Ok(_) = server_login_handler.process_one(&mut byte_stream) {}
-                    } else if source_port == CHARACTER_SERVER_PORT {
-                        let mut byte_stream = ByteStream::without_metadata(tcp_slice.payload());
-                        while let HandlerResult::Ok(_) = client_character_handler.process_one(&mut byte_stream) {}
-                    } else if destination_port == CHARACTER_SERVER_PORT {
-                        let mut byte_stream = ByteStream::without_metadata(tcp_slice.payload());
-                        while let HandlerResult::Ok(_) = server_character_handler.process_one(&mut byte_stream) {}
-                    } else if source_port == MAP_SERVER_PORT {
-                        let mut byte_stream = ByteStream::without_metadata(tcp_slice.payload());
-                        while let HandlerResult::Ok(_) = client_map_handler.process_one(&mut byte_stream) {}
-                    } else if destination_port == MAP_SERVER_PORT {
-                        let mut byte_stream = ByteStream::without_metadata(tcp_slice.payload());
-                        while let HandlerResult::Ok(_) = server_map_handler.process_one(&mut byte_stream) {}
+        let handler = self.handlers.get(server_type, direction);
+        let mut byte_stream = ByteStream::with_metadata(&stream.buffer, handler.profile().version());
+
+        while !byte_stream.is_empty() {
+            let start = byte_stream.get_offset();
+            let header = PacketHeader(
+                stream
+                    .buffer
+                    .get(start..start + 2)
+                    .map_or(0, |bytes| u16::from_le_bytes([bytes[0], bytes[1]])),
+            );
+
+            match handler.process_one(&mut byte_stream) {
+                HandlerResult::Ok(Some(packet)) => {
+                    if !self.options.includes_packet(header, packet.packet_type) {
+                        continue;
+                    }
+
+                    let statistics = self
+                        .statistics
+                        .packets
+                        .entry((server_type, direction, header, packet.packet_type))
+                        .or_default();
+                    statistics.count += 1;
+                    statistics.bytes += byte_stream.get_offset() - start;
+
+                    if !self.options.quiet {
+                        println!(
+                            "{} packet on {} server: {} ({})",
+                            direction.convert(),
+                            server_type.convert(),
+                            packet.packet_type.cyan(),
+                            format!("0x{:0>4x}", header.0).green()
+                        );
+
+                        if self.options.expand && !packet.is_ping {
+                            let arrow = match direction {
+                                Direction::Incoming => ARROW.green(),
+                                Direction::Outgoing => ARROW.red(),
+                            };
+                            println!(" {arrow} {}", packet.details)
+                        }
+                    }
+                }
+                HandlerResult::Ok(None) => unreachable!("every handler returns a packet"),
+                HandlerResult::PacketCutOff => break,
+                // Without knowing the packet, there is no way to know where the next one starts,
+                // so the rest of the stream is skipped.
+                HandlerResult::UnhandledPacket => {
+                    let skipped_bytes = stream.buffer.len() - start;
+
+                    if self.options.includes_header(header) {
+                        let statistics = self.statistics.unknown_headers.entry((server_type, direction, header)).or_default();
+                        statistics.count += 1;
+                        statistics.bytes += skipped_bytes;
+
+                        if !self.options.quiet {
+                            println!(
+                                "Unknown {} packet on {} server with header {}: {:?}",
+                                direction.convert(),
+                                server_type.convert(),
+                                format!("0x{:0>4x}", header.0).red(),
+                                &stream.buffer[start..]
+                            );
+                        }
+                    }
+
+                    self.statistics.skipped_bytes += skipped_bytes;
+                    stream.desynced = true;
+                    break;
+                }
+                HandlerResult::InternalError(error) => {
+                    let skipped_bytes = stream.buffer.len() - start;
+
+                    if self.options.includes_header(header) {
+                        let statistics = self.statistics.failures.entry((server_type, direction, header)).or_default();
+                        statistics.count += 1;
+                        statistics.last_error = format!("{error:?}");
+
+                        if !self.options.quiet {
+                            println!(
+                                "Error {} packet on {} server with header {} and error {:?}: {:?}",
+                                direction.convert(),
+                                server_type.convert(),
+                                format!("0x{:0>4x}", header.0).red(),
+                                error.red(),
+                                &stream.buffer[start..]
+                            );
+                        }
                     }
-                };
+
+                    self.statistics.skipped_bytes += skipped_bytes;
+                    stream.desynced = true;
+                    break;
+                }
             }
         }
+
+        let processed_bytes = match byte_stream.is_empty() {
+            true => stream.buffer.len(),
+            false => byte_stream.get_offset(),
+        };
+        stream.buffer.drain(..processed_bytes);
+    }
+}
+
+fn open_capture(options: &Options) -> Result<Capture<dyn Activated>, pcap::Error> {
+    let mut capture: Capture<dyn Activated> = match &options.source {
+        Source::File(path) => Capture::from_file(path)?.into(),
+        Source::Device(device) => Capture::from_device(device.as_str())?.immediate_mode(true).open()?.into(),
+    };
+
+    if let Some(filter) = &options.filter {
+        capture.filter(filter, true)?;
+    }
+
+    Ok(capture)
+}
+
+fn main() -> ExitCode {
+    let options = match Options::parse() {
+        Ok(options) => options,
+        Err(error) => {
+            eprintln!("{error}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut capture = match open_capture(&options) {
+        Ok(capture) => capture,
+        Err(error) => {
+            eprintln!("failed to open capture: {error}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let link_type = capture.get_datalink();
+    let mut analyzer = Analyzer {
+        handlers: Handlers::new(&options.packet_profile),
+        options,
+        streams: HashMap::new(),
+        statistics: Statistics::default(),
+    };
+
+    if !analyzer.options.quiet {
+        println!("{}", "Reading packets".green());
     }
+
+    while let Ok(packet) = capture.next_packet() {
+        let sliced_packet = match link_type {
+            Linktype::LINUX_SLL => SlicedPacket::from_linux_sll(packet.data),
+            Linktype::RAW | Linktype::IPV4 => SlicedPacket::from_ip(packet.data),
+            _ => SlicedPacket::from_ethernet(packet.data),
+        };
+
+        let Ok(sliced_packet) = sliced_packet else {
+            continue;
+        };
+
+        let (source_ip, destination_ip): (IpAddr, IpAddr) = match &sliced_packet.net {
+            Some(NetSlice::Ipv4(ipv4)) => (ipv4.header().source_addr().into(), ipv4.header().destination_addr().into()),
+            Some(NetSlice::Ipv6(ipv6)) => (ipv6.header().source_addr().into(), ipv6.header().destination_addr().into()),
+            None => continue,
+        };
+
+        if let Some(TransportSlice::Tcp(tcp_slice)) = &sliced_packet.transport {
+            analyzer.process_segment(
+                SocketAddr::new(source_ip, tcp_slice.source_port()),
+                SocketAddr::new(destination_ip, tcp_slice.destination_port()),
+                tcp_slice.sequence_number(),
+                tcp_slice.syn(),
+                tcp_slice.payload(),
+            );
+        }
+    }
+
+    match analyzer.options.json {
+        true => analyzer.statistics.print_json(),
+        false => analyzer.statistics.print_table(),
+    }
+
+    ExitCode::SUCCESS
 }
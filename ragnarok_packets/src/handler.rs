@@ -2,7 +2,7 @@ use std::collections::HashMap;
 
 use ragnarok_bytes::{ByteStream, ConversionError, ConversionResult, FromBytes};
 
-use crate::PacketHeader;
+use crate::{PacketHeader, PacketProfile};
 
 /// Possible results of [`PacketHandler::process_one`].
 pub enum HandlerResult<Output> {
@@ -23,8 +23,24 @@ pub struct DuplicateHandlerError {
     pub packet_header: PacketHeader,
 }
 
+/// The server on the other end of a connection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ServerType {
+    Login,
+    Character,
+    Map,
+}
+
 /// Trait for monitoring the incoming and outgoing packets.
 pub trait PacketCallback: Clone + 'static {
+    /// Called once for every server connection to get the callback used for
+    /// that connection. Callbacks that need to tell the servers apart can
+    /// return a copy that remembers the server type.
+    fn for_server(&self, server_type: ServerType) -> Self {
+        let _ = server_type;
+        self.clone()
+    }
+
     /// Called by the [`PacketHandler`] when a packet is received.
     fn incoming_packet<Packet>(&self, packet: &Packet)
     where
@@ -33,6 +49,18 @@ pub trait PacketCallback: Clone + 'static {
         let _ = packet;
     }
 
+    /// Called by the [`PacketHandler`] after a packet was received and handled,
+    /// with the bytes of the packet exactly as they were read from the stream.
+    fn incoming_bytes(&self, bytes: &[u8]) {
+        let _ = bytes;
+    }
+
+    /// Called when connecting to a server, before any packets of the
+    /// connection are sent or received.
+    fn connected(&self, packet_profile: &PacketProfile) {
+        let _ = packet_profile;
+    }
+
     /// Called by when a packet is sent.
     fn outgoing_packet<Packet>(&self, packet: &Packet)
     where
@@ -41,6 +69,12 @@ pub trait PacketCallback: Clone + 'static {
         let _ = packet;
     }
 
+    /// Called when a packet is sent, with the bytes of the packet exactly as
+    /// they are written to the stream.
+    fn outgoing_bytes(&self, bytes: &[u8]) {
+        let _ = bytes;
+    }
+
     /// Called by the [`PacketHandler`] when a packet arrives that doesn't have
     /// a handler registered.
     fn unknown_packet(&self, bytes: Vec<u8>) {
@@ -64,12 +98,18 @@ pub type HandlerFunction<Output, Meta> = Box<dyn Fn(&mut ByteStream<Meta>) -> Co
 /// converting them to some common event type.
 ///
 /// It allows passing a packet callback to monitor incoming packets.
+///
+/// Handlers are registered under the header that the [`PacketProfile`] uses
+/// for the packet. To read packets with the layout of the profile, the byte
+/// stream should carry its [`PacketVersion`](crate::PacketVersion) as
+/// metadata.
 pub struct PacketHandler<Output, Meta, Callback>
 where
     Meta: 'static,
 {
     handlers: HashMap<PacketHeader, HandlerFunction<Output, Meta>>,
     packet_callback: Callback,
+    profile: PacketProfile,
 }
 
 impl<Output, Meta, Callback> Default for PacketHandler<Output, Meta, Callback>
@@ -81,6 +121,7 @@ where
         Self {
             handlers: Default::default(),
             packet_callback: Default::default(),
+            profile: Default::default(),
         }
     }
 }
@@ -93,12 +134,23 @@ where
 {
     /// Create a new packet handler with a callback.
     pub fn with_callback(packet_callback: Callback) -> Self {
+        Self::with_profile(packet_callback, PacketProfile::default())
+    }
+
+    /// Create a new packet handler with a callback for a specific
+    /// [`PacketProfile`].
+    pub fn with_profile(packet_callback: Callback, profile: PacketProfile) -> Self {
         Self {
             handlers: Default::default(),
             packet_callback,
+            profile,
         }
     }
 
+    pub fn profile(&self) -> &PacketProfile {
+        &self.profile
+    }
+
     /// Register a new packet handler.
     pub fn register<Packet, Return>(&mut self, handler: impl Fn(Packet) -> Return + 'static) -> Result<(), DuplicateHandlerError>
     where
@@ -106,8 +158,9 @@ where
         Return: Into<Output>,
     {
         let packet_callback = self.packet_callback.clone();
+        let packet_header = self.profile.header::<Packet>();
         let old_handler = self.handlers.insert(
-            Packet::HEADER,
+            packet_header,
             Box::new(move |byte_stream| {
                 let packet = Packet::payload_from_bytes(byte_stream)?;
 
@@ -118,9 +171,7 @@ where
         );
 
         match old_handler.is_some() {
-            true => Err(DuplicateHandlerError {
-                packet_header: Packet::HEADER,
-            }),
+            true => Err(DuplicateHandlerError { packet_header }),
             false => Ok(()),
         }
     }
@@ -131,8 +182,9 @@ where
         Packet: ragnarok_packets::Packet,
     {
         let packet_callback = self.packet_callback.clone();
+        let packet_header = self.profile.header::<Packet>();
         let old_handler = self.handlers.insert(
-            Packet::HEADER,
+            packet_header,
             Box::new(move |byte_stream| {
                 let packet = Packet::payload_from_bytes(byte_stream)?;
 
@@ -143,9 +195,7 @@ where
         );
 
         match old_handler.is_some() {
-            true => Err(DuplicateHandlerError {
-                packet_header: Packet::HEADER,
-            }),
+            true => Err(DuplicateHandlerError { packet_header }),
             false => Ok(()),
         }
     }
@@ -169,7 +219,10 @@ where
         };
 
         match handler(byte_stream) {
-            Ok(output) => HandlerResult::Ok(output),
+            Ok(output) => {
+                self.packet_callback.incoming_bytes(byte_stream.bytes_since(&save_point));
+                HandlerResult::Ok(output)
+            }
             // Cut-off packet (probably).
             Err(error) if error.is_byte_stream_too_short() => {
                 byte_stream.restore_save_point(save_point);
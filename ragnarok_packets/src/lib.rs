@@ -1,5 +1,6 @@
 pub mod handler;
 mod position;
+mod profile;
 
 use std::net::Ipv4Addr;
 
@@ -12,6 +13,7 @@ pub use ragnarok_procedural::{CharacterServer, ClientPacket, LoginServer, MapSer
 use ragnarok_procedural::{CharacterServer, ClientPacket, LoginServer, MapServer, Packet, ServerPacket};
 
 pub use self::position::{WorldPosition, WorldPosition2};
+pub use self::profile::{PacketProfile, PacketVersion};
 
 // To make proc macros work in korangar_interface.
 extern crate self as ragnarok_packets;
@@ -33,6 +35,14 @@ pub trait Packet: std::fmt::Debug + Clone {
     /// The header of the Packet.
     const HEADER: PacketHeader;
 
+    /// The header of the packet for a specific [`PacketVersion`]. Only differs
+    /// from [`Packet::HEADER`] for packets that changed their header over
+    /// time. To also respect header overrides, use [`PacketProfile::header`].
+    fn header_for_version(version: PacketVersion) -> PacketHeader {
+        let _ = version;
+        Self::HEADER
+    }
+
     /// Read packet **without the header**. To read the packet with the header,
     /// use [`PacketExt::packet_from_bytes`].
     fn payload_from_bytes<Meta>(byte_stream: &mut ByteStream<Meta>) -> ConversionResult<Self>;
@@ -58,6 +68,10 @@ pub trait PacketExt: Packet {
     /// Write packet **with the header**. To write the packet without the
     /// header, use [`Packet::payload_to_bytes`].
     fn packet_to_bytes(&self) -> ConversionResult<Vec<u8>>;
+
+    /// Write packet **with the header** that the [`PacketProfile`] uses for
+    /// it.
+    fn packet_to_bytes_with_profile(&self, profile: &PacketProfile) -> ConversionResult<Vec<u8>>;
 }
 
 impl<T> PacketExt for T
@@ -81,6 +95,14 @@ where
 
         Ok(bytes)
     }
+
+    fn packet_to_bytes_with_profile(&self, profile: &PacketProfile) -> ConversionResult<Vec<u8>> {
+        let mut bytes = profile.header::<Self>().to_bytes()?;
+
+        bytes.extend(self.payload_to_bytes()?);
+
+        Ok(bytes)
+    }
 }
 
 /// Marker trait for packets sent by the client.
@@ -100,34 +122,67 @@ pub trait MapServerPacket: Packet {}
 
 #[derive(Clone, Copy, Debug, ByteConvertable, FixedByteSize)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct ClientTick(pub u32);
 
 #[derive(Clone, Copy, Debug, ByteConvertable, FixedByteSize, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct AccountId(pub u32);
 
 #[derive(Clone, Copy, Debug, ByteConvertable, FixedByteSize, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct CharacterId(pub u32);
 
 #[derive(Clone, Copy, Debug, ByteConvertable, FixedByteSize, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct PartyId(pub u32);
 
 #[derive(Clone, Copy, Debug, ByteConvertable, FixedByteSize, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct GuildId(pub u32);
+
+#[derive(Clone, Copy, Debug, ByteConvertable, FixedByteSize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct ChatRoomId(pub u32);
+
+#[derive(Clone, Copy, Debug, ByteConvertable, FixedByteSize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct MailId(pub u64);
+
+#[derive(Clone, Copy, Debug, ByteConvertable, FixedByteSize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct VendorId(pub u32);
+
+#[derive(Clone, Copy, Debug, ByteConvertable, FixedByteSize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct BuyingStoreId(pub u32);
+
+#[derive(Clone, Copy, Debug, ByteConvertable, FixedByteSize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct EntityId(pub u32);
 
 #[derive(Clone, Copy, Debug, ByteConvertable, FixedByteSize, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct SkillId(pub u16);
 
 #[derive(Clone, Copy, Debug, ByteConvertable, FixedByteSize, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct SkillLevel(pub u16);
 
 #[derive(Clone, Copy, Debug, ByteConvertable, FixedByteSize, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct HotbarTab(pub u16);
 
 #[derive(Clone, Copy, Debug, ByteConvertable, FixedByteSize, PartialEq, Eq, Hash)]
@@ -136,14 +191,17 @@ pub struct HotbarSlot(pub u16);
 
 #[derive(Clone, Copy, Debug, ByteConvertable, FixedByteSize, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct ShopId(pub u32);
 
 #[derive(Clone, Copy, Debug, ByteConvertable, FixedByteSize, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Price(pub u32);
 
 #[derive(Clone, Copy, Debug, ByteConvertable, FixedByteSize)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct ServerAddress(pub [u8; 4]);
 
 #[derive(Clone, Copy, Debug, ByteConvertable, FixedByteSize)]
@@ -162,6 +220,7 @@ impl From<ServerAddress> for Ipv4Addr {
 
 #[derive(Clone, Copy, Debug, ByteConvertable, FixedByteSize, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct TilePosition {
     pub x: u16,
     pub y: u16,
@@ -169,6 +228,7 @@ pub struct TilePosition {
 
 #[derive(Clone, Copy, Debug, ByteConvertable, FixedByteSize, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct LargeTilePosition {
     pub x: u32,
     pub y: u32,
@@ -185,6 +245,7 @@ pub struct ColorBGRA {
 
 #[derive(Debug, Clone, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct ColorRGBA {
     pub red: u8,
     pub green: u8,
@@ -195,6 +256,7 @@ pub struct ColorRGBA {
 /// Item index is always actual index + 2.
 #[derive(Clone, Copy, Debug, FixedByteSize, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct InventoryIndex(pub u16);
 
 impl FromBytes for InventoryIndex {
@@ -209,12 +271,32 @@ impl ToBytes for InventoryIndex {
     }
 }
 
+/// Storage index is always actual index + 1.
+#[derive(Clone, Copy, Debug, FixedByteSize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct StorageIndex(pub u16);
+
+impl FromBytes for StorageIndex {
+    fn from_bytes<Meta>(byte_stream: &mut ByteStream<Meta>) -> ConversionResult<Self> {
+        u16::from_bytes(byte_stream).map(|raw| Self(raw - 1))
+    }
+}
+
+impl ToBytes for StorageIndex {
+    fn to_bytes(&self) -> ConversionResult<Vec<u8>> {
+        u16::to_bytes(&(self.0 + 1))
+    }
+}
+
 #[derive(Clone, Copy, Debug, ByteConvertable, FixedByteSize, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct ItemId(pub u32);
 
 #[derive(Copy, Debug, Clone, ByteConvertable, FixedByteSize, PartialEq)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum Sex {
     Female,
     Male,
@@ -310,17 +392,20 @@ pub struct Packet0b18 {
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
 #[header(0x02EB)]
+#[header_version_smaller(20080102, 0x0073)]
 pub struct MapServerLoginSuccessPacket {
     pub client_tick: ClientTick,
     pub position: WorldPosition,
     /// Always [5, 5] on rAthena
     #[new_default]
     pub ignored: [u8; 2],
-    pub font: u16,
+    #[packet_version_equals_or_above(20080102)]
+    pub font: Option<u16>,
 }
 
 #[derive(Debug, Clone, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum LoginFailedReason {
     #[numeric_value(1)]
     ServerClosed,
@@ -401,6 +486,7 @@ pub struct CharacterSelectionSuccessPacket {
 
 #[derive(Debug, Clone, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum CharacterCreationFailedReason {
     CharacterNameAlreadyUsed,
     NotOldEnough,
@@ -432,6 +518,7 @@ pub struct LoginServerKeepalivePacket {
 
 #[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct CharacterServerInformation {
     pub server_ip: ServerAddress,
     pub server_port: u16,
@@ -441,6 +528,7 @@ pub struct CharacterServerInformation {
     pub server_type: u16, // ServerType
     pub display_new: u16, // bool16 ?
     #[new_value([0; 128])]
+    #[cfg_attr(feature = "serde", serde(skip))]
     pub unknown: [u8; 128],
 }
 
@@ -503,6 +591,7 @@ pub struct CreateCharacterPacket {
 
 #[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct CharacterInformation {
     pub character_id: CharacterId,
     pub experience: i64,
@@ -661,6 +750,7 @@ pub struct DeleteCharacterPacket {
 
 #[derive(Debug, Clone, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum CharacterDeletionFailedReason {
     NotAllowed,
     CharacterNotFound,
@@ -745,6 +835,8 @@ pub struct RequestEntityDetailsSuccessPacket {
     pub title: String,
 }
 
+/// Sent by the map server when logging in and whenever the player receives
+/// new mail.
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
 #[header(0x09E7)]
@@ -805,12 +897,21 @@ pub struct SpriteChangePacket {
     pub value2: u32,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+pub enum InventoryType {
+    Inventory,
+    Cart,
+    Storage,
+    GuildStorage,
+}
+
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
 #[header(0x0B08)]
 #[variable_length]
 pub struct InventoyStartPacket {
-    pub inventory_type: u8,
+    pub inventory_type: InventoryType,
     #[length_remaining]
     pub inventory_name: String,
 }
@@ -819,12 +920,13 @@ pub struct InventoyStartPacket {
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
 #[header(0x0B0B)]
 pub struct InventoyEndPacket {
-    pub inventory_type: u8,
+    pub inventory_type: InventoryType,
     pub flag: u8, // maybe char ?
 }
 
 #[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct ItemOptions {
     pub index: u16,
     pub value: u16,
@@ -834,6 +936,7 @@ pub struct ItemOptions {
 bitflags::bitflags! {
     #[derive(Debug, Clone)]
     #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize))]
     pub struct RegularItemFlags: u8 {
         const IDENTIFIED = 0b01;
         const IN_ETC_TAB = 0b10;
@@ -861,7 +964,9 @@ impl ToBytes for RegularItemFlags {
 #[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
 pub struct RegularItemInformation {
-    pub index: InventoryIndex,
+    /// Offset by 2 for the inventory and the cart and by 1 for the storage, see
+    /// [`InventoryIndex`] and [`StorageIndex`].
+    pub index: u16,
     pub item_id: ItemId,
     pub item_type: u8,
     pub amount: u16,
@@ -876,7 +981,7 @@ pub struct RegularItemInformation {
 #[header(0x0B09)]
 #[variable_length]
 pub struct RegularItemListPacket {
-    pub inventory_type: u8,
+    pub inventory_type: InventoryType,
     #[repeating_remaining]
     pub item_information: Vec<RegularItemInformation>,
 }
@@ -884,6 +989,7 @@ pub struct RegularItemListPacket {
 bitflags::bitflags! {
     #[derive(Debug, Clone)]
     #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize))]
     pub struct EquippableItemFlags: u8 {
         const IDENTIFIED = 0b001;
         const IS_BROKEN = 0b010;
@@ -912,7 +1018,9 @@ impl ToBytes for EquippableItemFlags {
 #[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
 pub struct EquippableItemInformation {
-    pub index: InventoryIndex,
+    /// Offset by 2 for the inventory and the cart and by 1 for the storage, see
+    /// [`InventoryIndex`] and [`StorageIndex`].
+    pub index: u16,
     pub item_id: ItemId,
     pub item_type: u8,
     pub equip_position: EquipPosition,
@@ -933,7 +1041,7 @@ pub struct EquippableItemInformation {
 #[header(0x0B39)]
 #[variable_length]
 pub struct EquippableItemListPacket {
-    pub inventory_type: u8,
+    pub inventory_type: InventoryType,
     #[repeating_remaining]
     pub item_information: Vec<EquippableItemInformation>,
 }
@@ -1025,11 +1133,21 @@ pub struct DisplayEmotionPacket {
     pub emotion: u8,
 }
 
+/// Sent by the client to the map server when the player uses an emotion. The
+/// map server answers with a [`DisplayEmotionPacket`] to everyone in sight.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x00BF)]
+pub struct RequestEmotionPacket {
+    pub emotion: u8,
+}
+
 /// Every value that can be set from the server through [UpdateStatusPacket],
 /// [UpdateStatusPacket1], [UpdateStatusPacket2], and [UpdateStatusPacket3].
 /// All UpdateStatusPackets do the same, they just have different sizes
 /// correlating to the space the updated value requires.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum StatusType {
     Weight(u32),
     MaximumWeight(u32),
@@ -1549,6 +1667,7 @@ pub struct EntityAppeared2Packet {
 #[derive(Clone, Copy, Debug, ByteConvertable, FixedByteSize)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
 #[numeric_type(u32)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum SkillType {
     #[numeric_value(0)]
     Passive,
@@ -1566,6 +1685,7 @@ pub enum SkillType {
 
 #[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct SkillInformation {
     pub skill_id: SkillId,
     pub skill_type: SkillType,
@@ -1588,6 +1708,7 @@ pub struct UpdateSkillTreePacket {
 
 #[derive(Debug, Clone, PartialEq, Eq, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct HotkeyData {
     pub is_skill: u8,
     pub skill_id: u32,
@@ -1809,6 +1930,7 @@ pub struct QuestNotificationPacket1 {
 
 #[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct HuntingObjective {
     pub quest_id: u32,
     pub mob_id: u32,
@@ -1933,6 +2055,7 @@ pub struct DisplayGainedExperiencePacket {
 
 #[derive(Debug, Clone, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum ImageLocation {
     BottomLeft,
     BottomMiddle,
@@ -2001,6 +2124,7 @@ pub struct ItemPickupPacket {
 #[derive(Debug, Clone, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
 #[numeric_type(u16)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum RemoveItemReason {
     Normal,
     ItemUsedForSkill,
@@ -2021,10 +2145,94 @@ pub struct RemoveItemFromInventoryPacket {
     pub amount: u16,
 }
 
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0439)]
+pub struct UseItemPacket {
+    pub index: InventoryIndex,
+    pub account_id: AccountId,
+}
+
+/// Sent to everyone in the area, so the entity id is not necessarily the one
+/// of the player.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x01C8)]
+pub struct UseItemResultPacket {
+    pub index: InventoryIndex,
+    pub item_id: ItemId,
+    pub entity_id: EntityId,
+    /// The amount of the item left after using it.
+    pub amount: u16,
+    pub success: u8,
+}
+
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0363)]
+pub struct DropItemPacket {
+    pub index: InventoryIndex,
+    pub amount: u16,
+}
+
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x00AF)]
+pub struct DropItemResultPacket {
+    pub index: InventoryIndex,
+    pub amount: u16,
+}
+
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0362)]
+pub struct PickUpItemPacket {
+    pub entity_id: EntityId,
+}
+
+/// Sent when an item on the ground comes into view.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x009D)]
+pub struct GroundItemAppearedPacket {
+    pub entity_id: EntityId,
+    pub item_id: ItemId,
+    pub is_identified: u8,
+    pub position: TilePosition,
+    pub amount: u16,
+    pub sub_x: u8,
+    pub sub_y: u8,
+}
+
+/// Sent when an item is dropped on the ground.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0ADD)]
+pub struct GroundItemDroppedPacket {
+    pub entity_id: EntityId,
+    pub item_id: ItemId,
+    pub item_type: u16,
+    pub is_identified: u8,
+    pub position: TilePosition,
+    pub sub_x: u8,
+    pub sub_y: u8,
+    pub amount: u16,
+    pub show_drop_effect: u8,
+    pub drop_effect_mode: u16,
+}
+
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x00A1)]
+pub struct GroundItemDisappearedPacket {
+    pub entity_id: EntityId,
+}
+
 // TODO: improve names
 #[derive(Debug, Clone, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
 #[numeric_type(u16)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum QuestEffect {
     Quest,
     Quest2,
@@ -2044,6 +2252,7 @@ pub enum QuestEffect {
 #[derive(Debug, Clone, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
 #[numeric_type(u16)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum QuestColor {
     Yellow,
     Orange,
@@ -2054,6 +2263,7 @@ pub enum QuestColor {
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
 #[header(0x0446)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct QuestEffectPacket {
     pub entity_id: EntityId,
     pub position: TilePosition,
@@ -2120,6 +2330,7 @@ pub struct ChooseDialogOptionPacket {
 bitflags::bitflags! {
     #[derive(Clone, Copy, Debug, PartialEq, Eq)]
     #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize))]
     pub struct EquipPosition: u32 {
         const NONE = 0;
         const HEAD_LOWER = 1;
@@ -2331,9 +2542,18 @@ pub struct ToUseSkillSuccessPacket {
     pub cause: u8,
 }
 
+/// Sent when an entity stops casting a skill before the cast time is over.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x01B9)]
+pub struct CastCancelPacket {
+    pub entity_id: EntityId,
+}
+
 #[derive(Debug, Clone, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
 #[numeric_type(u32)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum UnitId {
     #[numeric_value(0x7E)]
     Safetywall,
@@ -2560,6 +2780,7 @@ pub struct SkillUnitDisappearPacket {
 
 #[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Friend {
     pub account_id: AccountId,
     pub character_id: CharacterId,
@@ -2600,8 +2821,9 @@ pub struct FriendListPacket {
     pub friends: Vec<Friend>,
 }
 
-#[derive(Debug, Clone, ByteConvertable)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable, FixedByteSize)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum OnlineState {
     Online,
     Offline,
@@ -2669,274 +2891,2353 @@ pub struct PartyInvitePacket {
     pub party_name: String,
 }
 
-#[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
-pub struct ReputationEntry {
-    pub reputation_type: u64,
-    pub points: i64,
+pub enum PartyItemPickupRule {
+    EachTake,
+    PartyShare,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+pub enum PartyItemShareRule {
+    EachTake,
+    PartyShare,
+}
+
+/// Sent by the client to the map server when the player wants to create a new
+/// party.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x01E8)]
+pub struct CreatePartyPacket {
+    #[length(24)]
+    pub party_name: String,
+    pub item_pickup_rule: PartyItemPickupRule,
+    pub item_share_rule: PartyItemShareRule,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+pub enum PartyCreationResult {
+    Created,
+    NameAlreadyExists,
+    AlreadyInParty,
+    NotAllowedOnMap,
 }
 
+/// Sent by the map server to the client as a response to
+/// [CreatePartyPacket].
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
-#[header(0x0B8D)]
-#[variable_length]
-pub struct ReputationPacket {
-    pub success: u8,
-    #[repeating_remaining]
-    pub entries: Vec<ReputationEntry>,
+#[header(0x00FA)]
+pub struct PartyCreationResultPacket {
+    pub result: PartyCreationResult,
 }
 
-#[derive(Debug, Clone, ByteConvertable)]
+/// Sent by the client to the map server when the player wants to invite
+/// another player to their party.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
-pub struct Aliance {
+#[header(0x02C4)]
+pub struct InviteToPartyPacket {
     #[length(24)]
     pub name: String,
 }
 
 #[derive(Debug, Clone, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
-pub struct Antagonist {
+pub enum PartyInviteResponse {
+    Reject,
+    Accept,
+}
+
+/// Sent by the client to the map server as a response to
+/// [PartyInvitePacket].
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x02C7)]
+pub struct PartyInviteResponsePacket {
+    pub party_id: PartyId,
+    pub response: PartyInviteResponse,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[numeric_type(u32)]
+pub enum PartyInviteResult {
+    AlreadyInParty,
+    Rejected,
+    Accepted,
+    PartyFull,
+    SameAccount,
+    InvitesBlocked,
+    UnknownError,
+    CharacterOffline,
+    NotAllowedOnMap,
+    NotAllowedOnMapLevel,
+}
+
+/// Sent by the map server to the client as a response to
+/// [InviteToPartyPacket] once the invited player responded.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x02C5)]
+pub struct PartyInviteResultPacket {
+    #[length(24)]
+    pub name: String,
+    pub result: PartyInviteResult,
+}
+
+/// Sent by the client to the map server when the player wants to leave their
+/// party.
+#[derive(Debug, Clone, Default, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0100)]
+pub struct LeavePartyPacket {}
+
+/// Sent by the client to the map server when the party leader wants to remove
+/// a member from the party.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0103)]
+pub struct KickPartyMemberPacket {
+    pub account_id: AccountId,
     #[length(24)]
     pub name: String,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+pub enum PartyLeaveReason {
+    Left,
+    Kicked,
+    CannotLeave,
+    CannotKick,
+}
+
+/// Sent by the map server to the client when a member left or was removed from
+/// the party. If the account id is the one of the player, the player is no
+/// longer in a party.
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
-#[header(0x098A)]
-#[variable_length]
-pub struct ClanInfoPacket {
-    pub clan_id: u32,
+#[header(0x0105)]
+pub struct PartyMemberLeftPacket {
+    pub account_id: AccountId,
     #[length(24)]
-    pub clan_name: String,
+    pub name: String,
+    pub reason: PartyLeaveReason,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable, FixedByteSize)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum PartyMemberRole {
+    Leader,
+    Member,
+}
+
+#[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct PartyMember {
+    pub account_id: AccountId,
+    pub character_id: CharacterId,
     #[length(24)]
-    pub clan_master: String,
+    pub name: String,
     #[length(16)]
-    pub clan_map: String,
-    #[new_derive]
-    pub aliance_count: u8,
-    #[new_derive]
-    pub antagonist_count: u8,
-    #[repeating(aliance_count)]
-    pub aliances: Vec<Aliance>,
-    #[repeating(antagonist_count)]
-    pub antagonists: Vec<Antagonist>,
+    pub map_name: String,
+    pub role: PartyMemberRole,
+    pub state: OnlineState,
+    pub job: u16,
+    pub base_level: u16,
 }
 
+/// Sent by the map server to the client when the player joins a party or the
+/// party changes. Provides the name of the party and a list of all members.
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
-#[header(0x0988)]
-pub struct ClanOnlineCountPacket {
-    pub online_members: u16,
-    pub maximum_members: u16,
+#[header(0x0A44)]
+#[variable_length]
+pub struct PartyInformationPacket {
+    #[length(24)]
+    pub party_name: String,
+    #[repeating_remaining]
+    pub members: Vec<PartyMember>,
 }
 
+/// Sent by the map server to the client when a new member joins the party or
+/// an existing member logs in or changes maps.
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
-#[header(0x0192)]
-pub struct ChangeMapCellPacket {
+#[header(0x0AE4)]
+pub struct PartyMemberJoinedPacket {
+    pub account_id: AccountId,
+    pub character_id: CharacterId,
+    /// 0 for the party leader and 1 for all other members.
+    pub role: u32,
+    pub job: u16,
+    pub base_level: u16,
     pub position: TilePosition,
-    pub cell_type: u16,
+    pub state: OnlineState,
+    #[length(24)]
+    pub party_name: String,
+    #[length(24)]
+    pub name: String,
     #[length(16)]
     pub map_name: String,
+    pub item_pickup_rule: PartyItemPickupRule,
+    pub item_share_rule: PartyItemShareRule,
 }
 
-#[derive(Debug, Clone, FixedByteSize, ByteConvertable)]
+/// Sent by the map server to the client when the party settings change.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
-pub struct MarketItemInformation {
-    pub name_id: u32,
-    pub item_type: u8,
-    pub price: Price,
-    pub quantity: u32,
-    pub weight: u16,
-    pub location: u32,
+#[header(0x07D8)]
+pub struct PartySettingsPacket {
+    pub experience_share: u32,
+    pub item_pickup_rule: PartyItemPickupRule,
+    pub item_share_rule: PartyItemShareRule,
 }
 
+/// Sent by the map server to the client when the leader of the party changes.
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
-#[header(0x0B7A)]
-#[variable_length]
-pub struct OpenMarketPacket {
-    #[repeating_remaining]
-    pub items: Vec<MarketItemInformation>,
+#[header(0x07FC)]
+pub struct PartyLeaderChangedPacket {
+    pub old_leader_account_id: AccountId,
+    pub new_leader_account_id: AccountId,
 }
 
-#[derive(Debug, Clone, FixedByteSize, ByteConvertable)]
+/// Sent by the map server to the client periodically to update the position
+/// of party members on the same map.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
-pub struct ShopItemInformation {
-    pub item_id: ItemId,
-    pub price: Price,
-    pub discount_price: Price,
-    pub item_type: u8,
-    pub view_sprite: u16,
-    pub location: u32,
+#[header(0x0107)]
+pub struct PartyMemberPositionPacket {
+    pub account_id: AccountId,
+    pub position: TilePosition,
 }
 
+/// Sent by the map server to the client when the health of a party member
+/// on the same map changes.
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
-#[header(0x0B77)]
+#[header(0x080E)]
+pub struct PartyMemberHealthPacket {
+    pub account_id: AccountId,
+    pub health_points: u32,
+    pub maximum_health_points: u32,
+}
+
+/// Sent by the client to the map server when the player writes in the party
+/// chat.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0108)]
 #[variable_length]
-pub struct ShopItemListPacket {
-    #[repeating_remaining]
-    pub items: Vec<ShopItemInformation>,
+pub struct PartyMessagePacket {
+    #[length_remaining_off_by_one]
+    pub message: String,
 }
 
+/// Sent by the map server to the client when a party member writes in the party
+/// chat.
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
-#[header(0x00C4)]
-pub struct BuyOrSellPacket {
-    pub shop_id: ShopId,
+#[header(0x0109)]
+#[variable_length]
+pub struct NotifyPartyMessagePacket {
+    pub account_id: AccountId,
+    #[length_remaining]
+    pub message: String,
 }
 
-#[derive(Debug, Clone, ByteConvertable)]
+/// Sent by the map server to the client when the player logs in as a member of
+/// a guild or joins one.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
-#[numeric_type(u8)]
-pub enum BuyOrSellOption {
-    Buy,
-    Sell,
+#[header(0x016C)]
+pub struct UpdateGuildIdPacket {
+    pub guild_id: GuildId,
+    pub emblem_id: u32,
+    pub permissions: u32,
+    pub is_master: u8,
+    pub inter_server_id: u32,
+    #[length(24)]
+    pub guild_name: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[numeric_type(u32)]
+pub enum GuildInformationType {
+    BasicInformation,
+    Members,
+    Positions,
+    Skills,
+    ExpulsionList,
 }
 
+/// Sent by the client to the map server to request information about the guild
+/// of the player. Depending on the requested type, the map server will respond
+/// with one or more of the guild information packets.
 #[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
-#[header(0x00C5)]
-pub struct SelectBuyOrSellPacket {
-    pub shop_id: ShopId,
-    pub option: BuyOrSellOption,
+#[header(0x014F)]
+pub struct RequestGuildInformationPacket {
+    pub information_type: GuildInformationType,
 }
 
 #[derive(Debug, Clone, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
-#[numeric_type(u8)]
-pub enum BuyItemResult {
-    #[numeric_value(0)]
-    Successful,
-    #[numeric_value(1)]
-    NotEoughZeny,
-    #[numeric_value(2)]
-    WeightLimitExceeded,
-    #[numeric_value(3)]
-    TooManyItems,
-    #[numeric_value(9)]
-    TooManyOfThisItem,
-    #[numeric_value(10)]
-    PropsOpenAir,
-    #[numeric_value(11)]
-    ExchangeFailed,
-    #[numeric_value(12)]
-    ExchangeWellDone,
-    #[numeric_value(13)]
-    ItemSoldOut,
-    #[numeric_value(14)]
-    NotEnoughGoods,
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct GuildInformation {
+    pub guild_id: GuildId,
+    pub level: u32,
+    pub online_member_count: u32,
+    pub maximum_member_count: u32,
+    pub average_level: u32,
+    pub experience: u32,
+    pub maximum_experience: u32,
+    pub points: u32,
+    pub honor: i32,
+    pub virtue: i32,
+    pub emblem_id: u32,
+    #[length(24)]
+    pub name: String,
+    #[length(16)]
+    pub managed_land: String,
+    pub zeny: u32,
+    pub master_character_id: CharacterId,
+}
+
+/// Sent by the map server to the client as a response to
+/// [RequestGuildInformationPacket] with
+/// [BasicInformation](GuildInformationType::BasicInformation).
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0A84)]
+pub struct GuildInformationPacket {
+    pub information: GuildInformation,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable, FixedByteSize)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[numeric_type(u32)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum GuildRelationType {
+    Alliance,
+    Antagonist,
 }
 
 #[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
-pub struct BuyItemInformation {
-    pub amount: u16,
-    pub item_id: u16,
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct GuildRelation {
+    pub relation_type: GuildRelationType,
+    pub guild_id: GuildId,
+    #[length(24)]
+    pub guild_name: String,
 }
 
+/// Sent by the map server to the client as a response to
+/// [RequestGuildInformationPacket] with
+/// [BasicInformation](GuildInformationType::BasicInformation). Provides the
+/// allied and antagonistic guilds.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x014C)]
+#[variable_length]
+pub struct GuildRelationsPacket {
+    #[repeating_remaining]
+    pub relations: Vec<GuildRelation>,
+}
+
+/// Sent by the client to the map server to end an alliance or antagonism with
+/// another guild.
 #[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
-#[header(0x00C8)]
+#[header(0x0183)]
+pub struct RemoveGuildRelationPacket {
+    pub guild_id: GuildId,
+    pub relation_type: GuildRelationType,
+}
+
+/// Sent by the map server to the client when an alliance or antagonism with
+/// another guild ended.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0184)]
+pub struct GuildRelationRemovedPacket {
+    pub guild_id: GuildId,
+    pub relation_type: GuildRelationType,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable, FixedByteSize)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[numeric_type(u32)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum GuildMemberState {
+    Offline,
+    Online,
+}
+
+#[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct GuildMember {
+    pub account_id: AccountId,
+    pub character_id: CharacterId,
+    pub head_style: u16,
+    pub head_palette: u16,
+    pub sex: u16,
+    pub job: u16,
+    pub base_level: u16,
+    pub contributed_experience: u32,
+    pub state: GuildMemberState,
+    pub position_id: u32,
+    pub last_login: u32,
+}
+
+/// Sent by the map server to the client as a response to
+/// [RequestGuildInformationPacket] with
+/// [Members](GuildInformationType::Members). The names of the members are not
+/// included and need to be requested with [RequestCharacterNamePacket].
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0AA5)]
 #[variable_length]
-pub struct BuyItemsPacket {
+pub struct GuildMemberListPacket {
     #[repeating_remaining]
-    pub items: Vec<BuyItemInformation>,
+    pub members: Vec<GuildMember>,
 }
 
-#[derive(Debug, Clone, FixedByteSize, ByteConvertable)]
+/// Sent by the map server to the client when a guild member logs in or out.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
-pub struct BuyShopItemInformation {
-    pub item_id: ItemId,
-    pub amount: u32,
+#[header(0x01F2)]
+pub struct GuildMemberStatePacket {
+    pub account_id: AccountId,
+    pub character_id: CharacterId,
+    pub state: GuildMemberState,
+    pub sex: u16,
+    pub head_style: u16,
+    pub head_palette: u16,
 }
 
+/// Sent by the client to the map server to request the name of a character
+/// that is not necessarily on the same map.
 #[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
-#[header(0x09D6)]
-#[variable_length]
-pub struct BuyShopItemsPacket {
-    pub items: Vec<BuyShopItemInformation>,
+#[header(0x0369)]
+pub struct RequestCharacterNamePacket {
+    pub character_id: CharacterId,
 }
 
-#[derive(Debug, Clone, Copy, ByteConvertable)]
+/// Sent by the map server to the client as a response to
+/// [RequestCharacterNamePacket].
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
-#[numeric_type(u16)]
-pub enum BuyShopItemsResult {
-    #[numeric_value(0)]
-    Success,
-    #[numeric_value(0xFFFF)]
-    Error,
+#[header(0x0194)]
+pub struct CharacterNamePacket {
+    pub character_id: CharacterId,
+    #[length(24)]
+    pub name: String,
 }
 
-#[derive(Debug, Clone, FixedByteSize, ByteConvertable)]
+#[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
-pub struct BoughtShopItemInformation {
-    pub item_id: ItemId,
-    pub amount: u16,
-    pub price: Price,
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct GuildPositionName {
+    pub position_id: u32,
+    #[length(24)]
+    pub name: String,
 }
 
+/// Sent by the map server to the client as a response to
+/// [RequestGuildInformationPacket] with
+/// [Members](GuildInformationType::Members) or
+/// [Positions](GuildInformationType::Positions).
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
-#[header(0x0B4E)]
+#[header(0x0166)]
 #[variable_length]
-pub struct BuyShopItemsResultPacket {
-    pub result: BuyShopItemsResult,
+pub struct GuildPositionNamesPacket {
     #[repeating_remaining]
-    pub purchased_items: Vec<BoughtShopItemInformation>,
+    pub positions: Vec<GuildPositionName>,
+}
+
+#[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct GuildPosition {
+    pub position_id: u32,
+    pub permissions: u32,
+    pub ranking: u32,
+    pub experience_tax: u32,
+}
+
+/// Sent by the map server to the client as a response to
+/// [RequestGuildInformationPacket] with
+/// [Positions](GuildInformationType::Positions).
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0160)]
+#[variable_length]
+pub struct GuildPositionsPacket {
+    #[repeating_remaining]
+    pub positions: Vec<GuildPosition>,
+}
+
+/// Sent by the map server to the client when the player logs in or the notice
+/// of the guild changes.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x016F)]
+pub struct GuildNoticePacket {
+    #[length(60)]
+    pub subject: String,
+    #[length(120)]
+    pub notice: String,
 }
 
+/// Sent by the client to the map server when the guild master changes the
+/// notice of the guild.
 #[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
-#[header(0x09D4)]
-pub struct CloseShopPacket {}
+#[header(0x016E)]
+pub struct ChangeGuildNoticePacket {
+    pub guild_id: GuildId,
+    #[length(60)]
+    pub subject: String,
+    #[length(120)]
+    pub notice: String,
+}
 
-#[derive(Debug, Clone, FixedByteSize, ByteConvertable)]
+/// Sent by the client to the map server to request the emblem of a guild.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
-pub struct SellItemInformation {
-    pub inventory_index: InventoryIndex,
-    pub price: Price,
-    pub overcharge_price: Price,
+#[header(0x0151)]
+pub struct RequestGuildEmblemPacket {
+    pub guild_id: GuildId,
 }
 
+/// Sent by the map server to the client as a response to
+/// [RequestGuildEmblemPacket]. The emblem data is a zlib compressed BMP or GIF
+/// image.
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
-#[header(0x00C7)]
+#[header(0x0152)]
 #[variable_length]
-pub struct SellListPacket {
-    #[repeating_remaining]
-    pub items: Vec<SellItemInformation>,
+pub struct GuildEmblemPacket {
+    pub guild_id: GuildId,
+    pub emblem_id: u32,
+    #[length_remaining]
+    pub emblem_data: Vec<u8>,
 }
 
-#[derive(Debug, Clone, FixedByteSize, ByteConvertable)]
+/// Sent by the client to the map server when the guild master uploads a new
+/// emblem. The emblem data is a zlib compressed BMP or GIF image.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
-pub struct SoldItemInformation {
-    pub inventory_index: InventoryIndex,
-    pub amount: u16,
+#[header(0x0153)]
+#[variable_length]
+pub struct UploadGuildEmblemPacket {
+    #[length_remaining]
+    pub emblem_data: Vec<u8>,
+}
+
+/// Sent by the map server to the client when the emblem of a guild changes.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x01B4)]
+pub struct GuildEmblemChangedPacket {
+    pub entity_id: EntityId,
+    pub guild_id: GuildId,
+    pub emblem_id: u16,
 }
 
+/// Sent by the client to the map server when the player writes in the guild
+/// chat.
 #[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
-#[header(0x00C9)]
+#[header(0x017E)]
 #[variable_length]
-pub struct SellItemsPacket {
+pub struct GuildMessagePacket {
+    #[length_remaining_off_by_one]
+    pub message: String,
+}
+
+/// Sent by the map server to the client when a guild member writes in the guild
+/// chat.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x017F)]
+#[variable_length]
+pub struct NotifyGuildMessagePacket {
+    #[length_remaining]
+    pub message: String,
+}
+
+#[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct ReputationEntry {
+    pub reputation_type: u64,
+    pub points: i64,
+}
+
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0B8D)]
+#[variable_length]
+pub struct ReputationPacket {
+    pub success: u8,
     #[repeating_remaining]
-    pub items: Vec<SoldItemInformation>,
+    pub entries: Vec<ReputationEntry>,
 }
 
-#[derive(Debug, Clone, Copy, ByteConvertable)]
+#[derive(Debug, Clone, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
-#[numeric_type(u8)]
-pub enum SellItemsResult {
-    Success,
-    Error,
+pub struct Aliance {
+    #[length(24)]
+    pub name: String,
+}
+
+#[derive(Debug, Clone, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+pub struct Antagonist {
+    #[length(24)]
+    pub name: String,
 }
 
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
-#[header(0x00CB)]
-pub struct SellItemsResultPacket {
-    pub result: SellItemsResult,
+#[header(0x098A)]
+#[variable_length]
+pub struct ClanInfoPacket {
+    pub clan_id: u32,
+    #[length(24)]
+    pub clan_name: String,
+    #[length(24)]
+    pub clan_master: String,
+    #[length(16)]
+    pub clan_map: String,
+    #[new_derive]
+    pub aliance_count: u8,
+    #[new_derive]
+    pub antagonist_count: u8,
+    #[repeating(aliance_count)]
+    pub aliances: Vec<Aliance>,
+    #[repeating(antagonist_count)]
+    pub antagonists: Vec<Antagonist>,
+}
+
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0988)]
+pub struct ClanOnlineCountPacket {
+    pub online_members: u16,
+    pub maximum_members: u16,
+}
+
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0192)]
+pub struct ChangeMapCellPacket {
+    pub position: TilePosition,
+    pub cell_type: u16,
+    #[length(16)]
+    pub map_name: String,
+}
+
+#[derive(Debug, Clone, FixedByteSize, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+pub struct MarketItemInformation {
+    pub name_id: u32,
+    pub item_type: u8,
+    pub price: Price,
+    pub quantity: u32,
+    pub weight: u16,
+    pub location: u32,
+}
+
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0B7A)]
+#[variable_length]
+pub struct OpenMarketPacket {
+    #[repeating_remaining]
+    pub items: Vec<MarketItemInformation>,
+}
+
+#[derive(Debug, Clone, FixedByteSize, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+pub struct ShopItemInformation {
+    pub item_id: ItemId,
+    pub price: Price,
+    pub discount_price: Price,
+    pub item_type: u8,
+    pub view_sprite: u16,
+    pub location: u32,
+}
+
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0B77)]
+#[variable_length]
+pub struct ShopItemListPacket {
+    #[repeating_remaining]
+    pub items: Vec<ShopItemInformation>,
+}
+
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x00C4)]
+pub struct BuyOrSellPacket {
+    pub shop_id: ShopId,
+}
+
+#[derive(Debug, Clone, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[numeric_type(u8)]
+pub enum BuyOrSellOption {
+    Buy,
+    Sell,
+}
+
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x00C5)]
+pub struct SelectBuyOrSellPacket {
+    pub shop_id: ShopId,
+    pub option: BuyOrSellOption,
+}
+
+#[derive(Debug, Clone, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[numeric_type(u8)]
+pub enum BuyItemResult {
+    #[numeric_value(0)]
+    Successful,
+    #[numeric_value(1)]
+    NotEoughZeny,
+    #[numeric_value(2)]
+    WeightLimitExceeded,
+    #[numeric_value(3)]
+    TooManyItems,
+    #[numeric_value(9)]
+    TooManyOfThisItem,
+    #[numeric_value(10)]
+    PropsOpenAir,
+    #[numeric_value(11)]
+    ExchangeFailed,
+    #[numeric_value(12)]
+    ExchangeWellDone,
+    #[numeric_value(13)]
+    ItemSoldOut,
+    #[numeric_value(14)]
+    NotEnoughGoods,
+}
+
+#[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+pub struct BuyItemInformation {
+    pub amount: u16,
+    pub item_id: u16,
+}
+
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x00C8)]
+#[variable_length]
+pub struct BuyItemsPacket {
+    #[repeating_remaining]
+    pub items: Vec<BuyItemInformation>,
+}
+
+#[derive(Debug, Clone, FixedByteSize, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+pub struct BuyShopItemInformation {
+    pub item_id: ItemId,
+    pub amount: u32,
+}
+
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x09D6)]
+#[variable_length]
+pub struct BuyShopItemsPacket {
+    pub items: Vec<BuyShopItemInformation>,
+}
+
+#[derive(Debug, Clone, Copy, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[numeric_type(u16)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum BuyShopItemsResult {
+    #[numeric_value(0)]
+    Success,
+    #[numeric_value(0xFFFF)]
+    Error,
+}
+
+#[derive(Debug, Clone, FixedByteSize, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+pub struct BoughtShopItemInformation {
+    pub item_id: ItemId,
+    pub amount: u16,
+    pub price: Price,
+}
+
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0B4E)]
+#[variable_length]
+pub struct BuyShopItemsResultPacket {
+    pub result: BuyShopItemsResult,
+    #[repeating_remaining]
+    pub purchased_items: Vec<BoughtShopItemInformation>,
+}
+
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x09D4)]
+pub struct CloseShopPacket {}
+
+#[derive(Debug, Clone, FixedByteSize, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct SellItemInformation {
+    pub inventory_index: InventoryIndex,
+    pub price: Price,
+    pub overcharge_price: Price,
+}
+
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x00C7)]
+#[variable_length]
+pub struct SellListPacket {
+    #[repeating_remaining]
+    pub items: Vec<SellItemInformation>,
+}
+
+#[derive(Debug, Clone, FixedByteSize, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+pub struct SoldItemInformation {
+    pub inventory_index: InventoryIndex,
+    pub amount: u16,
+}
+
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x00C9)]
+#[variable_length]
+pub struct SellItemsPacket {
+    #[repeating_remaining]
+    pub items: Vec<SoldItemInformation>,
+}
+
+#[derive(Debug, Clone, Copy, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[numeric_type(u8)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum SellItemsResult {
+    Success,
+    Error,
+}
+
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x00CB)]
+pub struct SellItemsResultPacket {
+    pub result: SellItemsResult,
+}
+
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x00E4)]
+pub struct RequestTradePacket {
+    pub entity_id: EntityId,
+}
+
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x01F4)]
+pub struct TradeRequestPacket {
+    #[length(24)]
+    pub name: String,
+    pub character_id: CharacterId,
+    pub base_level: u16,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum TradeResponse {
+    TooFarAway,
+    CharacterDoesNotExist,
+    Failed,
+    Accept,
+    Cancel,
+    Busy,
+}
+
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x00E6)]
+pub struct TradeResponsePacket {
+    pub response: TradeResponse,
+}
+
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x01F5)]
+pub struct TradeResponseResultPacket {
+    pub result: TradeResponse,
+    pub character_id: CharacterId,
+    pub base_level: u16,
+}
+
+/// Index 0 refers to zeny, every other index is the inventory index of the
+/// item + 2.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x00E8)]
+pub struct AddTradeItemPacket {
+    pub index: u16,
+    pub amount: u32,
+}
+
+/// Sent to the trade partner. An item id of 0 means that zeny were added.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0B42)]
+pub struct TradeItemAddedPacket {
+    pub item_id: ItemId,
+    pub item_type: u8,
+    pub amount: u32,
+    pub is_identified: u8,
+    pub is_broken: u8,
+    pub cards: [u32; 4],
+    pub option_data: [ItemOptions; 5],
+    pub location: u32,
+    pub look: u16,
+    pub refinement_level: u8,
+    pub enchantment_level: u8,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum AddTradeItemResult {
+    Success,
+    Overweight,
+    TradeClosed,
+    TooManyItems,
+    TooManyOfItem,
+}
+
+/// Index 0 refers to zeny, every other index is the inventory index of the
+/// item + 2.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x00EA)]
+pub struct AddTradeItemResultPacket {
+    pub index: u16,
+    pub result: AddTradeItemResult,
+}
+
+#[derive(Debug, Clone, Default, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x00EB)]
+pub struct LockTradePacket {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum TradeParty {
+    Player,
+    Partner,
+}
+
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x00EC)]
+pub struct TradeLockedPacket {
+    pub party: TradeParty,
+}
+
+#[derive(Debug, Clone, Default, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x00ED)]
+pub struct CancelTradePacket {}
+
+#[derive(Debug, Clone, Default, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x00EE)]
+pub struct TradeCancelledPacket {}
+
+#[derive(Debug, Clone, Default, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x00EF)]
+pub struct CommitTradePacket {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum TradeResult {
+    Success,
+    Failed,
+}
+
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x00F0)]
+pub struct TradeCompletedPacket {
+    pub result: TradeResult,
+}
+
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x00F2)]
+pub struct StorageCapacityPacket {
+    pub amount: u16,
+    pub maximum_amount: u16,
+}
+
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0364)]
+pub struct MoveItemToStoragePacket {
+    pub index: InventoryIndex,
+    pub amount: u32,
+}
+
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0365)]
+pub struct MoveItemFromStoragePacket {
+    pub index: StorageIndex,
+    pub amount: u32,
+}
+
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0B44)]
+pub struct StorageItemAddedPacket {
+    pub index: StorageIndex,
+    pub amount: u32,
+    pub item_id: ItemId,
+    pub item_type: u8,
+    pub is_identified: u8,
+    pub is_broken: u8,
+    pub cards: [u32; 4],
+    pub option_data: [ItemOptions; 5],
+    pub refinement_level: u8,
+    pub enchantment_level: u8,
+}
+
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x00F6)]
+pub struct StorageItemRemovedPacket {
+    pub index: StorageIndex,
+    pub amount: u32,
+}
+
+#[derive(Debug, Clone, Default, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0193)]
+pub struct CloseStoragePacket {}
+
+#[derive(Debug, Clone, Default, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x00F8)]
+pub struct StorageClosedPacket {}
+
+/// The stats that can be raised by spending status points.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[numeric_type(u16)]
+pub enum StatType {
+    #[numeric_value(13)]
+    Strength,
+    Agility,
+    Vitality,
+    Intelligence,
+    Dexterity,
+    Luck,
+}
+
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x00BB)]
+pub struct RequestStatUpPacket {
+    pub stat_type: StatType,
+    /// rAthena only ever raises the stat by one point, regardless of the
+    /// amount requested.
+    pub amount: u8,
+}
+
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x00BC)]
+pub struct RequestStatUpResponsePacket {
+    pub stat_type: StatType,
+    pub success: u8,
+    pub value: u8,
+}
+
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0112)]
+pub struct RequestSkillUpPacket {
+    pub skill_id: SkillId,
+}
+
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x010E)]
+pub struct UpdateSkillPacket {
+    pub skill_id: SkillId,
+    pub skill_level: SkillLevel,
+    pub spell_point_cost: u16,
+    pub attack_range: u16,
+    pub upgraded: u8,
+}
+
+/// Sent by the client to the map server when the player sends a private
+/// message to another player.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0096)]
+#[variable_length]
+pub struct WhisperMessagePacket {
+    #[length(24)]
+    pub recipient_name: String,
+    #[length_remaining_off_by_one]
+    pub message: String,
+}
+
+/// Sent by the map server to the client when another player sends a private
+/// message to the player.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x09DE)]
+#[variable_length]
+pub struct NotifyWhisperMessagePacket {
+    pub sender_character_id: CharacterId,
+    #[length(24)]
+    pub sender_name: String,
+    pub is_admin: u8,
+    #[length_remaining]
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum WhisperResult {
+    Success,
+    RecipientOffline,
+    IgnoredByRecipient,
+    RecipientIgnoresEveryone,
+}
+
+/// Sent by the map server to the client in response to a
+/// [`WhisperMessagePacket`].
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x09DF)]
+pub struct WhisperResultPacket {
+    pub result: WhisperResult,
+    pub character_id: CharacterId,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum ChatRoomType {
+    /// Requires a password to enter.
+    Private,
+    Public,
+    /// Waiting room of an NPC (e.g. for an arena).
+    Arena,
+    PkZone,
+}
+
+/// Sent by the client to the map server when the player opens a new chat room.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x00D5)]
+#[variable_length]
+pub struct CreateChatRoomPacket {
+    pub limit: u16,
+    pub chat_room_type: ChatRoomType,
+    #[length(8)]
+    pub password: String,
+    #[length_remaining]
+    pub title: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum CreateChatRoomResult {
+    Success,
+    LimitExceeded,
+    AlreadyExists,
+}
+
+/// Sent by the map server to the client in response to a
+/// [`CreateChatRoomPacket`].
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x00D6)]
+pub struct CreateChatRoomResultPacket {
+    pub result: CreateChatRoomResult,
+}
+
+/// Sent by the map server to the client when a chat room appears on screen.
+/// The chat room is displayed as a signboard above its owner.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x00D7)]
+#[variable_length]
+pub struct ChatRoomAppearedPacket {
+    pub owner_id: EntityId,
+    pub chat_room_id: ChatRoomId,
+    pub limit: u16,
+    pub member_count: u16,
+    pub chat_room_type: ChatRoomType,
+    #[length_remaining]
+    pub title: String,
+}
+
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x00D8)]
+pub struct ChatRoomDestroyedPacket {
+    pub chat_room_id: ChatRoomId,
+}
+
+/// Sent by the client to the map server when the player wants to enter a chat
+/// room. The password is ignored for public chat rooms.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x00D9)]
+pub struct EnterChatRoomPacket {
+    pub chat_room_id: ChatRoomId,
+    #[length(8)]
+    pub password: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum EnterChatRoomFailedReason {
+    RoomFull,
+    WrongPassword,
+    Kicked,
+    Success,
+    NotEnoughZeny,
+    LevelTooLow,
+    LevelTooHigh,
+    UnsuitableJob,
+}
+
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x00DA)]
+pub struct EnterChatRoomFailedPacket {
+    pub reason: EnterChatRoomFailedReason,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable, FixedByteSize)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[numeric_type(u32)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum ChatRoomRole {
+    #[numeric_value(0)]
+    Owner,
+    #[numeric_value(1)]
+    Member,
+}
+
+#[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct ChatRoomMember {
+    pub role: ChatRoomRole,
+    #[length(24)]
+    pub name: String,
+}
+
+/// Sent by the map server to the client when the player entered a chat room.
+/// Provides a list of all members, including the player.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x00DB)]
+#[variable_length]
+pub struct ChatRoomEnteredPacket {
+    pub chat_room_id: ChatRoomId,
+    #[repeating_remaining]
+    pub members: Vec<ChatRoomMember>,
+}
+
+/// Sent by the map server to all members of a chat room when someone enters
+/// it.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x00DC)]
+pub struct ChatRoomMemberEnteredPacket {
+    pub member_count: u16,
+    #[length(24)]
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum ChatRoomExitReason {
+    Left,
+    Kicked,
+}
+
+/// Sent by the map server to all members of a chat room when someone leaves
+/// it, including the member that left.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x00DD)]
+pub struct ChatRoomMemberExitPacket {
+    pub member_count: u16,
+    #[length(24)]
+    pub name: String,
+    pub reason: ChatRoomExitReason,
+}
+
+/// Sent by the client to the map server when the owner of a chat room changes
+/// its settings.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x00DE)]
+#[variable_length]
+pub struct ChangeChatRoomPacket {
+    pub limit: u16,
+    pub chat_room_type: ChatRoomType,
+    #[length(8)]
+    pub password: String,
+    #[length_remaining]
+    pub title: String,
+}
+
+/// Sent by the map server to the client when the settings of a chat room
+/// change.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x00DF)]
+#[variable_length]
+pub struct ChatRoomChangedPacket {
+    pub owner_id: EntityId,
+    pub chat_room_id: ChatRoomId,
+    pub limit: u16,
+    pub member_count: u16,
+    pub chat_room_type: ChatRoomType,
+    #[length_remaining]
+    pub title: String,
+}
+
+/// Sent by the client to the map server when the owner of a chat room hands
+/// it over to another member. rAthena ignores the role and always makes the
+/// member the new owner.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x00E0)]
+pub struct ChangeChatRoomRolePacket {
+    pub role: ChatRoomRole,
+    #[length(24)]
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x00E1)]
+pub struct ChatRoomRoleChangedPacket {
+    pub role: ChatRoomRole,
+    #[length(24)]
+    pub name: String,
+}
+
+/// Sent by the client to the map server when the owner of a chat room kicks a
+/// member.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x00E2)]
+pub struct KickFromChatRoomPacket {
+    #[length(24)]
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Default, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x00E3)]
+pub struct LeaveChatRoomPacket {}
+
+/// Sent by the map server to the client after using the vending skill. The
+/// player may now put up to `slot_count` items from their cart up for sale.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x012D)]
+pub struct OpenVendingShopSetupPacket {
+    pub slot_count: u16,
+}
+
+#[derive(Debug, Clone, FixedByteSize, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+pub struct VendingShopItemInformation {
+    /// Index of the item in the cart.
+    pub index: InventoryIndex,
+    pub amount: u16,
+    pub price: Price,
+}
+
+/// Sent by the client to the map server to open a vending shop.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x01B2)]
+#[variable_length]
+pub struct OpenVendingShopPacket {
+    #[length(80)]
+    pub shop_name: String,
+    /// 1 to open the shop, 0 to cancel.
+    pub open: u8,
+    #[repeating_remaining]
+    pub items: Vec<VendingShopItemInformation>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+pub enum OpenVendingShopResult {
+    Success,
+    Failed,
+}
+
+/// Sent by the map server to the client in response to an
+/// [`OpenVendingShopPacket`].
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0A28)]
+pub struct OpenVendingShopResultPacket {
+    pub result: OpenVendingShopResult,
+}
+
+#[derive(Debug, Clone, FixedByteSize, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+pub struct OwnVendingItemInformation {
+    pub price: Price,
+    pub index: InventoryIndex,
+    pub amount: u16,
+    pub item_type: u8,
+    pub item_id: ItemId,
+    pub identified: u8,
+    pub damaged: u8,
+    pub slot: [u32; 4], // card ?
+    pub option_data: [ItemOptions; 5],
+    pub refinement_level: u8,
+    pub enchantment_level: u8,
+}
+
+/// Sent by the map server to the vendor once the vending shop is open.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0136)]
+#[variable_length]
+pub struct OwnVendingItemListPacket {
+    pub entity_id: EntityId,
+    #[repeating_remaining]
+    pub items: Vec<OwnVendingItemInformation>,
+}
+
+/// Sent by the map server to the client when a vending shop appears, either
+/// because it was opened or because the vendor came into view.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0131)]
+pub struct VendingShopAppearedPacket {
+    pub entity_id: EntityId,
+    #[length(80)]
+    pub shop_name: String,
+}
+
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0132)]
+pub struct VendingShopDisappearedPacket {
+    pub entity_id: EntityId,
+}
+
+#[derive(Debug, Clone, Default, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x012E)]
+pub struct CloseVendingShopPacket {}
+
+/// Sent by the client to the map server to browse the vending shop of
+/// another player.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0130)]
+pub struct RequestVendingItemListPacket {
+    pub entity_id: EntityId,
+}
+
+#[derive(Debug, Clone, FixedByteSize, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+pub struct VendingItemInformation {
+    pub price: Price,
+    pub amount: u16,
+    pub index: InventoryIndex,
+    pub item_type: u8,
+    pub item_id: ItemId,
+    pub identified: u8,
+    pub damaged: u8,
+    pub slot: [u32; 4], // card ?
+    pub option_data: [ItemOptions; 5],
+    pub location: u32,
+    pub view_sprite: u16,
+    pub refinement_level: u8,
+    pub enchantment_level: u8,
+}
+
+/// Sent by the map server to the client in response to a
+/// [`RequestVendingItemListPacket`].
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0800)]
+#[variable_length]
+pub struct VendingItemListPacket {
+    pub entity_id: EntityId,
+    pub vendor_id: VendorId,
+    #[repeating_remaining]
+    pub items: Vec<VendingItemInformation>,
+}
+
+#[derive(Debug, Clone, FixedByteSize, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+pub struct PurchaseVendingItemInformation {
+    pub amount: u16,
+    pub index: InventoryIndex,
+}
+
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0801)]
+#[variable_length]
+pub struct PurchaseVendingItemsPacket {
+    pub entity_id: EntityId,
+    pub vendor_id: VendorId,
+    #[repeating_remaining]
+    pub items: Vec<PurchaseVendingItemInformation>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum VendingPurchaseResult {
+    #[numeric_value(0)]
+    Success,
+    #[numeric_value(1)]
+    NotEnoughZeny,
+    #[numeric_value(2)]
+    Overweight,
+    #[numeric_value(4)]
+    OutOfStock,
+    #[numeric_value(5)]
+    TradeFailed,
+    #[numeric_value(7)]
+    NoSalesInformation,
+}
+
+/// Sent by the map server to the client for every item that could not be
+/// bought from a vending shop.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0135)]
+pub struct VendingPurchaseResultPacket {
+    pub index: InventoryIndex,
+    pub amount: u16,
+    pub result: VendingPurchaseResult,
+}
+
+/// Sent by the map server to the vendor when another player bought an item.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x09E5)]
+pub struct VendingItemSoldPacket {
+    /// Index of the item in the cart.
+    pub index: InventoryIndex,
+    pub amount: u16,
+    pub buyer_id: CharacterId,
+    pub sold_at: u32,
+    pub zeny: u32,
+}
+
+/// Sent by the map server to the client after using the buying store skill.
+/// The player may now ask for up to `slot_count` different items.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0810)]
+pub struct OpenBuyingStoreSetupPacket {
+    pub slot_count: u8,
+}
+
+#[derive(Debug, Clone, FixedByteSize, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+pub struct BuyingStoreItemInformation {
+    pub item_id: ItemId,
+    pub amount: u16,
+    pub price: Price,
+}
+
+/// Sent by the client to the map server to open a buying store.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0811)]
+#[variable_length]
+pub struct OpenBuyingStorePacket {
+    pub zeny_limit: u32,
+    /// 1 to open the store, 0 to cancel.
+    pub open: u8,
+    #[length(80)]
+    pub shop_name: String,
+    #[repeating_remaining]
+    pub items: Vec<BuyingStoreItemInformation>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[numeric_type(u16)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum OpenBuyingStoreFailure {
+    #[numeric_value(1)]
+    Failed,
+    #[numeric_value(2)]
+    Overweight,
+    #[numeric_value(8)]
+    NoItems,
+}
+
+/// Sent by the map server to the client in response to an
+/// [`OpenBuyingStorePacket`] that failed.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0812)]
+pub struct OpenBuyingStoreFailedPacket {
+    pub result: OpenBuyingStoreFailure,
+    /// Weight that the player is over the limit by.
+    pub weight: u32,
+}
+
+#[derive(Debug, Clone, FixedByteSize, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+pub struct BuyingStoreItemListInformation {
+    pub price: Price,
+    pub amount: u16,
+    pub item_type: u8,
+    pub item_id: ItemId,
+}
+
+/// Sent by the map server to the buyer once the buying store is open.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0813)]
+#[variable_length]
+pub struct OwnBuyingStoreItemListPacket {
+    pub entity_id: EntityId,
+    pub zeny_limit: u32,
+    #[repeating_remaining]
+    pub items: Vec<BuyingStoreItemListInformation>,
+}
+
+/// Sent by the map server to the client when a buying store appears, either
+/// because it was opened or because the buyer came into view.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0814)]
+pub struct BuyingStoreAppearedPacket {
+    pub entity_id: EntityId,
+    #[length(80)]
+    pub shop_name: String,
+}
+
+#[derive(Debug, Clone, Default, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0815)]
+pub struct CloseBuyingStorePacket {}
+
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0816)]
+pub struct BuyingStoreDisappearedPacket {
+    pub entity_id: EntityId,
+}
+
+/// Sent by the client to the map server to browse the buying store of another
+/// player.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0817)]
+pub struct RequestBuyingStoreItemListPacket {
+    pub entity_id: EntityId,
+}
+
+/// Sent by the map server to the client in response to a
+/// [`RequestBuyingStoreItemListPacket`].
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0818)]
+#[variable_length]
+pub struct BuyingStoreItemListPacket {
+    pub entity_id: EntityId,
+    pub store_id: BuyingStoreId,
+    pub zeny_limit: u32,
+    #[repeating_remaining]
+    pub items: Vec<BuyingStoreItemListInformation>,
+}
+
+#[derive(Debug, Clone, FixedByteSize, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+pub struct SellToBuyingStoreItemInformation {
+    pub index: InventoryIndex,
+    pub item_id: ItemId,
+    pub amount: u16,
+}
+
+/// Sent by the client to the map server to sell items from the inventory to
+/// a buying store.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0819)]
+#[variable_length]
+pub struct SellToBuyingStorePacket {
+    pub entity_id: EntityId,
+    pub store_id: BuyingStoreId,
+    #[repeating_remaining]
+    pub items: Vec<SellToBuyingStoreItemInformation>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[numeric_type(u16)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum BuyingStoreClosedReason {
+    #[numeric_value(3)]
+    ZenyLimitReached,
+    #[numeric_value(4)]
+    AllItemsBought,
+}
+
+/// Sent by the map server to the buyer when the buying store was closed
+/// because nothing more can be bought.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x081A)]
+pub struct BuyingStoreClosedPacket {
+    pub reason: BuyingStoreClosedReason,
+}
+
+/// Sent by the map server to the buyer when another player sold an item to
+/// the buying store.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x09E6)]
+pub struct BuyingStoreItemBoughtPacket {
+    pub item_id: ItemId,
+    pub amount: u16,
+    pub zeny: u32,
+    pub zeny_limit: u32,
+    pub seller_id: CharacterId,
+    pub bought_at: u32,
+}
+
+/// Sent by the map server to the seller for every item sold to a buying
+/// store.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x081C)]
+pub struct BuyingStoreItemSoldPacket {
+    pub index: InventoryIndex,
+    pub amount: u16,
+    pub zeny: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[numeric_type(u16)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum SellToBuyingStoreFailure {
+    #[numeric_value(5)]
+    Failed,
+    #[numeric_value(6)]
+    AmountTooHigh,
+    #[numeric_value(7)]
+    NotEnoughZeny,
+}
+
+/// Sent by the map server to the seller when selling an item to a buying
+/// store failed.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0824)]
+pub struct SellToBuyingStoreFailedPacket {
+    pub result: SellToBuyingStoreFailure,
+    pub item_id: ItemId,
+}
+
+/// The tab of the mailbox that a mail is in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum MailOpenType {
+    Character,
+    Account,
+    Returned,
+}
+
+bitflags::bitflags! {
+    #[derive(Debug, Clone, Copy)]
+    #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+    pub struct MailAttachmentFlags: u8 {
+        const ZENY = 0b0000_0010;
+        const ITEM = 0b0000_0100;
+    }
+}
+
+impl FixedByteSize for MailAttachmentFlags {
+    fn size_in_bytes() -> usize {
+        <<Self as bitflags::Flags>::Bits as FixedByteSize>::size_in_bytes()
+    }
+}
+
+impl FromBytes for MailAttachmentFlags {
+    fn from_bytes<Meta>(byte_stream: &mut ByteStream<Meta>) -> ConversionResult<Self> {
+        <Self as bitflags::Flags>::Bits::from_bytes(byte_stream).map(Self::from_bits_truncate)
+    }
+}
+
+impl ToBytes for MailAttachmentFlags {
+    fn to_bytes(&self) -> ConversionResult<Vec<u8>> {
+        self.bits().to_bytes()
+    }
+}
+
+/// Sent by the client to the map server when opening the mailbox. The map
+/// server responds with a [`MailListPacket`].
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0AC0)]
+pub struct OpenMailboxPacket {
+    /// Only mail older than this is listed. Zero lists the newest mail.
+    pub mail_id: MailId,
+    #[new_default]
+    pub unknown: [u8; 16],
+}
+
+/// Sent by the client to the map server to request the next page of the
+/// mailbox.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0AC1)]
+pub struct RequestNextMailPagePacket {
+    /// Only mail older than this is listed.
+    pub mail_id: MailId,
+    #[new_default]
+    pub unknown: [u8; 16],
+}
+
+#[derive(Debug, Clone, Default, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x09E9)]
+pub struct CloseMailboxPacket {}
+
+#[derive(Debug, Clone, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+pub struct MailInformation {
+    pub open_type: MailOpenType,
+    pub mail_id: MailId,
+    pub is_read: u8,
+    pub attachments: MailAttachmentFlags,
+    #[length(24)]
+    pub sender_name: String,
+    /// Seconds until the mail expires.
+    pub expires_in: u32,
+    pub title_length: u16,
+    #[length(title_length)]
+    pub title: String,
+}
+
+/// Sent by the map server to the client in response to an
+/// [`OpenMailboxPacket`] or [`RequestNextMailPagePacket`].
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0AC2)]
+#[variable_length]
+pub struct MailListPacket {
+    pub is_end: u8,
+    #[length_remaining]
+    pub mails: Vec<MailInformation>,
+}
+
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x09EA)]
+pub struct ReadMailPacket {
+    pub open_type: MailOpenType,
+    pub mail_id: MailId,
+}
+
+#[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+pub struct MailItemInformation {
+    pub amount: u16,
+    pub item_id: ItemId,
+    pub identified: u8,
+    pub damaged: u8,
+    pub slot: [u32; 4],
+    pub location: u32,
+    pub item_type: u8,
+    pub view_sprite: u16,
+    pub bind_on_equip: u16,
+    pub option_data: [ItemOptions; 5],
+    pub refinement_level: u8,
+    pub enchantment_level: u8,
+}
+
+/// Sent by the map server to the client in response to a [`ReadMailPacket`].
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x09EB)]
+#[variable_length]
+pub struct MailContentsPacket {
+    pub open_type: MailOpenType,
+    pub mail_id: MailId,
+    pub text_length: u16,
+    pub zeny: u64,
+    pub item_count: u8,
+    #[length(text_length)]
+    pub text: String,
+    #[repeating(item_count)]
+    pub items: Vec<MailItemInformation>,
+}
+
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x09F5)]
+pub struct DeleteMailPacket {
+    pub open_type: MailOpenType,
+    pub mail_id: MailId,
+}
+
+/// Sent by the map server to the client when a mail was deleted.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x09F6)]
+pub struct MailDeletedPacket {
+    pub open_type: MailOpenType,
+    pub mail_id: MailId,
+}
+
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x09F1)]
+pub struct RetrieveMailZenyPacket {
+    pub mail_id: MailId,
+    pub open_type: MailOpenType,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum RetrieveMailZenyResult {
+    Success,
+    Failed,
+    ZenyLimitReached,
+}
+
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x09F2)]
+pub struct RetrieveMailZenyResultPacket {
+    pub mail_id: MailId,
+    pub open_type: MailOpenType,
+    pub result: RetrieveMailZenyResult,
+}
+
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x09F3)]
+pub struct RetrieveMailItemsPacket {
+    pub mail_id: MailId,
+    pub open_type: MailOpenType,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum RetrieveMailItemsResult {
+    Success,
+    Failed,
+    InventoryFull,
+}
+
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x09F4)]
+pub struct RetrieveMailItemsResultPacket {
+    pub mail_id: MailId,
+    pub open_type: MailOpenType,
+    pub result: RetrieveMailItemsResult,
+}
+
+/// Sent by the client to the map server before writing a mail. Attachments
+/// can only be added after this.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0A08)]
+pub struct StartWritingMailPacket {
+    #[length(24)]
+    pub receiver_name: String,
+}
+
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0A12)]
+pub struct StartWritingMailResultPacket {
+    #[length(24)]
+    pub receiver_name: String,
+    pub result: u8,
+}
+
+/// Sent by the client to the map server when discarding a mail that is
+/// being written. Attached items are returned to the inventory.
+#[derive(Debug, Clone, Default, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0A03)]
+pub struct CancelWritingMailPacket {}
+
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0A04)]
+pub struct AddMailItemPacket {
+    pub index: InventoryIndex,
+    pub amount: u16,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum AddMailItemResult {
+    Success,
+    Overweight,
+    Failed,
+    NoSpace,
+    NotTradeable,
+}
+
+/// Sent by the map server to the client in response to an
+/// [`AddMailItemPacket`].
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0A05)]
+pub struct AddMailItemResultPacket {
+    pub result: AddMailItemResult,
+    pub index: InventoryIndex,
+    pub amount: u16,
+    pub item_id: ItemId,
+    pub item_type: u8,
+    pub identified: u8,
+    pub damaged: u8,
+    pub slot: [u32; 4],
+    pub option_data: [ItemOptions; 5],
+    /// Total weight of the attached items.
+    pub weight: u16,
+    pub favorite: u8,
+    pub location: u32,
+    pub refinement_level: u8,
+    pub enchantment_level: u8,
+}
+
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0A06)]
+pub struct RemoveMailItemPacket {
+    pub index: InventoryIndex,
+    pub amount: u16,
+}
+
+/// Sent by the map server to the client in response to a
+/// [`RemoveMailItemPacket`].
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0A07)]
+pub struct RemoveMailItemResultPacket {
+    pub result: u8,
+    pub index: InventoryIndex,
+    /// Amount of the item that is still attached.
+    pub amount: u16,
+    /// Total weight of the attached items.
+    pub weight: u16,
+}
+
+/// Sent by the client to the map server to look up the receiver of a mail.
+/// Mail can only be sent after the receiver was checked.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0A13)]
+pub struct CheckMailReceiverPacket {
+    #[length(24)]
+    pub receiver_name: String,
+}
+
+/// Sent by the map server to the client in response to a
+/// [`CheckMailReceiverPacket`]. The character id is zero if no character with
+/// that name exists.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0A51)]
+pub struct MailReceiverPacket {
+    pub character_id: CharacterId,
+    pub job: u16,
+    pub base_level: u16,
+    #[length(24)]
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0A6E)]
+#[variable_length]
+pub struct SendMailPacket {
+    #[length(24)]
+    pub receiver_name: String,
+    #[length(24)]
+    pub sender_name: String,
+    pub zeny: u64,
+    pub title_length: u16,
+    pub text_length: u16,
+    pub receiver_id: CharacterId,
+    #[length(title_length)]
+    pub title: String,
+    #[length(text_length)]
+    pub text: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum SendMailResult {
+    Success,
+    Failed,
+    TooManyMails,
+    ItemFailed,
+    ReceiverNotFound,
+    ReceiverBlocked,
+}
+
+/// Sent by the map server to the client in response to a [`SendMailPacket`].
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x09ED)]
+pub struct SendMailResultPacket {
+    pub result: SendMailResult,
+}
+
+#[cfg(test)]
+mod packet_layout {
+    use ragnarok_bytes::ByteStream;
+
+    use crate::{
+        AddMailItemResult, AddMailItemResultPacket, EntityId, InventoryIndex, ItemId, MailContentsPacket, MailId, OwnVendingItemListPacket,
+        PacketExt, PartyId, PartyInviteResponse, PartyInviteResponsePacket, Price, StorageIndex, StorageItemAddedPacket,
+        TradeItemAddedPacket, VendingItemListPacket, VendorId,
+    };
+
+    /// Item data as the server sends it since 20200916: cards, then options,
+    /// then refinement and enchantment level. The item is a +7 Sword with a
+    /// Hydra card (4035) and a single random option.
+    const ITEM_TAIL: [u8; 43] = [
+        0xC3, 0x0F, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, // cards
+        0x0A, 0, 0x05, 0, 0, // option 1
+        0, 0, 0, 0, 0, // option 2
+        0, 0, 0, 0, 0, // option 3
+        0, 0, 0, 0, 0, // option 4
+        0, 0, 0, 0, 0, // option 5
+        7, // refinement level
+        0, // enchantment level
+    ];
+
+    #[test]
+    fn party_invite_response() {
+        let packet = PartyInviteResponsePacket::new(PartyId(5), PartyInviteResponse::Accept);
+        let bytes = packet.packet_to_bytes().unwrap();
+
+        assert_eq!(bytes, [0xC7, 0x02, 5, 0, 0, 0, 1]);
+    }
+
+    #[test]
+    fn own_vending_item_list() {
+        let mut bytes = vec![0x36, 0x01, 0, 0, 0x4D, 0x00, 0x00, 0x00];
+        bytes.extend([0x10, 0x27, 0, 0, 0x05, 0x00, 0x01, 0x00, 0x05, 0x4D, 0x04, 0, 0, 0x01, 0x00]);
+        bytes.extend(ITEM_TAIL);
+        let length = bytes.len() as u16;
+        bytes[2..4].copy_from_slice(&length.to_le_bytes());
+
+        let mut byte_stream = ByteStream::<()>::without_metadata(&bytes);
+        let packet = OwnVendingItemListPacket::packet_from_bytes(&mut byte_stream).unwrap();
+
+        assert!(byte_stream.is_empty());
+        assert_eq!(packet.entity_id, EntityId(77));
+        assert_eq!(packet.items.len(), 1);
+
+        let item = &packet.items[0];
+        assert_eq!(item.price, Price(10000));
+        // Inventory indices are offset by two on the wire.
+        assert_eq!(item.index, InventoryIndex(3));
+        assert_eq!(item.item_id, ItemId(1101));
+        assert_eq!(item.slot, [4035, 0, 0, 0]);
+        assert_eq!(item.option_data[0].index, 10);
+        assert_eq!(item.option_data[0].value, 5);
+        assert_eq!(item.refinement_level, 7);
+        assert_eq!(item.enchantment_level, 0);
+    }
+
+    #[test]
+    fn vending_item_list() {
+        let mut bytes = vec![0x00, 0x08, 0, 0, 0x4D, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00];
+        bytes.extend([0x10, 0x27, 0, 0, 0x01, 0x00, 0x05, 0x00, 0x05, 0x4D, 0x04, 0, 0, 0x01, 0x00]);
+        // Cards and options, then location and view sprite, then refinement
+        // and enchantment level.
+        bytes.extend(&ITEM_TAIL[..41]);
+        bytes.extend([0x02, 0x00, 0x00, 0x00, 0x02, 0x00]);
+        bytes.extend(&ITEM_TAIL[41..]);
+        let length = bytes.len() as u16;
+        bytes[2..4].copy_from_slice(&length.to_le_bytes());
+
+        let mut byte_stream = ByteStream::<()>::without_metadata(&bytes);
+        let packet = VendingItemListPacket::packet_from_bytes(&mut byte_stream).unwrap();
+
+        assert!(byte_stream.is_empty());
+        assert_eq!(packet.vendor_id, VendorId(2));
+        assert_eq!(packet.items.len(), 1);
+
+        let item = &packet.items[0];
+        assert_eq!(item.price, Price(10000));
+        assert_eq!(item.item_id, ItemId(1101));
+        assert_eq!(item.slot, [4035, 0, 0, 0]);
+        assert_eq!(item.location, 2);
+        assert_eq!(item.view_sprite, 2);
+        assert_eq!(item.refinement_level, 7);
+    }
+
+    #[test]
+    fn mail_contents() {
+        let mut bytes = vec![0xEB, 0x09, 0, 0, 0x00, 0x0C, 0, 0, 0, 0, 0, 0, 0, 0x03, 0x00];
+        bytes.extend([0xE8, 0x03, 0, 0, 0, 0, 0, 0, 0x01]);
+        // The text length includes the null terminator.
+        bytes.extend(b"Hi\0");
+        bytes.extend([0x01, 0x00, 0x4D, 0x04, 0, 0, 0x01, 0x00]);
+        // Cards, then location, type, view sprite and bind on equip, then options,
+        // refinement and enchantment level.
+        bytes.extend(&ITEM_TAIL[..16]);
+        bytes.extend([0x02, 0x00, 0x00, 0x00, 0x05, 0x02, 0x00, 0x00, 0x00]);
+        bytes.extend(&ITEM_TAIL[16..]);
+        let length = bytes.len() as u16;
+        bytes[2..4].copy_from_slice(&length.to_le_bytes());
+
+        let mut byte_stream = ByteStream::<()>::without_metadata(&bytes);
+        let packet = MailContentsPacket::packet_from_bytes(&mut byte_stream).unwrap();
+
+        assert!(byte_stream.is_empty());
+        assert_eq!(packet.mail_id, MailId(12));
+        assert_eq!(packet.zeny, 1000);
+        assert_eq!(packet.text, "Hi");
+        assert_eq!(packet.items.len(), 1);
+
+        let item = &packet.items[0];
+        assert_eq!(item.item_id, ItemId(1101));
+        assert_eq!(item.slot, [4035, 0, 0, 0]);
+        assert_eq!(item.location, 2);
+        assert_eq!(item.item_type, 5);
+        assert_eq!(item.option_data[0].index, 10);
+        assert_eq!(item.refinement_level, 7);
+        assert_eq!(item.enchantment_level, 0);
+    }
+
+    #[test]
+    fn add_mail_item_result() {
+        let mut bytes = vec![0x05, 0x0A, 0x00, 0x05, 0x00, 0x01, 0x00, 0x4D, 0x04, 0, 0, 0x05, 0x01, 0x00];
+        // Cards and options, then weight, favorite and location, then refinement and
+        // enchantment level.
+        bytes.extend(&ITEM_TAIL[..41]);
+        bytes.extend([0x50, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00]);
+        bytes.extend(&ITEM_TAIL[41..]);
+
+        let mut byte_stream = ByteStream::<()>::without_metadata(&bytes);
+        let packet = AddMailItemResultPacket::packet_from_bytes(&mut byte_stream).unwrap();
+
+        assert!(byte_stream.is_empty());
+        assert_eq!(packet.result, AddMailItemResult::Success);
+        assert_eq!(packet.index, InventoryIndex(3));
+        assert_eq!(packet.item_id, ItemId(1101));
+        assert_eq!(packet.slot, [4035, 0, 0, 0]);
+        assert_eq!(packet.weight, 80);
+        assert_eq!(packet.location, 2);
+        assert_eq!(packet.refinement_level, 7);
+        assert_eq!(packet.enchantment_level, 0);
+    }
+
+    #[test]
+    fn trade_item_added() {
+        let mut bytes = vec![0x42, 0x0B, 0x4D, 0x04, 0, 0, 0x05, 0x01, 0, 0, 0, 0x01, 0x00];
+        // Cards and options, then location and look, then refinement and
+        // enchantment level.
+        bytes.extend(&ITEM_TAIL[..41]);
+        bytes.extend([0x02, 0x00, 0x00, 0x00, 0x02, 0x00]);
+        bytes.extend(&ITEM_TAIL[41..]);
+
+        let mut byte_stream = ByteStream::<()>::without_metadata(&bytes);
+        let packet = TradeItemAddedPacket::packet_from_bytes(&mut byte_stream).unwrap();
+
+        assert!(byte_stream.is_empty());
+        assert_eq!(packet.item_id, ItemId(1101));
+        assert_eq!(packet.amount, 1);
+        assert_eq!(packet.is_identified, 1);
+        assert_eq!(packet.cards, [4035, 0, 0, 0]);
+        assert_eq!(packet.option_data[0].index, 10);
+        assert_eq!(packet.location, 2);
+        assert_eq!(packet.look, 2);
+        assert_eq!(packet.refinement_level, 7);
+        assert_eq!(packet.enchantment_level, 0);
+    }
+
+    #[test]
+    fn storage_item_added() {
+        let mut bytes = vec![0x44, 0x0B, 0x01, 0x00, 0x01, 0, 0, 0, 0x4D, 0x04, 0, 0, 0x05, 0x01, 0x00];
+        bytes.extend(ITEM_TAIL);
+
+        let mut byte_stream = ByteStream::<()>::without_metadata(&bytes);
+        let packet = StorageItemAddedPacket::packet_from_bytes(&mut byte_stream).unwrap();
+
+        assert!(byte_stream.is_empty());
+        // Storage indices are offset by one on the wire.
+        assert_eq!(packet.index, StorageIndex(0));
+        assert_eq!(packet.amount, 1);
+        assert_eq!(packet.item_id, ItemId(1101));
+        assert_eq!(packet.cards, [4035, 0, 0, 0]);
+        assert_eq!(packet.option_data[0].index, 10);
+        assert_eq!(packet.refinement_level, 7);
+        assert_eq!(packet.enchantment_level, 0);
+    }
 }
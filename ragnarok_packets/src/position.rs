@@ -2,6 +2,7 @@ use ragnarok_bytes::{ByteStream, ConversionResult, FromBytes, ToBytes};
 
 #[derive(Debug, Clone, Copy)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct WorldPosition {
     pub x: usize,
     pub y: usize,
@@ -0,0 +1,136 @@
+use std::collections::HashMap;
+
+use ragnarok_bytes::ByteStream;
+
+use crate::{Packet, PacketHeader};
+
+/// Date of the client that a server was compiled for, called `PACKETVER` in
+/// rAthena and Hercules (e.g. `20220406`). Packet headers and layouts change
+/// between versions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PacketVersion(pub u32);
+
+impl PacketVersion {
+    /// The version that the packet definitions in this crate are written for.
+    pub const DEFAULT: Self = Self(20220406);
+
+    pub fn smaller(self, version: u32) -> bool {
+        self.0 < version
+    }
+
+    pub fn equals_or_above(self, version: u32) -> bool {
+        self.0 >= version
+    }
+
+    /// Get the packet version from the metadata of a byte stream. Streams that
+    /// don't carry a packet version are read with [`PacketVersion::DEFAULT`].
+    pub fn from_byte_stream<Caller, Meta>(byte_stream: &ByteStream<Meta>) -> Self {
+        byte_stream.get_metadata::<Caller, Self>().copied().unwrap_or_default()
+    }
+}
+
+impl Default for PacketVersion {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// Headers and layouts used to talk to a specific server.
+///
+/// The layouts are picked based on the [`PacketVersion`] through the
+/// `#[packet_version_smaller(..)]` and `#[packet_version_equals_or_above(..)]`
+/// field attributes, the headers through the `#[header_version_smaller(..)]`
+/// packet attribute. Servers that use different headers for other reasons
+/// (e.g. packet obfuscation) can be supported with header overrides.
+#[derive(Debug, Clone, Default)]
+pub struct PacketProfile {
+    version: PacketVersion,
+    header_overrides: HashMap<PacketHeader, PacketHeader>,
+}
+
+impl PacketProfile {
+    pub fn new(version: PacketVersion) -> Self {
+        Self {
+            version,
+            header_overrides: HashMap::new(),
+        }
+    }
+
+    /// Use a different header for a packet, no matter the version.
+    pub fn with_header_override<P: Packet>(mut self, header: PacketHeader) -> Self {
+        self.header_overrides.insert(P::HEADER, header);
+        self
+    }
+
+    /// Use a different header for the packet with the default header
+    /// `original`, no matter the version.
+    pub fn with_raw_header_override(mut self, original: PacketHeader, header: PacketHeader) -> Self {
+        self.header_overrides.insert(original, header);
+        self
+    }
+
+    /// Overridden headers, keyed by the default header of the packet.
+    pub fn header_overrides(&self) -> &HashMap<PacketHeader, PacketHeader> {
+        &self.header_overrides
+    }
+
+    pub fn version(&self) -> PacketVersion {
+        self.version
+    }
+
+    /// The header that is sent over the wire for a packet.
+    pub fn header<P: Packet>(&self) -> PacketHeader {
+        self.header_overrides
+            .get(&P::HEADER)
+            .copied()
+            .unwrap_or_else(|| P::header_for_version(self.version))
+    }
+}
+
+#[cfg(test)]
+mod packet_version {
+    use ragnarok_bytes::ByteStream;
+
+    use crate::{ClientTick, MapServerLoginSuccessPacket, Packet, PacketExt, PacketHeader, PacketProfile, PacketVersion, WorldPosition};
+
+    #[test]
+    fn header_for_version() {
+        let old_profile = PacketProfile::new(PacketVersion(20071106));
+
+        assert_eq!(
+            PacketProfile::default().header::<MapServerLoginSuccessPacket>(),
+            PacketHeader(0x02EB)
+        );
+        assert_eq!(old_profile.header::<MapServerLoginSuccessPacket>(), PacketHeader(0x0073));
+    }
+
+    #[test]
+    fn header_override() {
+        let profile = PacketProfile::default().with_header_override::<MapServerLoginSuccessPacket>(PacketHeader(0x1234));
+
+        assert_eq!(profile.header::<MapServerLoginSuccessPacket>(), PacketHeader(0x1234));
+    }
+
+    #[test]
+    fn layout_for_version() {
+        let old_version = PacketVersion(20071106);
+        let packet = MapServerLoginSuccessPacket::new(ClientTick(100), WorldPosition::new(10, 20), None);
+        let bytes = packet.packet_to_bytes_with_profile(&PacketProfile::new(old_version)).unwrap();
+
+        // Header, client tick, position and two ignored bytes, but no font.
+        assert_eq!(bytes.len(), 2 + 4 + 3 + 2);
+
+        let mut byte_stream = ByteStream::with_metadata(&bytes[2..], old_version);
+        let parsed = MapServerLoginSuccessPacket::payload_from_bytes(&mut byte_stream).unwrap();
+        assert!(parsed.font.is_none());
+        assert!(byte_stream.is_empty());
+
+        // Without a version, the stream is read with the default layout.
+        let bytes = MapServerLoginSuccessPacket::new(ClientTick(100), WorldPosition::new(10, 20), Some(3))
+            .payload_to_bytes()
+            .unwrap();
+        let mut byte_stream = ByteStream::<()>::without_metadata(&bytes);
+        let parsed = MapServerLoginSuccessPacket::payload_from_bytes(&mut byte_stream).unwrap();
+        assert_eq!(parsed.font, Some(3));
+    }
+}
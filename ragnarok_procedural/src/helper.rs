@@ -2,7 +2,7 @@ use std::collections::HashMap;
 
 use proc_macro2::{Delimiter, TokenStream};
 use quote::{format_ident, quote};
-use syn::{DataStruct, Field};
+use syn::{DataStruct, Field, LitInt};
 
 use crate::utils::{get_unique_attribute, Version};
 
@@ -53,12 +53,12 @@ pub fn byte_convertable_helper(data_struct: DataStruct) -> (TokenStream, Vec<Tok
             None => quote!(ragnarok_bytes::FromBytes::from_bytes(byte_stream)),
         };
 
-        let to_length = match length {
+        let to_length = |value: TokenStream| match length.clone() {
             Some(length) if syn::parse::<syn::Ident>(length.clone().into()).is_ok() => {
-                quote!(ragnarok_bytes::ToBytesExt::to_n_bytes(&self.#field_identifier, self.#length as usize))
+                quote!(ragnarok_bytes::ToBytesExt::to_n_bytes(#value, self.#length as usize))
             }
-            Some(length) => quote!(ragnarok_bytes::ToBytesExt::to_n_bytes(&self.#field_identifier, #length as usize)),
-            None => quote!(ragnarok_bytes::ToBytes::to_bytes(&self.#field_identifier)),
+            Some(length) => quote!(ragnarok_bytes::ToBytesExt::to_n_bytes(#value, #length as usize)),
+            None => quote!(ragnarok_bytes::ToBytes::to_bytes(#value)),
         };
 
         let mut repeating: Option<(syn::Ident, bool)> = None;
@@ -100,6 +100,24 @@ pub fn byte_convertable_helper(data_struct: DataStruct) -> (TokenStream, Vec<Tok
         let version_function = version_smaller.or(version_equals_or_above);
         let version_restricted = version_function.is_some();
 
+        let packet_version_smaller = get_unique_attribute(&mut field.attrs, "packet_version_smaller")
+            .map(|attribute| attribute.parse_args::<LitInt>().expect("failed to parse packet version"))
+            .map(|version| quote!(smaller(#version)));
+
+        let packet_version_equals_or_above = get_unique_attribute(&mut field.attrs, "packet_version_equals_or_above")
+            .map(|attribute| attribute.parse_args::<LitInt>().expect("failed to parse packet version"))
+            .map(|version| quote!(equals_or_above(#version)));
+
+        assert!(
+            (version_restricted as usize)
+                + (packet_version_smaller.is_some() as usize)
+                + (packet_version_equals_or_above.is_some() as usize)
+                <= 1,
+            "version restriction may only be specified once"
+        );
+        let packet_version_function = packet_version_smaller.or(packet_version_equals_or_above);
+        let packet_version_restricted = packet_version_function.is_some();
+
         // base from bytes implementation
         let from_implementation = quote!(ragnarok_bytes::ConversionResultExt::trace::<Self>(#from_length)?);
 
@@ -180,17 +198,37 @@ pub fn byte_convertable_helper(data_struct: DataStruct) -> (TokenStream, Vec<Tok
                     };
                 }
             }
-            None => quote!(let #field_variable = #from_implementation;),
+            None => match packet_version_function {
+                Some(function) => quote! {
+                    let #field_variable = match ragnarok_packets::PacketVersion::from_byte_stream::<Self, _>(byte_stream).#function {
+                        true => Some(#from_implementation),
+                        false => None,
+                    };
+                },
+                None => quote!(let #field_variable = #from_implementation;),
+            },
         };
         from_bytes_implementations.push(from_implementation);
 
         // base to byte implementation
-        let to_implementation = match version_restricted {
-            true => quote!({
+        let to_implementation = match (version_restricted, packet_version_restricted) {
+            (true, _) => quote!({
                 panic!("version restricted fields can't be serialized at the moment");
                 [0u8].as_slice()
             }),
-            false => {
+            // Fields that are not part of the packet version are `None` and simply skipped.
+            (false, true) => {
+                let to_length = to_length(quote!(value));
+
+                quote!(match &self.#field_identifier {
+                    Some(value) => ragnarok_bytes::ConversionResultExt::trace::<Self>(#to_length)?,
+                    None => Vec::new(),
+                }
+                .as_slice())
+            }
+            (false, false) => {
+                let to_length = to_length(quote!(&self.#field_identifier));
+
                 quote!(ragnarok_bytes::ConversionResultExt::trace::<Self>(#to_length)?.as_slice())
             }
         };
@@ -41,6 +41,8 @@ pub fn derive_fixed_byte_size(token_stream: InterfaceTokenStream) -> InterfaceTo
         new_value,
         numeric_type,
         numeric_value,
+        packet_version_equals_or_above,
+        packet_version_smaller,
         repeating,
         repeating_expr,
         repeating_option,
@@ -71,6 +73,8 @@ pub fn derive_byte_convertable(token_stream: InterfaceTokenStream) -> InterfaceT
         length,
         numeric_type,
         numeric_value,
+        packet_version_equals_or_above,
+        packet_version_smaller,
         repeating,
         repeating_expr,
         repeating_option,
@@ -104,6 +108,8 @@ pub fn derive_from_bytes(token_stream: InterfaceTokenStream) -> InterfaceTokenSt
         new_value,
         numeric_type,
         numeric_value,
+        packet_version_equals_or_above,
+        packet_version_smaller,
         version,
         version_equals_or_above,
         version_smaller,
@@ -129,12 +135,15 @@ pub fn derive_to_bytes(token_stream: InterfaceTokenStream) -> InterfaceTokenStre
     Packet,
     attributes(
         header,
+        header_version_smaller,
         length,
         length_remaining,
         length_remaining_off_by_one,
         new_default,
         new_derive,
         new_value,
+        packet_version_equals_or_above,
+        packet_version_smaller,
         ping,
         repeating,
         repeating_option,
@@ -3,7 +3,7 @@ use quote::quote;
 use syn::{Attribute, DataStruct, Generics, Ident};
 
 use super::helper::byte_convertable_helper;
-use crate::utils::{get_unique_attribute, PacketSignature};
+use crate::utils::{get_unique_attribute, PacketSignature, VersionedPacketSignature};
 
 pub fn derive_packet_struct(
     data_struct: DataStruct,
@@ -17,6 +17,10 @@ pub fn derive_packet_struct(
         .map(|attribute| attribute.parse_args::<PacketSignature>())
         .expect("packet needs to specify a signature")
         .expect("failed to parse packet header");
+    let mut versioned_signatures: Vec<VersionedPacketSignature> = attributes
+        .extract_if(|attribute| attribute.path().segments[0].ident == "header_version_smaller")
+        .map(|attribute| attribute.parse_args().expect("failed to parse versioned packet header"))
+        .collect();
     let is_ping = get_unique_attribute(&mut attributes, "ping").is_some();
     let is_variable_length = get_unique_attribute(&mut attributes, "variable_length").is_some();
 
@@ -24,6 +28,25 @@ pub fn derive_packet_struct(
     let (new_implementation, from_bytes_implementations, implemented_fields, to_bytes_implementations, delimiter) =
         byte_convertable_helper(data_struct);
 
+    // The header with the lowest version that is still above the requested version
+    // wins, so sort them in ascending order.
+    versioned_signatures.sort_by_key(|versioned_signature| versioned_signature.version);
+
+    let header_for_version = (!versioned_signatures.is_empty()).then(|| {
+        let conditions = versioned_signatures.iter().map(|VersionedPacketSignature { version, signature }| {
+            quote!(if version.smaller(#version) {
+                return ragnarok_packets::PacketHeader(#signature);
+            })
+        });
+
+        quote! {
+            fn header_for_version(version: ragnarok_packets::PacketVersion) -> ragnarok_packets::PacketHeader {
+                #(#conditions)*
+                Self::HEADER
+            }
+        }
+    });
+
     let instanciate = match delimiter {
         proc_macro2::Delimiter::Brace => quote!(Self { #(#implemented_fields),* }),
         proc_macro2::Delimiter::Parenthesis => quote!(Self ( #(#implemented_fields),* )),
@@ -63,6 +86,8 @@ pub fn derive_packet_struct(
             const IS_PING: bool = #is_ping;
             const HEADER: ragnarok_packets::PacketHeader = ragnarok_packets::PacketHeader(#signature);
 
+            #header_for_version
+
             fn payload_from_bytes<Meta>(byte_stream: &mut ragnarok_bytes::ByteStream<Meta>) -> ragnarok_bytes::ConversionResult<Self> {
                 let base_offset = byte_stream.get_offset();
                 #insert_packet_length
@@ -16,6 +16,28 @@ impl Parse for PacketSignature {
     }
 }
 
+/// Header that a packet uses before a specific packet version, e.g.
+/// `#[header_version_smaller(20080102, 0x0073)]`.
+#[derive(Clone)]
+pub struct VersionedPacketSignature {
+    pub version: u32,
+    pub signature: u16,
+}
+
+impl Parse for VersionedPacketSignature {
+    fn parse(input: ParseStream) -> Result<Self, Error> {
+        let version: LitInt = input.parse().expect("packet version must be u32");
+        input
+            .parse::<Punct>()
+            .expect("packet version and header must be separated by commas");
+        let signature: LitInt = input.parse().expect("packet header must be u16");
+        Ok(VersionedPacketSignature {
+            version: version.base10_parse::<u32>()?,
+            signature: signature.base10_parse::<u16>()?,
+        })
+    }
+}
+
 #[derive(Clone)]
 pub struct Version {
     pub major: LitInt,